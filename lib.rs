@@ -5,12 +5,39 @@
 mod reportes {
     #[cfg(not(test))]
     use votacion::{UserManager, ReportMessage};
-    use votacion::Usuario;
+    use votacion::{GettersUsuario, HashDatos, Usuario};
+    use votacion::Fecha;
+    use votacion::{ConfigEleccion, PoliticaDesempate, PoliticaRetiro};
+    use votacion::EstadoEleccion;
+    use votacion::Participacion;
+    use votacion::{GettersLista, Lista};
+    use votacion::ResultadoBalotaje;
     use votacion::VotacionRef;
     use votacion::VotacionError;
+    use ink::prelude::vec;
     use ink::prelude::vec::Vec;
+    use ink::prelude::string::String;
+    use core::cmp::Ordering;
     type Result<T> = core::result::Result<T, VotacionError>;
 
+    /// Reporte de una eleccion junto al estado de la eleccion al momento de generarlo
+    #[derive(Debug, Clone, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    pub struct DataReporte<T> {
+        /// Indica, entre otras cosas, si el resultado de la eleccion ya fue certificado
+        estado: EstadoEleccion,
+        data: T
+    }
+
+    impl<T> DataReporte<T> {
+        fn new(estado: EstadoEleccion, data: T) -> DataReporte<T> {
+            DataReporte {
+                estado,
+                data
+            }
+        }
+    }
+
     #[derive(Debug, Clone, PartialEq, Default)]
     #[ink::scale_derive(Encode, Decode, TypeInfo)]
     #[cfg_attr(
@@ -18,17 +45,46 @@ mod reportes {
         derive(ink::storage::traits::StorageLayout)
     )]
     pub struct DataRegistroVotantes {
-        votantes: Vec<Usuario>
+        votantes: Vec<DataVotante>
     }
 
     impl DataRegistroVotantes {
-        fn new(votantes: Vec<Usuario>) -> DataRegistroVotantes {
+        fn new(votantes: Vec<DataVotante>) -> DataRegistroVotantes {
             DataRegistroVotantes{
                 votantes
             }
         }
     }
 
+    /// Datos no sensibles de un votante, sin su dni, su direccion ni su fecha de nacimiento
+    #[derive(Debug, Clone, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(
+        feature = "std",
+        derive(ink::storage::traits::StorageLayout)
+    )]
+    pub struct DataVotante {
+        id: AccountId,
+        nombre: String,
+        apellido: String,
+        /// Edad del votante a la fecha de inicio de la eleccion
+        edad: u32,
+        /// Hash de los datos personales de un usuario en modo privado
+        hash_datos: Option<HashDatos>
+    }
+
+    impl DataVotante {
+        fn new(usuario: Usuario, fecha_inicio: &Fecha) -> DataVotante {
+            DataVotante{
+                id: usuario.get_addres(),
+                nombre: usuario.get_nombre(),
+                apellido: usuario.get_apellido(),
+                edad: usuario.calcular_edad(fecha_inicio),
+                hash_datos: usuario.get_hash_datos()
+            }
+        }
+    }
+
     #[derive(Debug, Clone, PartialEq, Default)]
     #[ink::scale_derive(Encode, Decode, TypeInfo)]
     #[cfg_attr(
@@ -36,16 +92,50 @@ mod reportes {
         derive(ink::storage::traits::StorageLayout)
     )]
     pub struct DataParticipacion {
+        /// Votos emitidos directamente por los votantes
         votos: u32,
-        porcentaje_participacion: u128
+        /// Votos emitidos por delegacion
+        votos_delegados: u32,
+        porcentaje_participacion: u128,
+        /// Suma de los pesos de los votos emitidos, directamente o por delegacion
+        votos_ponderados: u128,
+        /// Porcentaje de la suma de los pesos de los votantes que votó
+        porcentaje_ponderado: u128,
+        /// Votos revelados de una eleccion secreta, en la que `votos` son los votos comprometidos
+        votos_revelados: Option<u32>,
+        /// Si la eleccion tiene regla de quorum, indica si se alcanzo
+        quorum_alcanzado: Option<bool>
     }
 
     impl DataParticipacion {
-        fn new(votos: u32, porcentaje_participacion: u128) -> DataParticipacion {
+        fn new(votos: u32, votos_delegados: u32, porcentaje_participacion: u128, votos_ponderados: u128, porcentaje_ponderado: u128, votos_revelados: Option<u32>, quorum_alcanzado: Option<bool>) -> DataParticipacion {
             DataParticipacion{
                 votos,
-                porcentaje_participacion
+                votos_delegados,
+                porcentaje_participacion,
+                votos_ponderados,
+                porcentaje_ponderado,
+                votos_revelados,
+                quorum_alcanzado
+            }
+        }
+
+        /// Calcula el porcentaje de participacion contando los votos directos y los delegados,
+        /// por cantidad de votantes y por peso
+        fn calcular(data: Participacion) -> DataParticipacion {
+            let votos_revelados = data.votos_revelados.map(|revelados| revelados as u32);
+
+            if data.votantes == 0 {
+                return DataParticipacion::new(0, 0, 0, 0, 0, votos_revelados, data.quorum_alcanzado);
             }
+
+            let participacion = ((data.votos + data.votos_delegados) * 100) / data.votantes;
+            // La suma de los pesos entra en u128 multiplicada por 100, ver `Participacion`
+            let ponderado = match data.peso_votantes {
+                0 => 0,
+                peso => (data.votos_ponderados * 100) / peso
+            };
+            DataParticipacion::new(data.votos as u32, data.votos_delegados as u32, participacion, data.votos_ponderados, ponderado, votos_revelados, data.quorum_alcanzado)
         }
     }
 
@@ -56,13 +146,350 @@ mod reportes {
         derive(ink::storage::traits::StorageLayout)
     )]
     pub struct DataResultado {
-        resultado: Vec<(AccountId, u32)>
+        /// Candidatos de mayor a menor cantidad de votos, con su posicion, desde 1,
+        /// y la suma de los pesos de sus votos
+        resultado: Vec<(u32, AccountId, u128)>,
+        /// Grupos de candidatos empatados en votos, en el orden del resultado
+        empates: Vec<Vec<AccountId>>,
+        /// Indica si un empate decide un cargo y la politica de desempate requiere una segunda vuelta
+        requiere_segunda_vuelta: bool,
+        votos_blanco: u128,
+        votos_nulos: u128,
+        /// Candidatos que se retiraron de la eleccion, con sus votos si se conservan
+        retirados: Vec<(AccountId, u128)>,
+        /// Suma de los votos anulados por el retiro de candidatos
+        votos_anulados: u128,
+        /// Si la eleccion tiene regla de quorum, indica si se alcanzo
+        quorum_alcanzado: Option<bool>
     }
 
     impl DataResultado {
-        fn new(resultado: Vec<(AccountId, u32)>) -> DataResultado {
+        fn new(resultado: Vec<(u32, AccountId, u128)>, votos_blanco: u128, votos_nulos: u128) -> DataResultado {
             DataResultado {
-                resultado
+                resultado,
+                empates: Vec::new(),
+                requiere_segunda_vuelta: false,
+                votos_blanco,
+                votos_nulos,
+                retirados: Vec::new(),
+                votos_anulados: 0,
+                quorum_alcanzado: None
+            }
+        }
+
+        /// Asigna las posiciones a los candidatos, ordenados de mayor a menor cantidad de votos
+        /// y con los empates ya resueltos. Con `PoliticaDesempate::SegundaVuelta` los empatados
+        /// comparten la posicion y, si el empate decide un cargo, se requiere una segunda vuelta
+        fn calcular(ordenado: Vec<(AccountId, u128)>, politica: PoliticaDesempate, cantidad_cargos: u32, votos_blanco: u128, votos_nulos: u128) -> DataResultado {
+            let comparten = politica == PoliticaDesempate::SegundaVuelta;
+
+            let mut resultado: Vec<(u32, AccountId, u128)> = Vec::new();
+            for (i, (id, votos)) in ordenado.iter().enumerate() {
+                let posicion = match resultado.last() {
+                    Some((anterior, _, votos_anterior)) if comparten && votos_anterior == votos => *anterior,
+                    _ => i as u32 + 1
+                };
+                resultado.push((posicion, *id, *votos));
+            }
+
+            let empates = ordenado
+                .chunk_by(|(_, a), (_, b)| a == b)
+                .filter(|grupo| grupo.len() > 1)
+                .map(|grupo| grupo.iter().map(|(id, _)| *id).collect())
+                .collect();
+
+            // El empate decide un cargo si el ultimo electo y el siguiente tienen los mismos votos
+            let cargos = cantidad_cargos as usize;
+            let decide_cargo = matches!(
+                (cargos.checked_sub(1).and_then(|i| ordenado.get(i)), ordenado.get(cargos)),
+                (Some((_, ultimo)), Some((_, siguiente))) if ultimo == siguiente
+            );
+
+            let mut data = DataResultado::new(resultado, votos_blanco, votos_nulos);
+            data.empates = empates;
+            data.requiere_segunda_vuelta = comparten && decide_cargo;
+            data
+        }
+
+        /// Agrega los candidatos retirados al resultado, anulando sus votos si asi lo indica la politica
+        fn agregar_retirados(&mut self, retirados: Vec<(AccountId, u128)>, politica: PoliticaRetiro) {
+            self.retirados = match politica {
+                PoliticaRetiro::ConservarVotos => retirados,
+                PoliticaRetiro::AnularVotos => {
+                    self.votos_anulados = retirados.iter().map(|(_, votos)| votos).sum();
+                    retirados.into_iter().map(|(id, _)| (id, 0)).collect()
+                }
+            };
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, Default)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(
+        feature = "std",
+        derive(ink::storage::traits::StorageLayout)
+    )]
+    pub struct DataRonda {
+        votos: Vec<(AccountId, u32)>,
+        eliminado: Option<AccountId>
+    }
+
+    impl DataRonda {
+        fn new(votos: Vec<(AccountId, u32)>, eliminado: Option<AccountId>) -> DataRonda {
+            DataRonda {
+                votos,
+                eliminado
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, Default)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(
+        feature = "std",
+        derive(ink::storage::traits::StorageLayout)
+    )]
+    pub struct DataPreferencial {
+        rondas: Vec<DataRonda>,
+        ganador: Option<AccountId>,
+        /// Candidatos empatados en el ultimo lugar con `PoliticaDesempate::SegundaVuelta`,
+        /// el recuento se detiene sin ganador
+        empatados: Vec<AccountId>
+    }
+
+    impl DataPreferencial {
+        fn new(rondas: Vec<DataRonda>, ganador: Option<AccountId>, empatados: Vec<AccountId>) -> DataPreferencial {
+            DataPreferencial {
+                rondas,
+                ganador,
+                empatados
+            }
+        }
+
+        /// Realiza el recuento por segunda vuelta instantanea.
+        /// En cada ronda cada boleta cuenta para su candidato preferido que siga en carrera.
+        /// Gana quien supere la mitad de las boletas activas; si nadie lo hace se elimina
+        /// al ultimo. Ante un empate en el ultimo lugar se elimina al que queda ultimo en
+        /// `prioridad`, el orden de los candidatos segun la politica de desempate, salvo con
+        /// `PoliticaDesempate::SegundaVuelta`, donde se informan los empatados y no hay ganador
+        fn calcular(candidatos: Vec<AccountId>, boletas: &[Vec<AccountId>], prioridad: &[AccountId], politica: PoliticaDesempate) -> DataPreferencial {
+            let mut restantes = candidatos;
+            let mut rondas = Vec::new();
+
+            loop {
+                let mut votos: Vec<(AccountId, u32)> = restantes.iter().map(|id| (*id, 0)).collect();
+                let mut activas: u32 = 0;
+
+                for boleta in boletas {
+                    // Las boletas sin candidatos restantes quedan agotadas y no cuentan
+                    let posicion = boleta
+                        .iter()
+                        .find_map(|preferido| votos.iter().position(|(id, _)| id == preferido));
+                    if let Some(posicion) = posicion {
+                        votos[posicion].1 += 1;
+                        activas += 1;
+                    }
+                }
+
+                if activas == 0 {
+                    rondas.push(DataRonda::new(votos, None));
+                    return DataPreferencial::new(rondas, None, Vec::new());
+                }
+
+                if let Some((ganador, _)) = votos.iter().find(|(_, voto)| voto * 2 > activas) {
+                    let ganador = *ganador;
+                    rondas.push(DataRonda::new(votos, None));
+                    return DataPreferencial::new(rondas, Some(ganador), Vec::new());
+                }
+
+                let minimo = votos.iter().map(|(_, voto)| *voto).min();
+                let ultimos: Vec<AccountId> = votos.iter()
+                    .filter(|(_, voto)| Some(*voto) == minimo)
+                    .map(|(id, _)| *id)
+                    .collect();
+                if ultimos.len() > 1 && politica == PoliticaDesempate::SegundaVuelta {
+                    rondas.push(DataRonda::new(votos, None));
+                    return DataPreferencial::new(rondas, None, ultimos);
+                }
+
+                let eliminado = ultimos.into_iter()
+                    .max_by_key(|id| prioridad.iter().position(|prioritario| prioritario == id));
+                restantes.retain(|id| Some(*id) != eliminado);
+                rondas.push(DataRonda::new(votos, eliminado));
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, Default)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(
+        feature = "std",
+        derive(ink::storage::traits::StorageLayout)
+    )]
+    pub struct DataGanadores {
+        cantidad_cargos: u32,
+        /// Candidatos de mayor a menor cantidad de votos, indicando si resultaron electos
+        candidatos: Vec<(AccountId, u128, bool)>,
+        /// Candidatos empatados en el limite de cargos, ninguno de ellos queda electo
+        empatados: Vec<AccountId>
+    }
+
+    impl DataGanadores {
+        fn new(cantidad_cargos: u32, candidatos: Vec<(AccountId, u128, bool)>, empatados: Vec<AccountId>) -> DataGanadores {
+            DataGanadores {
+                cantidad_cargos,
+                candidatos,
+                empatados
+            }
+        }
+
+        /// Asigna los cargos a los candidatos mas votados. Los empatados conservan el orden
+        /// en el que se pasan, ya resuelto segun la politica de desempate.
+        /// Con `PoliticaDesempate::SegundaVuelta`, si hay un empate en el ultimo cargo solo quedan
+        /// electos los candidatos con mas votos que los empatados y se informan los empatados
+        fn calcular(mut resultado: Vec<(AccountId, u128)>, cantidad_cargos: u32, politica: PoliticaDesempate) -> DataGanadores {
+            // sort_by es estable, los empatados conservan el orden del desempate
+            resultado.sort_by(|(_, a), (_, b)| b.cmp(a));
+            let cargos = cantidad_cargos as usize;
+
+            let limite = match (cargos.checked_sub(1).and_then(|i| resultado.get(i)), resultado.get(cargos)) {
+                (Some((_, ultimo)), Some((_, siguiente))) if ultimo == siguiente && politica == PoliticaDesempate::SegundaVuelta => Some(*ultimo),
+                _ => None
+            };
+
+            let candidatos = resultado
+                .iter()
+                .enumerate()
+                .map(|(i, (id, votos))| {
+                    let electo = match limite {
+                        Some(limite) => *votos > limite,
+                        None => i < cargos
+                    };
+                    (*id, *votos, electo)
+                })
+                .collect();
+            let empatados = resultado
+                .iter()
+                .filter(|(_, votos)| Some(*votos) == limite)
+                .map(|(id, _)| *id)
+                .collect();
+
+            DataGanadores::new(cantidad_cargos, candidatos, empatados)
+        }
+    }
+
+    /// Metodo de reparto proporcional de cargos entre listas
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    pub enum MetodoReparto {
+        /// Divide los votos de cada lista por 1, 2, 3, ...
+        DHondt,
+        /// Divide los votos de cada lista por 1, 3, 5, ...
+        SainteLague,
+    }
+
+    impl MetodoReparto {
+        /// Devuelve el divisor que corresponde a una lista que ya obtuvo la cantidad de cargos pasada por parametro
+        fn divisor(&self, cargos: u32) -> u128 {
+            match self {
+                MetodoReparto::DHondt => cargos as u128 + 1,
+                MetodoReparto::SainteLague => 2 * cargos as u128 + 1,
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, Default)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(
+        feature = "std",
+        derive(ink::storage::traits::StorageLayout)
+    )]
+    pub struct DataReparto {
+        /// Id, votos y cargos obtenidos por cada lista
+        listas: Vec<(u32, u128, u32)>,
+        /// Candidatos electos de cada lista, en el orden de la lista
+        electos: Vec<AccountId>
+    }
+
+    impl DataReparto {
+        fn new(listas: Vec<(u32, u128, u32)>, electos: Vec<AccountId>) -> DataReparto {
+            DataReparto {
+                listas,
+                electos
+            }
+        }
+
+        /// Reparte los cargos de a uno, cada vez a la lista con mayor cociente entre sus votos
+        /// y el divisor del metodo. Solo participan las listas con votos que alcanzan el umbral
+        /// y que todavia tienen candidatos; en caso de empate se elige la lista creada primero
+        fn calcular(listas: Vec<(Lista, u128)>, cantidad_cargos: u32, metodo: MetodoReparto, umbral: u32) -> DataReparto {
+            let total: u128 = listas.iter().map(|(_, votos)| *votos).sum();
+            let mut cargos = vec![0u32; listas.len()];
+
+            for _ in 0..cantidad_cargos {
+                let habilitadas = listas.iter().enumerate().filter(|(i, (lista, votos))| {
+                    *votos > 0
+                        && *votos * 100 >= umbral as u128 * total
+                        && (cargos[*i] as usize) < lista.get_candidatos().len()
+                });
+
+                let mut mejor: Option<(usize, u128, u128)> = None;
+                for (i, (_, votos)) in habilitadas {
+                    let (votos, divisor) = (*votos, metodo.divisor(cargos[i]));
+                    // Compara los cocientes votos / divisor sin perder precision. Los votos ponderados
+                    // ocupan a lo sumo 96 bits y el divisor esta acotado por los candidatos de la lista
+                    // que entran en una celda del storage, por lo que el producto entra en u128
+                    if mejor.is_none_or(|(_, votos_mejor, divisor_mejor)| votos * divisor_mejor > votos_mejor * divisor) {
+                        mejor = Some((i, votos, divisor));
+                    }
+                }
+
+                match mejor {
+                    Some((i, _, _)) => cargos[i] += 1,
+                    None => break
+                }
+            }
+
+            let mut electos = Vec::new();
+            for ((lista, _), cargos_lista) in listas.iter().zip(cargos.iter()) {
+                electos.extend(lista.get_candidatos().into_iter().take(*cargos_lista as usize));
+            }
+            let listas = listas
+                .iter()
+                .zip(cargos)
+                .map(|((lista, votos), cargos_lista)| (lista.get_id(), *votos, cargos_lista))
+                .collect();
+
+            DataReparto::new(listas, electos)
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, Default)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(
+        feature = "std",
+        derive(ink::storage::traits::StorageLayout)
+    )]
+    pub struct DataBalotaje {
+        /// Id de la primera vuelta, si la eleccion es una segunda vuelta
+        primera_vuelta: Option<u32>,
+        /// Id de la segunda vuelta, si la regla de balotaje la creó
+        segunda_vuelta: Option<u32>,
+        /// Ganador en primera vuelta, si la regla de balotaje lo eligió
+        ganador: Option<AccountId>
+    }
+
+    impl DataBalotaje {
+        fn new(primera_vuelta: Option<u32>, resultado: Option<ResultadoBalotaje>) -> DataBalotaje {
+            let (segunda_vuelta, ganador) = match resultado {
+                Some(ResultadoBalotaje::SegundaVuelta(id)) => (Some(id), None),
+                Some(ResultadoBalotaje::Ganador(ganador)) => (None, Some(ganador)),
+                None => (None, None)
+            };
+            DataBalotaje {
+                primera_vuelta,
+                segunda_vuelta,
+                ganador
             }
         }
     }
@@ -96,16 +523,64 @@ mod reportes {
 
         /// Devuelve la informacion necesaria para crear el reporte de la participacion en una eleccion
         #[cfg(not(test))]
-        fn data_reporte_participacion(&self, eleccion_id: u32) -> Result<(u128, u128)> {
+        fn data_reporte_participacion(&self, eleccion_id: u32) -> Result<Participacion> {
             self.votacion.reporte_participacion(eleccion_id)
         }
 
         /// Devuelve la informacion necesaria para crear el reporte del resultado de una eleccion
         #[cfg(not(test))]
-        fn data_reporte_resultado(&self, eleccion_id: u32) -> Result<Vec<(AccountId, u32)>> {
+        fn data_reporte_resultado(&self, eleccion_id: u32) -> Result<Vec<(AccountId, u128)>> {
             self.votacion.reporte_resultado(eleccion_id)
         }
 
+        /// Devuelve las boletas de una eleccion preferencial
+        #[cfg(not(test))]
+        fn data_reporte_boletas(&self, eleccion_id: u32) -> Result<Vec<Vec<AccountId>>> {
+            self.votacion.reporte_boletas(eleccion_id)
+        }
+
+        /// Devuelve la configuracion de una eleccion
+        #[cfg(not(test))]
+        fn data_reporte_config(&self, eleccion_id: u32) -> Result<ConfigEleccion> {
+            self.votacion.reporte_config(eleccion_id)
+        }
+
+        /// Devuelve las listas de una eleccion por listas junto a sus votos
+        #[cfg(not(test))]
+        fn data_reporte_listas(&self, eleccion_id: u32) -> Result<Vec<(Lista, u128)>> {
+            self.votacion.reporte_listas(eleccion_id)
+        }
+
+        /// Devuelve la primera vuelta y el resultado del balotaje de una eleccion
+        #[cfg(not(test))]
+        fn data_reporte_balotaje(&self, eleccion_id: u32) -> Result<(Option<u32>, Option<ResultadoBalotaje>)> {
+            self.votacion.reporte_balotaje(eleccion_id)
+        }
+
+        /// Devuelve los candidatos retirados de una eleccion junto a sus votos
+        #[cfg(not(test))]
+        fn data_reporte_retirados(&self, eleccion_id: u32) -> Result<Vec<(AccountId, u128)>> {
+            self.votacion.reporte_retirados(eleccion_id)
+        }
+
+        /// Devuelve la semilla con la que se desempata en forma aleatoria el resultado de una eleccion
+        #[cfg(not(test))]
+        fn data_reporte_semilla_desempate(&self, eleccion_id: u32) -> Result<Option<[u8; 32]>> {
+            self.votacion.reporte_semilla_desempate(eleccion_id)
+        }
+
+        /// Devuelve la fecha de inicio de una eleccion
+        #[cfg(not(test))]
+        fn data_reporte_fecha_inicio(&self, eleccion_id: u32) -> Result<Fecha> {
+            self.votacion.reporte_fecha_inicio(eleccion_id)
+        }
+
+        /// Devuelve el estado actual de una eleccion
+        #[cfg(not(test))]
+        fn data_reporte_estado(&self, eleccion_id: u32) -> Result<EstadoEleccion> {
+            self.votacion.reporte_estado(eleccion_id)
+        }
+
         /// Devuelve un usuario a partir de su id
         #[cfg(not(test))]
         fn get_usuario(&self, id: AccountId) -> Result<Usuario> {
@@ -118,74 +593,309 @@ mod reportes {
             Ok(vec![AccountId::from([0x1; 32]), AccountId::from([0x2; 32]), AccountId::from([0x3; 32])])
         }
 
-        /// Funcion mockeada para devolver la informacion de la participacion de una eleccion
+        /// Funcion mockeada para devolver la informacion de la participacion de una eleccion secreta
+        /// con quorum, que solo la eleccion 2 no alcanza
+        #[cfg(test)]
+        fn data_reporte_participacion(&self, eleccion_id: u32) -> Result<Participacion> {
+            let quorum_alcanzado = Some(eleccion_id != 2);
+            Ok(Participacion { votantes: 10, votos: 4, votos_delegados: 0, peso_votantes: 10, votos_ponderados: 4, votos_revelados: Some(3), votos_blanco: 1, votos_nulos: 0, quorum_alcanzado })
+        }
+
+        /// Funcion mockeada para devolver la informacion del resultado de una eleccion.
+        /// Desde la eleccion 4 el segundo y el tercero empatan
+        #[cfg(test)]
+        fn data_reporte_resultado(&self, eleccion_id: u32) -> Result<Vec<(AccountId, u128)>> {
+            match eleccion_id {
+                0..=3 => Ok(vec![(AccountId::from([0x1;32]), 2), (AccountId::from([0x2;32]), 0), (AccountId::from([0x3;32]), 1)]),
+                _ => Ok(vec![(AccountId::from([0x1;32]), 3), (AccountId::from([0x2;32]), 2), (AccountId::from([0x3;32]), 2)])
+            }
+        }
+
+        /// Funcion mockeada para devolver las boletas de una eleccion preferencial.
+        /// Desde la eleccion 4 el segundo y el tercero empatan en el ultimo lugar
+        #[cfg(test)]
+        fn data_reporte_boletas(&self, eleccion_id: u32) -> Result<Vec<Vec<AccountId>>> {
+            match eleccion_id {
+                0..=3 => Ok(vec![
+                    vec![AccountId::from([0x1;32])],
+                    vec![AccountId::from([0x1;32]), AccountId::from([0x2;32])],
+                    vec![AccountId::from([0x2;32]), AccountId::from([0x3;32])],
+                    vec![AccountId::from([0x3;32]), AccountId::from([0x2;32])],
+                    vec![AccountId::from([0x3;32])],
+                ]),
+                _ => Ok(vec![
+                    vec![AccountId::from([0x1;32])],
+                    vec![AccountId::from([0x1;32])],
+                    vec![AccountId::from([0x2;32]), AccountId::from([0x3;32])],
+                    vec![AccountId::from([0x3;32]), AccountId::from([0x2;32])],
+                ])
+            }
+        }
+
+        /// Funcion mockeada para devolver la configuracion de una eleccion de dos cargos,
+        /// en la que solo la eleccion 3 conserva los votos de los candidatos retirados
+        /// y las elecciones 5, 6 y 7 desempatan por edad, en forma aleatoria y por segunda vuelta
+        #[cfg(test)]
+        fn data_reporte_config(&self, eleccion_id: u32) -> Result<ConfigEleccion> {
+            let retiro_candidatos = match eleccion_id {
+                3 => PoliticaRetiro::ConservarVotos,
+                _ => PoliticaRetiro::AnularVotos
+            };
+            let desempate = match eleccion_id {
+                5 => PoliticaDesempate::MayorEdad,
+                6 => PoliticaDesempate::Aleatorio,
+                7 => PoliticaDesempate::SegundaVuelta,
+                _ => PoliticaDesempate::OrdenRegistro
+            };
+            Ok(ConfigEleccion { cantidad_cargos: 2, retiro_candidatos, desempate, ..Default::default() })
+        }
+
+        /// Funcion mockeada para devolver las listas de una eleccion por listas
+        #[cfg(test)]
+        fn data_reporte_listas(&self, _eleccion_id: u32) -> Result<Vec<(Lista, u128)>> {
+            Ok(vec![
+                (Lista::new(0, "A".to_string(), vec![AccountId::from([0x1;32]), AccountId::from([0x2;32])]), 60),
+                (Lista::new(1, "B".to_string(), vec![AccountId::from([0x3;32]), AccountId::from([0x4;32])]), 35),
+                (Lista::new(2, "C".to_string(), vec![AccountId::from([0x5;32])]), 5),
+            ])
+        }
+
+        /// Funcion mockeada en la que la eleccion 0 tiene como segunda vuelta a la eleccion 1
+        #[cfg(test)]
+        fn data_reporte_balotaje(&self, eleccion_id: u32) -> Result<(Option<u32>, Option<ResultadoBalotaje>)> {
+            match eleccion_id {
+                0 => Ok((None, Some(ResultadoBalotaje::SegundaVuelta(1)))),
+                1 => Ok((Some(0), None)),
+                _ => Err(VotacionError::EleccionNoEncontrada)
+            }
+        }
+
+        /// Funcion mockeada para devolver un candidato retirado durante la votacion
+        #[cfg(test)]
+        fn data_reporte_retirados(&self, _eleccion_id: u32) -> Result<Vec<(AccountId, u128)>> {
+            Ok(vec![(AccountId::from([0x4;32]), 3)])
+        }
+
+        /// Funcion mockeada para devolver la semilla de desempate de una eleccion certificada
+        #[cfg(test)]
+        fn data_reporte_semilla_desempate(&self, _eleccion_id: u32) -> Result<Option<[u8; 32]>> {
+            Ok(Some([0x5; 32]))
+        }
+
+        /// Funcion mockeada para devolver la fecha de inicio de una eleccion
         #[cfg(test)]
-        fn data_reporte_participacion(&self, _eleccion_id: u32) -> Result<(u128, u128)> {
-            Ok((10, 4))
+        fn data_reporte_fecha_inicio(&self, _eleccion_id: u32) -> Result<Fecha> {
+            Ok(Fecha::new(1, 1, 2024))
         }
 
-        /// Funcion mockeada para devolver la informacion del resultado de una eleccion
+        /// Funcion mockeada en la que solo la eleccion 2 no tiene su resultado certificado
         #[cfg(test)]
-        fn data_reporte_resultado(&self, _eleccion_id: u32) -> Result<Vec<(AccountId, u32)>> {
-            Ok(vec![(AccountId::from([0x1;32]), 2), (AccountId::from([0x2;32]), 0), (AccountId::from([0x3;32]), 1)])
+        fn data_reporte_estado(&self, eleccion_id: u32) -> Result<EstadoEleccion> {
+            match eleccion_id {
+                2 => Ok(EstadoEleccion::Cerrada),
+                _ => Ok(EstadoEleccion::Certificada)
+            }
         }
 
-        /// Funcion mockeada para devolver un usuario
+        /// Funcion mockeada para devolver un usuario, en la que el usuario 0x3 es el de mayor edad
         #[cfg(test)]
         fn get_usuario(&self, id: AccountId) -> Result<Usuario> {
-            Ok(Usuario::new(id, "test".to_string(), "test".to_string(), "direccion".to_string(), "12345678".to_string(), 18))
+            let anio = if id == AccountId::from([0x3; 32]) { 1990 } else { 2000 };
+            Ok(Usuario::new(id, "test".to_string(), "test".to_string(), "direccion".to_string(), "12345678".to_string(), Fecha::new(1, 1, anio)))
+        }
+
+        /// Devuelve el resultado de una eleccion de mayor a menor cantidad de votos, con los empatados
+        /// ordenados segun la politica de desempate. Con `PoliticaDesempate::SegundaVuelta`
+        /// quedan en orden de registro
+        ///
+        /// # Errores
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion desempata en forma aleatoria y no tiene semilla
+        fn ordenar_resultado(&self, eleccion_id: u32, politica: PoliticaDesempate) -> Result<Vec<(AccountId, u128)>> {
+            let mut resultado = self.ordenar_desempate(eleccion_id, politica)?;
+            // sort_by es estable, los empatados conservan el orden del desempate
+            resultado.sort_by(|(_, a), (_, b)| b.cmp(a));
+            Ok(resultado)
+        }
+
+        /// Devuelve el resultado de una eleccion ordenado solo segun la politica de desempate,
+        /// primero el candidato que se prioriza ante un empate
+        ///
+        /// # Errores
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion desempata en forma aleatoria y no tiene semilla
+        fn ordenar_desempate(&self, eleccion_id: u32, politica: PoliticaDesempate) -> Result<Vec<(AccountId, u128)>> {
+            // Los candidatos llegan en orden de registro
+            let mut resultado = self.data_reporte_resultado(eleccion_id)?;
+
+            match politica {
+                PoliticaDesempate::OrdenRegistro | PoliticaDesempate::SegundaVuelta => {}
+                PoliticaDesempate::MayorEdad => {
+                    let mut con_nacimiento = Vec::new();
+                    for (id, votos) in resultado {
+                        con_nacimiento.push(((id, votos), self.get_usuario(id)?.get_fecha_nacimiento()));
+                    }
+                    // A igual fecha de nacimiento se conserva el orden de registro
+                    con_nacimiento.sort_by(|(_, a), (_, b)| match (a.es_mayor(b), b.es_mayor(a)) {
+                        (true, _) => Ordering::Greater,
+                        (_, true) => Ordering::Less,
+                        _ => Ordering::Equal
+                    });
+                    resultado = con_nacimiento.into_iter().map(|(candidato, _)| candidato).collect();
+                }
+                PoliticaDesempate::Aleatorio => {
+                    let semilla = self.data_reporte_semilla_desempate(eleccion_id)?
+                        .ok_or(VotacionError::EleccionNoCertificada)?;
+                    resultado.sort_by_cached_key(|(id, _)| PoliticaDesempate::clave_aleatoria(&semilla, *id));
+                }
+            }
+
+            Ok(resultado)
+        }
+
+        /// Devuelve el estado de una eleccion, que debe tener su resultado certificado
+        ///
+        /// # Errores
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si el resultado de la eleccion no fue certificado
+        fn estado_certificado(&self, eleccion_id: u32) -> Result<EstadoEleccion> {
+            let estado = self.data_reporte_estado(eleccion_id)?;
+            if estado != EstadoEleccion::Certificada {
+                return Err(VotacionError::EleccionNoCertificada);
+            }
+            Ok(estado)
         }
 
-        /// Crea y devuelve un nuevo reporte de los votantes registrados en una eleccion
+        /// Crea y devuelve un nuevo reporte de los votantes registrados en una eleccion,
+        /// sin su dni, su direccion ni su fecha de nacimiento, solo su edad al inicio de la eleccion
         /// En caso de no haber votantes registrados, se devuelve un reporte con una lista vacia
         /// 
         /// # Errores
         /// Devuelve un error si la eleccion no es encontrada
         #[ink(message)]
-        pub fn reporte_registro_votantes(&self, eleccion_id: u32) -> Result<DataRegistroVotantes> {
+        pub fn reporte_registro_votantes(&self, eleccion_id: u32) -> Result<DataReporte<DataRegistroVotantes>> {
+            let estado = self.data_reporte_estado(eleccion_id)?;
             let id_votantes = self.data_reporte_registro_votantes(eleccion_id)?;
+            let fecha_inicio = self.data_reporte_fecha_inicio(eleccion_id)?;
             let mut usuarios_votantes = Vec::new();
 
             // Itero sobre los id de los votantes para recuperar su usuario en el sistema y devolverlo en el reporte
             // Jamas deberia dar error el get_usuario(id) debido a que se verifica siempre que sean usuarios
             // aceptados aquellos que se los acepte como votantes y los candidadtos
             for id in id_votantes {
-                usuarios_votantes.push(self.get_usuario(id)?);
+                usuarios_votantes.push(DataVotante::new(self.get_usuario(id)?, &fecha_inicio));
             }
 
-            Ok(DataRegistroVotantes::new(usuarios_votantes))
+            Ok(DataReporte::new(estado, DataRegistroVotantes::new(usuarios_votantes)))
         }
 
-        /// Crea y devuelve un nuevo reporte de la participacion en una eleccion
+        /// Crea y devuelve un nuevo reporte de la participacion en una eleccion,
+        /// que indica si se alcanzo el quorum
         /// 
         /// # Errores
         /// - Devuelve un error si la eleccion no es encontrada
         /// - Devuelve un error si la eleccion no finalizó
         #[ink(message)]
-        pub fn reporte_participacion(&self, eleccion_id: u32) -> Result<DataParticipacion> {
+        pub fn reporte_participacion(&self, eleccion_id: u32) -> Result<DataReporte<DataParticipacion>> {
+            let estado = self.data_reporte_estado(eleccion_id)?;
             let data = self.data_reporte_participacion(eleccion_id)?;
-            let num_votantes = data.0;
-            let num_votantes_voto = data.1;
+            Ok(DataReporte::new(estado, DataParticipacion::calcular(data)))
+        }
 
-            if num_votantes == 0 {
-                return Ok(DataParticipacion::new(0, 0));
+        /// Crea y devuelve un nuevo reporte del resultado de una eleccion 
+        /// ordenado por cantidad de votos de mayor a menor, con la posicion de cada candidato
+        /// y los grupos de empatados, desempatados segun la politica de la eleccion,
+        /// junto a la cantidad de votos en blanco y nulos y si se alcanzo el quorum.
+        /// Los candidatos retirados se informan por separado y no pueden resultar electos
+        /// 
+        /// # Errores
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si el resultado de la eleccion no fue certificado
+        #[ink(message)]
+        pub fn reporte_resultado(&self, eleccion_id: u32) -> Result<DataReporte<DataResultado>> {
+            let estado = self.estado_certificado(eleccion_id)?;
+            let config = self.data_reporte_config(eleccion_id)?;
+            let data = self.ordenar_resultado(eleccion_id, config.desempate)?;
+            let participacion = self.data_reporte_participacion(eleccion_id)?;
+            let mut resultado = DataResultado::calcular(data, config.desempate, config.cantidad_cargos, participacion.votos_blanco, participacion.votos_nulos);
+            resultado.agregar_retirados(self.data_reporte_retirados(eleccion_id)?, config.retiro_candidatos);
+            resultado.quorum_alcanzado = participacion.quorum_alcanzado;
+            Ok(DataReporte::new(estado, resultado))
+        }
+
+        /// Crea y devuelve el reporte ronda por ronda de una eleccion preferencial,
+        /// eliminando al ultimo candidato de cada ronda hasta que alguno obtenga la mayoria.
+        /// Los empates en el ultimo lugar se resuelven segun la politica de desempate de la eleccion;
+        /// si la politica requiere segunda vuelta, los empatados se informan y no hay ganador
+        /// 
+        /// # Errores
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si el resultado de la eleccion no fue certificado
+        /// - Devuelve un error si la eleccion no es preferencial
+        #[ink(message)]
+        pub fn reporte_preferencial(&self, eleccion_id: u32) -> Result<DataReporte<DataPreferencial>> {
+            let estado = self.estado_certificado(eleccion_id)?;
+            let boletas = self.data_reporte_boletas(eleccion_id)?;
+            let politica = self.data_reporte_config(eleccion_id)?.desempate;
+            let candidatos = self.data_reporte_resultado(eleccion_id)?
+                .into_iter()
+                .map(|(id, _)| id)
+                .collect();
+            let prioridad: Vec<AccountId> = self.ordenar_desempate(eleccion_id, politica)?
+                .into_iter()
+                .map(|(id, _)| id)
+                .collect();
+            Ok(DataReporte::new(estado, DataPreferencial::calcular(candidatos, &boletas, &prioridad, politica)))
+        }
+
+        /// Crea y devuelve el reporte de los candidatos electos en una eleccion,
+        /// ordenado por cantidad de votos de mayor a menor y desempatado segun la politica de la eleccion.
+        /// Si la politica requiere segunda vuelta y hay un empate en el ultimo cargo,
+        /// los empatados se informan y no quedan electos
+        /// 
+        /// # Errores
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si el resultado de la eleccion no fue certificado
+        #[ink(message)]
+        pub fn reporte_ganadores(&self, eleccion_id: u32) -> Result<DataReporte<DataGanadores>> {
+            let estado = self.estado_certificado(eleccion_id)?;
+            let config = self.data_reporte_config(eleccion_id)?;
+            let data = self.ordenar_resultado(eleccion_id, config.desempate)?;
+            Ok(DataReporte::new(estado, DataGanadores::calcular(data, config.cantidad_cargos, config.desempate)))
+        }
+
+        /// Crea y devuelve el reparto proporcional de los cargos de una eleccion por listas,
+        /// con la cantidad de cargos de cada lista y los candidatos electos.
+        /// Las listas que no alcanzan el umbral, como porcentaje de los votos, no obtienen cargos
+        /// 
+        /// # Errores
+        /// - Devuelve un error si el umbral es mayor a 100
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si el resultado de la eleccion no fue certificado
+        /// - Devuelve un error si la eleccion no es por listas
+        #[ink(message)]
+        pub fn reporte_reparto(&self, eleccion_id: u32, metodo: MetodoReparto, umbral: u32) -> Result<DataReporte<DataReparto>> {
+            if umbral > 100 {
+                return Err(VotacionError::UmbralInvalido);
             }
 
-            let participacion = (num_votantes_voto * 100) / num_votantes;
-            Ok(DataParticipacion::new(num_votantes_voto as u32, participacion))
+            let estado = self.estado_certificado(eleccion_id)?;
+            let config = self.data_reporte_config(eleccion_id)?;
+            let listas = self.data_reporte_listas(eleccion_id)?;
+            Ok(DataReporte::new(estado, DataReparto::calcular(listas, config.cantidad_cargos, metodo, umbral)))
         }
 
-        /// Crea y devuelve un nuevo reporte del resultado de una eleccion 
-        /// ordenado por cantidad de votos de mayor a menor
+        /// Crea y devuelve el reporte del balotaje de una eleccion, que vincula
+        /// la primera vuelta con la segunda vuelta
         /// 
         /// # Errores
         /// - Devuelve un error si la eleccion no es encontrada
-        /// - Devuelve un error si la eleccion no finalizó
+        /// - Devuelve un error si el resultado de la eleccion no fue certificado
         #[ink(message)]
-        pub fn reporte_resultado(&self, eleccion_id: u32) -> Result<DataResultado> {
-            let mut data = self.data_reporte_resultado(eleccion_id)?;
-            data.sort_by_key(|(_, voto)| *voto);
-            Ok(DataResultado::new(data))
+        pub fn reporte_balotaje(&self, eleccion_id: u32) -> Result<DataReporte<DataBalotaje>> {
+            let estado = self.estado_certificado(eleccion_id)?;
+            let (primera_vuelta, resultado) = self.data_reporte_balotaje(eleccion_id)?;
+            Ok(DataReporte::new(estado, DataBalotaje::new(primera_vuelta, resultado)))
         }
     }
 
@@ -195,46 +905,355 @@ mod reportes {
         
         #[test]
         fn test_new_data_registro_votantes() {
-            let data = DataRegistroVotantes::new(vec![Usuario::new(AccountId::from([0x1; 32]), "test".to_string(), "test".to_string(), "direccion".to_string(), "12345678".to_string(), 18)]);
+            let data = DataRegistroVotantes::new(vec![DataVotante::new(Usuario::new(AccountId::from([0x1; 32]), "test".to_string(), "test".to_string(), "direccion".to_string(), "12345678".to_string(), Fecha::new(1, 1, 2000)), &Fecha::new(1, 1, 2024))]);
             assert_eq!(data.votantes.len(), 1);
         }
 
+        #[test]
+        fn test_new_data_votante() {
+            let data = DataVotante::new(Usuario::new_privado(AccountId::from([0x1; 32]), "test".to_string(), "test".to_string(), [0x7; 32], Fecha::new(1, 1, 2000)), &Fecha::new(15, 6, 2024));
+            assert_eq!(data.id, AccountId::from([0x1; 32]));
+            assert_eq!(data.nombre, "test".to_string());
+            assert_eq!(data.edad, 24);
+            assert_eq!(data.hash_datos, Some([0x7; 32]));
+        }
+
         #[test]
         fn test_new_data_participacion() {
-            let data = DataParticipacion::new(1, 1);
+            let data = DataParticipacion::new(1, 0, 1, 1, 1, None, None);
             assert_eq!(data.votos, 1);
             assert_eq!(data.porcentaje_participacion, 1);
+            assert_eq!(data.votos_revelados, None);
+        }
+
+        #[test]
+        fn test_calcular_participacion_ponderada() {
+            let data = DataParticipacion::calcular(Participacion { votantes: 4, votos: 1, peso_votantes: 200, votos_ponderados: 150, ..Default::default() });
+            assert_eq!(data.porcentaje_participacion, 25);
+            assert_eq!(data.votos_ponderados, 150);
+            assert_eq!(data.porcentaje_ponderado, 75);
+        }
+
+        #[test]
+        fn test_calcular_participacion_con_delegados() {
+            let data = DataParticipacion::calcular(Participacion { votantes: 8, votos: 3, votos_delegados: 3, ..Default::default() });
+            assert_eq!(data, DataParticipacion::new(3, 3, 75, 0, 0, None, None));
+            assert_eq!(DataParticipacion::calcular(Participacion::default()), DataParticipacion::new(0, 0, 0, 0, 0, None, None));
         }
 
         #[test]
         fn test_new_data_resultado() {
-            let data = DataResultado::new(vec![(AccountId::from([0x1; 32]), 1)]);
+            let data = DataResultado::new(vec![(1, AccountId::from([0x1; 32]), 1)], 2, 3);
             assert_eq!(data.resultado.len(), 1);
+            assert_eq!(data.votos_blanco, 2);
+            assert_eq!(data.votos_nulos, 3);
         }
 
         #[test]
         fn test_reporte_registro_votantes() {
             let reportes = Reportes::new();
-            let data = reportes.reporte_registro_votantes(1).unwrap();
+            let data = reportes.reporte_registro_votantes(1).unwrap().data;
             assert_eq!(data.votantes.len(), 3);
+            // la edad se calcula al inicio de la eleccion
+            assert_eq!(data.votantes[0].edad, 24);
         }
 
         #[test]
         fn test_reporte_participacion() {
             let reportes = Reportes::new();
-            let data = reportes.reporte_participacion(1).unwrap();
+            let data = reportes.reporte_participacion(1).unwrap().data;
             assert_eq!(data.votos, 4);
             assert_eq!(data.porcentaje_participacion, 40);
+            assert_eq!(data.votos_revelados, Some(3));
+            assert_eq!(data.quorum_alcanzado, Some(true));
+        }
+
+        #[test]
+        fn test_reporte_participacion_quorum_no_alcanzado() {
+            let reportes = Reportes::new();
+            let data = reportes.reporte_participacion(2).unwrap().data;
+            assert_eq!(data.quorum_alcanzado, Some(false));
         }
 
         #[test]
         fn test_reporte_resultado() {
             let reportes = Reportes::new();
-            let mut data = reportes.reporte_resultado(1).unwrap();
+            let data = reportes.reporte_resultado(1).unwrap().data;
+            assert_eq!(data.resultado, vec![
+                (1, AccountId::from([0x1; 32]), 2),
+                (2, AccountId::from([0x3; 32]), 1),
+                (3, AccountId::from([0x2; 32]), 0),
+            ]);
+            assert!(data.empates.is_empty());
+            assert!(!data.requiere_segunda_vuelta);
+            assert_eq!(data.votos_blanco, 1);
+            assert_eq!(data.votos_nulos, 0);
+            assert_eq!(data.retirados, vec![(AccountId::from([0x4; 32]), 0)]);
+            assert_eq!(data.votos_anulados, 3);
+            assert_eq!(data.quorum_alcanzado, Some(true));
+        }
+
+        #[test]
+        fn test_reporte_resultado_conserva_votos_retirados() {
+            let reportes = Reportes::new();
+            let data = reportes.reporte_resultado(3).unwrap().data;
             assert_eq!(data.resultado.len(), 3);
-            assert_eq!(data.resultado.pop(), Some((AccountId::from([0x1; 32]), 2)));
-            assert_eq!(data.resultado.pop(), Some((AccountId::from([0x3; 32]), 1)));
-            assert_eq!(data.resultado.pop(), Some((AccountId::from([0x2; 32]), 0)));
+            assert_eq!(data.retirados, vec![(AccountId::from([0x4; 32]), 3)]);
+            assert_eq!(data.votos_anulados, 0);
+        }
+
+        #[test]
+        fn test_reporte_preferencial() {
+            let reportes = Reportes::new();
+            let data = reportes.reporte_preferencial(1).unwrap().data;
+            let (a, b, c) = (AccountId::from([0x1; 32]), AccountId::from([0x2; 32]), AccountId::from([0x3; 32]));
+            assert_eq!(data.rondas, vec![
+                DataRonda::new(vec![(a, 2), (b, 1), (c, 2)], Some(b)),
+                DataRonda::new(vec![(a, 2), (c, 3)], None),
+            ]);
+            assert_eq!(data.ganador, Some(c));
+        }
+
+        #[test]
+        fn test_calcular_preferencial_mayoria_primera_ronda() {
+            let (a, b) = (AccountId::from([0x1; 32]), AccountId::from([0x2; 32]));
+            let data = DataPreferencial::calcular(vec![a, b], &[vec![b], vec![b, a], vec![a]], &[a, b], PoliticaDesempate::OrdenRegistro);
+            assert_eq!(data.rondas.len(), 1);
+            assert_eq!(data.ganador, Some(b));
+        }
+
+        #[test]
+        fn test_calcular_preferencial_empate_elimina_ultimo_registrado() {
+            let (a, b, c) = (AccountId::from([0x1; 32]), AccountId::from([0x2; 32]), AccountId::from([0x3; 32]));
+            // b y c empatan en el ultimo lugar, se elimina a c por registrarse despues
+            let data = DataPreferencial::calcular(vec![a, b, c], &[vec![a], vec![a], vec![b], vec![c, b]], &[a, b, c], PoliticaDesempate::OrdenRegistro);
+            assert_eq!(data.rondas[0].eliminado, Some(c));
+            assert_eq!(data.rondas[1].votos, vec![(a, 2), (b, 2)]);
+            assert_eq!(data.rondas[1].eliminado, Some(b));
+            assert_eq!(data.ganador, Some(a));
+        }
+
+        #[test]
+        fn test_reporte_preferencial_empate_orden_registro() {
+            let reportes = Reportes::new();
+            let data = reportes.reporte_preferencial(4).unwrap().data;
+            let (a, b, c) = (AccountId::from([0x1; 32]), AccountId::from([0x2; 32]), AccountId::from([0x3; 32]));
+            // b y c empatan en el ultimo lugar y luego a y b empatan, se elimina al registrado despues
+            assert_eq!(data.rondas, vec![
+                DataRonda::new(vec![(a, 2), (b, 1), (c, 1)], Some(c)),
+                DataRonda::new(vec![(a, 2), (b, 2)], Some(b)),
+                DataRonda::new(vec![(a, 2)], None),
+            ]);
+            assert_eq!(data.ganador, Some(a));
+        }
+
+        #[test]
+        fn test_reporte_preferencial_empate_mayor_edad() {
+            let reportes = Reportes::new();
+            let data = reportes.reporte_preferencial(5).unwrap().data;
+            let (a, b, c) = (AccountId::from([0x1; 32]), AccountId::from([0x2; 32]), AccountId::from([0x3; 32]));
+            // c es el de mayor edad, por lo que se elimina a b y luego a a
+            assert_eq!(data.rondas, vec![
+                DataRonda::new(vec![(a, 2), (b, 1), (c, 1)], Some(b)),
+                DataRonda::new(vec![(a, 2), (c, 2)], Some(a)),
+                DataRonda::new(vec![(c, 2)], None),
+            ]);
+            assert_eq!(data.ganador, Some(c));
+        }
+
+        #[test]
+        fn test_reporte_preferencial_empate_aleatorio() {
+            let reportes = Reportes::new();
+            let data = reportes.reporte_preferencial(6).unwrap().data;
+            let (b, c) = (AccountId::from([0x2; 32]), AccountId::from([0x3; 32]));
+            // se elimina al empatado con la mayor clave derivada de la semilla
+            let eliminado = [b, c].into_iter()
+                .max_by_key(|id| PoliticaDesempate::clave_aleatoria(&[0x5; 32], *id));
+            assert_eq!(data.rondas[0].eliminado, eliminado);
+            assert!(data.ganador.is_some());
+            assert!(data.empatados.is_empty());
+        }
+
+        #[test]
+        fn test_reporte_preferencial_empate_segunda_vuelta() {
+            let reportes = Reportes::new();
+            let data = reportes.reporte_preferencial(7).unwrap().data;
+            let (a, b, c) = (AccountId::from([0x1; 32]), AccountId::from([0x2; 32]), AccountId::from([0x3; 32]));
+            // el empate en el ultimo lugar detiene el recuento sin ganador
+            assert_eq!(data.rondas, vec![DataRonda::new(vec![(a, 2), (b, 1), (c, 1)], None)]);
+            assert_eq!(data.ganador, None);
+            assert_eq!(data.empatados, vec![b, c]);
+        }
+
+        #[test]
+        fn test_reporte_ganadores() {
+            let reportes = Reportes::new();
+            let data = reportes.reporte_ganadores(1).unwrap().data;
+            assert_eq!(data.cantidad_cargos, 2);
+            assert_eq!(data.candidatos, vec![
+                (AccountId::from([0x1; 32]), 2, true),
+                (AccountId::from([0x3; 32]), 1, true),
+                (AccountId::from([0x2; 32]), 0, false),
+            ]);
+            assert!(data.empatados.is_empty());
+        }
+
+        #[test]
+        fn test_calcular_ganadores_empate_en_limite() {
+            let (a, b, c, d) = (AccountId::from([0x1; 32]), AccountId::from([0x2; 32]), AccountId::from([0x3; 32]), AccountId::from([0x4; 32]));
+            let data = DataGanadores::calcular(vec![(a, 1), (b, 5), (c, 3), (d, 3)], 2, PoliticaDesempate::SegundaVuelta);
+            assert_eq!(data.candidatos, vec![(b, 5, true), (c, 3, false), (d, 3, false), (a, 1, false)]);
+            assert_eq!(data.empatados, vec![c, d]);
+        }
+
+        #[test]
+        fn test_calcular_ganadores_empate_desempatado() {
+            let (a, b, c, d) = (AccountId::from([0x1; 32]), AccountId::from([0x2; 32]), AccountId::from([0x3; 32]), AccountId::from([0x4; 32]));
+            let data = DataGanadores::calcular(vec![(b, 5), (d, 3), (c, 3), (a, 1)], 2, PoliticaDesempate::MayorEdad);
+            assert_eq!(data.candidatos, vec![(b, 5, true), (d, 3, true), (c, 3, false), (a, 1, false)]);
+            assert!(data.empatados.is_empty());
+        }
+
+        #[test]
+        fn test_reporte_resultado_desempate() {
+            let reportes = Reportes::new();
+            let (a, b, c) = (AccountId::from([0x1; 32]), AccountId::from([0x2; 32]), AccountId::from([0x3; 32]));
+
+            // por orden de registro
+            let data = reportes.reporte_resultado(4).unwrap().data;
+            assert_eq!(data.resultado, vec![(1, a, 3), (2, b, 2), (3, c, 2)]);
+            assert_eq!(data.empates, vec![vec![b, c]]);
+            assert!(!data.requiere_segunda_vuelta);
+
+            // por mayor edad
+            let data = reportes.reporte_resultado(5).unwrap().data;
+            assert_eq!(data.resultado, vec![(1, a, 3), (2, c, 2), (3, b, 2)]);
+            assert_eq!(data.empates, vec![vec![c, b]]);
+            assert_eq!(reportes.reporte_ganadores(5).unwrap().data.candidatos, vec![(a, 3, true), (c, 2, true), (b, 2, false)]);
+
+            // en forma aleatoria, siempre con el mismo orden para la misma semilla
+            let data = reportes.reporte_resultado(6).unwrap().data;
+            assert_eq!(data.resultado[0], (1, a, 3));
+            assert_eq!(data.resultado.iter().map(|(posicion, _, _)| *posicion).collect::<Vec<_>>(), vec![1, 2, 3]);
+            assert_eq!(data.empates, vec![vec![data.resultado[1].1, data.resultado[2].1]]);
+            assert_eq!(reportes.reporte_resultado(6).unwrap().data, data);
+
+            // por segunda vuelta
+            let data = reportes.reporte_resultado(7).unwrap().data;
+            assert_eq!(data.resultado, vec![(1, a, 3), (2, b, 2), (2, c, 2)]);
+            assert_eq!(data.empates, vec![vec![b, c]]);
+            assert!(data.requiere_segunda_vuelta);
+            let ganadores = reportes.reporte_ganadores(7).unwrap().data;
+            assert_eq!(ganadores.candidatos, vec![(a, 3, true), (b, 2, false), (c, 2, false)]);
+            assert_eq!(ganadores.empatados, vec![b, c]);
+        }
+
+        #[test]
+        fn test_calcular_resultado_empate_sin_cargo_en_juego() {
+            let (a, b, c) = (AccountId::from([0x1; 32]), AccountId::from([0x2; 32]), AccountId::from([0x3; 32]));
+            // El empate entre los dos primeros no requiere segunda vuelta si ambos quedan electos
+            let data = DataResultado::calcular(vec![(a, 2), (b, 2), (c, 1)], PoliticaDesempate::SegundaVuelta, 2, 0, 0);
+            assert_eq!(data.resultado, vec![(1, a, 2), (1, b, 2), (3, c, 1)]);
+            assert_eq!(data.empates, vec![vec![a, b]]);
+            assert!(!data.requiere_segunda_vuelta);
+        }
+
+        #[test]
+        fn test_calcular_ganadores_empate_fuera_del_limite() {
+            let (a, b, c) = (AccountId::from([0x1; 32]), AccountId::from([0x2; 32]), AccountId::from([0x3; 32]));
+            // Un empate que no decide ningun cargo no se informa
+            let data = DataGanadores::calcular(vec![(a, 4), (b, 2), (c, 2)], 1, PoliticaDesempate::SegundaVuelta);
+            assert_eq!(data.candidatos, vec![(a, 4, true), (b, 2, false), (c, 2, false)]);
+            assert!(data.empatados.is_empty());
+
+            // Con mas cargos que candidatos quedan todos electos
+            let data = DataGanadores::calcular(vec![(a, 4), (b, 2)], 3, PoliticaDesempate::SegundaVuelta);
+            assert_eq!(data.candidatos, vec![(a, 4, true), (b, 2, true)]);
+        }
+
+        #[test]
+        fn test_reporte_reparto() {
+            let reportes = Reportes::new();
+            let data = reportes.reporte_reparto(1, MetodoReparto::DHondt, 0).unwrap().data;
+            assert_eq!(data.listas, vec![(0, 60, 1), (1, 35, 1), (2, 5, 0)]);
+            assert_eq!(data.electos, vec![AccountId::from([0x1; 32]), AccountId::from([0x3; 32])]);
+            assert_eq!(reportes.reporte_reparto(1, MetodoReparto::DHondt, 101), Err(VotacionError::UmbralInvalido));
+        }
+
+        #[test]
+        fn test_reporte_balotaje() {
+            let reportes = Reportes::new();
+            assert_eq!(reportes.reporte_balotaje(0), Ok(DataReporte::new(EstadoEleccion::Certificada, DataBalotaje::new(None, Some(ResultadoBalotaje::SegundaVuelta(1))))));
+            let data = reportes.reporte_balotaje(1).unwrap().data;
+            assert_eq!((data.primera_vuelta, data.segunda_vuelta, data.ganador), (Some(0), None, None));
+            assert_eq!(reportes.reporte_balotaje(3), Err(VotacionError::EleccionNoEncontrada));
+        }
+
+        #[test]
+        fn test_reportes_eleccion_no_certificada() {
+            let reportes = Reportes::new();
+            assert_eq!(reportes.reporte_resultado(2), Err(VotacionError::EleccionNoCertificada));
+            assert_eq!(reportes.reporte_preferencial(2), Err(VotacionError::EleccionNoCertificada));
+            assert_eq!(reportes.reporte_ganadores(2), Err(VotacionError::EleccionNoCertificada));
+            assert_eq!(reportes.reporte_reparto(2, MetodoReparto::DHondt, 0), Err(VotacionError::EleccionNoCertificada));
+            assert_eq!(reportes.reporte_balotaje(2), Err(VotacionError::EleccionNoCertificada));
+            // Los reportes que no dependen del resultado informan el estado de la eleccion
+            assert_eq!(reportes.reporte_participacion(2).unwrap().estado, EstadoEleccion::Cerrada);
+            assert_eq!(reportes.reporte_registro_votantes(2).unwrap().estado, EstadoEleccion::Cerrada);
+            assert_eq!(reportes.reporte_resultado(1).unwrap().estado, EstadoEleccion::Certificada);
+        }
+
+        #[test]
+        fn test_new_data_balotaje_ganador() {
+            let a = AccountId::from([0x1; 32]);
+            let data = DataBalotaje::new(None, Some(ResultadoBalotaje::Ganador(a)));
+            assert_eq!((data.primera_vuelta, data.segunda_vuelta, data.ganador), (None, None, Some(a)));
+        }
+
+        /// Listas con 5 candidatos cada una y los votos pasados por parametro
+        fn listas_con_votos(votos: &[u128]) -> Vec<(Lista, u128)> {
+            votos.iter().enumerate().map(|(i, votos)| {
+                let candidatos = (0..5).map(|j| AccountId::from([(i * 5 + j) as u8; 32])).collect();
+                (Lista::new(i as u32, "lista".to_string(), candidatos), *votos)
+            }).collect()
+        }
+
+        #[test]
+        fn test_calcular_reparto_dhondt_y_sainte_lague() {
+            // Con 7 cargos D'Hondt favorece a la lista mas votada y Sainte-Laguë a las chicas
+            let listas = listas_con_votos(&[53, 24, 23]);
+            let dhondt = DataReparto::calcular(listas.clone(), 7, MetodoReparto::DHondt, 0);
+            let sainte_lague = DataReparto::calcular(listas, 7, MetodoReparto::SainteLague, 0);
+            assert_eq!(dhondt.listas, vec![(0, 53, 4), (1, 24, 2), (2, 23, 1)]);
+            assert_eq!(sainte_lague.listas, vec![(0, 53, 3), (1, 24, 2), (2, 23, 2)]);
+            assert_eq!(sainte_lague.electos.len(), 7);
+        }
+
+        #[test]
+        fn test_calcular_reparto_umbral() {
+            let listas = listas_con_votos(&[50, 30, 20]);
+            let data = DataReparto::calcular(listas.clone(), 5, MetodoReparto::SainteLague, 0);
+            assert_eq!(data.listas, vec![(0, 50, 3), (1, 30, 1), (2, 20, 1)]);
+            // Con un umbral del 25% la lista 2 queda afuera del reparto
+            let data = DataReparto::calcular(listas, 5, MetodoReparto::SainteLague, 25);
+            assert_eq!(data.listas, vec![(0, 50, 3), (1, 30, 2), (2, 20, 0)]);
+        }
+
+        #[test]
+        fn test_calcular_reparto_lista_sin_candidatos_suficientes() {
+            // La lista 0 solo tiene 5 candidatos, los cargos restantes pasan a la otra lista
+            let data = DataReparto::calcular(listas_con_votos(&[90, 10]), 7, MetodoReparto::DHondt, 0);
+            assert_eq!(data.listas, vec![(0, 90, 5), (1, 10, 2)]);
+            let data = DataReparto::calcular(listas_con_votos(&[90, 0]), 7, MetodoReparto::DHondt, 0);
+            assert_eq!(data.listas, vec![(0, 90, 5), (1, 0, 0)]);
+        }
+
+        #[test]
+        fn test_calcular_preferencial_sin_boletas() {
+            let a = AccountId::from([0x1; 32]);
+            let data = DataPreferencial::calcular(vec![a], &[], &[a], PoliticaDesempate::OrdenRegistro);
+            assert_eq!(data.rondas, vec![DataRonda::new(vec![(a, 0)], None)]);
+            assert_eq!(data.ganador, None);
         }
     }
 }
@@ -1,29 +1,694 @@
 #![cfg_attr(not(feature = "std"), no_std, no_main)]
 #![allow(clippy::arithmetic_side_effects)]
 pub use self::errors::VotacionError;
-pub use self::votacion::{ReportMessage, UserManager, Usuario, Votacion, VotacionRef};
+pub use self::fecha::Fecha;
+pub use self::votacion::{
+    Accion, CambioEstado, Compromiso, ConfigEleccion, ConsejoManager, EstadoEleccion,
+    EstadoImpugnacion, GettersLista, GettersUsuario, HashDatos, Impugnacion, Lista, MotivoRechazo,
+    Participacion, PeriodoInscripcion, PoliticaDesempate, PoliticaRetiro, Propuesta, Quorum,
+    ReglaBalotaje, ReglaQuorum, ReglasElegibilidad, ReportMessage, ResultadoBalotaje,
+    ResultadoQuorum, Rol, RolManager, TipoEleccion, UserManager, Usuario, Votacion, VotacionRef,
+};
 
 #[ink::contract]
 mod votacion {
     use crate::errors::VotacionError;
     use crate::fecha::Fecha;
+    use core::cmp::Ordering;
+    use ink::prelude::boxed::Box;
     use ink::prelude::string::String;
     use ink::prelude::vec::Vec;
+    use ink::storage::Mapping;
     type Result<T> = core::result::Result<T, VotacionError>;
-
+    /// Candidatos de una boleta, ordenados por preferencia
+    type Boleta = Vec<AccountId>;
+    /// Hash Blake2x256 del candidato elegido y una sal, con el que se compromete un voto secreto
+    pub type Compromiso = [u8; 32];
+    /// Hash Blake2x256 del dni, la dirección y una sal de un usuario en modo privado
+    pub type HashDatos = [u8; 32];
+    /// Votantes que delegaron su voto en un mismo votante
+    type Delegantes = Vec<AccountId>;
+    /// Voto de un delegado y los votos delegados que representa
+    type VotoRepresentado = (DestinoVoto, VotosDelegados);
+    /// Tiempo en milisegundos que una propuesta puede juntar aprobaciones (7 días)
+    const VIGENCIA_PROPUESTA: Timestamp = 7 * 24 * 60 * 60 * 1000;
+    /// Cantidad máxima de bytes del motivo de una impugnación
+    const LONGITUD_MAXIMA_MOTIVO: usize = 280;
+
+    /// Datos generales de una elección.
+    /// Los participantes y los votos se guardan por separado en el storage de Votacion
+    /// para que el costo de un voto no dependa del tamaño de la elección
     #[derive(Debug, Clone, PartialEq, Default)]
     #[ink::scale_derive(Encode, Decode, TypeInfo)]
     #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
     pub struct Eleccion {
         id: u32,
-        votantes_sin_aceptar: Vec<AccountId>,
-        candidatos_sin_aceptar: Vec<AccountId>,
-        votantes: Vec<AccountId>,
-        candidatos: Vec<AccountId>,
-        votantes_voto: Vec<AccountId>,
-        votos: Vec<(AccountId, u32)>,
+        /// Votantes aceptados, incluidos los que se retiraron, que conservan su posición
+        cantidad_votantes: u32,
+        /// Candidatos aceptados, incluidos los que se retiraron, que conservan su posición
+        cantidad_candidatos: u32,
+        votantes_retirados: u32,
+        candidatos_retirados: u32,
+        cantidad_votos: u32,
+        cantidad_revelados: u32,
+        cantidad_listas: u32,
+        /// Suma de los pesos de los votos en blanco
+        votos_blanco: u128,
+        /// Suma de los pesos de los votos nulos
+        votos_nulos: u128,
+        /// Votos emitidos por delegación, sumados a los votos de los delegados
+        votos_delegados: u32,
+        /// Suma de los pesos de los votantes aceptados
+        peso_votantes: u128,
+        /// Suma de los pesos de los votos emitidos, directamente o por delegación
+        votos_ponderados: u128,
         fecha_inicio: Fecha,
         fecha_fin: Fecha,
+        config: ConfigEleccion,
+        /// Id de la primera vuelta si la elección es una segunda vuelta
+        primera_vuelta: Option<u32>,
+        /// Resultado de la regla de balotaje, una vez aplicada
+        resultado_balotaje: Option<ResultadoBalotaje>,
+        /// Id de la elección que no alcanzó el quórum si la elección es su segundo llamado
+        primer_llamado: Option<u32>,
+        /// Resultado de la regla de quórum, una vez aplicada
+        resultado_quorum: Option<ResultadoQuorum>,
+        /// Semilla con la que se desempata en forma aleatoria. Parte del bloque en el que se
+        /// creó la elección y cada voto la combina con el votante y su bloque, por lo que queda
+        /// fija al terminar la votación. Solo quien vota último podría intentar influir en ella
+        /// eligiendo el bloque de su voto
+        semilla_desempate: Option<[u8; 32]>,
+        /// Último estado conocido. Suspendida, Certificada y Cancelada los fija el admin,
+        /// el resto se deriva de las fechas
+        estado: EstadoEleccion,
+        /// Cantidad de impugnaciones presentadas contra el resultado
+        cantidad_impugnaciones: u32,
+        /// Impugnaciones que todavia no fueron resueltas
+        impugnaciones_pendientes: u32,
+        /// Impugnaciones aceptadas, que impiden certificar el resultado
+        impugnaciones_aceptadas: u32,
+    }
+
+    /// Estado de una elección en su ciclo de vida
+    #[derive(Debug, Clone, Copy, PartialEq, Default)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum EstadoEleccion {
+        /// Todavía no abrió el período de inscripción
+        #[default]
+        Borrador,
+        /// Los usuarios pueden postularse y las autoridades revisan las postulaciones
+        Inscripcion,
+        /// Los votantes pueden votar
+        Abierta,
+        /// El admin pausó la elección, no admite postulaciones ni votos
+        Suspendida,
+        /// Terminó la votación
+        Cerrada,
+        /// El admin certificó el resultado
+        Certificada,
+        /// El admin canceló la elección, que no tiene resultado
+        Cancelada,
+        /// La elección no alcanzó el quórum, su resultado no es válido
+        Invalida,
+    }
+
+    /// Forma en la que los votantes emiten su voto en una elección
+    #[derive(Debug, Clone, Copy, PartialEq, Default)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum TipoEleccion {
+        /// Cada votante elige un único candidato
+        #[default]
+        Simple,
+        /// Cada votante ordena a los candidatos por preferencia y el resultado
+        /// se obtiene por segunda vuelta instantánea
+        Preferencial,
+        /// Cada votante aprueba a cualquier cantidad de candidatos
+        Aprobacion,
+        /// Cada votante elige una lista y los cargos se reparten entre las listas
+        Listas,
+    }
+
+    /// Voto de un votante que recibió delegaciones, en una elección simple
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    enum DestinoVoto {
+        Candidato(AccountId),
+        SinCandidato(VotoSinCandidato),
+    }
+
+    /// Cantidad de votos delegados y la suma de sus pesos
+    #[derive(Debug, Clone, Copy, PartialEq, Default)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    struct VotosDelegados {
+        cantidad: u32,
+        peso: u128,
+    }
+
+    /// Voto que no elige a ningún candidato ni lista
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum VotoSinCandidato {
+        Blanco,
+        Nulo,
+    }
+
+    /// Configuración de una elección, elegida por el admin al crearla
+    #[derive(Debug, Clone, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct ConfigEleccion {
+        /// Forma en la que los votantes emiten su voto
+        pub tipo: TipoEleccion,
+        /// Cantidad de cargos que se eligen, al menos uno
+        pub cantidad_cargos: u32,
+        /// Si tiene valor la elección es de voto secreto: durante la votación se guarda un
+        /// compromiso del voto y luego de la fecha de fin, hasta esta fecha, se revela
+        pub fin_revelacion: Option<Fecha>,
+        /// Regla para decidir si hace falta una segunda vuelta entre los dos más votados
+        pub balotaje: Option<ReglaBalotaje>,
+        /// Requisitos que deben cumplir los usuarios para postularse
+        pub elegibilidad: ReglasElegibilidad,
+        /// Período en el que los usuarios pueden postularse. Si no tiene valor
+        /// pueden postularse hasta el inicio de la votación
+        pub inscripcion: Option<PeriodoInscripcion>,
+        /// Tratamiento de los votos de los candidatos que se retiran durante la votación
+        pub retiro_candidatos: PoliticaRetiro,
+        /// Participación mínima para que la elección sea válida
+        pub quorum: Option<ReglaQuorum>,
+        /// Forma en la que se ordenan los candidatos empatados en votos en los reportes
+        pub desempate: PoliticaDesempate,
+        /// Si tiene valor, luego del fin de la votación y de la revelación los votantes
+        /// y candidatos pueden impugnar el resultado hasta esta fecha
+        pub fin_impugnacion: Option<Fecha>,
+    }
+
+    /// Período de inscripción de una elección. Las autoridades pueden aceptar o rechazar
+    /// postulaciones hasta el inicio de la votación
+    #[derive(Debug, Clone, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct PeriodoInscripcion {
+        /// Fecha desde la que se aceptan postulaciones
+        pub apertura: Fecha,
+        /// Fecha hasta la que se aceptan postulaciones
+        pub cierre: Fecha,
+    }
+
+    /// Tratamiento de los votos recibidos por un candidato que se retira durante la votación
+    #[derive(Debug, Clone, Copy, PartialEq, Default)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum PoliticaRetiro {
+        /// Los votos del candidato se anulan
+        #[default]
+        AnularVotos,
+        /// Los votos del candidato se conservan, pero no puede resultar electo
+        ConservarVotos,
+    }
+
+    /// Forma en la que se ordenan los candidatos empatados en votos
+    #[derive(Debug, Clone, Copy, PartialEq, Default)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum PoliticaDesempate {
+        /// Se prioriza al candidato aceptado primero
+        #[default]
+        OrdenRegistro,
+        /// Se prioriza al candidato de mayor edad, y a igual edad al aceptado primero
+        MayorEdad,
+        /// Orden pseudoaleatorio derivado de una semilla que combina los bloques en los que
+        /// se emitieron los votos y queda fija al terminar la votación
+        Aleatorio,
+        /// Los empatados comparten la posición y, si el empate decide un cargo,
+        /// debe resolverse en una segunda vuelta
+        SegundaVuelta,
+    }
+
+    impl PoliticaDesempate {
+        /// Devuelve la clave con la que `PoliticaDesempate::Aleatorio` ordena a un candidato
+        /// a partir de la semilla de desempate de la elección
+        pub fn clave_aleatoria(semilla: &[u8; 32], candidato: AccountId) -> [u8; 32] {
+            let mut clave = [0u8; 32];
+            ink::env::hash_encoded::<ink::env::hash::Blake2x256, _>(
+                &(semilla, candidato),
+                &mut clave,
+            );
+            clave
+        }
+    }
+
+    /// Requisitos para postularse a una elección. Las edades se calculan
+    /// a la fecha de inicio de la elección
+    #[derive(Debug, Clone, PartialEq, Default)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct ReglasElegibilidad {
+        /// Edad mínima de los votantes
+        pub edad_minima_votante: Option<u32>,
+        /// Edad máxima de los votantes
+        pub edad_maxima_votante: Option<u32>,
+        /// Edad mínima de los candidatos
+        pub edad_minima_candidato: Option<u32>,
+        /// Si es true solo pueden postularse los usuarios habilitados por una autoridad
+        pub solo_habilitados: bool,
+    }
+
+    /// Regla de balotaje de una elección simple de un cargo. Los porcentajes se calculan
+    /// sobre los votos a candidatos, incluidos los que se conservan de candidatos retirados,
+    /// sin contar los votos en blanco ni nulos
+    #[derive(Debug, Clone, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct ReglaBalotaje {
+        /// Porcentaje con el que el más votado gana en primera vuelta
+        pub porcentaje_ganador: u32,
+        /// Porcentaje menor con el que el más votado también gana si supera al segundo
+        /// por la diferencia indicada, en puntos porcentuales
+        pub porcentaje_con_diferencia: Option<(u32, u32)>,
+        /// Fecha de inicio de la segunda vuelta
+        pub fecha_inicio: Fecha,
+        /// Fecha de fin de la segunda vuelta
+        pub fecha_fin: Fecha,
+    }
+
+    /// Participación mínima de una elección, contando los votos directos y los delegados
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum Quorum {
+        /// Porcentaje de los votantes aceptados
+        Porcentaje(u32),
+        /// Cantidad de votantes
+        Cantidad(u32),
+    }
+
+    /// Regla de quórum de una elección
+    #[derive(Debug, Clone, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct ReglaQuorum {
+        pub minimo: Quorum,
+        /// Fechas de inicio y fin del segundo llamado, con los mismos participantes y sin quórum,
+        /// si la elección no alcanza el mínimo. Si no tiene valor la elección queda inválida
+        pub segundo_llamado: Option<(Fecha, Fecha)>,
+    }
+
+    /// Resultado de aplicar la regla de quórum a una elección que no lo alcanzó
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum ResultadoQuorum {
+        /// La elección quedó inválida, sin segundo llamado
+        Invalida,
+        /// Se creó el segundo llamado con el id indicado
+        SegundoLlamado(u32),
+    }
+
+    /// Resultado de aplicar la regla de balotaje al cierre de una elección
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum ResultadoBalotaje {
+        /// El candidato ganó en primera vuelta
+        Ganador(AccountId),
+        /// Se creó la segunda vuelta con el id indicado
+        SegundaVuelta(u32),
+    }
+
+    /// Roles que el admin puede otorgar a otras cuentas
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum Rol {
+        /// Tiene los mismos permisos que el admin del contrato
+        Admin,
+        /// Acepta a los usuarios postulados al sistema
+        Registrador,
+        /// Acepta a los candidatos y votantes de la elección con el id indicado
+        Autoridad(u32),
+    }
+
+    /// Acciones del admin que, una vez configurado el consejo, requieren sus aprobaciones
+    #[derive(Debug, Clone, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum Accion {
+        /// Nomina al nuevo admin del contrato, que debe aceptar el cargo
+        CambiarAdmin(AccountId),
+        /// Cambia el AccountId del contrato de reportes
+        CambiarReporte(AccountId),
+        /// Crea una elección con las fechas de inicio y fin y la configuración indicadas
+        CrearEleccion(Fecha, Fecha, Box<ConfigEleccion>),
+        /// Reemplaza a los miembros del consejo y la cantidad de aprobaciones necesarias
+        CambiarConsejo(Vec<AccountId>, u32),
+        /// Acepta o rechaza una impugnación, por (id_eleccion, id_impugnacion),
+        /// con el fundamento indicado
+        ResolverImpugnacion(u32, u32, bool, String),
+        /// Otorga el rol indicado a la cuenta
+        OtorgarRol(AccountId, Rol),
+        /// Revoca el rol indicado a la cuenta
+        RevocarRol(AccountId, Rol),
+        /// Aplica el cambio de estado a la elección con el id indicado, con el motivo indicado
+        CambiarEstadoEleccion(u32, CambioEstado, String),
+        /// Posterga la fecha de fin de la elección con el id indicado, con el motivo indicado
+        ExtenderEleccion(u32, Fecha, String),
+    }
+
+    /// Cambio de estado de una elección decidido por el admin
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum CambioEstado {
+        /// Cancela la elección, que no tiene resultado
+        Cancelar,
+        /// Suspende la elección en inscripción o abierta
+        Suspender,
+        /// Reanuda la elección suspendida
+        Reanudar,
+        /// Certifica el resultado de la elección
+        Certificar,
+    }
+
+    /// Acción propuesta por un miembro del consejo, pendiente de aprobación
+    #[derive(Debug, Clone, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct Propuesta {
+        pub accion: Accion,
+        pub proponente: AccountId,
+        /// Miembros que aprobaron la propuesta, incluido el proponente
+        pub aprobaciones: Vec<AccountId>,
+        /// Timestamp a partir del cual la propuesta vence
+        pub vencimiento: Timestamp,
+        /// Version del consejo que creó la propuesta.
+        /// Al cambiar el consejo vencen las propuestas pendientes
+        pub version_consejo: u32,
+        pub ejecutada: bool,
+    }
+
+    /// Impugnación del resultado de una elección, presentada por un votante o candidato
+    #[derive(Debug, Clone, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct Impugnacion {
+        pub autor: AccountId,
+        pub motivo: String,
+        pub estado: EstadoImpugnacion,
+        /// Fundamento con el que el admin o el consejo resolvió la impugnación
+        pub resolucion: Option<String>,
+    }
+
+    /// Estado de una impugnación
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum EstadoImpugnacion {
+        Pendiente,
+        Aceptada,
+        Rechazada,
+    }
+
+    /// Datos de participación de una elección
+    #[derive(Debug, Clone, PartialEq, Default)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    pub struct Participacion {
+        /// Cantidad de votantes aceptados
+        pub votantes: u128,
+        /// Cantidad de votantes que votaron, en las elecciones secretas los que comprometieron su voto
+        pub votos: u128,
+        /// Cantidad de votos emitidos por delegación, que no están incluidos en `votos`
+        pub votos_delegados: u128,
+        /// Suma de los pesos de los votantes aceptados
+        pub peso_votantes: u128,
+        /// Suma de los pesos de los votos emitidos, directamente o por delegación
+        pub votos_ponderados: u128,
+        /// Cantidad de votos revelados, solo en las elecciones secretas
+        pub votos_revelados: Option<u128>,
+        /// Suma de los pesos de los votos en blanco, incluidos en `votos_ponderados`
+        pub votos_blanco: u128,
+        /// Suma de los pesos de los votos nulos, incluidos en `votos_ponderados`
+        pub votos_nulos: u128,
+        /// Si la elección tiene regla de quórum, indica si se alcanzó
+        pub quorum_alcanzado: Option<bool>,
+    }
+
+    impl ConfigEleccion {
+        /// Devuelve error si la configuración no es válida para una elección
+        /// que se vota entre `fecha_inicio` y `fecha_fin`
+        fn validar(&self, fecha_inicio: &Fecha, fecha_fin: &Fecha) -> Result<()> {
+            // La segunda vuelta instantánea solo elige un cargo
+            if self.cantidad_cargos == 0
+                || (self.tipo == TipoEleccion::Preferencial && self.cantidad_cargos != 1)
+            {
+                return Err(VotacionError::CantidadCargosInvalida);
+            }
+
+            if let Some(fin_revelacion) = &self.fin_revelacion {
+                // Solo las elecciones simples pueden ser secretas
+                if self.tipo != TipoEleccion::Simple {
+                    return Err(VotacionError::TipoEleccionInvalido);
+                }
+
+                if !fin_revelacion.es_fecha_valida()
+                    || fin_revelacion.timestamp().unwrap() < fecha_fin.timestamp().unwrap()
+                {
+                    return Err(VotacionError::FechaRevelacionInvalida);
+                }
+            }
+
+            if let Some(fin_impugnacion) = &self.fin_impugnacion {
+                let fin_votacion = self.fin_revelacion.as_ref().unwrap_or(fecha_fin);
+                if !fin_impugnacion.es_fecha_valida()
+                    || fin_impugnacion.timestamp().unwrap() < fin_votacion.timestamp().unwrap()
+                {
+                    return Err(VotacionError::PeriodoImpugnacionInvalido);
+                }
+            }
+
+            if let Some(regla) = &self.balotaje {
+                if self.tipo != TipoEleccion::Simple || self.fin_revelacion.is_some() {
+                    return Err(VotacionError::TipoEleccionInvalido);
+                }
+
+                if self.cantidad_cargos != 1 {
+                    return Err(VotacionError::CantidadCargosInvalida);
+                }
+
+                // La segunda vuelta se decide con el resultado ya firme de la primera
+                regla.validar(self.fin_impugnacion.as_ref().unwrap_or(fecha_fin))?;
+            }
+
+            if let Some(inscripcion) = &self.inscripcion {
+                inscripcion.validar(fecha_inicio)?;
+            }
+
+            if let Some(regla) = &self.quorum {
+                // Las fechas de la revelación y de las listas corresponden a la primera convocatoria
+                if regla.segundo_llamado.is_some()
+                    && (self.tipo == TipoEleccion::Listas || self.fin_revelacion.is_some())
+                {
+                    return Err(VotacionError::TipoEleccionInvalido);
+                }
+
+                regla.validar(self.fin_revelacion.as_ref().unwrap_or(fecha_fin))?;
+            }
+
+            self.elegibilidad.validar()
+        }
+    }
+
+    impl PeriodoInscripcion {
+        /// Devuelve error si las fechas no son válidas o si la inscripción
+        /// no cierra antes de `fecha_inicio`
+        fn validar(&self, fecha_inicio: &Fecha) -> Result<()> {
+            if !self.apertura.es_fecha_valida()
+                || !self.cierre.es_fecha_valida()
+                || self.apertura.timestamp().unwrap() > self.cierre.timestamp().unwrap()
+                || self.cierre.timestamp().unwrap() >= fecha_inicio.timestamp().unwrap()
+            {
+                return Err(VotacionError::PeriodoInscripcionInvalido);
+            }
+            Ok(())
+        }
+    }
+
+    impl ReglasElegibilidad {
+        /// Devuelve error si la edad mínima de los votantes es mayor que la máxima
+        fn validar(&self) -> Result<()> {
+            if let (Some(minima), Some(maxima)) =
+                (self.edad_minima_votante, self.edad_maxima_votante)
+            {
+                if minima > maxima {
+                    return Err(VotacionError::ReglasElegibilidadInvalidas);
+                }
+            }
+            Ok(())
+        }
+
+        /// Devuelve error si un votante con la edad pasada por parametro no puede postularse
+        fn validar_votante(&self, edad: u32, habilitado: bool) -> Result<()> {
+            if self.solo_habilitados && !habilitado {
+                return Err(VotacionError::UsuarioNoHabilitado);
+            }
+
+            if self.edad_minima_votante.is_some_and(|minima| edad < minima) {
+                return Err(VotacionError::EdadMenorAMinima);
+            }
+
+            if self.edad_maxima_votante.is_some_and(|maxima| edad > maxima) {
+                return Err(VotacionError::EdadMayorAMaxima);
+            }
+            Ok(())
+        }
+
+        /// Devuelve error si un candidato con la edad pasada por parametro no puede postularse
+        fn validar_candidato(&self, edad: u32, habilitado: bool) -> Result<()> {
+            if self.solo_habilitados && !habilitado {
+                return Err(VotacionError::UsuarioNoHabilitado);
+            }
+
+            if self
+                .edad_minima_candidato
+                .is_some_and(|minima| edad < minima)
+            {
+                return Err(VotacionError::EdadMenorAMinimaCandidato);
+            }
+            Ok(())
+        }
+    }
+
+    impl ReglaQuorum {
+        /// Devuelve error si el mínimo no es válido o si el segundo llamado
+        /// no empieza después de `fin_votacion`
+        fn validar(&self, fin_votacion: &Fecha) -> Result<()> {
+            match self.minimo {
+                Quorum::Porcentaje(porcentaje) if porcentaje == 0 || porcentaje > 100 => {
+                    return Err(VotacionError::ReglaQuorumInvalida);
+                }
+                Quorum::Cantidad(0) => return Err(VotacionError::ReglaQuorumInvalida),
+                _ => {}
+            }
+
+            if let Some((fecha_inicio, fecha_fin)) = &self.segundo_llamado {
+                if !fecha_inicio.es_fecha_valida()
+                    || !fecha_fin.es_fecha_valida()
+                    || fecha_inicio.timestamp().unwrap() <= fin_votacion.timestamp().unwrap()
+                    || fecha_inicio.timestamp().unwrap() > fecha_fin.timestamp().unwrap()
+                {
+                    return Err(VotacionError::ReglaQuorumInvalida);
+                }
+            }
+
+            Ok(())
+        }
+
+        /// Devuelve si alcanzan el mínimo los votos, directos y delegados, de `votos`
+        /// sobre `votantes` aceptados
+        fn alcanzado(&self, votos: u32, votantes: u32) -> bool {
+            match self.minimo {
+                Quorum::Porcentaje(porcentaje) => {
+                    votos as u64 * 100 >= porcentaje as u64 * votantes as u64
+                }
+                Quorum::Cantidad(cantidad) => votos >= cantidad,
+            }
+        }
+    }
+
+    impl ReglaBalotaje {
+        /// Devuelve error si los porcentajes no son válidos o si la segunda vuelta
+        /// no empieza después de `fecha_fin`, el fin de la votación o de su período de impugnación
+        fn validar(&self, fecha_fin: &Fecha) -> Result<()> {
+            if self.porcentaje_ganador == 0 || self.porcentaje_ganador > 100 {
+                return Err(VotacionError::ReglaBalotajeInvalida);
+            }
+
+            if let Some((porcentaje, diferencia)) = self.porcentaje_con_diferencia {
+                if porcentaje > self.porcentaje_ganador || diferencia > 100 {
+                    return Err(VotacionError::ReglaBalotajeInvalida);
+                }
+            }
+
+            if !self.fecha_inicio.es_fecha_valida()
+                || !self.fecha_fin.es_fecha_valida()
+                || self.fecha_inicio.timestamp().unwrap() <= fecha_fin.timestamp().unwrap()
+                || self.fecha_inicio.timestamp().unwrap() > self.fecha_fin.timestamp().unwrap()
+            {
+                return Err(VotacionError::ReglaBalotajeInvalida);
+            }
+
+            Ok(())
+        }
+
+        /// Devuelve si el más votado gana en primera vuelta, a partir de sus votos,
+        /// los del segundo y el total de votos a candidatos
+        fn gana_primera_vuelta(&self, primero: u128, segundo: u128, total: u128) -> bool {
+            if total == 0 {
+                return false;
+            }
+
+            // Los votos ponderados suman a lo sumo u32::MAX pesos u64, por lo que entran en u128
+            // multiplicados por 100
+            if primero * 100 >= self.porcentaje_ganador as u128 * total {
+                return true;
+            }
+
+            match self.porcentaje_con_diferencia {
+                Some((porcentaje, diferencia)) => {
+                    primero * 100 >= porcentaje as u128 * total
+                        && (primero - segundo) * 100 >= diferencia as u128 * total
+                }
+                None => false,
+            }
+        }
+    }
+
+    impl Default for ConfigEleccion {
+        fn default() -> Self {
+            ConfigEleccion {
+                tipo: TipoEleccion::Simple,
+                cantidad_cargos: 1,
+                fin_revelacion: None,
+                balotaje: None,
+                elegibilidad: ReglasElegibilidad::default(),
+                inscripcion: None,
+                retiro_candidatos: PoliticaRetiro::AnularVotos,
+                quorum: None,
+                desempate: PoliticaDesempate::OrdenRegistro,
+                fin_impugnacion: None,
+            }
+        }
+    }
+
+    /// Estado de un usuario dentro de una elección
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum EstadoParticipante {
+        PostuladoVotante,
+        PostuladoCandidato,
+        Votante,
+        Candidato,
+        /// Votante que se retiró antes del inicio de la votación
+        VotanteRetirado,
+        /// Candidato que se retiró de la elección
+        CandidatoRetirado,
+    }
+
+    /// Motivo por el que se rechaza la postulación de un usuario al sistema o a una elección
+    #[derive(Debug, Clone, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum MotivoRechazo {
+        DatosInvalidos,
+        DocumentoDuplicado,
+        NoCumpleRequisitos,
+        /// Motivo descripto en el texto
+        Otro(String),
     }
 
     #[derive(Debug, Clone, PartialEq)]
@@ -35,24 +700,63 @@ mod votacion {
         apellido: String,
         direccion: String,
         dni: String,
-        edad: u8,
+        fecha_nacimiento: Fecha,
+        /// Hash de los datos personales de un usuario en modo privado,
+        /// en el que la dirección y el dni quedan vacíos
+        hash_datos: Option<HashDatos>,
+    }
+
+    /// Lista de candidatos de un partido, ordenados según el orden en que ocupan los cargos
+    #[derive(Debug, Clone, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct Lista {
+        id: u32,
+        nombre: String,
+        candidatos: Vec<AccountId>,
     }
 
     pub trait GettersEleccion {
         /// Devuelve el id de la elección
         fn get_id(&self) -> u32;
-        /// Devuelve true si el id esta postulado como candidato
-        fn is_postulado_candidato(&self, id: &AccountId) -> bool;
-        /// Devuelve true si el id esta postulado como votante
-        fn is_postulado_votante(&self, id: &AccountId) -> bool;
-        /// Dado un id , devuelve true si esta registrado como votante en la eleccion
-        fn is_votante(&self, id: &AccountId) -> bool;
-        /// Dado un id , devuelve true si esta registrado como candidato en la eleccion
-        fn is_candidato(&self, id: &AccountId) -> bool;
+        /// Devuelve la cantidad de votantes aceptados en la elección que no se retiraron
+        fn get_cantidad_votantes(&self) -> u32;
+        /// Devuelve la cantidad de candidatos aceptados en la elección que no se retiraron
+        fn get_cantidad_candidatos(&self) -> u32;
+        /// Devuelve la cantidad de votantes que ya votaron en la elección
+        fn get_cantidad_votos(&self) -> u32;
         /// Devuelve la fecha de inicio de la elección
         fn get_fecha_inicio(&self) -> Fecha;
         /// Devuelve la fecha de fin de la elección
         fn get_fecha_fin(&self) -> Fecha;
+        /// Devuelve el tipo de la elección
+        fn get_tipo(&self) -> TipoEleccion;
+        /// Devuelve la cantidad de cargos que se eligen en la elección
+        fn get_cantidad_cargos(&self) -> u32;
+        /// Devuelve la cantidad de listas de la elección
+        fn get_cantidad_listas(&self) -> u32;
+        /// Devuelve la cantidad de votos revelados en una elección secreta
+        fn get_cantidad_revelados(&self) -> u32;
+        /// Devuelve la cantidad de votos en blanco de la elección
+        fn get_votos_blanco(&self) -> u128;
+        /// Devuelve la cantidad de votos nulos de la elección
+        fn get_votos_nulos(&self) -> u128;
+        /// Devuelve la cantidad de votos emitidos por delegación
+        fn get_votos_delegados(&self) -> u32;
+        /// Devuelve la suma de los pesos de los votantes aceptados
+        fn get_peso_votantes(&self) -> u128;
+        /// Devuelve la suma de los pesos de los votos emitidos
+        fn get_votos_ponderados(&self) -> u128;
+        /// Devuelve el id de la primera vuelta si la elección es una segunda vuelta
+        fn get_primera_vuelta(&self) -> Option<u32>;
+        /// Devuelve el resultado de la regla de balotaje si ya se aplicó
+        fn get_resultado_balotaje(&self) -> Option<ResultadoBalotaje>;
+        /// Devuelve el estado de la elección al momento de consultarla
+        fn get_estado(&self) -> EstadoEleccion;
+        /// Devuelve la cantidad de impugnaciones presentadas contra el resultado
+        fn get_cantidad_impugnaciones(&self) -> u32;
+        /// Devuelve la cantidad de impugnaciones que todavia no fueron resueltas
+        fn get_impugnaciones_pendientes(&self) -> u32;
     }
 
     pub trait GettersUsuario {
@@ -66,22 +770,38 @@ mod votacion {
         fn get_direccion(&self) -> String;
         /// Devuelve el dni del usuario
         fn get_dni(&self) -> String;
-        /// Devuelve la edad del usuario
-        fn get_edad(&self) -> u8;
+        /// Devuelve el hash de los datos personales de un usuario en modo privado
+        fn get_hash_datos(&self) -> Option<HashDatos>;
+        /// Devuelve la fecha de nacimiento del usuario
+        fn get_fecha_nacimiento(&self) -> Fecha;
+    }
+
+    pub trait GettersLista {
+        /// Devuelve el id de la lista dentro de la elección
+        fn get_id(&self) -> u32;
+        /// Devuelve el nombre de la lista
+        fn get_nombre(&self) -> String;
+        /// Devuelve los candidatos de la lista en orden
+        fn get_candidatos(&self) -> Vec<AccountId>;
     }
 
     #[ink::trait_definition]
     pub trait UserManager {
-        /// Devuelve true si el caller es el admin del contrato, false en cualquier otro caso
+        /// Devuelve true si el caller es el admin del contrato o tiene el rol Admin,
+        /// false en cualquier otro caso
         #[ink(message)]
         fn caller_is_admin(&self) -> bool;
         /// Crea un usuario y lo agrega a la lista de usuarios_por_aceptar
         /// Devuelve el usuario creado si no hay errores
         ///
         /// # Errores
+        /// - Devuelve un error si el contrato está en modo privado
         /// - Devuelve un error si el usuario ya está registrado
         /// - Devuelve un error si el usuario ya está postulado
         /// - Devuelve un error si el usuario ya está aceptado
+        /// - Devuelve un error si la fecha de nacimiento es inválida o posterior a la fecha actual
+        /// - Devuelve un error si el dni está vacío, no es numérico o no tiene 7 u 8 dígitos
+        /// - Devuelve un error si el dni ya está registrado por otro usuario
         #[ink(message)]
         fn postular_usuario(
             &mut self,
@@ -89,15 +809,45 @@ mod votacion {
             apellido: String,
             direccion: String,
             dni: String,
-            edad: u8,
+            fecha_nacimiento: Fecha,
+        ) -> Result<Usuario>;
+        /// Crea un usuario en modo privado y lo agrega a la lista de usuarios_por_aceptar.
+        /// En lugar del dni y la dirección se guarda el hash calculado con calcular_hash_datos,
+        /// que el registrador verifica fuera de la cadena junto a la unicidad del dni
+        /// Devuelve el usuario creado si no hay errores
+        ///
+        /// # Errores
+        /// - Devuelve un error si el contrato no está en modo privado
+        /// - Devuelve un error si el usuario ya está registrado
+        /// - Devuelve un error si el usuario ya está postulado
+        /// - Devuelve un error si la fecha de nacimiento es inválida o posterior a la fecha actual
+        #[ink(message)]
+        fn postular_usuario_privado(
+            &mut self,
+            nombre: String,
+            apellido: String,
+            hash_datos: HashDatos,
+            fecha_nacimiento: Fecha,
         ) -> Result<Usuario>;
         /// Acepta un usuario de la lista usuarios_por_aceptar y lo agrega a la lista de usuarios
         ///
         /// # Errores
-        /// - Devuelve un error si el caller no es el admin
+        /// - Devuelve un error si el caller no es admin ni registrador
         /// - Devuelve un error si el usuario no se encuentra en la lista de usuarios postulados
         #[ink(message)]
         fn aceptar_usuario(&mut self, id: AccountId) -> Result<()>;
+        /// Rechaza un usuario de la lista usuarios_por_aceptar con el motivo pasado por parametro
+        /// y libera su dni. El usuario puede consultar el motivo y volver a postularse
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es admin ni registrador
+        /// - Devuelve un error si el usuario no se encuentra en la lista de usuarios postulados
+        #[ink(message)]
+        fn rechazar_usuario(&mut self, id: AccountId, motivo: MotivoRechazo) -> Result<()>;
+        /// Devuelve el motivo por el que se rechazó la postulación del usuario,
+        /// None si no fue rechazado o si volvió a postularse
+        #[ink(message)]
+        fn get_rechazo_usuario(&self, id: AccountId) -> Option<MotivoRechazo>;
         /// Obtiene un usuario sin aceptar por su id
         ///
         /// # Errores
@@ -110,42 +860,173 @@ mod votacion {
         /// Devuelve un error si no se encuentra el usuario
         #[ink(message)]
         fn get_usuario(&self, id: AccountId) -> Result<Usuario>;
-    }
-
-    #[ink::trait_definition]
-    pub trait EleccionSystemInk {
-        /// Postula un candidato a la eleccion con el id pasado por parametro
+        /// Actualiza los datos del usuario (caller) y devuelve el usuario actualizado.
+        /// Si el usuario ya fue aceptado y cambia su nombre, apellido, dni o fecha de nacimiento,
+        /// vuelve a la lista de usuarios_por_aceptar para ser validado otra vez
         ///
         /// # Errores
-        /// - Devuelve un error si el usuario no esta aceptado
+        /// - Devuelve un error si el contrato está en modo privado
         /// - Devuelve un error si el usuario no es encontrado
-        /// - Devuelve un error si la eleccion no se encuentra
-        /// - Devuelve un error si la eleccion ya finalizo
-        /// - Devuelve un error si la eleccion ya inicio
-        /// - Devuelve un error si el usuario ya está registrado como candidato
-        /// - Devuelve un error si el usuario ya está postulado como candidato
-        /// - Devuelve un error si el usuario ya está registrado como votante
-        /// - Devuelve un error si el usuario ya está postulado como votante
+        /// - Devuelve un error si cambian los datos de identidad de un candidato o votante
+        ///   de una elección que todavia no tiene resultado
+        /// - Devuelve un error si la fecha de nacimiento es inválida o posterior a la fecha actual
+        /// - Devuelve un error si el dni está vacío, no es numérico o no tiene 7 u 8 dígitos
+        /// - Devuelve un error si el dni ya está registrado por otro usuario
         #[ink(message)]
-        fn postular_candidato(&mut self, id_eleccion: u32) -> Result<()>;
-        /// Postula un votante a la eleccion con el id pasado por parametro
+        fn actualizar_usuario(
+            &mut self,
+            nombre: String,
+            apellido: String,
+            direccion: String,
+            dni: String,
+            fecha_nacimiento: Fecha,
+        ) -> Result<Usuario>;
+        /// Actualiza los datos del usuario (caller) en modo privado y devuelve el usuario
+        /// actualizado. Si el usuario ya fue aceptado y cambia su nombre, apellido, fecha de
+        /// nacimiento o el hash de sus datos, vuelve a la lista de usuarios_por_aceptar
+        /// para ser validado otra vez
         ///
         /// # Errores
-        /// - Devuelve un error si el usuario no esta aceptado
+        /// - Devuelve un error si el contrato no está en modo privado
         /// - Devuelve un error si el usuario no es encontrado
-        /// - Devuelve un error si la eleccion no es encontrada
-        /// - Devuelve un error si la eleccion ya finalizo
+        /// - Devuelve un error si cambian los datos de identidad de un candidato o votante
+        ///   de una elección que todavia no tiene resultado
+        /// - Devuelve un error si la fecha de nacimiento es inválida o posterior a la fecha actual
+        #[ink(message)]
+        fn actualizar_usuario_privado(
+            &mut self,
+            nombre: String,
+            apellido: String,
+            hash_datos: HashDatos,
+            fecha_nacimiento: Fecha,
+        ) -> Result<Usuario>;
+        /// Da de baja al usuario (caller), aceptado o postulado, libera su dni y retira
+        /// sus postulaciones a elecciones. El usuario puede volver a postularse
+        ///
+        /// # Errores
+        /// - Devuelve un error si el usuario no es encontrado
+        /// - Devuelve un error si el usuario es candidato o votante de una elección
+        ///   que todavia no tiene resultado, o que todavia puede pasar a una segunda vuelta
+        ///   o a un segundo llamado
+        #[ink(message)]
+        fn darse_de_baja(&mut self) -> Result<()>;
+    }
+
+    #[ink::trait_definition]
+    pub trait RolManager {
+        /// Otorga el rol pasado por parametro a la cuenta
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el admin
+        /// - Devuelve un error si el rol es Admin y hay un consejo configurado
+        /// - Devuelve un error si la eleccion de una autoridad no es encontrada
+        /// - Devuelve un error si la cuenta ya tiene el rol
+        #[ink(message)]
+        fn otorgar_rol(&mut self, cuenta: AccountId, rol: Rol) -> Result<()>;
+        /// Revoca el rol pasado por parametro a la cuenta
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el admin
+        /// - Devuelve un error si el rol es Admin y hay un consejo configurado
+        /// - Devuelve un error si la cuenta no tiene el rol
+        #[ink(message)]
+        fn revocar_rol(&mut self, cuenta: AccountId, rol: Rol) -> Result<()>;
+        /// Devuelve true si la cuenta tiene el rol pasado por parametro
+        #[ink(message)]
+        fn tiene_rol(&self, cuenta: AccountId, rol: Rol) -> bool;
+        /// Devuelve las cuentas que tienen el rol pasado por parametro, en el orden en que se otorgó
+        #[ink(message)]
+        fn get_cuentas_rol(&self, rol: Rol) -> Vec<AccountId>;
+    }
+
+    #[ink::trait_definition]
+    pub trait ConsejoManager {
+        /// Configura el consejo que aprueba las acciones del admin.
+        /// Desde ese momento set_admin, set_reporte, crear_eleccion y otorgar o revocar
+        /// el rol Admin solo se ejecutan mediante propuestas, y el consejo solo se cambia con una propuesta
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el admin
+        /// - Devuelve un error si el consejo ya está configurado
+        /// - Devuelve un error si hay miembros repetidos, o si las aprobaciones necesarias
+        ///   son cero o más que los miembros
+        #[ink(message)]
+        fn configurar_consejo(&mut self, miembros: Vec<AccountId>, umbral: u32) -> Result<()>;
+        /// Crea una propuesta con la acción pasada por parametro, aprobada por el caller.
+        /// Si alcanza las aprobaciones necesarias se ejecuta en el momento
+        /// Devuelve el id de la propuesta creada
+        ///
+        /// # Errores
+        /// - Devuelve un error si el consejo no está configurado
+        /// - Devuelve un error si el caller no es miembro del consejo
+        /// - Devuelve un error si la acción no es válida
+        #[ink(message)]
+        fn proponer(&mut self, accion: Accion) -> Result<u32>;
+        /// Aprueba la propuesta con el id pasado por parametro y la ejecuta
+        /// si alcanza las aprobaciones necesarias.
+        /// Devuelve true si la propuesta se ejecutó
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es miembro del consejo
+        /// - Devuelve un error si la propuesta no es encontrada
+        /// - Devuelve un error si la propuesta ya se ejecutó
+        /// - Devuelve un error si la propuesta venció o la creó un consejo anterior
+        /// - Devuelve un error si el caller ya aprobó la propuesta
+        /// - Devuelve un error si la acción deja de ser válida al ejecutarse
+        #[ink(message)]
+        fn aprobar_propuesta(&mut self, id_propuesta: u32) -> Result<bool>;
+        /// Devuelve una propuesta por su ID
+        /// Devuelve None si la propuesta no se encuentra
+        #[ink(message)]
+        fn get_propuesta(&self, id_propuesta: u32) -> Option<Propuesta>;
+        /// Devuelve los miembros del consejo y las aprobaciones necesarias,
+        /// 0 si el consejo no está configurado
+        #[ink(message)]
+        fn get_consejo(&self) -> (Vec<AccountId>, u32);
+    }
+
+    #[ink::trait_definition]
+    pub trait EleccionSystemInk {
+        /// Postula un candidato a la eleccion con el id pasado por parametro
+        ///
+        /// # Errores
+        /// - Devuelve un error si el usuario no esta aceptado
+        /// - Devuelve un error si el usuario no es encontrado
+        /// - Devuelve un error si la eleccion no se encuentra
+        /// - Devuelve un error si la eleccion ya finalizo
         /// - Devuelve un error si la eleccion ya inicio
+        /// - Devuelve un error si el periodo de inscripcion no inició o ya cerró
         /// - Devuelve un error si el usuario ya está registrado como candidato
         /// - Devuelve un error si el usuario ya está postulado como candidato
         /// - Devuelve un error si el usuario ya está registrado como votante
         /// - Devuelve un error si el usuario ya está postulado como votante
+        /// - Devuelve un error si la eleccion solo admite usuarios habilitados
+        ///   y el usuario no fue habilitado
+        /// - Devuelve un error si la edad del usuario no cumple las reglas de la eleccion
+        #[ink(message)]
+        fn postular_candidato(&mut self, id_eleccion: u32) -> Result<()>;
+        /// Postula un votante a la eleccion con el id pasado por parametro
+        ///
+        /// # Errores
+        /// - Devuelve un error si el usuario no esta aceptado
+        /// - Devuelve un error si el usuario no es encontrado
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion ya finalizo
+        /// - Devuelve un error si la eleccion ya inicio
+        /// - Devuelve un error si el periodo de inscripcion no inició o ya cerró
+        /// - Devuelve un error si el usuario ya está registrado como candidato
+        /// - Devuelve un error si el usuario ya está postulado como candidato
+        /// - Devuelve un error si el usuario ya está registrado como votante
+        /// - Devuelve un error si el usuario ya está postulado como votante
+        /// - Devuelve un error si la eleccion solo admite usuarios habilitados
+        ///   y el usuario no fue habilitado
+        /// - Devuelve un error si la edad del usuario no cumple las reglas de la eleccion
         #[ink(message)]
         fn postular_votante(&mut self, id_eleccion: u32) -> Result<()>;
         /// Acepta un candidato postulado a la eleccion con el id pasado por parametro
         ///
         /// # Errores
-        /// - Devuelve un error si el caller no es el admin
+        /// - Devuelve un error si el caller no es admin ni autoridad de la eleccion
         /// - Devuelve un error si el usuario no es encontrado
         /// - Devuelve un error si la eleccion no es encontrada
         /// - Devuelve un error si la eleccion ya finalizo
@@ -153,18 +1034,87 @@ mod votacion {
         /// - Devuelve un error si el usuario no está postulado como candidato
         #[ink(message)]
         fn agregar_candidato(&mut self, id_eleccion: u32, id_candidato: AccountId) -> Result<()>;
-        /// Acepta un votante postulado a la eleccion con el id pasado por parametro
+        /// Acepta un votante postulado a la eleccion con el id pasado por parametro,
+        /// con el peso que tendrá su voto
         ///
         /// # Errores
-        /// - Devuelve un error si el caller no es el admin
+        /// - Devuelve un error si el caller no es admin ni autoridad de la eleccion
         /// - Devuelve un error si el usuario no es encontrado
         /// - Devuelve un error si la eleccion no es encontrada
         /// - Devuelve un error si la eleccion ya finalizo
         /// - Devuelve un error si la eleccion ya inicio
         /// - Devuelve un error si el usuario no está postulado como votante
+        /// - Devuelve un error si el peso es 0, o distinto de 1 en una eleccion preferencial
         #[ink(message)]
-        fn agregar_votante(&mut self, id_eleccion: u32, id_votante: AccountId) -> Result<()>;
-        /// El votante (caller) vota por un candidato en una eleccion con el id pasado por parametro
+        fn agregar_votante(
+            &mut self,
+            id_eleccion: u32,
+            id_votante: AccountId,
+            peso: u64,
+        ) -> Result<()>;
+        /// Rechaza un candidato postulado a la eleccion con el id pasado por parametro,
+        /// con el motivo pasado por parametro. El usuario puede volver a postularse
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es admin ni autoridad de la eleccion
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si el usuario no está postulado como candidato
+        #[ink(message)]
+        fn rechazar_candidato(
+            &mut self,
+            id_eleccion: u32,
+            id_candidato: AccountId,
+            motivo: MotivoRechazo,
+        ) -> Result<()>;
+        /// Rechaza un votante postulado a la eleccion con el id pasado por parametro,
+        /// con el motivo pasado por parametro. El usuario puede volver a postularse
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es admin ni autoridad de la eleccion
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si el usuario no está postulado como votante
+        #[ink(message)]
+        fn rechazar_votante(
+            &mut self,
+            id_eleccion: u32,
+            id_votante: AccountId,
+            motivo: MotivoRechazo,
+        ) -> Result<()>;
+        /// Devuelve el motivo por el que se rechazó la postulación del usuario a la eleccion,
+        /// None si no fue rechazado o si volvió a postularse
+        #[ink(message)]
+        fn get_rechazo_postulacion(&self, id_eleccion: u32, id: AccountId)
+            -> Option<MotivoRechazo>;
+        /// El candidato (caller) se retira de la eleccion con el id pasado por parametro.
+        /// Si se retira durante la votación ya no puede recibir votos, y los que recibió
+        /// se anulan o se conservan según la configuración de la eleccion. En ningun caso
+        /// puede resultar electo
+        ///
+        /// # Errores
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion ya finalizo
+        /// - Devuelve un error si la eleccion está suspendida, cancelada o ya fue certificada
+        /// - Devuelve un error si el caller no está registrado como candidato
+        /// - Devuelve un error si el caller integra una lista
+        /// - Devuelve un error si la eleccion ya inicio y no es simple ni por aprobacion
+        /// - Devuelve un error si la eleccion ya inicio y no quedarían candidatos para todos los cargos
+        #[ink(message)]
+        fn retirar_candidato(&mut self, id_eleccion: u32) -> Result<()>;
+        /// El votante (caller) se retira de la eleccion con el id pasado por parametro
+        /// antes de que inicie. Se revoca su delegación y quienes delegaron en él
+        /// vuelven a votar por sí mismos
+        ///
+        /// # Errores
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion ya finalizo
+        /// - Devuelve un error si la eleccion ya inicio
+        /// - Devuelve un error si la eleccion está suspendida o cancelada
+        /// - Devuelve un error si la eleccion es una segunda vuelta
+        /// - Devuelve un error si el caller no está registrado como votante
+        #[ink(message)]
+        fn retirar_votante(&mut self, id_eleccion: u32) -> Result<()>;
+        /// El votante (caller) vota por un candidato en una eleccion con el id pasado por parametro.
+        /// El candidato suma el peso del votante y el de quienes delegaron en él
         ///
         /// # Errores
         /// - Devuelve un error si el votante no es encontrado
@@ -175,8 +1125,115 @@ mod votacion {
         /// - Devuelve un error si el votante ya voto
         /// - Devuelve un error si el votante no está registrado como votante
         /// - Devuelve un error si el candidato no está registrado como
+        /// - Devuelve un error si la eleccion no es simple
         #[ink(message)]
         fn votar(&mut self, id_eleccion: u32, id_candidato: AccountId) -> Result<()>;
+        /// El votante (caller) vota en una eleccion preferencial con el id pasado por parametro,
+        /// ordenando a los candidatos de mayor a menor preferencia.
+        /// No es necesario ordenar a todos los candidatos
+        ///
+        /// # Errores
+        /// - Devuelve un error si el votante no es encontrado
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion no es preferencial
+        /// - Devuelve un error si la eleccion ya finalizo
+        /// - Devuelve un error si la eleccion no inicio
+        /// - Devuelve un error si el votante no está registrado como votante
+        /// - Devuelve un error si no se ordena ningun candidato
+        /// - Devuelve un error si algun candidato no está registrado como candidato
+        /// - Devuelve un error si algun candidato está repetido
+        /// - Devuelve un error si el votante ya voto
+        #[ink(message)]
+        fn votar_preferencial(
+            &mut self,
+            id_eleccion: u32,
+            preferencias: Vec<AccountId>,
+        ) -> Result<()>;
+        /// El votante (caller) vota en una eleccion por aprobacion con el id pasado por parametro,
+        /// aprobando a cualquier cantidad de candidatos. Cada candidato aprobado suma el peso del votante
+        ///
+        /// # Errores
+        /// - Devuelve un error si el votante no es encontrado
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion no es por aprobacion
+        /// - Devuelve un error si la eleccion ya finalizo
+        /// - Devuelve un error si la eleccion no inicio
+        /// - Devuelve un error si el votante no está registrado como votante
+        /// - Devuelve un error si algun candidato no está registrado como candidato
+        /// - Devuelve un error si algun candidato está repetido
+        /// - Devuelve un error si el votante ya voto
+        #[ink(message)]
+        fn votar_aprobacion(&mut self, id_eleccion: u32, aprobados: Vec<AccountId>) -> Result<()>;
+        /// El votante (caller) vota por una lista en una eleccion por listas
+        ///
+        /// # Errores
+        /// - Devuelve un error si el votante no es encontrado
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion no es por listas
+        /// - Devuelve un error si la eleccion ya finalizo
+        /// - Devuelve un error si la eleccion no inicio
+        /// - Devuelve un error si el votante no está registrado como votante
+        /// - Devuelve un error si la lista no es encontrada
+        /// - Devuelve un error si el votante ya voto
+        #[ink(message)]
+        fn votar_lista(&mut self, id_eleccion: u32, id_lista: u32) -> Result<()>;
+        /// El votante (caller) compromete su voto en una eleccion secreta con el hash de la eleccion,
+        /// su cuenta, el candidato elegido y una sal, calculado como en `Votacion::calcular_compromiso`
+        ///
+        /// # Errores
+        /// - Devuelve un error si el votante no es encontrado
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion no es secreta
+        /// - Devuelve un error si la eleccion ya finalizo
+        /// - Devuelve un error si la eleccion no inicio
+        /// - Devuelve un error si el votante no está registrado como votante
+        /// - Devuelve un error si el votante ya voto
+        #[ink(message)]
+        fn votar_secreto(&mut self, id_eleccion: u32, compromiso: Compromiso) -> Result<()>;
+        /// El votante (caller) vota en blanco en una eleccion con el id pasado por parametro
+        ///
+        /// # Errores
+        /// - Devuelve un error si el votante no es encontrado
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion es secreta
+        /// - Devuelve un error si la eleccion ya finalizo
+        /// - Devuelve un error si la eleccion no inicio
+        /// - Devuelve un error si el votante no está registrado como votante
+        /// - Devuelve un error si el votante ya voto
+        #[ink(message)]
+        fn votar_en_blanco(&mut self, id_eleccion: u32) -> Result<()>;
+        /// El votante (caller) anula su voto en una eleccion con el id pasado por parametro
+        ///
+        /// # Errores
+        /// - Devuelve un error si el votante no es encontrado
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion es secreta
+        /// - Devuelve un error si la eleccion ya finalizo
+        /// - Devuelve un error si la eleccion no inicio
+        /// - Devuelve un error si el votante no está registrado como votante
+        /// - Devuelve un error si el votante ya voto
+        #[ink(message)]
+        fn votar_nulo(&mut self, id_eleccion: u32) -> Result<()>;
+        /// El votante (caller) revela el candidato y la sal de su voto secreto,
+        /// luego de la fecha de fin y hasta la fecha de fin de revelación.
+        /// Solo los votos revelados se cuentan en el resultado
+        ///
+        /// # Errores
+        /// - Devuelve un error si el votante no es encontrado
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion no es secreta
+        /// - Devuelve un error si la eleccion no finalizó
+        /// - Devuelve un error si terminó el periodo de revelación
+        /// - Devuelve un error si el votante no comprometió su voto o ya lo reveló
+        /// - Devuelve un error si el candidato y la sal no corresponden al compromiso
+        /// - Devuelve un error si el candidato no está registrado como candidato
+        #[ink(message)]
+        fn revelar_voto(
+            &mut self,
+            id_eleccion: u32,
+            id_candidato: AccountId,
+            sal: [u8; 32],
+        ) -> Result<()>;
         /// Devuelve true si el usuario ya voto en la eleccion con el id pasado como parametro
         /// false en cualquier otro caso
         ///
@@ -208,47 +1265,239 @@ mod votacion {
         /// - Devuelve un error si la eleccion no finalizó
         /// - Devuelve un error si el candidato no es encontrado
         #[ink(message)]
-        fn get_votos_candidato(&self, id_eleccion: u32, id_candidato: AccountId) -> Result<u32>;
+        fn get_votos_candidato(&self, id_eleccion: u32, id_candidato: AccountId) -> Result<u128>;
+        /// El votante (caller) delega su voto en otro votante de la eleccion antes de que inicie.
+        /// La delegación es transitiva: el delegado vota con los votos de quienes delegaron en él
+        /// y todavia no votaron. Si el caller ya había delegado su voto, la delegación se reemplaza.
+        /// Si el caller vota directamente, su voto reemplaza a la delegación
+        ///
+        /// # Errores
+        /// - Devuelve un error si el votante o el delegado no son encontrados
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion no es simple o es secreta
+        /// - Devuelve un error si la eleccion ya inició
+        /// - Devuelve un error si el caller no está registrado como votante
+        /// - Devuelve un error si el delegado no está registrado como votante
+        /// - Devuelve un error si la delegación genera un ciclo
+        #[ink(message)]
+        fn delegar_voto(&mut self, id_eleccion: u32, delegado: AccountId) -> Result<()>;
+        /// El votante (caller) revoca la delegación de su voto antes de que inicie la eleccion
+        ///
+        /// # Errores
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion no es simple o es secreta
+        /// - Devuelve un error si la eleccion ya inició
+        /// - Devuelve un error si el caller no delegó su voto
+        #[ink(message)]
+        fn revocar_delegacion(&mut self, id_eleccion: u32) -> Result<()>;
+        /// Devuelve el votante en el que delegó su voto el votante pasado por parametro
+        #[ink(message)]
+        fn get_delegacion(&self, id_eleccion: u32, votante: AccountId) -> Option<AccountId>;
+        /// Habilita a los usuarios pasados por parametro para postularse a una eleccion
+        /// que solo admite usuarios habilitados
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es admin ni autoridad de la eleccion
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion ya finalizo
+        /// - Devuelve un error si la eleccion ya inicio
+        /// - Devuelve un error si la eleccion no solo admite usuarios habilitados
+        #[ink(message)]
+        fn habilitar_usuarios(&mut self, id_eleccion: u32, usuarios: Vec<AccountId>) -> Result<()>;
+        /// Devuelve true si el usuario fue habilitado para postularse a la eleccion
+        #[ink(message)]
+        fn get_habilitado(&self, id_eleccion: u32, usuario: AccountId) -> bool;
     }
 
+    /// Reglas de una elección. El estado de cada participante se lee del storage de Votacion
+    /// y se pasa por parametro, Votacion se encarga de persistir los cambios
     pub trait EleccionSystem {
-        /// Postula un candidato, si este esta postulado como votante, devolvera error
+        /// Valida la postulacion de un candidato a partir de su estado en la elección,
+        /// si este esta postulado como votante, devolvera error.
+        /// El usuario debe cumplir las reglas de elegibilidad de los candidatos
         fn postular_candidato(
-            &mut self,
-            id_candidato: AccountId,
+            &self,
+            estado: Option<EstadoParticipante>,
+            usuario: &Usuario,
+            habilitado: bool,
+            current_time: &Fecha,
+        ) -> Result<()>;
+        /// Valida la postulacion de un votante a partir de su estado en la elección,
+        /// si este esta postulado como candidato, devolvera error.
+        /// El usuario debe cumplir las reglas de elegibilidad de los votantes
+        fn postular_votante(
+            &self,
+            estado: Option<EstadoParticipante>,
+            usuario: &Usuario,
+            habilitado: bool,
             current_time: &Fecha,
         ) -> Result<()>;
-        /// Postula un votante, si este esta postulado como candidato, devolvera error
-        fn postular_votante(&mut self, id_votante: AccountId, current_time: &Fecha) -> Result<()>;
+        /// Valida que se puedan habilitar usuarios para postularse a la elección
+        fn habilitar_usuarios(&self, current_time: &Fecha) -> Result<()>;
         /// Dado un candidato postulado, es aceptado por el Admin
+        /// Devuelve la posicion del candidato en el registro de la elección
         fn agregar_candidato(
             &mut self,
-            id_candidato: AccountId,
+            estado: Option<EstadoParticipante>,
             current_time: &Fecha,
-        ) -> Result<()>;
+        ) -> Result<u32>;
         /// Dado un votante postulado, es aceptado por el Admin
-        fn agregar_votante(&mut self, id_votante: AccountId, current_time: &Fecha) -> Result<()>;
-        /// Vota un usuario por un candidato
+        /// Devuelve la posicion del votante en el registro de la elección
+        fn agregar_votante(
+            &mut self,
+            estado: Option<EstadoParticipante>,
+            peso: u64,
+            current_time: &Fecha,
+        ) -> Result<u32>;
+        /// Valida el rechazo de un candidato postulado.
+        /// Un postulado se puede rechazar aunque la elección ya haya iniciado
+        fn rechazar_candidato(&self, estado: Option<EstadoParticipante>) -> Result<()>;
+        /// Retira a un candidato de la elección hasta su finalización.
+        /// Durante la votación solo se puede retirar de las elecciones simples y por aprobacion
+        fn retirar_candidato(
+            &mut self,
+            estado: Option<EstadoParticipante>,
+            en_lista: bool,
+            current_time: &Fecha,
+        ) -> Result<()>;
+        /// Retira a un votante de la elección antes del inicio de la votación,
+        /// descontando su peso
+        fn retirar_votante(
+            &mut self,
+            estado: Option<EstadoParticipante>,
+            peso: u64,
+            current_time: &Fecha,
+        ) -> Result<()>;
+        /// Valida el rechazo de un votante postulado.
+        /// Un postulado se puede rechazar aunque la elección ya haya iniciado
+        fn rechazar_votante(&self, estado: Option<EstadoParticipante>) -> Result<()>;
+        /// Vota un usuario por un candidato, sumando el peso del votante
         fn votar(
             &mut self,
-            id_votante: &AccountId,
-            id_candidato: &AccountId,
+            estado_votante: Option<EstadoParticipante>,
+            peso: u64,
+            estado_candidato: Option<EstadoParticipante>,
+            ya_voto: bool,
+            current_time: &Fecha,
+        ) -> Result<()>;
+        /// Registra la boleta de un votante que ordena a los candidatos por preferencia
+        /// Devuelve el numero de la boleta dentro de la elección
+        fn votar_preferencial(
+            &mut self,
+            estado_votante: Option<EstadoParticipante>,
+            preferencias: &[(AccountId, Option<EstadoParticipante>)],
+            ya_voto: bool,
+            current_time: &Fecha,
+        ) -> Result<u32>;
+        /// Registra el voto de un votante que aprueba a un conjunto de candidatos
+        fn votar_aprobacion(
+            &mut self,
+            estado_votante: Option<EstadoParticipante>,
+            peso: u64,
+            aprobados: &[(AccountId, Option<EstadoParticipante>)],
+            ya_voto: bool,
+            current_time: &Fecha,
+        ) -> Result<()>;
+        /// Valida una lista de candidatos a partir del estado de cada uno en la elección
+        /// Devuelve la posicion de la lista en el registro de la elección
+        fn agregar_lista(
+            &mut self,
+            candidatos: &[(AccountId, Option<EstadoParticipante>)],
+            en_otra_lista: bool,
+            current_time: &Fecha,
+        ) -> Result<u32>;
+        /// Vota un usuario por una lista
+        fn votar_lista(
+            &mut self,
+            estado_votante: Option<EstadoParticipante>,
+            peso: u64,
+            id_lista: u32,
+            ya_voto: bool,
+            current_time: &Fecha,
+        ) -> Result<()>;
+        /// Registra un voto en blanco o nulo de un usuario
+        fn votar_sin_candidato(
+            &mut self,
+            estado_votante: Option<EstadoParticipante>,
+            peso: u64,
+            voto: VotoSinCandidato,
+            ya_voto: bool,
             current_time: &Fecha,
         ) -> Result<()>;
-        /// Devuelve true si el usuario ya voto, false en cualquier otro caso
-        fn ya_voto(&self, id_votante: &AccountId) -> bool;
+        /// Valida la delegación del voto de un votante en otro votante de la elección
+        fn delegar_voto(
+            &self,
+            estado_votante: Option<EstadoParticipante>,
+            estado_delegado: Option<EstadoParticipante>,
+            genera_ciclo: bool,
+            current_time: &Fecha,
+        ) -> Result<()>;
+        /// Valida que un votante pueda revocar la delegación de su voto
+        fn revocar_delegacion(&self, delego: bool, current_time: &Fecha) -> Result<()>;
+        /// Aplica la regla de balotaje a los votos de la elección, ordenados de mayor a menor
+        /// con los empates ya resueltos según la política de desempate. Los votos conservados
+        /// de candidatos retirados solo cuentan para el total
+        /// Devuelve el ganador, o None si hace falta una segunda vuelta entre los dos primeros
+        fn aplicar_balotaje(
+            &self,
+            votos: &[(AccountId, u128)],
+            votos_conservados: u128,
+            current_time: &Fecha,
+        ) -> Result<Option<AccountId>>;
+        /// Registra el compromiso del voto de un usuario en una elección secreta
+        fn comprometer_voto(
+            &mut self,
+            estado_votante: Option<EstadoParticipante>,
+            peso: u64,
+            ya_voto: bool,
+            current_time: &Fecha,
+        ) -> Result<()>;
+        /// Valida la revelación de un voto comprometido, comparando el compromiso guardado
+        /// con el calculado a partir del candidato y la sal revelados
+        fn revelar_voto(
+            &mut self,
+            compromiso: Option<Compromiso>,
+            ya_voto: bool,
+            apertura: Compromiso,
+            estado_candidato: Option<EstadoParticipante>,
+            current_time: &Fecha,
+        ) -> Result<()>;
+        /// Devuelve el estado de la elección a la fecha pasada por parametro
+        fn calcular_estado(&self, current_time: &Fecha) -> EstadoEleccion;
+        /// Marca como inválida una elección finalizada que no alcanzó el quórum,
+        /// antes de la fecha de inicio de su segundo llamado si lo tiene
+        fn invalidar(&mut self, current_time: &Fecha) -> Result<()>;
+        /// Cancela la elección, que deja de admitir postulaciones y votos y no tiene resultado
+        fn cancelar(&mut self) -> Result<()>;
+        /// Suspende una elección en inscripción o abierta
+        fn suspender(&mut self, current_time: &Fecha) -> Result<()>;
+        /// Reanuda una elección suspendida, que vuelve al estado que indican sus fechas
+        /// Devuelve el nuevo estado
+        fn reanudar(&mut self, current_time: &Fecha) -> Result<EstadoEleccion>;
+        /// Posterga la fecha de fin de una elección que todavia no cerró
+        fn extender(&mut self, fecha_fin: Fecha, current_time: &Fecha) -> Result<()>;
+        /// Certifica el resultado de una elección cerrada, una vez terminado el período
+        /// de impugnación, sin impugnaciones pendientes ni aceptadas y con su regla
+        /// de balotaje aplicada si la tiene
+        fn certificar(&mut self, current_time: &Fecha) -> Result<()>;
+        /// Registra una impugnación del resultado presentada por un participante,
+        /// a partir de su estado en la elección
+        /// Devuelve el id de la impugnación dentro de la elección
+        fn impugnar(
+            &mut self,
+            estado: Option<EstadoParticipante>,
+            current_time: &Fecha,
+        ) -> Result<u32>;
+        /// Resuelve una impugnación a partir de su estado actual
+        fn resolver_impugnacion(
+            &mut self,
+            estado: Option<EstadoImpugnacion>,
+            aceptada: bool,
+        ) -> Result<()>;
         /// Devuelve si la elección ya inició
         fn get_inicio(&self, current_time: &Fecha) -> bool;
         /// Devuelve si la elección ya finalizó
         fn get_finalizada(&self, current_time: &Fecha) -> bool;
-        /// Devuelve la cantidad de votos para un candidato dado
-        fn get_votos_candidato(
-            &self,
-            id_candidato: &AccountId,
-            current_time: &Fecha,
-        ) -> Result<u32>;
-        /// Devuelve los votos de todos los candidatos, almacenados por id
-        fn get_votos(&self, current_time: &Fecha) -> Result<Vec<(AccountId, u32)>>;
     }
 
     #[ink::trait_definition]
@@ -258,110 +1507,729 @@ mod votacion {
         ///
         /// # Errores
         /// - Devuelve un error si el caller no es el admin
+        /// - Devuelve un error si hay un consejo configurado
         /// - Devuelve un error si la fecha de inicio o fin no es válida
         /// - Devuelve un error si la fecha de inicio es mayor que la fecha de fin
+        /// - Devuelve un error si la cantidad de cargos es cero, o mayor a uno en una elección preferencial
+        /// - Devuelve un error si una elección secreta no es simple
+        /// - Devuelve un error si la fecha de fin de revelación no es válida o es anterior a la fecha de fin
+        /// - Devuelve un error si una elección con balotaje no es simple, es secreta o elige más de un cargo
+        /// - Devuelve un error si la regla de balotaje no es válida
+        /// - Devuelve un error si la fecha de fin de impugnación no es válida
+        ///   o es anterior al fin de la votación o de la revelación
+        /// - Devuelve un error si la regla de quórum no es válida, o tiene segundo llamado
+        ///   en una elección por listas o secreta
         #[ink(message)]
-        fn crear_eleccion(&mut self, fecha_inicio: Fecha, fecha_fin: Fecha) -> Result<u32>;
-        /// Devuelve una elección por su ID
+        fn crear_eleccion(
+            &mut self,
+            fecha_inicio: Fecha,
+            fecha_fin: Fecha,
+            config: ConfigEleccion,
+        ) -> Result<u32>;
+        /// Devuelve una elección por su ID, con su estado actual
         /// Devuelve None si la elección no se encuentra
         #[ink(message)]
         fn get_eleccion(&self, id: u32) -> Option<Eleccion>;
-    }
-
-    #[ink(storage)]
-    pub struct Votacion {
-        admin: AccountId,
-        reporte: AccountId,
-        elecciones: Vec<Eleccion>,
-        usuarios: Vec<Usuario>,
-        usuarios_sin_aceptar: Vec<Usuario>,
-    }
-
-    impl Eleccion {
-        pub fn new(id: u32, fecha_inicio: Fecha, fecha_fin: Fecha) -> Self {
-            Eleccion {
-                id,
-                votantes_sin_aceptar: Vec::new(),
-                candidatos_sin_aceptar: Vec::new(),
-                votantes: Vec::new(),
-                candidatos: Vec::new(),
-                votantes_voto: Vec::new(),
-                votos: Vec::new(),
-                fecha_inicio,
-                fecha_fin,
-            }
-        }
-    }
-
-    impl EleccionSystem for Eleccion {
-        fn postular_votante(&mut self, id_votante: AccountId, current_time: &Fecha) -> Result<()> {
-            if self.get_finalizada(current_time) {
-                return Err(VotacionError::EleccionYaFinalizada);
-            }
-
-            if self.get_inicio(current_time) {
-                return Err(VotacionError::EleccionYaIniciada);
-            }
-
-            if self.is_candidato(&id_votante) {
-                return Err(VotacionError::UsuarioEsCandidato);
-            }
+        /// Crea una lista de candidatos en una elección por listas
+        /// Los candidatos ocupan los cargos de la lista en el orden pasado por parametro
+        /// Devuelve el id de la lista creada
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el admin
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion no es por listas
+        /// - Devuelve un error si la eleccion ya finalizo
+        /// - Devuelve un error si la eleccion ya inicio
+        /// - Devuelve un error si la lista no tiene candidatos
+        /// - Devuelve un error si algun candidato no está registrado como candidato
+        /// - Devuelve un error si algun candidato está repetido
+        /// - Devuelve un error si algun candidato ya integra otra lista
+        #[ink(message)]
+        fn crear_lista(
+            &mut self,
+            id_eleccion: u32,
+            nombre: String,
+            candidatos: Vec<AccountId>,
+        ) -> Result<u32>;
+        /// Devuelve una lista de una elección por su ID
+        /// Devuelve None si la lista no se encuentra
+        #[ink(message)]
+        fn get_lista(&self, id_eleccion: u32, id_lista: u32) -> Option<Lista>;
+        /// Aplica la regla de balotaje a una elección finalizada.
+        /// Si el más votado no cumple la regla, crea la segunda vuelta entre los dos
+        /// más votados con los mismos votantes. Los empates se resuelven según la política
+        /// de desempate de la elección
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el admin
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion no tiene regla de balotaje
+        /// - Devuelve un error si la eleccion no finalizó
+        /// - Devuelve un error si la regla ya se aplicó
+        /// - Devuelve un error si la eleccion no alcanzó el quórum
+        /// - Devuelve un error si no terminó el período de impugnación, o si hay impugnaciones
+        ///   pendientes o aceptadas
+        /// - Devuelve un error si la eleccion tiene menos de dos candidatos
+        /// - Devuelve un error si la eleccion desempata en segunda vuelta y hay un empate
+        ///   que no permite elegir a los finalistas
+        #[ink(message)]
+        fn aplicar_balotaje(&mut self, id_eleccion: u32) -> Result<ResultadoBalotaje>;
+        /// Aplica la regla de quórum a una elección finalizada que no lo alcanzó.
+        /// La elección queda inválida y, si la regla lo indica, se crea su segundo llamado
+        /// con los mismos candidatos y votantes
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el admin
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion está suspendida, cancelada, certificada o ya es inválida
+        /// - Devuelve un error si la eleccion no tiene regla de quórum
+        /// - Devuelve un error si la eleccion no finalizó o no terminó su revelación
+        /// - Devuelve un error si la eleccion alcanzó el quórum
+        /// - Devuelve un error si ya pasó la fecha de inicio del segundo llamado
+        #[ink(message)]
+        fn aplicar_quorum(&mut self, id_eleccion: u32) -> Result<ResultadoQuorum>;
+        /// Cancela una elección con el motivo pasado por parametro. La elección deja de
+        /// admitir postulaciones y votos, no tiene resultado y sus participantes pueden darse de baja
+        /// Con un consejo configurado se realiza por medio de una propuesta
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el admin
+        /// - Devuelve un error si hay un consejo configurado
+        /// - Devuelve un error si el motivo está vacío
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion ya fue certificada o cancelada
+        #[ink(message)]
+        fn cancelar_eleccion(&mut self, id_eleccion: u32, motivo: String) -> Result<()>;
+        /// Suspende una elección en inscripción o abierta con el motivo pasado por parametro.
+        /// Mientras está suspendida no admite postulaciones, votos ni consultas de resultado
+        /// Con un consejo configurado se realiza por medio de una propuesta
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el admin
+        /// - Devuelve un error si hay un consejo configurado
+        /// - Devuelve un error si el motivo está vacío
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion no está en inscripción ni abierta
+        #[ink(message)]
+        fn suspender_eleccion(&mut self, id_eleccion: u32, motivo: String) -> Result<()>;
+        /// Reanuda una elección suspendida con el motivo pasado por parametro.
+        /// La elección vuelve al estado que indican sus fechas
+        /// Con un consejo configurado se realiza por medio de una propuesta
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el admin
+        /// - Devuelve un error si hay un consejo configurado
+        /// - Devuelve un error si el motivo está vacío
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion no está suspendida
+        #[ink(message)]
+        fn reanudar_eleccion(&mut self, id_eleccion: u32, motivo: String) -> Result<()>;
+        /// Posterga la fecha de fin de una elección que todavia no cerró, o que está suspendida,
+        /// con el motivo pasado por parametro
+        /// Con un consejo configurado se realiza por medio de una propuesta
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el admin
+        /// - Devuelve un error si hay un consejo configurado
+        /// - Devuelve un error si el motivo está vacío
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion ya finalizó, fue certificada o cancelada
+        /// - Devuelve un error si la nueva fecha de fin no es válida o no es posterior a la actual
+        /// - Devuelve un error si la fecha de fin de revelación o la segunda vuelta
+        ///   no son posteriores a la nueva fecha de fin
+        #[ink(message)]
+        fn extender_eleccion(
+            &mut self,
+            id_eleccion: u32,
+            fecha_fin: Fecha,
+            motivo: String,
+        ) -> Result<()>;
+        /// Certifica el resultado de una elección cerrada, una vez terminado su período
+        /// de impugnación y resueltas sus impugnaciones
+        /// Con un consejo configurado se realiza por medio de una propuesta
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el admin
+        /// - Devuelve un error si hay un consejo configurado
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion no finalizó o no terminó su revelación
+        /// - Devuelve un error si la eleccion está suspendida, cancelada o ya fue certificada
+        /// - Devuelve un error si no terminó el período de impugnación
+        /// - Devuelve un error si hay impugnaciones pendientes o aceptadas
+        /// - Devuelve un error si la eleccion no alcanzó el quórum
+        /// - Devuelve un error si la eleccion tiene regla de balotaje y todavia no se aplicó
+        #[ink(message)]
+        fn certificar_eleccion(&mut self, id_eleccion: u32) -> Result<()>;
+        /// Impugna el resultado de una elección finalizada con el motivo pasado por parametro.
+        /// Solo los votantes y candidatos pueden impugnar, hasta la fecha de fin de impugnación
+        /// Devuelve el id de la impugnación
+        ///
+        /// # Errores
+        /// - Devuelve un error si el motivo está vacío o supera los 280 bytes
+        /// - Devuelve un error si el caller ya tiene una impugnación pendiente en la elección
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion no admite impugnaciones
+        /// - Devuelve un error si la eleccion no finalizó o no terminó su revelación
+        /// - Devuelve un error si la eleccion está suspendida, cancelada o ya fue certificada
+        /// - Devuelve un error si terminó el período de impugnación
+        /// - Devuelve un error si el caller no es votante ni candidato de la elección
+        #[ink(message)]
+        fn impugnar_resultado(&mut self, id_eleccion: u32, motivo: String) -> Result<u32>;
+        /// Acepta o rechaza una impugnación pendiente con el fundamento pasado por parametro.
+        /// Con un consejo configurado se resuelve por medio de una propuesta
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el admin
+        /// - Devuelve un error si hay un consejo configurado
+        /// - Devuelve un error si el fundamento está vacío
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la impugnación no es encontrada
+        /// - Devuelve un error si la impugnación ya fue resuelta
+        #[ink(message)]
+        fn resolver_impugnacion(
+            &mut self,
+            id_eleccion: u32,
+            id_impugnacion: u32,
+            aceptada: bool,
+            resolucion: String,
+        ) -> Result<()>;
+        /// Devuelve una impugnación de una elección por su ID
+        /// Devuelve None si la impugnación no se encuentra
+        #[ink(message)]
+        fn get_impugnacion(&self, id_eleccion: u32, id_impugnacion: u32) -> Option<Impugnacion>;
+    }
 
-            if self.is_postulado_candidato(&id_votante) {
-                return Err(VotacionError::UsuarioEsPostuladoCandidato);
-            }
+    #[ink(storage)]
+    pub struct Votacion {
+        admin: AccountId,
+        /// Si es true, de los usuarios solo se guarda el hash de su dni y su dirección
+        modo_privado: bool,
+        /// Cuenta nominada como nuevo admin, que todavia no aceptó el cargo
+        admin_nominado: Option<AccountId>,
+        reporte: AccountId,
+        elecciones: Mapping<u32, Eleccion>,
+        cantidad_elecciones: u32,
+        usuarios: Mapping<AccountId, Usuario>,
+        usuarios_sin_aceptar: Mapping<AccountId, Usuario>,
+        /// Usuario registrado con cada dni sin ceros a la izquierda, entre los usuarios aceptados y sin aceptar
+        dnis: Mapping<String, AccountId>,
+        /// Motivo del rechazo de cada usuario que no volvió a postularse
+        rechazos_usuario: Mapping<AccountId, MotivoRechazo>,
+        /// Estado de cada usuario en cada elección, por (id_eleccion, usuario)
+        participantes: Mapping<(u32, AccountId), EstadoParticipante>,
+        /// Motivo del rechazo de cada postulación a una elección, por (id_eleccion, usuario)
+        rechazos_postulacion: Mapping<(u32, AccountId), MotivoRechazo>,
+        /// Elecciones en curso en las que cada usuario se postuló o participa.
+        /// Las que ya concluyeron se quitan al registrar una nueva participación
+        elecciones_usuario: Mapping<AccountId, Vec<u32>>,
+        /// Usuarios habilitados para postularse a elecciones con solo_habilitados,
+        /// por (id_eleccion, usuario)
+        habilitados: Mapping<(u32, AccountId), ()>,
+        /// Votantes aceptados de cada elección, por (id_eleccion, posicion)
+        votantes: Mapping<(u32, u32), AccountId>,
+        /// Candidatos aceptados de cada elección, por (id_eleccion, posicion)
+        candidatos: Mapping<(u32, u32), AccountId>,
+        /// Votantes que ya votaron, por (id_eleccion, votante)
+        votantes_voto: Mapping<(u32, AccountId), ()>,
+        /// Peso de cada votante distinto de 1, por (id_eleccion, votante)
+        pesos: Mapping<(u32, AccountId), u64>,
+        /// Suma de los pesos de los votos recibidos por cada candidato, por (id_eleccion, candidato)
+        /// En las elecciones preferenciales se cuenta la primera preferencia
+        /// y en las elecciones por aprobacion cada aprobación
+        votos: Mapping<(u32, AccountId), u128>,
+        /// Boletas de las elecciones preferenciales, por (id_eleccion, numero de boleta)
+        boletas: Mapping<(u32, u32), Boleta>,
+        /// Listas de las elecciones por listas, por (id_eleccion, id_lista)
+        listas: Mapping<(u32, u32), Lista>,
+        /// Lista que integra cada candidato, por (id_eleccion, candidato)
+        listas_candidato: Mapping<(u32, AccountId), u32>,
+        /// Suma de los pesos de los votos recibidos por cada lista, por (id_eleccion, id_lista)
+        votos_lista: Mapping<(u32, u32), u128>,
+        /// Compromisos de los votos secretos todavia no revelados, por (id_eleccion, votante)
+        compromisos: Mapping<(u32, AccountId), Compromiso>,
+        /// Votante en el que cada votante delegó su voto, por (id_eleccion, votante)
+        delegaciones: Mapping<(u32, AccountId), AccountId>,
+        /// Votantes que delegaron su voto directamente en cada votante, por (id_eleccion, delegado)
+        delegantes: Mapping<(u32, AccountId), Delegantes>,
+        /// Voto de cada votante que recibió delegaciones y la cantidad de votos delegados
+        /// que todavia representa, por (id_eleccion, votante)
+        votos_delegados: Mapping<(u32, AccountId), VotoRepresentado>,
+        /// Roles otorgados, por (rol, cuenta)
+        roles: Mapping<(Rol, AccountId), ()>,
+        /// Cuentas que tienen cada rol
+        cuentas_rol: Mapping<Rol, Vec<AccountId>>,
+        /// Miembros del consejo que aprueba las acciones del admin
+        consejo: Vec<AccountId>,
+        /// Aprobaciones necesarias para ejecutar una propuesta, 0 si no hay consejo
+        umbral: u32,
+        /// Cantidad de veces que se configuró o cambió el consejo
+        version_consejo: u32,
+        /// Propuestas del consejo, por id
+        propuestas: Mapping<u32, Propuesta>,
+        cantidad_propuestas: u32,
+        /// Impugnaciones del resultado de cada elección, por (id_eleccion, id_impugnacion)
+        impugnaciones: Mapping<(u32, u32), Impugnacion>,
+        /// Autores con una impugnación pendiente, por (id_eleccion, autor)
+        impugnacion_pendiente: Mapping<(u32, AccountId), ()>,
+    }
 
-            if self.is_votante(&id_votante) {
-                return Err(VotacionError::UsuarioEsVotante);
-            }
+    /// Evento emitido cuando el admin crea una elección
+    #[ink(event)]
+    #[derive(Debug, PartialEq)]
+    pub struct EleccionCreada {
+        #[ink(topic)]
+        id_eleccion: u32,
+        fecha_inicio: Fecha,
+        fecha_fin: Fecha,
+        config: ConfigEleccion,
+    }
 
-            if self.is_postulado_votante(&id_votante) {
-                return Err(VotacionError::UsuarioEsPostuladoVotante);
-            }
-            // Añado votante postulado
-            self.votantes_sin_aceptar.push(id_votante);
-            Ok(())
-        }
+    /// Evento emitido cuando el admin crea una lista en una elección por listas
+    #[ink(event)]
+    #[derive(Debug, PartialEq)]
+    pub struct ListaCreada {
+        #[ink(topic)]
+        id_eleccion: u32,
+        id_lista: u32,
+    }
 
-        fn postular_candidato(
+    /// Evento emitido cuando el admin aplica la regla de balotaje de una elección
+    #[ink(event)]
+    #[derive(Debug, PartialEq)]
+    pub struct BalotajeAplicado {
+        #[ink(topic)]
+        id_eleccion: u32,
+        resultado: ResultadoBalotaje,
+    }
+
+    /// Evento emitido cuando el admin aplica la regla de quórum de una elección que no lo alcanzó
+    #[ink(event)]
+    #[derive(Debug, PartialEq)]
+    pub struct QuorumAplicado {
+        #[ink(topic)]
+        id_eleccion: u32,
+        resultado: ResultadoQuorum,
+    }
+
+    /// Evento emitido cuando el admin cancela, suspende, reanuda, certifica o invalida una elección
+    #[ink(event)]
+    #[derive(Debug, PartialEq)]
+    pub struct EstadoEleccionCambiado {
+        #[ink(topic)]
+        id_eleccion: u32,
+        estado_anterior: EstadoEleccion,
+        estado_nuevo: EstadoEleccion,
+        motivo: String,
+    }
+
+    /// Evento emitido cuando el admin posterga la fecha de fin de una elección
+    #[ink(event)]
+    #[derive(Debug, PartialEq)]
+    pub struct EleccionExtendida {
+        #[ink(topic)]
+        id_eleccion: u32,
+        fecha_fin_anterior: Fecha,
+        fecha_fin_nueva: Fecha,
+        motivo: String,
+    }
+
+    /// Evento emitido cuando un votante o candidato impugna el resultado de una elección
+    #[ink(event)]
+    #[derive(Debug, PartialEq)]
+    pub struct ImpugnacionPresentada {
+        #[ink(topic)]
+        id_eleccion: u32,
+        id_impugnacion: u32,
+        autor: AccountId,
+    }
+
+    /// Evento emitido cuando el admin o el consejo resuelve una impugnación
+    #[ink(event)]
+    #[derive(Debug, PartialEq)]
+    pub struct ImpugnacionResuelta {
+        #[ink(topic)]
+        id_eleccion: u32,
+        id_impugnacion: u32,
+        aceptada: bool,
+    }
+
+    /// Evento emitido cuando se configura o cambia el consejo
+    #[ink(event)]
+    #[derive(Debug, PartialEq)]
+    pub struct ConsejoCambiado {
+        miembros: Vec<AccountId>,
+        umbral: u32,
+    }
+
+    /// Evento emitido cuando un miembro del consejo crea una propuesta
+    #[ink(event)]
+    #[derive(Debug, PartialEq)]
+    pub struct PropuestaCreada {
+        #[ink(topic)]
+        id_propuesta: u32,
+        #[ink(topic)]
+        proponente: AccountId,
+        accion: Accion,
+    }
+
+    /// Evento emitido cuando un miembro del consejo aprueba una propuesta
+    #[ink(event)]
+    #[derive(Debug, PartialEq)]
+    pub struct PropuestaAprobada {
+        #[ink(topic)]
+        id_propuesta: u32,
+        #[ink(topic)]
+        miembro: AccountId,
+    }
+
+    /// Evento emitido cuando una propuesta alcanza las aprobaciones necesarias y se ejecuta
+    #[ink(event)]
+    #[derive(Debug, PartialEq)]
+    pub struct PropuestaEjecutada {
+        #[ink(topic)]
+        id_propuesta: u32,
+    }
+
+    /// Evento emitido cuando el admin otorga un rol a una cuenta
+    #[ink(event)]
+    #[derive(Debug, PartialEq)]
+    pub struct RolOtorgado {
+        #[ink(topic)]
+        cuenta: AccountId,
+        rol: Rol,
+    }
+
+    /// Evento emitido cuando el admin revoca un rol a una cuenta
+    #[ink(event)]
+    #[derive(Debug, PartialEq)]
+    pub struct RolRevocado {
+        #[ink(topic)]
+        cuenta: AccountId,
+        rol: Rol,
+    }
+
+    /// Evento emitido cuando un usuario se postula al sistema
+    #[ink(event)]
+    #[derive(Debug, PartialEq)]
+    pub struct UsuarioPostulado {
+        #[ink(topic)]
+        usuario: AccountId,
+    }
+
+    /// Evento emitido cuando el admin acepta a un usuario postulado
+    #[ink(event)]
+    #[derive(Debug, PartialEq)]
+    pub struct UsuarioAceptado {
+        #[ink(topic)]
+        usuario: AccountId,
+    }
+
+    /// Evento emitido cuando un usuario actualiza sus datos.
+    /// revalidar indica si el usuario volvió a la lista de usuarios por aceptar
+    #[ink(event)]
+    #[derive(Debug, PartialEq)]
+    pub struct UsuarioActualizado {
+        #[ink(topic)]
+        usuario: AccountId,
+        revalidar: bool,
+    }
+
+    /// Evento emitido cuando un usuario se da de baja
+    #[ink(event)]
+    #[derive(Debug, PartialEq)]
+    pub struct UsuarioDadoDeBaja {
+        #[ink(topic)]
+        usuario: AccountId,
+    }
+
+    /// Evento emitido cuando se rechaza a un usuario postulado
+    #[ink(event)]
+    #[derive(Debug, PartialEq)]
+    pub struct UsuarioRechazado {
+        #[ink(topic)]
+        usuario: AccountId,
+        motivo: MotivoRechazo,
+    }
+
+    /// Evento emitido cuando un usuario se postula como candidato a una elección
+    #[ink(event)]
+    #[derive(Debug, PartialEq)]
+    pub struct CandidatoPostulado {
+        #[ink(topic)]
+        id_eleccion: u32,
+        #[ink(topic)]
+        candidato: AccountId,
+    }
+
+    /// Evento emitido cuando una autoridad habilita usuarios para postularse a una elección
+    #[ink(event)]
+    #[derive(Debug, PartialEq)]
+    pub struct UsuariosHabilitados {
+        #[ink(topic)]
+        id_eleccion: u32,
+        usuarios: Vec<AccountId>,
+    }
+
+    /// Evento emitido cuando un usuario se postula como votante a una elección
+    #[ink(event)]
+    #[derive(Debug, PartialEq)]
+    pub struct VotantePostulado {
+        #[ink(topic)]
+        id_eleccion: u32,
+        #[ink(topic)]
+        votante: AccountId,
+    }
+
+    /// Evento emitido cuando el admin acepta a un candidato postulado
+    #[ink(event)]
+    #[derive(Debug, PartialEq)]
+    pub struct CandidatoAgregado {
+        #[ink(topic)]
+        id_eleccion: u32,
+        #[ink(topic)]
+        candidato: AccountId,
+    }
+
+    /// Evento emitido cuando el admin acepta a un votante postulado
+    #[ink(event)]
+    #[derive(Debug, PartialEq)]
+    pub struct VotanteAgregado {
+        #[ink(topic)]
+        id_eleccion: u32,
+        #[ink(topic)]
+        votante: AccountId,
+    }
+
+    /// Evento emitido cuando se rechaza a un candidato postulado
+    #[ink(event)]
+    #[derive(Debug, PartialEq)]
+    pub struct CandidatoRechazado {
+        #[ink(topic)]
+        id_eleccion: u32,
+        #[ink(topic)]
+        candidato: AccountId,
+        motivo: MotivoRechazo,
+    }
+
+    /// Evento emitido cuando se rechaza a un votante postulado
+    #[ink(event)]
+    #[derive(Debug, PartialEq)]
+    pub struct VotanteRechazado {
+        #[ink(topic)]
+        id_eleccion: u32,
+        #[ink(topic)]
+        votante: AccountId,
+        motivo: MotivoRechazo,
+    }
+
+    /// Evento emitido cuando un candidato se retira de una elección
+    #[ink(event)]
+    #[derive(Debug, PartialEq)]
+    pub struct CandidatoRetirado {
+        #[ink(topic)]
+        id_eleccion: u32,
+        #[ink(topic)]
+        candidato: AccountId,
+    }
+
+    /// Evento emitido cuando un votante se retira de una elección
+    #[ink(event)]
+    #[derive(Debug, PartialEq)]
+    pub struct VotanteRetirado {
+        #[ink(topic)]
+        id_eleccion: u32,
+        #[ink(topic)]
+        votante: AccountId,
+    }
+
+    /// Evento emitido cuando un votante emite su voto.
+    /// No incluye el candidato elegido para no exponer resultados parciales
+    #[ink(event)]
+    #[derive(Debug, PartialEq)]
+    pub struct VotoEmitido {
+        #[ink(topic)]
+        id_eleccion: u32,
+        #[ink(topic)]
+        votante: AccountId,
+    }
+
+    /// Evento emitido cuando un votante delega su voto en otro votante
+    #[ink(event)]
+    #[derive(Debug, PartialEq)]
+    pub struct VotoDelegado {
+        #[ink(topic)]
+        id_eleccion: u32,
+        #[ink(topic)]
+        votante: AccountId,
+        delegado: AccountId,
+    }
+
+    /// Evento emitido cuando un votante revoca la delegación de su voto
+    #[ink(event)]
+    #[derive(Debug, PartialEq)]
+    pub struct DelegacionRevocada {
+        #[ink(topic)]
+        id_eleccion: u32,
+        #[ink(topic)]
+        votante: AccountId,
+    }
+
+    /// Evento emitido cuando un votante revela su voto secreto
+    #[ink(event)]
+    #[derive(Debug, PartialEq)]
+    pub struct VotoRevelado {
+        #[ink(topic)]
+        id_eleccion: u32,
+        #[ink(topic)]
+        votante: AccountId,
+    }
+
+    /// Evento emitido cuando se nomina al nuevo admin del contrato
+    #[ink(event)]
+    #[derive(Debug, PartialEq)]
+    pub struct AdminNominado {
+        #[ink(topic)]
+        admin: AccountId,
+        #[ink(topic)]
+        admin_nominado: AccountId,
+    }
+
+    /// Evento emitido cuando se cancela la nominación del nuevo admin
+    #[ink(event)]
+    #[derive(Debug, PartialEq)]
+    pub struct NominacionAdminCancelada {
+        #[ink(topic)]
+        admin_nominado: AccountId,
+    }
+
+    /// Evento emitido cuando el admin nominado acepta y se cambia el admin del contrato
+    #[ink(event)]
+    #[derive(Debug, PartialEq)]
+    pub struct AdminCambiado {
+        #[ink(topic)]
+        admin_anterior: AccountId,
+        #[ink(topic)]
+        admin_nuevo: AccountId,
+    }
+
+    /// Evento emitido cuando se cambia el AccountId del contrato de reportes
+    #[ink(event)]
+    #[derive(Debug, PartialEq)]
+    pub struct ReporteCambiado {
+        #[ink(topic)]
+        reporte_anterior: AccountId,
+        #[ink(topic)]
+        reporte_nuevo: AccountId,
+    }
+
+    impl Eleccion {
+        /// Aplica el cambio de estado decidido por el admin y devuelve el nuevo estado
+        fn cambiar_estado(
             &mut self,
-            id_candidato: AccountId,
+            cambio: CambioEstado,
             current_time: &Fecha,
-        ) -> Result<()> {
-            if self.get_finalizada(current_time) {
-                return Err(VotacionError::EleccionYaFinalizada);
+        ) -> Result<EstadoEleccion> {
+            match cambio {
+                CambioEstado::Cancelar => {
+                    self.cancelar()?;
+                    Ok(EstadoEleccion::Cancelada)
+                }
+                CambioEstado::Suspender => {
+                    self.suspender(current_time)?;
+                    Ok(EstadoEleccion::Suspendida)
+                }
+                CambioEstado::Reanudar => self.reanudar(current_time),
+                CambioEstado::Certificar => {
+                    self.certificar(current_time)?;
+                    Ok(EstadoEleccion::Certificada)
+                }
             }
+        }
 
-            if self.get_inicio(current_time) {
-                return Err(VotacionError::EleccionYaIniciada);
+        pub fn new(id: u32, fecha_inicio: Fecha, fecha_fin: Fecha, config: ConfigEleccion) -> Self {
+            Eleccion {
+                id,
+                cantidad_votantes: 0,
+                cantidad_candidatos: 0,
+                votantes_retirados: 0,
+                candidatos_retirados: 0,
+                cantidad_votos: 0,
+                cantidad_revelados: 0,
+                cantidad_listas: 0,
+                votos_blanco: 0,
+                votos_nulos: 0,
+                votos_delegados: 0,
+                peso_votantes: 0,
+                votos_ponderados: 0,
+                fecha_inicio,
+                fecha_fin,
+                config,
+                primera_vuelta: None,
+                resultado_balotaje: None,
+                primer_llamado: None,
+                resultado_quorum: None,
+                semilla_desempate: None,
+                estado: EstadoEleccion::Borrador,
+                cantidad_impugnaciones: 0,
+                impugnaciones_pendientes: 0,
+                impugnaciones_aceptadas: 0,
             }
+        }
 
-            if self.is_candidato(&id_candidato) {
-                return Err(VotacionError::UsuarioEsCandidato);
+        /// Devuelve error si el admin suspendió, certificó, canceló o invalidó la elección
+        fn validar_estado_activo(&self) -> Result<()> {
+            match self.estado {
+                EstadoEleccion::Suspendida => Err(VotacionError::EleccionSuspendida),
+                EstadoEleccion::Certificada => Err(VotacionError::EleccionCertificada),
+                EstadoEleccion::Cancelada => Err(VotacionError::EleccionCancelada),
+                EstadoEleccion::Invalida => Err(VotacionError::EleccionInvalida),
+                _ => Ok(()),
             }
+        }
 
-            if self.is_postulado_candidato(&id_candidato) {
-                return Err(VotacionError::UsuarioEsPostuladoCandidato);
-            }
+        /// Devuelve si la elección alcanzó el quórum, None si no tiene regla de quórum
+        fn quorum_alcanzado(&self) -> Option<bool> {
+            self.config.quorum.as_ref().map(|regla| {
+                regla.alcanzado(
+                    self.get_cantidad_votos() + self.get_votos_delegados(),
+                    self.get_cantidad_votantes(),
+                )
+            })
+        }
 
-            if self.is_votante(&id_candidato) {
-                return Err(VotacionError::UsuarioEsVotante);
+        /// Devuelve error si el usuario ya participa de alguna forma en la elección
+        fn validar_sin_participar(estado: Option<EstadoParticipante>) -> Result<()> {
+            match estado {
+                None => Ok(()),
+                Some(EstadoParticipante::Candidato) => Err(VotacionError::UsuarioEsCandidato),
+                Some(EstadoParticipante::PostuladoCandidato) => {
+                    Err(VotacionError::UsuarioEsPostuladoCandidato)
+                }
+                Some(EstadoParticipante::Votante) => Err(VotacionError::UsuarioEsVotante),
+                Some(EstadoParticipante::PostuladoVotante) => {
+                    Err(VotacionError::UsuarioEsPostuladoVotante)
+                }
+                Some(EstadoParticipante::VotanteRetirado)
+                | Some(EstadoParticipante::CandidatoRetirado) => {
+                    Err(VotacionError::UsuarioRetirado)
+                }
             }
+        }
 
-            if self.is_postulado_votante(&id_candidato) {
-                return Err(VotacionError::UsuarioEsPostuladoVotante);
+        /// Devuelve error si la elección ya inició o ya finalizó
+        fn validar_periodo_postulacion(&self, current_time: &Fecha) -> Result<()> {
+            self.validar_estado_activo()?;
+
+            // Los participantes de una segunda vuelta se toman de la primera
+            if self.primera_vuelta.is_some() {
+                return Err(VotacionError::EleccionEsSegundaVuelta);
             }
 
-            // Añado candidato postulado
-            self.candidatos_sin_aceptar.push(id_candidato);
-            Ok(())
-        }
+            // Los participantes de un segundo llamado se toman del primero
+            if self.primer_llamado.is_some() {
+                return Err(VotacionError::EleccionEsSegundoLlamado);
+            }
 
-        fn agregar_candidato(
-            &mut self,
-            id_candidato: AccountId,
-            current_time: &Fecha,
-        ) -> Result<()> {
             if self.get_finalizada(current_time) {
                 return Err(VotacionError::EleccionYaFinalizada);
             }
@@ -370,51 +2238,35 @@ mod votacion {
                 return Err(VotacionError::EleccionYaIniciada);
             }
 
-            //Transfiero el id desde 'candidatos sin aceptar de la eleccion',
-            //-> hacia 'candidatos de la eleccion' (candidatos aceptados por el admin)
-            if let Some(pos) = self
-                .candidatos_sin_aceptar
-                .iter()
-                .position(|candidato| *candidato == id_candidato)
-            {
-                self.candidatos
-                    .push(self.candidatos_sin_aceptar.remove(pos));
-                self.votos.push((id_candidato, 0));
-                Ok(())
-            } else {
-                Err(VotacionError::UsuarioNoPostuladoCandidato)
-            }
+            Ok(())
         }
 
-        fn agregar_votante(&mut self, id_votante: AccountId, current_time: &Fecha) -> Result<()> {
-            if self.get_finalizada(current_time) {
-                return Err(VotacionError::EleccionYaFinalizada);
-            }
+        /// Devuelve error si no se puede postular a la elección o si la fecha
+        /// está fuera del período de inscripción
+        fn validar_periodo_inscripcion(&self, current_time: &Fecha) -> Result<()> {
+            self.validar_periodo_postulacion(current_time)?;
 
-            if self.get_inicio(current_time) {
-                return Err(VotacionError::EleccionYaIniciada);
-            }
+            if let Some(inscripcion) = &self.config.inscripcion {
+                let timestamp = current_time.timestamp().unwrap();
+                if timestamp < inscripcion.apertura.timestamp().unwrap() {
+                    return Err(VotacionError::InscripcionNoIniciada);
+                }
 
-            //Transfiero el id desde 'votantes sin aceptar de la eleccion',
-            //-> hacia 'votantes de la eleccion' (votantes aceptados por el admin)
-            if let Some(pos) = self
-                .votantes_sin_aceptar
-                .iter()
-                .position(|votante| *votante == id_votante)
-            {
-                self.votantes.push(self.votantes_sin_aceptar.remove(pos));
-                Ok(())
-            } else {
-                Err(VotacionError::UsuarioNoPostuladoVotante)
+                if timestamp > inscripcion.cierre.timestamp().unwrap() {
+                    return Err(VotacionError::InscripcionFinalizada);
+                }
             }
+            Ok(())
         }
 
-        fn votar(
-            &mut self,
-            id_votante: &AccountId,
-            id_candidato: &AccountId,
+        /// Devuelve error si la elección no está en curso o si el votante no puede votar
+        fn validar_votante(
+            &self,
+            estado_votante: Option<EstadoParticipante>,
             current_time: &Fecha,
         ) -> Result<()> {
+            self.validar_estado_activo()?;
+
             if self.get_finalizada(current_time) {
                 return Err(VotacionError::EleccionYaFinalizada);
             }
@@ -423,2364 +2275,11591 @@ mod votacion {
                 return Err(VotacionError::EleccionNoIniciada);
             }
 
-            if !self.is_votante(id_votante) {
+            if estado_votante != Some(EstadoParticipante::Votante) {
                 return Err(VotacionError::UsuarioNoEsVotante);
             }
 
-            if !self.is_candidato(id_candidato) {
-                return Err(VotacionError::UsuarioNoEsCandidato);
+            Ok(())
+        }
+
+        /// Devuelve error si la elección no admite delegaciones o si ya inició
+        fn validar_periodo_delegacion(&self, current_time: &Fecha) -> Result<()> {
+            // El peso de un voto delegado solo se suma en las elecciones simples y públicas
+            if self.config.tipo != TipoEleccion::Simple || self.es_secreta() {
+                return Err(VotacionError::TipoEleccionInvalido);
             }
 
-            if self.ya_voto(id_votante) {
-                return Err(VotacionError::UsuarioYaVoto);
+            self.validar_estado_activo()?;
+
+            if self.get_finalizada(current_time) {
+                return Err(VotacionError::EleccionYaFinalizada);
             }
 
-            self.votantes_voto.push(*id_votante);
-            if let Some((_, votos)) = self
-                .votos
-                .iter_mut()
-                .find(|(candidato, _)| candidato == id_candidato)
-            {
-                *votos += 1;
+            if self.get_inicio(current_time) {
+                return Err(VotacionError::EleccionYaIniciada);
             }
+
             Ok(())
         }
 
-        fn ya_voto(&self, id_votante: &AccountId) -> bool {
-            self.votantes_voto
-                .iter()
-                .any(|votante| votante == id_votante)
+        /// Suma votos delegados al voto de un delegado.
+        /// Los votos a candidatos se suman en el storage de Votacion
+        fn sumar_delegados(&mut self, destino: &DestinoVoto, delegados: &VotosDelegados) {
+            match destino {
+                DestinoVoto::SinCandidato(VotoSinCandidato::Blanco) => {
+                    self.votos_blanco += delegados.peso
+                }
+                DestinoVoto::SinCandidato(VotoSinCandidato::Nulo) => {
+                    self.votos_nulos += delegados.peso
+                }
+                DestinoVoto::Candidato(_) => {}
+            }
+            self.votos_delegados += delegados.cantidad;
+            self.votos_ponderados += delegados.peso;
         }
 
-        fn get_inicio(&self, current_time: &Fecha) -> bool {
-            // Si current_time >= fecha_inicio   -> true
-            // Le puedo hacer unwrap porque me fijo si es valida al momento de crear la eleccion
-            current_time.timestamp().unwrap() >= self.get_fecha_inicio().timestamp().unwrap()
+        /// Resta votos delegados del voto de un delegado.
+        /// Los votos a candidatos se restan en el storage de Votacion
+        fn restar_delegados(&mut self, destino: &DestinoVoto, delegados: &VotosDelegados) {
+            match destino {
+                DestinoVoto::SinCandidato(VotoSinCandidato::Blanco) => {
+                    self.votos_blanco -= delegados.peso
+                }
+                DestinoVoto::SinCandidato(VotoSinCandidato::Nulo) => {
+                    self.votos_nulos -= delegados.peso
+                }
+                DestinoVoto::Candidato(_) => {}
+            }
+            self.votos_delegados -= delegados.cantidad;
+            self.votos_ponderados -= delegados.peso;
         }
 
-        fn get_finalizada(&self, current_time: &Fecha) -> bool {
-            // Si fecha_act > fecha_fin -> true
-            // Le puedo hacer unwrap porque me fijo si es valida al momento de crear la eleccion
-            current_time.timestamp().unwrap() > self.get_fecha_fin().timestamp().unwrap()
+        /// Devuelve si la elección es de voto secreto
+        fn es_secreta(&self) -> bool {
+            self.config.fin_revelacion.is_some()
+        }
+
+        /// Devuelve si ya terminó el periodo de revelación de una elección secreta
+        fn get_revelacion_finalizada(&self, current_time: &Fecha) -> bool {
+            match &self.config.fin_revelacion {
+                // Le puedo hacer unwrap porque me fijo si es valida al momento de crear la eleccion
+                Some(fin) => current_time.timestamp().unwrap() > fin.timestamp().unwrap(),
+                None => true,
+            }
         }
 
-        fn get_votos_candidato(
+        /// Devuelve error si el participante es candidato o votante
+        /// y todavia no se conoce el resultado de la elección, salvo que esté cancelada
+        fn validar_baja(
             &self,
-            id_candidato: &AccountId,
+            estado: Option<EstadoParticipante>,
             current_time: &Fecha,
-        ) -> Result<u32> {
-            if !self.get_finalizada(current_time) {
-                return Err(VotacionError::EleccionNoFinalizada);
+        ) -> Result<()> {
+            match estado {
+                Some(EstadoParticipante::Candidato) | Some(EstadoParticipante::Votante)
+                    if !self.get_concluida(current_time) =>
+                {
+                    Err(VotacionError::UsuarioParticipaEnEleccion)
+                }
+                _ => Ok(()),
             }
+        }
 
-            if self.is_candidato(id_candidato) {
-                let votos_candidato = self
-                    .votos
-                    .iter()
-                    .find(|(candidato, _)| candidato == id_candidato)
-                    .map(|(_, votos)| *votos)
-                    .unwrap_or(0);
-                Ok(votos_candidato)
-            } else {
-                Err(VotacionError::UsuarioNoEsCandidato)
+        /// Devuelve si la elección fue cancelada, invalidada o certificada, o si ya se pueden
+        /// conocer sus votos y no queda pendiente una segunda vuelta o un segundo llamado que
+        /// copie a sus participantes, por lo que ya no la tienen en curso
+        fn get_concluida(&self, current_time: &Fecha) -> bool {
+            match self.estado {
+                EstadoEleccion::Cancelada
+                | EstadoEleccion::Invalida
+                | EstadoEleccion::Certificada => true,
+                _ => {
+                    self.validar_resultado(current_time).is_ok()
+                        && !self.tiene_eleccion_pendiente(current_time)
+                }
+            }
+        }
+
+        /// Devuelve si todavia se puede crear a partir de la elección una segunda vuelta,
+        /// porque no se aplicó su regla de balotaje, o un segundo llamado, porque no alcanzó
+        /// el quórum y no pasó la fecha de inicio del segundo llamado
+        fn tiene_eleccion_pendiente(&self, current_time: &Fecha) -> bool {
+            if self.quorum_alcanzado() == Some(false) {
+                return self
+                    .config
+                    .quorum
+                    .as_ref()
+                    .and_then(|regla| regla.segundo_llamado.as_ref())
+                    .is_some_and(|(fecha_inicio, _)| {
+                        current_time.timestamp().unwrap() <= fecha_inicio.timestamp().unwrap()
+                    });
             }
+
+            self.config.balotaje.is_some() && self.resultado_balotaje.is_none()
         }
 
-        fn get_votos(&self, current_time: &Fecha) -> Result<Vec<(AccountId, u32)>> {
+        /// Devuelve error si todavia no se pueden conocer los votos de la elección
+        fn validar_resultado(&self, current_time: &Fecha) -> Result<()> {
+            match self.estado {
+                EstadoEleccion::Suspendida => return Err(VotacionError::EleccionSuspendida),
+                EstadoEleccion::Cancelada => return Err(VotacionError::EleccionCancelada),
+                _ => {}
+            }
+
             if !self.get_finalizada(current_time) {
                 return Err(VotacionError::EleccionNoFinalizada);
             }
-            Ok(self.votos.clone())
-        }
-    }
 
-    impl GettersEleccion for Eleccion {
-        /// Devuelve el id de la elección
-        fn get_id(&self) -> u32 {
-            self.id
+            if !self.get_revelacion_finalizada(current_time) {
+                return Err(VotacionError::RevelacionNoFinalizada);
+            }
+
+            Ok(())
         }
 
-        /// Devuelve true si el id esta postulado como candidato
-        fn is_postulado_candidato(&self, id: &AccountId) -> bool {
-            self.candidatos_sin_aceptar
-                .iter()
-                .any(|candidato| candidato == id)
-        }
+        /// Devuelve error si el resultado todavia se puede impugnar, tiene impugnaciones
+        /// pendientes o fue impugnado con éxito
+        fn validar_impugnaciones(&self, current_time: &Fecha) -> Result<()> {
+            if let Some(fin_impugnacion) = &self.config.fin_impugnacion {
+                if current_time.timestamp().unwrap() <= fin_impugnacion.timestamp().unwrap() {
+                    return Err(VotacionError::PeriodoImpugnacionNoFinalizado);
+                }
+            }
 
-        /// Devuelve true si el id esta postulado como votante
-        fn is_postulado_votante(&self, id: &AccountId) -> bool {
-            self.votantes_sin_aceptar
-                .iter()
-                .any(|votante| votante == id)
-        }
+            if self.get_impugnaciones_pendientes() > 0 {
+                return Err(VotacionError::ImpugnacionesPendientes);
+            }
 
-        /// Dado un id , devuelve true si esta registrado como votante en la eleccion
-        fn is_votante(&self, id: &AccountId) -> bool {
-            self.votantes.iter().any(|votante| votante == id)
+            // Un resultado impugnado con éxito no se certifica, el admin debe cancelar la elección
+            if self.impugnaciones_aceptadas > 0 {
+                return Err(VotacionError::ResultadoImpugnado);
+            }
+            Ok(())
         }
 
-        /// Dado un id , devuelve true si esta registrado como candidato en la eleccion
-        fn is_candidato(&self, id: &AccountId) -> bool {
-            self.candidatos.iter().any(|candidato| candidato == id)
+        /// Devuelve error si la impugnación no existe o ya fue resuelta
+        fn validar_impugnacion_pendiente(estado: Option<EstadoImpugnacion>) -> Result<()> {
+            match estado {
+                None => Err(VotacionError::ImpugnacionNoEncontrada),
+                Some(EstadoImpugnacion::Pendiente) => Ok(()),
+                Some(_) => Err(VotacionError::ImpugnacionYaResuelta),
+            }
         }
 
-        /// Devuelve la fecha de inicio de la elección
-        fn get_fecha_inicio(&self) -> Fecha {
-            self.fecha_inicio.clone()
-        }
+        /// Devuelve error si algun usuario de la boleta no es candidato o está repetido
+        fn validar_boleta(candidatos: &[(AccountId, Option<EstadoParticipante>)]) -> Result<()> {
+            for (i, (candidato, estado)) in candidatos.iter().enumerate() {
+                if *estado != Some(EstadoParticipante::Candidato) {
+                    return Err(VotacionError::UsuarioNoEsCandidato);
+                }
 
-        /// Devuelve la fecha de fin de la elección
-        fn get_fecha_fin(&self) -> Fecha {
-            self.fecha_fin.clone()
+                if candidatos[..i].iter().any(|(otro, _)| otro == candidato) {
+                    return Err(VotacionError::CandidatoRepetido);
+                }
+            }
+
+            Ok(())
         }
     }
 
-    impl ReportMessageEleccion for Eleccion {
-        /// Devuelve un listado de los id de los votantes aprobados
-        fn reporte_registro_votantes(&self) -> Vec<AccountId> {
-            self.votantes.clone()
+    impl EleccionSystem for Eleccion {
+        fn postular_votante(
+            &self,
+            estado: Option<EstadoParticipante>,
+            usuario: &Usuario,
+            habilitado: bool,
+            current_time: &Fecha,
+        ) -> Result<()> {
+            self.validar_periodo_inscripcion(current_time)?;
+            Eleccion::validar_sin_participar(estado)?;
+            self.config
+                .elegibilidad
+                .validar_votante(usuario.calcular_edad(&self.fecha_inicio), habilitado)
         }
 
-        fn reporte_participacion(&self, current_time: &Fecha) -> Result<(u128, u128)> {
-            if !self.get_finalizada(current_time) {
-                return Err(VotacionError::EleccionNoFinalizada);
-            }
+        fn postular_candidato(
+            &self,
+            estado: Option<EstadoParticipante>,
+            usuario: &Usuario,
+            habilitado: bool,
+            current_time: &Fecha,
+        ) -> Result<()> {
+            self.validar_periodo_inscripcion(current_time)?;
+            Eleccion::validar_sin_participar(estado)?;
+            self.config
+                .elegibilidad
+                .validar_candidato(usuario.calcular_edad(&self.fecha_inicio), habilitado)
+        }
 
-            let num_votantes = self.votantes.len() as u128;
-            let num_votantes_voto = self.votantes_voto.len() as u128;
+        fn habilitar_usuarios(&self, current_time: &Fecha) -> Result<()> {
+            self.validar_periodo_postulacion(current_time)?;
 
-            Ok((num_votantes, num_votantes_voto))
+            if !self.config.elegibilidad.solo_habilitados {
+                return Err(VotacionError::EleccionSinHabilitados);
+            }
+            Ok(())
         }
 
-        fn reporte_resultado(&self, current_time: &Fecha) -> Result<Vec<(AccountId, u32)>> {
-            let resultados = self.get_votos(current_time)?;
-            Ok(resultados)
-        }
-    }
+        fn agregar_candidato(
+            &mut self,
+            estado: Option<EstadoParticipante>,
+            current_time: &Fecha,
+        ) -> Result<u32> {
+            self.validar_periodo_postulacion(current_time)?;
 
-    impl Usuario {
-        pub fn new(
-            addres: AccountId,
-            nombre: String,
-            apellido: String,
-            direccion: String,
-            dni: String,
-            edad: u8,
-        ) -> Self {
-            Usuario {
-                addres,
-                nombre,
-                apellido,
-                direccion,
-                dni,
-                edad,
+            if estado != Some(EstadoParticipante::PostuladoCandidato) {
+                return Err(VotacionError::UsuarioNoPostuladoCandidato);
             }
-        }
-    }
 
-    impl GettersUsuario for Usuario {
-        /// Devuelve el id del usuario
-        fn get_addres(&self) -> AccountId {
-            self.addres
+            let posicion = self.cantidad_candidatos;
+            self.cantidad_candidatos += 1;
+            Ok(posicion)
         }
 
-        /// Devuelve el nombre del usuario
-        fn get_nombre(&self) -> String {
-            self.nombre.clone()
-        }
+        fn agregar_votante(
+            &mut self,
+            estado: Option<EstadoParticipante>,
+            peso: u64,
+            current_time: &Fecha,
+        ) -> Result<u32> {
+            self.validar_periodo_postulacion(current_time)?;
 
-        /// Devuelve el apellido del usuario
-        fn get_apellido(&self) -> String {
-            self.apellido.clone()
-        }
+            if estado != Some(EstadoParticipante::PostuladoVotante) {
+                return Err(VotacionError::UsuarioNoPostuladoVotante);
+            }
 
-        /// Devuelve la direccion del usuario
-        fn get_direccion(&self) -> String {
-            self.direccion.clone()
-        }
+            // Las boletas preferenciales se cuentan de a una en cada ronda
+            if peso == 0 || (self.config.tipo == TipoEleccion::Preferencial && peso != 1) {
+                return Err(VotacionError::PesoInvalido);
+            }
 
-        /// Devuelve el dni del usuario
-        fn get_dni(&self) -> String {
-            self.dni.clone()
+            let posicion = self.cantidad_votantes;
+            self.cantidad_votantes += 1;
+            self.peso_votantes += peso as u128;
+            Ok(posicion)
         }
 
-        /// Devuelve la edad del usuario
-        fn get_edad(&self) -> u8 {
-            self.edad
-        }
-    }
+        fn rechazar_candidato(&self, estado: Option<EstadoParticipante>) -> Result<()> {
+            self.validar_estado_activo()?;
 
-    impl Votacion {
-        /// Constructor por defecto
-        #[cfg(test)]
-        pub fn new() -> Self {
-            Self {
-                admin: Self::env().caller(),
-                reporte: AccountId::from([0x10; 32]),
-                elecciones: Vec::new(),
-                usuarios: Vec::new(),
-                usuarios_sin_aceptar: Vec::new(),
+            if estado != Some(EstadoParticipante::PostuladoCandidato) {
+                return Err(VotacionError::UsuarioNoPostuladoCandidato);
             }
+            Ok(())
         }
 
-        /// Constructor del contrato
-        #[ink(constructor)]
-        #[cfg(not(test))]
-        pub fn new(reporte: AccountId) -> Self {
-            Self {
-                admin: Self::env().caller(),
-                reporte,
-                elecciones: Vec::new(),
-                usuarios: Vec::new(),
-                usuarios_sin_aceptar: Vec::new(),
+        fn retirar_candidato(
+            &mut self,
+            estado: Option<EstadoParticipante>,
+            en_lista: bool,
+            current_time: &Fecha,
+        ) -> Result<()> {
+            self.validar_estado_activo()?;
+
+            if self.get_finalizada(current_time) {
+                return Err(VotacionError::EleccionYaFinalizada);
             }
-        }
 
-        /// Cambia el administrador del contrato al nuevo pasado por parametro
-        /// solo el admin puede cambiar el admin
-        ///
-        /// # Errores
-        /// Devuelve un error si el caller no es el admin
-        #[ink(message)]
-        pub fn set_admin(&mut self, new_admin: AccountId) -> Result<()> {
-            if !self.caller_is_admin() {
-                return Err(VotacionError::NoEsAdmin);
+            if estado != Some(EstadoParticipante::Candidato) {
+                return Err(VotacionError::UsuarioNoEsCandidato);
+            }
+
+            // Los cargos de una lista se asignan en el orden de sus candidatos
+            if en_lista {
+                return Err(VotacionError::CandidatoEnLista);
+            }
+
+            if self.get_inicio(current_time) {
+                // Solo en estos tipos los votos de cada candidato se cuentan por separado
+                if self.config.tipo != TipoEleccion::Simple
+                    && self.config.tipo != TipoEleccion::Aprobacion
+                {
+                    return Err(VotacionError::TipoEleccionInvalido);
+                }
+
+                // Una vez abierta la votación deben quedar candidatos para todos los cargos
+                if self.get_cantidad_candidatos() <= self.get_cantidad_cargos() {
+                    return Err(VotacionError::CandidatosInsuficientes);
+                }
             }
 
-            self.admin = new_admin;
+            self.candidatos_retirados += 1;
             Ok(())
         }
 
-        /// Cambia el AccountId del reporte al nuevo pasado por parametro
-        /// solo el admin puede cambiar el reporte
-        ///
-        /// # Errores
-        /// Devuelve un error si el caller no es el admin
-        #[ink(message)]
-        pub fn set_reporte(&mut self, new_reporte: AccountId) -> Result<()> {
-            if !self.caller_is_admin() {
-                return Err(VotacionError::NoEsAdmin);
+        fn retirar_votante(
+            &mut self,
+            estado: Option<EstadoParticipante>,
+            peso: u64,
+            current_time: &Fecha,
+        ) -> Result<()> {
+            self.validar_periodo_postulacion(current_time)?;
+
+            if estado != Some(EstadoParticipante::Votante) {
+                return Err(VotacionError::UsuarioNoEsVotante);
             }
 
-            self.reporte = new_reporte;
+            self.votantes_retirados += 1;
+            self.peso_votantes -= peso as u128;
             Ok(())
         }
 
-        /// Devuelve true si el caller es el reporte, false en cualquier otro caso
-        fn caller_is_reporte(&self) -> bool {
-            self.env().caller() == self.reporte
+        fn rechazar_votante(&self, estado: Option<EstadoParticipante>) -> Result<()> {
+            self.validar_estado_activo()?;
+
+            if estado != Some(EstadoParticipante::PostuladoVotante) {
+                return Err(VotacionError::UsuarioNoPostuladoVotante);
+            }
+            Ok(())
         }
-    }
 
-    impl EleccionManager for Votacion {
-        /// Crea una elección y la agrega a la lista de elecciones
-        /// devuelve el id de la elección creada
-        ///
-        /// # Errores
-        /// - Devuelve un error si el caller no es el admin
-        /// - Devuelve un error si la fecha de inicio o fin no es válida
-        /// - Devuelve un error si la fecha de inicio es mayor que la fecha de fin
-        #[ink(message)]
-        fn crear_eleccion(&mut self, fecha_inicio: Fecha, fecha_fin: Fecha) -> Result<u32> {
-            if !self.caller_is_admin() {
-                return Err(VotacionError::NoEsAdmin);
+        fn votar(
+            &mut self,
+            estado_votante: Option<EstadoParticipante>,
+            peso: u64,
+            estado_candidato: Option<EstadoParticipante>,
+            ya_voto: bool,
+            current_time: &Fecha,
+        ) -> Result<()> {
+            if self.config.tipo != TipoEleccion::Simple || self.es_secreta() {
+                return Err(VotacionError::TipoEleccionInvalido);
             }
 
-            if !fecha_inicio.es_fecha_valida() || !fecha_fin.es_fecha_valida() {
-                return Err(VotacionError::FechaInvalida);
+            self.validar_votante(estado_votante, current_time)?;
+
+            if estado_candidato != Some(EstadoParticipante::Candidato) {
+                return Err(VotacionError::UsuarioNoEsCandidato);
             }
 
-            if fecha_inicio.timestamp().unwrap() > fecha_fin.timestamp().unwrap() {
-                return Err(VotacionError::FechaInicioMayorQueFin);
+            if ya_voto {
+                return Err(VotacionError::UsuarioYaVoto);
             }
 
-            let id = self.elecciones.len() as u32;
-            let eleccion = Eleccion::new(id, fecha_inicio, fecha_fin);
-            self.elecciones.push(eleccion.clone());
-            Ok(id)
+            self.cantidad_votos += 1;
+            self.votos_ponderados += peso as u128;
+            Ok(())
         }
 
-        /// Devuelve una elección por su ID, None si la elección no se encuentra
-        #[ink(message)]
-        fn get_eleccion(&self, id: u32) -> Option<Eleccion> {
-            self.elecciones.get(id as usize).cloned()
-        }
-    }
+        fn votar_preferencial(
+            &mut self,
+            estado_votante: Option<EstadoParticipante>,
+            preferencias: &[(AccountId, Option<EstadoParticipante>)],
+            ya_voto: bool,
+            current_time: &Fecha,
+        ) -> Result<u32> {
+            if self.config.tipo != TipoEleccion::Preferencial {
+                return Err(VotacionError::TipoEleccionInvalido);
+            }
 
-    impl UserManager for Votacion {
-        /// Devuelve true si el caller es el admin del contrato, false en cualquier otro caso
-        #[ink(message)]
-        fn caller_is_admin(&self) -> bool {
-            self.admin == self.env().caller()
+            self.validar_votante(estado_votante, current_time)?;
+
+            if preferencias.is_empty() {
+                return Err(VotacionError::PreferenciasVacias);
+            }
+
+            Eleccion::validar_boleta(preferencias)?;
+
+            if ya_voto {
+                return Err(VotacionError::UsuarioYaVoto);
+            }
+
+            let numero_boleta = self.cantidad_votos;
+            self.cantidad_votos += 1;
+            self.votos_ponderados += 1;
+            Ok(numero_boleta)
         }
 
-        /// Crea un usuario y lo agrega a la lista de usuarios_por_aceptar
-        /// Devuelve el usuario creado si no hay errores
-        ///
-        /// # Errores
-        /// - Devuelve un error si el usuario ya está registrado
-        /// - Devuelve un error si el usuario ya está postulado
-        #[ink(message)]
-        fn postular_usuario(
+        fn votar_aprobacion(
             &mut self,
-            nombre: String,
-            apellido: String,
-            direccion: String,
-            dni: String,
-            edad: u8,
-        ) -> Result<Usuario> {
-            let id = self.env().caller();
+            estado_votante: Option<EstadoParticipante>,
+            peso: u64,
+            aprobados: &[(AccountId, Option<EstadoParticipante>)],
+            ya_voto: bool,
+            current_time: &Fecha,
+        ) -> Result<()> {
+            if self.config.tipo != TipoEleccion::Aprobacion {
+                return Err(VotacionError::TipoEleccionInvalido);
+            }
 
-            if self.get_usuario(id).is_ok() {
-                return Err(VotacionError::UsuarioYaRegistrado);
+            self.validar_votante(estado_votante, current_time)?;
+            Eleccion::validar_boleta(aprobados)?;
+
+            if ya_voto {
+                return Err(VotacionError::UsuarioYaVoto);
             }
 
-            if self.get_usuario_sin_aceptar(id).is_ok() {
-                return Err(VotacionError::UsuarioNoAceptado);
+            self.cantidad_votos += 1;
+            self.votos_ponderados += peso as u128;
+            Ok(())
+        }
+
+        fn agregar_lista(
+            &mut self,
+            candidatos: &[(AccountId, Option<EstadoParticipante>)],
+            en_otra_lista: bool,
+            current_time: &Fecha,
+        ) -> Result<u32> {
+            if self.config.tipo != TipoEleccion::Listas {
+                return Err(VotacionError::TipoEleccionInvalido);
             }
 
-            let usuario = Usuario::new(id, nombre, apellido, direccion, dni, edad);
+            self.validar_periodo_postulacion(current_time)?;
 
-            self.usuarios_sin_aceptar.push(usuario.clone());
-            Ok(usuario)
+            if candidatos.is_empty() {
+                return Err(VotacionError::ListaVacia);
+            }
+
+            Eleccion::validar_boleta(candidatos)?;
+
+            if en_otra_lista {
+                return Err(VotacionError::CandidatoEnOtraLista);
+            }
+
+            let posicion = self.cantidad_listas;
+            self.cantidad_listas += 1;
+            Ok(posicion)
         }
 
-        /// Acepta un usuario de la lista usuarios_por_aceptar y lo agrega a la lista de usuarios
-        ///
-        /// # Errores
-        /// - Devuelve un error si el caller no es el admin
-        /// - Devuelve un error si el usuario no se encuentra en la lista de usuarios postulados
-        #[ink(message)]
-        fn aceptar_usuario(&mut self, id: AccountId) -> Result<()> {
-            if !self.caller_is_admin() {
-                return Err(VotacionError::NoEsAdmin);
+        fn votar_lista(
+            &mut self,
+            estado_votante: Option<EstadoParticipante>,
+            peso: u64,
+            id_lista: u32,
+            ya_voto: bool,
+            current_time: &Fecha,
+        ) -> Result<()> {
+            if self.config.tipo != TipoEleccion::Listas {
+                return Err(VotacionError::TipoEleccionInvalido);
             }
 
-            if let Some(pos) = self
-                .usuarios_sin_aceptar
-                .iter()
-                .position(|usuario| usuario.addres == id)
-            {
-                self.usuarios.push(self.usuarios_sin_aceptar.remove(pos));
-                Ok(())
-            } else {
-                Err(VotacionError::UsuarioSinAceptarNoEncontrado)
+            self.validar_votante(estado_votante, current_time)?;
+
+            if id_lista >= self.cantidad_listas {
+                return Err(VotacionError::ListaNoEncontrada);
             }
-        }
 
-        /// Obtiene un usuario sin aceptar por su id
-        ///
-        /// # Errores
-        /// Devuelve un error si no se encuentra el usuario
-        #[ink(message)]
-        fn get_usuario_sin_aceptar(&self, id: AccountId) -> Result<Usuario> {
-            self.usuarios_sin_aceptar
-                .iter()
-                .find(|usuario| usuario.addres == id)
-                .cloned()
-                .ok_or(VotacionError::UsuarioSinAceptarNoEncontrado)
+            if ya_voto {
+                return Err(VotacionError::UsuarioYaVoto);
+            }
+
+            self.cantidad_votos += 1;
+            self.votos_ponderados += peso as u128;
+            Ok(())
         }
 
-        /// Obtiene un usuario por su id
-        ///
-        /// # Errores
-        /// Devuelve un error si no se encuentra el usuario
-        #[ink(message)]
-        fn get_usuario(&self, id: AccountId) -> Result<Usuario> {
-            self.usuarios
-                .iter()
-                .find(|usuario| usuario.addres == id)
-                .cloned()
-                .ok_or(VotacionError::UsuarioNoEncontrado)
-        }
-    }
+        fn votar_sin_candidato(
+            &mut self,
+            estado_votante: Option<EstadoParticipante>,
+            peso: u64,
+            voto: VotoSinCandidato,
+            ya_voto: bool,
+            current_time: &Fecha,
+        ) -> Result<()> {
+            // En una elección secreta un voto en blanco o nulo revelaría la elección del votante
+            if self.es_secreta() {
+                return Err(VotacionError::TipoEleccionInvalido);
+            }
 
-    impl EleccionSystemInk for Votacion {
-        /// Postula un candidato a la eleccion con el id pasado por parametro
-        ///
-        /// # Errores
-        /// - Devuelve un error si el usuario no esta aceptado
-        /// - Devuelve un error si el usuario no es encontrado
-        /// - Devuelve un error si la eleccion no se encuentra
-        /// - Devuelve un error si la eleccion ya finalizo
-        /// - Devuelve un error si la eleccion ya inicio
-        /// - Devuelve un error si el usuario ya está registrado como candidato
-        /// - Devuelve un error si el usuario ya está postulado como candidato
-        /// - Devuelve un error si el usuario ya está registrado como votante
-        /// - Devuelve un error si el usuario ya está postulado como votante
-        #[ink(message)]
-        fn postular_candidato(&mut self, id_eleccion: u32) -> Result<()> {
-            let usuario_actual = self.env().caller();
+            self.validar_votante(estado_votante, current_time)?;
 
-            if self.get_usuario_sin_aceptar(usuario_actual).is_ok() {
-                return Err(VotacionError::UsuarioNoAceptado);
+            if ya_voto {
+                return Err(VotacionError::UsuarioYaVoto);
             }
 
-            if self.get_usuario(usuario_actual).is_err() {
-                return Err(VotacionError::UsuarioNoEncontrado);
+            match voto {
+                VotoSinCandidato::Blanco => self.votos_blanco += peso as u128,
+                VotoSinCandidato::Nulo => self.votos_nulos += peso as u128,
             }
+            self.cantidad_votos += 1;
+            self.votos_ponderados += peso as u128;
+            Ok(())
+        }
 
-            let timestamp = self.env().block_timestamp();
+        fn delegar_voto(
+            &self,
+            estado_votante: Option<EstadoParticipante>,
+            estado_delegado: Option<EstadoParticipante>,
+            genera_ciclo: bool,
+            current_time: &Fecha,
+        ) -> Result<()> {
+            self.validar_periodo_delegacion(current_time)?;
 
-            if let Some(eleccion) = self.elecciones.get_mut(id_eleccion as usize) {
-                eleccion.postular_candidato(usuario_actual, &Fecha::from_timestamp(timestamp))
-            } else {
-                Err(VotacionError::EleccionNoEncontrada)
+            if estado_votante != Some(EstadoParticipante::Votante) {
+                return Err(VotacionError::UsuarioNoEsVotante);
             }
-        }
-
-        /// Postula un votante a la eleccion con el id pasado por parametro
-        ///
-        /// # Errores
-        /// - Devuelve un error si el usuario no esta aceptado
-        /// - Devuelve un error si el usuario no es encontrado
-        /// - Devuelve un error si la eleccion no es encontrada
-        /// - Devuelve un error si la eleccion ya finalizo
-        /// - Devuelve un error si la eleccion ya inicio
-        /// - Devuelve un error si el usuario ya está registrado como candidato
-        /// - Devuelve un error si el usuario ya está postulado como candidato
-        /// - Devuelve un error si el usuario ya está registrado como votante
-        /// - Devuelve un error si el usuario ya está postulado como votante
-        #[ink(message)]
-        fn postular_votante(&mut self, id_eleccion: u32) -> Result<()> {
-            let usuario_actual = self.env().caller();
 
-            if self.get_usuario_sin_aceptar(usuario_actual).is_ok() {
-                return Err(VotacionError::UsuarioNoAceptado);
+            if estado_delegado != Some(EstadoParticipante::Votante) {
+                return Err(VotacionError::DelegadoNoEsVotante);
             }
 
-            if self.get_usuario(usuario_actual).is_err() {
-                return Err(VotacionError::UsuarioNoEncontrado);
+            if genera_ciclo {
+                return Err(VotacionError::DelegacionCiclica);
             }
 
-            let timestamp = self.env().block_timestamp();
+            Ok(())
+        }
 
-            if let Some(eleccion) = self.elecciones.get_mut(id_eleccion as usize) {
-                eleccion.postular_votante(usuario_actual, &Fecha::from_timestamp(timestamp))
-            } else {
-                Err(VotacionError::EleccionNoEncontrada)
+        fn revocar_delegacion(&self, delego: bool, current_time: &Fecha) -> Result<()> {
+            self.validar_periodo_delegacion(current_time)?;
+
+            if !delego {
+                return Err(VotacionError::VotoNoDelegado);
             }
+
+            Ok(())
         }
 
-        /// Acepta un candidato postulado a la eleccion con el id pasado por parametro
-        ///
-        /// # Errores
-        /// - Devuelve un error si el caller no es el admin
-        /// - Devuelve un error si el usuario no es encontrado
-        /// - Devuelve un error si la eleccion no es encontrada
-        /// - Devuelve un error si la eleccion ya finalizo
-        /// - Devuelve un error si la eleccion ya inicio
-        /// - Devuelve un error si el usuario no está postulado como candidato
-        #[ink(message)]
-        fn agregar_candidato(&mut self, id_eleccion: u32, id_candidato: AccountId) -> Result<()> {
-            if !self.caller_is_admin() {
-                return Err(VotacionError::NoEsAdmin);
+        fn aplicar_balotaje(
+            &self,
+            votos: &[(AccountId, u128)],
+            votos_conservados: u128,
+            current_time: &Fecha,
+        ) -> Result<Option<AccountId>> {
+            let regla = self
+                .config
+                .balotaje
+                .as_ref()
+                .ok_or(VotacionError::EleccionSinBalotaje)?;
+
+            self.validar_resultado(current_time)?;
+
+            if self.resultado_balotaje.is_some() {
+                return Err(VotacionError::BalotajeYaAplicado);
             }
 
-            if self.get_usuario(id_candidato).is_err() {
-                return Err(VotacionError::UsuarioNoEncontrado);
+            if self.quorum_alcanzado() == Some(false) {
+                return Err(VotacionError::QuorumNoAlcanzado);
             }
 
-            let timestamp = self.env().block_timestamp();
+            self.validar_impugnaciones(current_time)?;
 
-            if let Some(eleccion) = self.elecciones.get_mut(id_eleccion as usize) {
-                eleccion.agregar_candidato(id_candidato, &Fecha::from_timestamp(timestamp))
-            } else {
-                Err(VotacionError::EleccionNoEncontrada)
-            }
-        }
+            let (primero, segundo) = match votos {
+                [primero, segundo, ..] => (primero, segundo),
+                _ => return Err(VotacionError::CandidatosInsuficientes),
+            };
+            let total = votos.iter().map(|(_, votos)| votos).sum::<u128>() + votos_conservados;
 
-        /// Acepta un votante postulado a la eleccion con el id pasado por parametro
-        ///
-        /// # Errores
-        /// - Devuelve un error si el caller no es el admin
-        /// - Devuelve un error si el usuario no es encontrado
-        /// - Devuelve un error si la eleccion no es encontrada
-        /// - Devuelve un error si la eleccion ya finalizo
-        /// - Devuelve un error si la eleccion ya inicio
-        /// - Devuelve un error si el usuario no está postulado como votante
-        #[ink(message)]
-        fn agregar_votante(&mut self, id_eleccion: u32, id_votante: AccountId) -> Result<()> {
-            if !self.caller_is_admin() {
-                return Err(VotacionError::NoEsAdmin);
+            if self.config.desempate != PoliticaDesempate::SegundaVuelta {
+                return Ok(regla
+                    .gana_primera_vuelta(primero.1, segundo.1, total)
+                    .then_some(primero.0));
             }
 
-            if self.get_usuario(id_votante).is_err() {
-                return Err(VotacionError::UsuarioNoEncontrado);
+            // Con SegundaVuelta los empatados comparten la posición: un empate en el primer
+            // puesto va a la segunda vuelta y uno en el segundo no permite elegir finalistas
+            if primero.1 > segundo.1 && regla.gana_primera_vuelta(primero.1, segundo.1, total) {
+                return Ok(Some(primero.0));
             }
 
-            let timestamp = self.env().block_timestamp();
-
-            if let Some(eleccion) = self.elecciones.get_mut(id_eleccion as usize) {
-                eleccion.agregar_votante(id_votante, &Fecha::from_timestamp(timestamp))
-            } else {
-                Err(VotacionError::EleccionNoEncontrada)
+            match votos.get(2) {
+                Some(tercero) if tercero.1 == segundo.1 => Err(VotacionError::EmpateFinalistas),
+                _ => Ok(None),
             }
         }
 
-        /// El votante (caller) vota por un candidato en una eleccion con el id pasado por parametro
-        ///
-        /// # Errores
-        /// - Devuelve un error si el votante no es encontrado
-        /// - Devuelve un error si el candidato no es encontrado
-        /// - Devuelve un error si la eleccion no es encontrada
-        /// - Devuelve un error si la eleccion ya finalizo
-        /// - Devuelve un error si la eleccion no inicio
-        /// - Devuelve un error si el votante ya voto
-        /// - Devuelve un error si el votante no está registrado como votante
-        /// - Devuelve un error si el candidato no está registrado como candidato
-        #[ink(message)]
-        fn votar(&mut self, id_eleccion: u32, id_candidato: AccountId) -> Result<()> {
-            let caller = self.env().caller();
-            if self.get_usuario(id_candidato).is_err() || self.get_usuario(caller).is_err() {
-                return Err(VotacionError::UsuarioNoEncontrado);
+        fn comprometer_voto(
+            &mut self,
+            estado_votante: Option<EstadoParticipante>,
+            peso: u64,
+            ya_voto: bool,
+            current_time: &Fecha,
+        ) -> Result<()> {
+            if !self.es_secreta() {
+                return Err(VotacionError::TipoEleccionInvalido);
             }
 
-            let timestamp = self.env().block_timestamp();
+            self.validar_votante(estado_votante, current_time)?;
 
-            if let Some(eleccion) = self.elecciones.get_mut(id_eleccion as usize) {
-                eleccion.votar(&caller, &id_candidato, &Fecha::from_timestamp(timestamp))
-            } else {
-                Err(VotacionError::EleccionNoEncontrada)
+            if ya_voto {
+                return Err(VotacionError::UsuarioYaVoto);
             }
+
+            self.cantidad_votos += 1;
+            self.votos_ponderados += peso as u128;
+            Ok(())
         }
 
-        /// Devuelve true si el usuario ya voto en la eleccion con el id pasado como parametro
-        /// false en cualquier otro caso
-        ///
-        /// # Errores
-        /// - Devuelve un error si el caller no es el admin
-        /// - Devuelve un error si el usuario no es encontrado
-        /// - Devuelve un error si la eleccion no es encontrada
-        #[ink(message)]
-        fn ya_voto(&self, id_eleccion: u32, id_votante: AccountId) -> Result<bool> {
-            if !self.caller_is_admin() {
-                return Err(VotacionError::NoEsAdmin);
+        fn revelar_voto(
+            &mut self,
+            compromiso: Option<Compromiso>,
+            ya_voto: bool,
+            apertura: Compromiso,
+            estado_candidato: Option<EstadoParticipante>,
+            current_time: &Fecha,
+        ) -> Result<()> {
+            if !self.es_secreta() {
+                return Err(VotacionError::TipoEleccionInvalido);
             }
 
-            if self.get_usuario(id_votante).is_err() {
-                return Err(VotacionError::UsuarioNoEncontrado);
-            }
+            self.validar_estado_activo()?;
 
-            if let Some(eleccion) = self.get_eleccion(id_eleccion) {
-                Ok(eleccion.ya_voto(&id_votante))
-            } else {
-                Err(VotacionError::EleccionNoEncontrada)
+            if !self.get_finalizada(current_time) {
+                return Err(VotacionError::EleccionNoFinalizada);
             }
-        }
 
-        /// Devuelve true si la elección ya inició
-        ///
-        /// # Errores
-        /// - Devuelve un error si el caller no es el admin
-        /// - Devuelve un error si la eleccion no es encontrada
-        #[ink(message)]
-        fn get_iniciada(&self, id_eleccion: u32) -> Result<bool> {
-            if !self.caller_is_admin() {
-                return Err(VotacionError::NoEsAdmin);
+            if self.get_revelacion_finalizada(current_time) {
+                return Err(VotacionError::RevelacionFinalizada);
             }
 
-            let timestamp = self.env().block_timestamp();
+            // Si el votante voto pero ya no tiene compromiso es porque ya lo reveló
+            let compromiso = match compromiso {
+                Some(compromiso) => compromiso,
+                None if ya_voto => return Err(VotacionError::VotoYaRevelado),
+                None => return Err(VotacionError::VotoNoComprometido),
+            };
 
-            if let Some(eleccion) = self.get_eleccion(id_eleccion) {
-                Ok(eleccion.get_inicio(&Fecha::from_timestamp(timestamp)))
-            } else {
-                Err(VotacionError::EleccionNoEncontrada)
+            if compromiso != apertura {
+                return Err(VotacionError::RevelacionInvalida);
             }
-        }
 
-        /// Devuelve true si la elección con el id pasado como parametro ya finalizó
-        ///
-        /// # Errores
-        /// - Devuelve un error si el caller no es el admin
-        /// - Devuelve un error si la eleccion no es encontrada
-        #[ink(message)]
-        fn get_finalizada(&self, id_eleccion: u32) -> Result<bool> {
-            if !self.caller_is_admin() {
-                return Err(VotacionError::NoEsAdmin);
+            // El voto pudo comprometerse antes de que el candidato se retirara
+            if !matches!(
+                estado_candidato,
+                Some(EstadoParticipante::Candidato) | Some(EstadoParticipante::CandidatoRetirado)
+            ) {
+                return Err(VotacionError::UsuarioNoEsCandidato);
             }
 
-            let timestamp = self.env().block_timestamp();
+            self.cantidad_revelados += 1;
+            Ok(())
+        }
 
-            if let Some(eleccion) = self.get_eleccion(id_eleccion) {
-                Ok(eleccion.get_finalizada(&Fecha::from_timestamp(timestamp)))
-            } else {
-                Err(VotacionError::EleccionNoEncontrada)
+        fn calcular_estado(&self, current_time: &Fecha) -> EstadoEleccion {
+            match self.estado {
+                EstadoEleccion::Suspendida
+                | EstadoEleccion::Certificada
+                | EstadoEleccion::Cancelada
+                | EstadoEleccion::Invalida => self.estado,
+                _ if self.get_finalizada(current_time) => EstadoEleccion::Cerrada,
+                _ if self.get_inicio(current_time) => EstadoEleccion::Abierta,
+                _ => match &self.config.inscripcion {
+                    Some(inscripcion)
+                        if current_time.timestamp().unwrap()
+                            < inscripcion.apertura.timestamp().unwrap() =>
+                    {
+                        EstadoEleccion::Borrador
+                    }
+                    _ => EstadoEleccion::Inscripcion,
+                },
             }
         }
 
-        /// Devuelve la cantidad de votos del candidato en la eleccion con el id pasado como parametro
-        ///
-        /// # Errores
-        /// - Devuelve un error si el caller no es el admin
-        /// - Devuelve un error si la eleccion no es encontrada
-        /// - Devuelve un error si la eleccion no finalizó
-        /// - Devuelve un error si el candidato no es encontrado
-        #[ink(message)]
-        fn get_votos_candidato(&self, id_eleccion: u32, id_candidato: AccountId) -> Result<u32> {
-            if !self.caller_is_admin() {
-                return Err(VotacionError::NoEsAdmin);
+        fn invalidar(&mut self, current_time: &Fecha) -> Result<()> {
+            self.validar_estado_activo()?;
+
+            if self.config.quorum.is_none() {
+                return Err(VotacionError::EleccionSinQuorum);
             }
 
-            let timestamp = self.env().block_timestamp();
+            self.validar_resultado(current_time)?;
 
-            if let Some(eleccion) = self.get_eleccion(id_eleccion) {
-                eleccion.get_votos_candidato(&id_candidato, &Fecha::from_timestamp(timestamp))
-            } else {
-                Err(VotacionError::EleccionNoEncontrada)
+            if self.quorum_alcanzado() == Some(true) {
+                return Err(VotacionError::QuorumAlcanzado);
             }
-        }
-    }
 
-    impl ReportMessage for Votacion {
-        /// Devuelve un vector con los id de los votantes aceptados
-        /// si no hay votantes devuelve un vector vacio
-        ///
-        /// # Errores
-        /// - Devuelve un error si el caller no es el reporte
-        /// - Devuelve un error si la eleccion no es encontrada
-        #[ink(message)]
-        fn reporte_registro_votantes(&self, eleccion_id: u32) -> Result<Vec<AccountId>> {
-            if !self.caller_is_reporte() {
-                return Err(VotacionError::SoloReportes);
+            // Un segundo llamado que ya debía empezar nacería sin tiempo para votar
+            if let Some(regla) = &self.config.quorum {
+                if let Some((fecha_inicio, _)) = &regla.segundo_llamado {
+                    if current_time.timestamp().unwrap() > fecha_inicio.timestamp().unwrap() {
+                        return Err(VotacionError::SegundoLlamadoVencido);
+                    }
+                }
             }
 
-            let eleccion = self
-                .get_eleccion(eleccion_id)
-                .ok_or(VotacionError::EleccionNoEncontrada)?;
-            let id_votantes = eleccion.reporte_registro_votantes(); // -> AccountId de votantes aceptados y aprobados para esa eleccion
-
-            Ok(id_votantes)
+            self.estado = EstadoEleccion::Invalida;
+            Ok(())
         }
 
-        /// Devuelve un tuple que contiene la cantidad de votantes y la cantidad de votos, en ese orden
-        ///
-        /// # Errores
-        /// - Devuelve un error si el caller no es el reporte
-        /// - Devuelve un error si la eleccion no es encontrada
-        /// - Devuelve un error si la eleccion no finalizó
-        #[ink(message)]
-        fn reporte_participacion(&self, eleccion_id: u32) -> Result<(u128, u128)> {
-            if !self.caller_is_reporte() {
-                return Err(VotacionError::SoloReportes);
+        fn cancelar(&mut self) -> Result<()> {
+            match self.estado {
+                EstadoEleccion::Certificada => Err(VotacionError::EleccionCertificada),
+                EstadoEleccion::Cancelada => Err(VotacionError::EleccionCancelada),
+                EstadoEleccion::Invalida => Err(VotacionError::EleccionInvalida),
+                _ => {
+                    self.estado = EstadoEleccion::Cancelada;
+                    Ok(())
+                }
             }
+        }
 
-            let timestamp = self.env().block_timestamp();
-            let eleccion = self
-                .get_eleccion(eleccion_id)
-                .ok_or(VotacionError::EleccionNoEncontrada)?;
+        fn suspender(&mut self, current_time: &Fecha) -> Result<()> {
+            self.validar_estado_activo()?;
 
-            eleccion.reporte_participacion(&Fecha::from_timestamp(timestamp))
+            let estado = self.calcular_estado(current_time);
+            if estado != EstadoEleccion::Inscripcion && estado != EstadoEleccion::Abierta {
+                return Err(VotacionError::TransicionEstadoInvalida);
+            }
+
+            self.estado = EstadoEleccion::Suspendida;
+            Ok(())
         }
 
-        /// Devuelve un vector que contiene para cada posicion el AccountId
-        /// de un candidato y la cantidad de votos que obtuvo
-        ///
-        /// # Errores
-        /// - Devuelve un error si el caller no es el reporte
-        /// - Devuelve un error si la eleccion no es encontrada
-        /// - Devuelve un error si la eleccion no finalizó
-        #[ink(message)]
-        fn reporte_resultado(&self, eleccion_id: u32) -> Result<Vec<(AccountId, u32)>> {
-            if !self.caller_is_reporte() {
-                return Err(VotacionError::SoloReportes);
+        fn reanudar(&mut self, current_time: &Fecha) -> Result<EstadoEleccion> {
+            if self.estado != EstadoEleccion::Suspendida {
+                return Err(VotacionError::EleccionNoSuspendida);
             }
 
-            let timestamp = self.env().block_timestamp();
+            // Se reemplaza por un estado no fijo para que se derive de las fechas
+            self.estado = EstadoEleccion::Borrador;
+            self.estado = self.calcular_estado(current_time);
+            Ok(self.estado)
+        }
 
-            let eleccion = self
+        fn extender(&mut self, fecha_fin: Fecha, current_time: &Fecha) -> Result<()> {
+            // Una elección suspendida se puede extender aunque haya pasado su fecha de fin
+            if self.estado != EstadoEleccion::Suspendida {
+                self.validar_estado_activo()?;
+
+                if self.get_finalizada(current_time) {
+                    return Err(VotacionError::EleccionYaFinalizada);
+                }
+            }
+
+            if !fecha_fin.es_fecha_valida() {
+                return Err(VotacionError::FechaInvalida);
+            }
+
+            if fecha_fin.timestamp().unwrap() <= self.fecha_fin.timestamp().unwrap() {
+                return Err(VotacionError::ExtensionInvalida);
+            }
+
+            // La revelación y la segunda vuelta deben seguir siendo posteriores al fin
+            self.config.validar(&self.fecha_inicio, &fecha_fin)?;
+            self.fecha_fin = fecha_fin;
+            Ok(())
+        }
+
+        fn certificar(&mut self, current_time: &Fecha) -> Result<()> {
+            match self.estado {
+                EstadoEleccion::Certificada => return Err(VotacionError::EleccionCertificada),
+                EstadoEleccion::Invalida => return Err(VotacionError::EleccionInvalida),
+                _ => {}
+            }
+
+            self.validar_resultado(current_time)?;
+
+            if self.quorum_alcanzado() == Some(false) {
+                return Err(VotacionError::QuorumNoAlcanzado);
+            }
+
+            self.validar_impugnaciones(current_time)?;
+
+            // El resultado certificado debe indicar si hubo ganador o segunda vuelta
+            if self.config.balotaje.is_some() && self.resultado_balotaje.is_none() {
+                return Err(VotacionError::BalotajeNoAplicado);
+            }
+
+            self.estado = EstadoEleccion::Certificada;
+            Ok(())
+        }
+
+        fn impugnar(
+            &mut self,
+            estado: Option<EstadoParticipante>,
+            current_time: &Fecha,
+        ) -> Result<u32> {
+            self.validar_estado_activo()?;
+
+            let fin_impugnacion = match &self.config.fin_impugnacion {
+                Some(fin) => fin.timestamp().unwrap(),
+                None => return Err(VotacionError::EleccionSinImpugnaciones),
+            };
+
+            self.validar_resultado(current_time)?;
+
+            if current_time.timestamp().unwrap() > fin_impugnacion {
+                return Err(VotacionError::PeriodoImpugnacionFinalizado);
+            }
+
+            if !matches!(
+                estado,
+                Some(EstadoParticipante::Votante) | Some(EstadoParticipante::Candidato)
+            ) {
+                return Err(VotacionError::UsuarioNoEsParticipante);
+            }
+
+            let id = self.get_cantidad_impugnaciones();
+            self.cantidad_impugnaciones += 1;
+            self.impugnaciones_pendientes += 1;
+            Ok(id)
+        }
+
+        fn resolver_impugnacion(
+            &mut self,
+            estado: Option<EstadoImpugnacion>,
+            aceptada: bool,
+        ) -> Result<()> {
+            Self::validar_impugnacion_pendiente(estado)?;
+
+            self.impugnaciones_pendientes -= 1;
+            if aceptada {
+                self.impugnaciones_aceptadas += 1;
+            }
+            Ok(())
+        }
+
+        fn get_inicio(&self, current_time: &Fecha) -> bool {
+            // Si current_time >= fecha_inicio   -> true
+            // Le puedo hacer unwrap porque me fijo si es valida al momento de crear la eleccion
+            current_time.timestamp().unwrap() >= self.get_fecha_inicio().timestamp().unwrap()
+        }
+
+        fn get_finalizada(&self, current_time: &Fecha) -> bool {
+            // Si fecha_act > fecha_fin -> true
+            // Le puedo hacer unwrap porque me fijo si es valida al momento de crear la eleccion
+            current_time.timestamp().unwrap() > self.get_fecha_fin().timestamp().unwrap()
+        }
+    }
+
+    impl GettersEleccion for Eleccion {
+        /// Devuelve el id de la elección
+        fn get_id(&self) -> u32 {
+            self.id
+        }
+
+        /// Devuelve la cantidad de votantes aceptados en la elección que no se retiraron
+        fn get_cantidad_votantes(&self) -> u32 {
+            self.cantidad_votantes - self.votantes_retirados
+        }
+
+        /// Devuelve la cantidad de candidatos aceptados en la elección que no se retiraron
+        fn get_cantidad_candidatos(&self) -> u32 {
+            self.cantidad_candidatos - self.candidatos_retirados
+        }
+
+        /// Devuelve la cantidad de votantes que ya votaron en la elección
+        fn get_cantidad_votos(&self) -> u32 {
+            self.cantidad_votos
+        }
+
+        /// Devuelve la fecha de inicio de la elección
+        fn get_fecha_inicio(&self) -> Fecha {
+            self.fecha_inicio.clone()
+        }
+
+        /// Devuelve la fecha de fin de la elección
+        fn get_fecha_fin(&self) -> Fecha {
+            self.fecha_fin.clone()
+        }
+
+        /// Devuelve el tipo de la elección
+        fn get_tipo(&self) -> TipoEleccion {
+            self.config.tipo
+        }
+
+        /// Devuelve la cantidad de cargos que se eligen en la elección
+        fn get_cantidad_cargos(&self) -> u32 {
+            self.config.cantidad_cargos
+        }
+
+        /// Devuelve la cantidad de listas de la elección
+        fn get_cantidad_listas(&self) -> u32 {
+            self.cantidad_listas
+        }
+
+        /// Devuelve la cantidad de votos revelados en una elección secreta
+        fn get_cantidad_revelados(&self) -> u32 {
+            self.cantidad_revelados
+        }
+
+        /// Devuelve la cantidad de votos en blanco de la elección
+        fn get_votos_blanco(&self) -> u128 {
+            self.votos_blanco
+        }
+
+        /// Devuelve la cantidad de votos nulos de la elección
+        fn get_votos_nulos(&self) -> u128 {
+            self.votos_nulos
+        }
+
+        /// Devuelve la cantidad de votos emitidos por delegación
+        fn get_votos_delegados(&self) -> u32 {
+            self.votos_delegados
+        }
+
+        /// Devuelve la suma de los pesos de los votantes aceptados
+        fn get_peso_votantes(&self) -> u128 {
+            self.peso_votantes
+        }
+
+        /// Devuelve la suma de los pesos de los votos emitidos
+        fn get_votos_ponderados(&self) -> u128 {
+            self.votos_ponderados
+        }
+
+        /// Devuelve el id de la primera vuelta si la elección es una segunda vuelta
+        fn get_primera_vuelta(&self) -> Option<u32> {
+            self.primera_vuelta
+        }
+
+        /// Devuelve el resultado de la regla de balotaje si ya se aplicó
+        fn get_resultado_balotaje(&self) -> Option<ResultadoBalotaje> {
+            self.resultado_balotaje
+        }
+
+        /// Devuelve el estado de la elección al momento de consultarla
+        fn get_estado(&self) -> EstadoEleccion {
+            self.estado
+        }
+
+        /// Devuelve la cantidad de impugnaciones presentadas contra el resultado
+        fn get_cantidad_impugnaciones(&self) -> u32 {
+            self.cantidad_impugnaciones
+        }
+
+        /// Devuelve la cantidad de impugnaciones que todavia no fueron resueltas
+        fn get_impugnaciones_pendientes(&self) -> u32 {
+            self.impugnaciones_pendientes
+        }
+    }
+
+    impl ReportMessageEleccion for Eleccion {
+        fn reporte_participacion(&self, current_time: &Fecha) -> Result<Participacion> {
+            self.validar_resultado(current_time)?;
+
+            let votos_revelados = if self.es_secreta() {
+                Some(self.get_cantidad_revelados() as u128)
+            } else {
+                None
+            };
+
+            Ok(Participacion {
+                votantes: self.get_cantidad_votantes() as u128,
+                votos: self.get_cantidad_votos() as u128,
+                votos_delegados: self.get_votos_delegados() as u128,
+                peso_votantes: self.get_peso_votantes(),
+                votos_ponderados: self.get_votos_ponderados(),
+                votos_revelados,
+                votos_blanco: self.get_votos_blanco(),
+                votos_nulos: self.get_votos_nulos(),
+                quorum_alcanzado: self.quorum_alcanzado(),
+            })
+        }
+    }
+
+    impl Usuario {
+        pub fn new(
+            addres: AccountId,
+            nombre: String,
+            apellido: String,
+            direccion: String,
+            dni: String,
+            fecha_nacimiento: Fecha,
+        ) -> Self {
+            Usuario {
+                addres,
+                nombre,
+                apellido,
+                direccion,
+                dni,
+                fecha_nacimiento,
+                hash_datos: None,
+            }
+        }
+
+        /// Crea un usuario en modo privado, que guarda el hash de su dni y su dirección
+        pub fn new_privado(
+            addres: AccountId,
+            nombre: String,
+            apellido: String,
+            hash_datos: HashDatos,
+            fecha_nacimiento: Fecha,
+        ) -> Self {
+            Usuario {
+                addres,
+                nombre,
+                apellido,
+                direccion: String::new(),
+                dni: String::new(),
+                fecha_nacimiento,
+                hash_datos: Some(hash_datos),
+            }
+        }
+
+        /// Devuelve error si el dni está vacío, tiene caracteres que no son dígitos
+        /// o no tiene 7 u 8 dígitos sin contar los ceros a la izquierda
+        fn validar_formato_dni(dni: &str) -> Result<()> {
+            if dni.is_empty() {
+                return Err(VotacionError::DniVacio);
+            }
+
+            if !dni.bytes().all(|c| c.is_ascii_digit()) {
+                return Err(VotacionError::DniNoNumerico);
+            }
+
+            if !(7..=8).contains(&Self::normalizar_dni(dni).len()) {
+                return Err(VotacionError::DniLongitudInvalida);
+            }
+            Ok(())
+        }
+
+        /// Devuelve el dni sin ceros a la izquierda, para que "01234567" y "1234567"
+        /// se registren como el mismo documento
+        fn normalizar_dni(dni: &str) -> &str {
+            dni.trim_start_matches('0')
+        }
+
+        /// Devuelve la edad en años cumplidos del usuario a la fecha pasada por parametro
+        pub fn calcular_edad(&self, fecha: &Fecha) -> u32 {
+            self.fecha_nacimiento.anios_hasta(fecha)
+        }
+
+        /// Devuelve true si los datos de identidad (nombre, apellido, dni y fecha de nacimiento)
+        /// difieren de los del otro usuario. En modo privado cualquier cambio del hash
+        /// de los datos personales se considera un cambio de identidad
+        fn cambia_identidad(&self, otro: &Usuario) -> bool {
+            self.nombre != otro.nombre
+                || self.apellido != otro.apellido
+                || self.dni != otro.dni
+                || self.fecha_nacimiento != otro.fecha_nacimiento
+                || self.hash_datos != otro.hash_datos
+        }
+    }
+
+    impl GettersUsuario for Usuario {
+        /// Devuelve el id del usuario
+        fn get_addres(&self) -> AccountId {
+            self.addres
+        }
+
+        /// Devuelve el nombre del usuario
+        fn get_nombre(&self) -> String {
+            self.nombre.clone()
+        }
+
+        /// Devuelve el apellido del usuario
+        fn get_apellido(&self) -> String {
+            self.apellido.clone()
+        }
+
+        /// Devuelve la direccion del usuario
+        fn get_direccion(&self) -> String {
+            self.direccion.clone()
+        }
+
+        /// Devuelve el dni del usuario
+        fn get_dni(&self) -> String {
+            self.dni.clone()
+        }
+
+        /// Devuelve el hash de los datos personales de un usuario en modo privado
+        fn get_hash_datos(&self) -> Option<HashDatos> {
+            self.hash_datos
+        }
+
+        /// Devuelve la fecha de nacimiento del usuario
+        fn get_fecha_nacimiento(&self) -> Fecha {
+            self.fecha_nacimiento.clone()
+        }
+    }
+
+    impl Lista {
+        pub fn new(id: u32, nombre: String, candidatos: Vec<AccountId>) -> Self {
+            Lista {
+                id,
+                nombre,
+                candidatos,
+            }
+        }
+    }
+
+    impl GettersLista for Lista {
+        /// Devuelve el id de la lista dentro de la elección
+        fn get_id(&self) -> u32 {
+            self.id
+        }
+
+        /// Devuelve el nombre de la lista
+        fn get_nombre(&self) -> String {
+            self.nombre.clone()
+        }
+
+        /// Devuelve los candidatos de la lista en orden
+        fn get_candidatos(&self) -> Vec<AccountId> {
+            self.candidatos.clone()
+        }
+    }
+
+    impl Votacion {
+        /// Constructor por defecto
+        #[cfg(test)]
+        pub fn new() -> Self {
+            Self::inicializar(AccountId::from([0x10; 32]), false)
+        }
+
+        /// Constructor por defecto en modo privado
+        #[cfg(test)]
+        pub fn new_privado() -> Self {
+            Self::inicializar(AccountId::from([0x10; 32]), true)
+        }
+
+        /// Constructor del contrato
+        #[ink(constructor)]
+        #[cfg(not(test))]
+        pub fn new(reporte: AccountId) -> Self {
+            Self::inicializar(reporte, false)
+        }
+
+        /// Constructor del contrato en modo privado, en el que de los usuarios
+        /// solo se guarda el hash de su dni y su dirección
+        #[ink(constructor)]
+        #[cfg(not(test))]
+        pub fn new_privado(reporte: AccountId) -> Self {
+            Self::inicializar(reporte, true)
+        }
+
+        /// Crea el storage inicial del contrato con el caller como admin
+        fn inicializar(reporte: AccountId, modo_privado: bool) -> Self {
+            Self {
+                admin: Self::env().caller(),
+                modo_privado,
+                admin_nominado: None,
+                reporte,
+                elecciones: Mapping::default(),
+                cantidad_elecciones: 0,
+                usuarios: Mapping::default(),
+                usuarios_sin_aceptar: Mapping::default(),
+                dnis: Mapping::default(),
+                rechazos_usuario: Mapping::default(),
+                participantes: Mapping::default(),
+                rechazos_postulacion: Mapping::default(),
+                elecciones_usuario: Mapping::default(),
+                habilitados: Mapping::default(),
+                votantes: Mapping::default(),
+                candidatos: Mapping::default(),
+                votantes_voto: Mapping::default(),
+                pesos: Mapping::default(),
+                votos: Mapping::default(),
+                boletas: Mapping::default(),
+                listas: Mapping::default(),
+                listas_candidato: Mapping::default(),
+                votos_lista: Mapping::default(),
+                compromisos: Mapping::default(),
+                delegaciones: Mapping::default(),
+                delegantes: Mapping::default(),
+                votos_delegados: Mapping::default(),
+                roles: Mapping::default(),
+                cuentas_rol: Mapping::default(),
+                consejo: Vec::new(),
+                umbral: 0,
+                version_consejo: 0,
+                propuestas: Mapping::default(),
+                cantidad_propuestas: 0,
+                impugnaciones: Mapping::default(),
+                impugnacion_pendiente: Mapping::default(),
+            }
+        }
+
+        /// Devuelve true si el caller es admin o tiene el rol pasado por parametro
+        fn caller_tiene_rol(&self, rol: Rol) -> bool {
+            self.caller_is_admin() || self.roles.contains((rol, self.env().caller()))
+        }
+
+        /// Nomina al nuevo administrador del contrato pasado por parametro
+        /// solo el admin puede nominar al admin, y el cambio se hace cuando el nominado
+        /// lo acepta. Una nueva nominación reemplaza a la anterior
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el admin
+        /// - Devuelve un error si hay un consejo configurado
+        #[ink(message)]
+        pub fn set_admin(&mut self, new_admin: AccountId) -> Result<()> {
+            self.validar_accion_directa()?;
+            self.nominar_admin(new_admin);
+            Ok(())
+        }
+
+        /// El caller acepta la nominación como admin y pasa a ser el admin del contrato
+        ///
+        /// # Errores
+        /// - Devuelve un error si no hay un admin nominado
+        /// - Devuelve un error si el caller no es el admin nominado
+        #[ink(message)]
+        pub fn aceptar_admin(&mut self) -> Result<()> {
+            let admin_nuevo = self.admin_nominado.ok_or(VotacionError::SinAdminNominado)?;
+            if admin_nuevo != self.env().caller() {
+                return Err(VotacionError::NoEsAdminNominado);
+            }
+
+            let admin_anterior = self.admin;
+            self.admin = admin_nuevo;
+            self.admin_nominado = None;
+            self.env().emit_event(AdminCambiado {
+                admin_anterior,
+                admin_nuevo,
+            });
+            Ok(())
+        }
+
+        /// Cancela la nominación del nuevo admin, la puede cancelar el admin o el nominado.
+        /// Con un consejo configurado la nominación la aprobó el consejo, por lo que
+        /// solo la puede cancelar el nominado
+        ///
+        /// # Errores
+        /// - Devuelve un error si no hay un admin nominado
+        /// - Devuelve un error si el caller no es el admin ni el admin nominado
+        /// - Devuelve un error si hay un consejo configurado y el caller no es el admin nominado
+        #[ink(message)]
+        pub fn cancelar_nominacion_admin(&mut self) -> Result<()> {
+            let admin_nominado = self.admin_nominado.ok_or(VotacionError::SinAdminNominado)?;
+            if admin_nominado != self.env().caller() {
+                self.validar_accion_directa()?;
+            }
+
+            self.admin_nominado = None;
+            self.env()
+                .emit_event(NominacionAdminCancelada { admin_nominado });
+            Ok(())
+        }
+
+        /// Devuelve la cuenta nominada como nuevo admin, None si no hay nominación pendiente
+        #[ink(message)]
+        pub fn get_admin_nominado(&self) -> Option<AccountId> {
+            self.admin_nominado
+        }
+
+        fn nominar_admin(&mut self, admin_nominado: AccountId) {
+            self.admin_nominado = Some(admin_nominado);
+            self.env().emit_event(AdminNominado {
+                admin: self.admin,
+                admin_nominado,
+            });
+        }
+
+        /// Cambia el AccountId del reporte al nuevo pasado por parametro
+        /// solo el admin puede cambiar el reporte
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el admin
+        /// - Devuelve un error si hay un consejo configurado
+        #[ink(message)]
+        pub fn set_reporte(&mut self, new_reporte: AccountId) -> Result<()> {
+            self.validar_accion_directa()?;
+            self.cambiar_reporte(new_reporte);
+            Ok(())
+        }
+
+        fn cambiar_reporte(&mut self, new_reporte: AccountId) {
+            let reporte_anterior = self.reporte;
+            self.reporte = new_reporte;
+            self.env().emit_event(ReporteCambiado {
+                reporte_anterior,
+                reporte_nuevo: new_reporte,
+            });
+        }
+
+        /// Agrega el usuario a la lista de usuarios_por_aceptar
+        fn registrar_postulacion_usuario(&mut self, usuario: Usuario) -> Result<Usuario> {
+            let id = usuario.addres;
+
+            if self.get_usuario(id).is_ok() {
+                return Err(VotacionError::UsuarioYaRegistrado);
+            }
+
+            if self.get_usuario_sin_aceptar(id).is_ok() {
+                return Err(VotacionError::UsuarioNoAceptado);
+            }
+
+            self.validar_fecha_nacimiento(&usuario)?;
+            self.validar_dni(&usuario)?;
+
+            self.reservar_dni(&usuario);
+            self.usuarios_sin_aceptar.insert(id, &usuario);
+            self.rechazos_usuario.remove(id);
+            self.env().emit_event(UsuarioPostulado { usuario: id });
+            Ok(usuario)
+        }
+
+        /// Reemplaza los datos del usuario, que vuelve a la lista de usuarios_por_aceptar
+        /// si ya fue aceptado y cambian sus datos de identidad
+        fn registrar_actualizacion_usuario(&mut self, usuario: Usuario) -> Result<Usuario> {
+            let id = usuario.addres;
+
+            let postulado = self.usuarios_sin_aceptar.contains(id);
+            let anterior = if postulado {
+                self.get_usuario_sin_aceptar(id)?
+            } else {
+                self.get_usuario(id)?
+            };
+            let revalidar = !postulado && anterior.cambia_identidad(&usuario);
+            if revalidar {
+                self.validar_sin_elecciones_activas(id)?;
+            }
+            self.validar_fecha_nacimiento(&usuario)?;
+            self.validar_dni(&usuario)?;
+
+            self.liberar_dni(&anterior);
+            self.reservar_dni(&usuario);
+            if revalidar {
+                self.usuarios.remove(id);
+            }
+            if postulado || revalidar {
+                self.usuarios_sin_aceptar.insert(id, &usuario);
+            } else {
+                self.usuarios.insert(id, &usuario);
+            }
+
+            self.env().emit_event(UsuarioActualizado {
+                usuario: id,
+                revalidar,
+            });
+            Ok(usuario)
+        }
+
+        /// Devuelve error si la fecha de nacimiento no es válida o es posterior a la fecha actual
+        fn validar_fecha_nacimiento(&self, usuario: &Usuario) -> Result<()> {
+            let current_time = Fecha::from_timestamp(self.env().block_timestamp());
+            if !usuario.fecha_nacimiento.es_fecha_valida()
+                || usuario.fecha_nacimiento.es_mayor(&current_time)
+            {
+                return Err(VotacionError::FechaNacimientoInvalida);
+            }
+            Ok(())
+        }
+
+        /// Devuelve error si el dni no tiene un formato válido
+        /// o si ya está registrado por otro usuario. En modo privado no hay dni que validar
+        fn validar_dni(&self, usuario: &Usuario) -> Result<()> {
+            if usuario.hash_datos.is_some() {
+                return Ok(());
+            }
+
+            Usuario::validar_formato_dni(&usuario.dni)?;
+            match self.dnis.get(Usuario::normalizar_dni(&usuario.dni)) {
+                Some(cuenta) if cuenta != usuario.addres => Err(VotacionError::DniDuplicado),
+                _ => Ok(()),
+            }
+        }
+
+        /// Registra el dni del usuario como usado por su cuenta
+        fn reservar_dni(&mut self, usuario: &Usuario) {
+            if usuario.hash_datos.is_none() {
+                self.dnis
+                    .insert(Usuario::normalizar_dni(&usuario.dni), &usuario.addres);
+            }
+        }
+
+        /// Libera el dni del usuario para que lo pueda usar otra cuenta
+        fn liberar_dni(&mut self, usuario: &Usuario) {
+            if usuario.hash_datos.is_none() {
+                self.dnis.remove(Usuario::normalizar_dni(&usuario.dni));
+            }
+        }
+
+        /// Agrega la elección a las elecciones en las que participa el usuario.
+        /// Quita las elecciones que ya concluyeron, para que la lista no crezca con
+        /// todas las elecciones en las que participó el usuario
+        fn registrar_participacion(&mut self, id_eleccion: u32, usuario: AccountId) {
+            let current_time = Fecha::from_timestamp(self.env().block_timestamp());
+            let mut elecciones = self.elecciones_usuario.get(usuario).unwrap_or_default();
+            elecciones.retain(|id| {
+                self.get_eleccion(*id)
+                    .is_some_and(|eleccion| !eleccion.get_concluida(&current_time))
+            });
+            if !elecciones.contains(&id_eleccion) {
+                elecciones.push(id_eleccion);
+            }
+            self.elecciones_usuario.insert(usuario, &elecciones);
+        }
+
+        /// Devuelve error si el usuario es candidato o votante de una elección
+        /// que todavia no tiene resultado
+        fn validar_sin_elecciones_activas(&self, usuario: AccountId) -> Result<()> {
+            let current_time = Fecha::from_timestamp(self.env().block_timestamp());
+            for id_eleccion in self.elecciones_usuario.get(usuario).unwrap_or_default() {
+                if let Some(eleccion) = self.get_eleccion(id_eleccion) {
+                    eleccion.validar_baja(
+                        self.get_estado_participante(id_eleccion, usuario),
+                        &current_time,
+                    )?;
+                }
+            }
+            Ok(())
+        }
+
+        /// Retira las postulaciones del usuario que todavia no fueron aceptadas
+        /// y olvida las elecciones en las que participa
+        fn retirar_postulaciones(&mut self, usuario: AccountId) {
+            for id_eleccion in self.elecciones_usuario.take(usuario).unwrap_or_default() {
+                if matches!(
+                    self.get_estado_participante(id_eleccion, usuario),
+                    Some(EstadoParticipante::PostuladoCandidato)
+                        | Some(EstadoParticipante::PostuladoVotante)
+                ) {
+                    self.participantes.remove((id_eleccion, usuario));
+                }
+            }
+        }
+
+        /// Verifica que el caller pueda ejecutar una acción del admin sin pasar por el consejo
+        fn validar_accion_directa(&self) -> Result<()> {
+            if !self.caller_is_admin() {
+                return Err(VotacionError::NoEsAdmin);
+            }
+
+            if self.umbral > 0 {
+                return Err(VotacionError::RequiereAprobacionConsejo);
+            }
+            Ok(())
+        }
+
+        /// Verifica que el caller pueda otorgar o revocar el rol de forma directa. El rol Admin
+        /// permite las mismas acciones que el admin, por lo que con un consejo configurado
+        /// solo se otorga o revoca mediante una propuesta
+        fn validar_cambio_rol(&self, rol: Rol) -> Result<()> {
+            if rol == Rol::Admin {
+                return self.validar_accion_directa();
+            }
+
+            if !self.caller_is_admin() {
+                return Err(VotacionError::NoEsAdmin);
+            }
+            Ok(())
+        }
+
+        /// Verifica que la cuenta no tenga el rol y que exista la eleccion de una autoridad
+        fn validar_otorgar_rol(&self, cuenta: AccountId, rol: Rol) -> Result<()> {
+            if let Rol::Autoridad(id_eleccion) = rol {
+                if id_eleccion >= self.cantidad_elecciones {
+                    return Err(VotacionError::EleccionNoEncontrada);
+                }
+            }
+
+            if self.tiene_rol(cuenta, rol) {
+                return Err(VotacionError::RolYaOtorgado);
+            }
+            Ok(())
+        }
+
+        /// Verifica que la cuenta tenga el rol
+        fn validar_revocar_rol(&self, cuenta: AccountId, rol: Rol) -> Result<()> {
+            if !self.tiene_rol(cuenta, rol) {
+                return Err(VotacionError::RolNoOtorgado);
+            }
+            Ok(())
+        }
+
+        fn registrar_rol(&mut self, cuenta: AccountId, rol: Rol) {
+            let mut cuentas = self.get_cuentas_rol(rol);
+            cuentas.push(cuenta);
+            self.cuentas_rol.insert(rol, &cuentas);
+            self.roles.insert((rol, cuenta), &());
+            self.env().emit_event(RolOtorgado { cuenta, rol });
+        }
+
+        fn quitar_rol(&mut self, cuenta: AccountId, rol: Rol) {
+            self.roles.remove((rol, cuenta));
+            let mut cuentas = self.get_cuentas_rol(rol);
+            cuentas.retain(|c| *c != cuenta);
+            self.cuentas_rol.insert(rol, &cuentas);
+            self.env().emit_event(RolRevocado { cuenta, rol });
+        }
+
+        /// Verifica que la acción de una propuesta se pueda ejecutar
+        fn validar_accion(&self, accion: &Accion) -> Result<()> {
+            match accion {
+                Accion::CambiarAdmin(_) | Accion::CambiarReporte(_) => Ok(()),
+                Accion::CrearEleccion(fecha_inicio, fecha_fin, config) => {
+                    Self::validar_eleccion(fecha_inicio, fecha_fin, config)
+                }
+                Accion::CambiarConsejo(miembros, umbral) => {
+                    Self::validar_consejo(miembros, *umbral)
+                }
+                Accion::ResolverImpugnacion(id_eleccion, id_impugnacion, _, resolucion) => {
+                    self.validar_resolucion(*id_eleccion, *id_impugnacion, resolucion)
+                }
+                Accion::OtorgarRol(cuenta, rol) => self.validar_otorgar_rol(*cuenta, *rol),
+                Accion::RevocarRol(cuenta, rol) => self.validar_revocar_rol(*cuenta, *rol),
+                Accion::CambiarEstadoEleccion(id_eleccion, cambio, motivo) => {
+                    self.validar_cambio_estado(*id_eleccion, *cambio, motivo)
+                }
+                Accion::ExtenderEleccion(id_eleccion, fecha_fin, motivo) => {
+                    self.validar_extension(*id_eleccion, fecha_fin, motivo)
+                }
+            }
+        }
+
+        /// Ejecuta la acción de una propuesta que alcanzó las aprobaciones necesarias
+        fn ejecutar_accion(&mut self, accion: Accion) -> Result<()> {
+            self.validar_accion(&accion)?;
+            match accion {
+                Accion::CambiarAdmin(admin) => self.nominar_admin(admin),
+                Accion::CambiarReporte(reporte) => self.cambiar_reporte(reporte),
+                Accion::CrearEleccion(fecha_inicio, fecha_fin, config) => {
+                    self.registrar_eleccion(fecha_inicio, fecha_fin, *config, None);
+                }
+                Accion::CambiarConsejo(miembros, umbral) => self.cambiar_consejo(miembros, umbral),
+                Accion::ResolverImpugnacion(id_eleccion, id_impugnacion, aceptada, resolucion) => {
+                    self.registrar_resolucion(id_eleccion, id_impugnacion, aceptada, resolucion)?
+                }
+                Accion::OtorgarRol(cuenta, rol) => self.registrar_rol(cuenta, rol),
+                Accion::RevocarRol(cuenta, rol) => self.quitar_rol(cuenta, rol),
+                Accion::CambiarEstadoEleccion(id_eleccion, cambio, motivo) => {
+                    self.registrar_cambio_estado(id_eleccion, cambio, motivo)?
+                }
+                Accion::ExtenderEleccion(id_eleccion, fecha_fin, motivo) => {
+                    self.registrar_extension(id_eleccion, fecha_fin, motivo)?
+                }
+            }
+            Ok(())
+        }
+
+        /// Verifica que la impugnación exista, esté pendiente y que la resolución tenga fundamento
+        fn validar_resolucion(
+            &self,
+            id_eleccion: u32,
+            id_impugnacion: u32,
+            resolucion: &str,
+        ) -> Result<()> {
+            if resolucion.is_empty() {
+                return Err(VotacionError::MotivoVacio);
+            }
+
+            if !self.elecciones.contains(id_eleccion) {
+                return Err(VotacionError::EleccionNoEncontrada);
+            }
+
+            Eleccion::validar_impugnacion_pendiente(
+                self.impugnaciones
+                    .get((id_eleccion, id_impugnacion))
+                    .map(|impugnacion| impugnacion.estado),
+            )
+        }
+
+        /// Guarda la resolución de una impugnación y emite el evento
+        fn registrar_resolucion(
+            &mut self,
+            id_eleccion: u32,
+            id_impugnacion: u32,
+            aceptada: bool,
+            resolucion: String,
+        ) -> Result<()> {
+            let mut eleccion = self
+                .get_eleccion(id_eleccion)
+                .ok_or(VotacionError::EleccionNoEncontrada)?;
+            let impugnacion = self.impugnaciones.get((id_eleccion, id_impugnacion));
+            eleccion.resolver_impugnacion(
+                impugnacion.as_ref().map(|impugnacion| impugnacion.estado),
+                aceptada,
+            )?;
+
+            // Si la impugnación no existiera resolver_impugnacion habría devuelto error
+            let mut impugnacion = impugnacion.unwrap();
+            impugnacion.estado = if aceptada {
+                EstadoImpugnacion::Aceptada
+            } else {
+                EstadoImpugnacion::Rechazada
+            };
+            impugnacion.resolucion = Some(resolucion);
+            self.impugnaciones
+                .insert((id_eleccion, id_impugnacion), &impugnacion);
+            self.impugnacion_pendiente
+                .remove((id_eleccion, impugnacion.autor));
+            self.elecciones.insert(id_eleccion, &eleccion);
+
+            self.env().emit_event(ImpugnacionResuelta {
+                id_eleccion,
+                id_impugnacion,
+                aceptada,
+            });
+            Ok(())
+        }
+
+        /// Verifica que el consejo no tenga miembros repetidos y que las aprobaciones
+        /// necesarias estén entre uno y la cantidad de miembros
+        fn validar_consejo(miembros: &[AccountId], umbral: u32) -> Result<()> {
+            if umbral == 0 || umbral as usize > miembros.len() {
+                return Err(VotacionError::ConsejoInvalido);
+            }
+
+            if miembros
+                .iter()
+                .enumerate()
+                .any(|(i, miembro)| miembros[..i].contains(miembro))
+            {
+                return Err(VotacionError::ConsejoInvalido);
+            }
+            Ok(())
+        }
+
+        fn cambiar_consejo(&mut self, miembros: Vec<AccountId>, umbral: u32) {
+            self.consejo = miembros.clone();
+            self.umbral = umbral;
+            self.version_consejo += 1;
+            self.env().emit_event(ConsejoCambiado { miembros, umbral });
+        }
+
+        /// Devuelve el estado del usuario en la elección con el id pasado por parametro,
+        /// None si el usuario no participa de la elección
+        #[ink(message)]
+        pub fn get_estado_participante(
+            &self,
+            id_eleccion: u32,
+            id: AccountId,
+        ) -> Option<EstadoParticipante> {
+            self.participantes.get((id_eleccion, id))
+        }
+
+        /// Devuelve true si el caller es el reporte, false en cualquier otro caso
+        fn caller_is_reporte(&self) -> bool {
+            self.env().caller() == self.reporte
+        }
+
+        /// Dado un id, devuelve true si esta registrado como candidato en la elección
+        fn is_candidato(&self, id_eleccion: u32, id: AccountId) -> bool {
+            self.get_estado_participante(id_eleccion, id) == Some(EstadoParticipante::Candidato)
+        }
+
+        /// Devuelve true si el votante ya votó en la elección
+        fn voto_registrado(&self, id_eleccion: u32, id_votante: AccountId) -> bool {
+            self.votantes_voto.contains((id_eleccion, id_votante))
+        }
+
+        /// Devuelve los votantes aceptados de la elección que no se retiraron, en orden de aceptación
+        fn get_votantes(&self, eleccion: &Eleccion) -> Vec<AccountId> {
+            let id_eleccion = eleccion.get_id();
+            (0..eleccion.cantidad_votantes)
+                .filter_map(|posicion| self.votantes.get((id_eleccion, posicion)))
+                .filter(|votante| {
+                    self.get_estado_participante(id_eleccion, *votante)
+                        != Some(EstadoParticipante::VotanteRetirado)
+                })
+                .collect()
+        }
+
+        /// Devuelve los candidatos aceptados de la elección en orden de aceptación,
+        /// incluidos los que se retiraron
+        fn get_candidatos(&self, eleccion: &Eleccion) -> Vec<AccountId> {
+            (0..eleccion.cantidad_candidatos)
+                .filter_map(|posicion| self.candidatos.get((eleccion.get_id(), posicion)))
+                .collect()
+        }
+
+        /// Devuelve si el candidato se retiró de la elección
+        fn is_retirado(&self, id_eleccion: u32, id: AccountId) -> bool {
+            self.get_estado_participante(id_eleccion, id)
+                == Some(EstadoParticipante::CandidatoRetirado)
+        }
+
+        /// Devuelve la cantidad de votos de un candidato, sin validar que la elección finalizó
+        fn get_votos_registrados(&self, id_eleccion: u32, id_candidato: AccountId) -> u128 {
+            self.votos.get((id_eleccion, id_candidato)).unwrap_or(0)
+        }
+
+        /// Suma votos a un candidato
+        fn sumar_votos(&mut self, id_eleccion: u32, id_candidato: AccountId, votos: u128) {
+            let total = self.get_votos_registrados(id_eleccion, id_candidato) + votos;
+            self.votos.insert((id_eleccion, id_candidato), &total);
+        }
+
+        /// Devuelve el peso de un votante en la elección, 1 si el admin no le asignó otro
+        fn get_peso(&self, id_eleccion: u32, id_votante: AccountId) -> u64 {
+            self.pesos.get((id_eleccion, id_votante)).unwrap_or(1)
+        }
+
+        /// Devuelve si delegar el voto del votante en el delegado cerraría un ciclo de delegaciones
+        fn genera_ciclo(&self, id_eleccion: u32, votante: AccountId, delegado: AccountId) -> bool {
+            let mut actual = Some(delegado);
+            while let Some(cuenta) = actual {
+                if cuenta == votante {
+                    return true;
+                }
+                actual = self.delegaciones.get((id_eleccion, cuenta));
+            }
+            false
+        }
+
+        /// Quita al votante de los delegantes del delegado
+        fn quitar_delegante(&mut self, id_eleccion: u32, delegado: AccountId, votante: AccountId) {
+            let mut delegantes = self
+                .delegantes
+                .get((id_eleccion, delegado))
+                .unwrap_or_default();
+            delegantes.retain(|delegante| *delegante != votante);
+            self.delegantes.insert((id_eleccion, delegado), &delegantes);
+        }
+
+        /// Devuelve los votos de quienes delegaron en el votante, directa o transitivamente,
+        /// y todavia no votaron. No sigue las delegaciones de quienes ya votaron, porque sus votos
+        /// ya fueron contados con el de ellos
+        fn contar_delegados(&self, id_eleccion: u32, votante: AccountId) -> VotosDelegados {
+            let mut pendientes = self
+                .delegantes
+                .get((id_eleccion, votante))
+                .unwrap_or_default();
+            let mut delegados = VotosDelegados::default();
+            while let Some(delegante) = pendientes.pop() {
+                if self.voto_registrado(id_eleccion, delegante) {
+                    continue;
+                }
+                delegados.cantidad += 1;
+                delegados.peso += self.get_peso(id_eleccion, delegante) as u128;
+                pendientes.extend(
+                    self.delegantes
+                        .get((id_eleccion, delegante))
+                        .unwrap_or_default(),
+                );
+            }
+            delegados
+        }
+
+        /// Devuelve el primer votante de la cadena de delegaciones del votante que ya votó,
+        /// que es quien representa su voto hasta que vote directamente
+        fn buscar_representante(&self, id_eleccion: u32, votante: AccountId) -> Option<AccountId> {
+            let mut actual = self.delegaciones.get((id_eleccion, votante));
+            while let Some(delegado) = actual {
+                if self.voto_registrado(id_eleccion, delegado) {
+                    return Some(delegado);
+                }
+                actual = self.delegaciones.get((id_eleccion, delegado));
+            }
+            None
+        }
+
+        /// Suma o resta votos delegados al destino de un voto
+        fn ajustar_delegados(
+            &mut self,
+            eleccion: &mut Eleccion,
+            destino: &DestinoVoto,
+            delegados: &VotosDelegados,
+            sumar: bool,
+        ) {
+            if let DestinoVoto::Candidato(candidato) = destino {
+                let votos = self.get_votos_registrados(eleccion.get_id(), *candidato);
+                let votos = if sumar {
+                    votos + delegados.peso
+                } else {
+                    votos - delegados.peso
+                };
+                self.votos.insert((eleccion.get_id(), *candidato), &votos);
+            }
+
+            if sumar {
+                eleccion.sumar_delegados(destino, delegados);
+            } else {
+                eleccion.restar_delegados(destino, delegados);
+            }
+        }
+
+        /// Suma al voto directo del votante los votos de quienes delegaron en él y todavia no votaron.
+        /// Si el votante había delegado su voto, el voto directo reemplaza a la delegación y
+        /// sus votos se descuentan del delegado que los representaba.
+        /// El costo es proporcional a la cantidad de delegaciones involucradas
+        fn registrar_delegados(
+            &mut self,
+            eleccion: &mut Eleccion,
+            votante: AccountId,
+            destino: DestinoVoto,
+        ) {
+            let id_eleccion = eleccion.get_id();
+            let delegados = self.contar_delegados(id_eleccion, votante);
+            if delegados.cantidad > 0 {
+                self.ajustar_delegados(eleccion, &destino, &delegados, true);
+                self.votos_delegados
+                    .insert((id_eleccion, votante), &(destino, delegados));
+            }
+
+            if let Some(representante) = self.buscar_representante(id_eleccion, votante) {
+                // El representante votó mientras el votante y sus delegados no lo habían hecho,
+                // por lo que sus votos delegados los incluyen
+                let (destino_representante, delegados_representante) = self
+                    .votos_delegados
+                    .get((id_eleccion, representante))
+                    .unwrap();
+                let descontados = VotosDelegados {
+                    cantidad: delegados.cantidad + 1,
+                    peso: delegados.peso + self.get_peso(id_eleccion, votante) as u128,
+                };
+                self.ajustar_delegados(eleccion, &destino_representante, &descontados, false);
+                let restantes = VotosDelegados {
+                    cantidad: delegados_representante.cantidad - descontados.cantidad,
+                    peso: delegados_representante.peso - descontados.peso,
+                };
+                self.votos_delegados.insert(
+                    (id_eleccion, representante),
+                    &(destino_representante, restantes),
+                );
+            }
+        }
+
+        /// Guarda una nueva elección y emite el evento de su creación
+        /// Devuelve el id de la elección
+        /// Verifica las fechas y la configuración de una elección a crear
+        fn validar_eleccion(
+            fecha_inicio: &Fecha,
+            fecha_fin: &Fecha,
+            config: &ConfigEleccion,
+        ) -> Result<()> {
+            if !fecha_inicio.es_fecha_valida() || !fecha_fin.es_fecha_valida() {
+                return Err(VotacionError::FechaInvalida);
+            }
+
+            if fecha_inicio.timestamp().unwrap() > fecha_fin.timestamp().unwrap() {
+                return Err(VotacionError::FechaInicioMayorQueFin);
+            }
+
+            config.validar(fecha_inicio, fecha_fin)
+        }
+
+        /// Aplica una transición de estado a la elección y emite el evento con el motivo.
+        /// La transición devuelve el nuevo estado. No valida el caller
+        fn cambiar_estado_eleccion(
+            &mut self,
+            id_eleccion: u32,
+            motivo: String,
+            transicion: impl FnOnce(&mut Eleccion, &Fecha) -> Result<EstadoEleccion>,
+        ) -> Result<()> {
+            if motivo.is_empty() {
+                return Err(VotacionError::MotivoVacio);
+            }
+
+            let current_time = Fecha::from_timestamp(self.env().block_timestamp());
+
+            let mut eleccion = self
+                .get_eleccion(id_eleccion)
+                .ok_or(VotacionError::EleccionNoEncontrada)?;
+            let estado_anterior = eleccion.get_estado();
+            let estado_nuevo = transicion(&mut eleccion, &current_time)?;
+            self.elecciones.insert(id_eleccion, &eleccion);
+
+            self.env().emit_event(EstadoEleccionCambiado {
+                id_eleccion,
+                estado_anterior,
+                estado_nuevo,
+                motivo,
+            });
+            Ok(())
+        }
+
+        /// Verifica que el cambio de estado se pueda aplicar a la elección, sin guardarla
+        fn validar_cambio_estado(
+            &self,
+            id_eleccion: u32,
+            cambio: CambioEstado,
+            motivo: &str,
+        ) -> Result<()> {
+            if motivo.is_empty() {
+                return Err(VotacionError::MotivoVacio);
+            }
+
+            let mut eleccion = self
+                .get_eleccion(id_eleccion)
+                .ok_or(VotacionError::EleccionNoEncontrada)?;
+            let current_time = Fecha::from_timestamp(self.env().block_timestamp());
+            eleccion.cambiar_estado(cambio, &current_time).map(|_| ())
+        }
+
+        /// Aplica el cambio de estado a la elección y emite el evento con el motivo
+        fn registrar_cambio_estado(
+            &mut self,
+            id_eleccion: u32,
+            cambio: CambioEstado,
+            motivo: String,
+        ) -> Result<()> {
+            self.cambiar_estado_eleccion(id_eleccion, motivo, |eleccion, current_time| {
+                eleccion.cambiar_estado(cambio, current_time)
+            })
+        }
+
+        /// Verifica que se pueda postergar la fecha de fin de la elección, sin guardarla
+        fn validar_extension(
+            &self,
+            id_eleccion: u32,
+            fecha_fin: &Fecha,
+            motivo: &str,
+        ) -> Result<()> {
+            if motivo.is_empty() {
+                return Err(VotacionError::MotivoVacio);
+            }
+
+            let mut eleccion = self
+                .get_eleccion(id_eleccion)
+                .ok_or(VotacionError::EleccionNoEncontrada)?;
+            let current_time = Fecha::from_timestamp(self.env().block_timestamp());
+            eleccion.extender(fecha_fin.clone(), &current_time)
+        }
+
+        /// Posterga la fecha de fin de la elección y emite el evento con el motivo
+        fn registrar_extension(
+            &mut self,
+            id_eleccion: u32,
+            fecha_fin: Fecha,
+            motivo: String,
+        ) -> Result<()> {
+            if motivo.is_empty() {
+                return Err(VotacionError::MotivoVacio);
+            }
+
+            let timestamp = self.env().block_timestamp();
+
+            let mut eleccion = self
+                .get_eleccion(id_eleccion)
+                .ok_or(VotacionError::EleccionNoEncontrada)?;
+            let fecha_fin_anterior = eleccion.get_fecha_fin();
+            eleccion.extender(fecha_fin.clone(), &Fecha::from_timestamp(timestamp))?;
+            self.elecciones.insert(id_eleccion, &eleccion);
+
+            self.env().emit_event(EleccionExtendida {
+                id_eleccion,
+                fecha_fin_anterior,
+                fecha_fin_nueva: fecha_fin,
+                motivo,
+            });
+            Ok(())
+        }
+
+        fn registrar_eleccion(
+            &mut self,
+            fecha_inicio: Fecha,
+            fecha_fin: Fecha,
+            config: ConfigEleccion,
+            primera_vuelta: Option<u32>,
+        ) -> u32 {
+            let id = self.cantidad_elecciones;
+            let mut eleccion =
+                Eleccion::new(id, fecha_inicio.clone(), fecha_fin.clone(), config.clone());
+            eleccion.primera_vuelta = primera_vuelta;
+            eleccion.semilla_desempate = Some(self.mezclar_semilla_desempate(id));
+            self.elecciones.insert(id, &eleccion);
+            self.cantidad_elecciones += 1;
+            self.env().emit_event(EleccionCreada {
+                id_eleccion: id,
+                fecha_inicio,
+                fecha_fin,
+                config,
+            });
+            id
+        }
+
+        /// Crea la segunda vuelta de una elección entre los dos candidatos pasados por parametro,
+        /// con los mismos votantes y pesos que la primera vuelta.
+        /// El costo es proporcional a la cantidad de votantes, que se copian a la segunda vuelta
+        fn crear_segunda_vuelta(
+            &mut self,
+            primera_vuelta: &Eleccion,
+            regla: ReglaBalotaje,
+            finalistas: [AccountId; 2],
+        ) -> u32 {
+            let config = ConfigEleccion {
+                balotaje: None,
+                inscripcion: None,
+                // El período de impugnación se fijó para las fechas de la primera vuelta
+                fin_impugnacion: None,
+                ..primera_vuelta.config.clone()
+            };
+            let id = self.registrar_eleccion(
+                regla.fecha_inicio,
+                regla.fecha_fin,
+                config,
+                Some(primera_vuelta.get_id()),
+            );
+            let mut segunda_vuelta = self.elecciones.get(id).unwrap();
+            self.copiar_participantes(primera_vuelta, &mut segunda_vuelta, &finalistas);
+            self.elecciones.insert(id, &segunda_vuelta);
+            id
+        }
+
+        /// Crea el segundo llamado de una elección que no alcanzó el quórum, entre las fechas
+        /// pasadas por parametro, con los mismos candidatos, votantes y pesos que el primero.
+        /// El costo es proporcional a la cantidad de votantes, que se copian al segundo llamado
+        fn crear_segundo_llamado(
+            &mut self,
+            primer_llamado: &Eleccion,
+            fecha_inicio: Fecha,
+            fecha_fin: Fecha,
+        ) -> u32 {
+            let config = ConfigEleccion {
+                // El segundo llamado es válido con cualquier participación
+                quorum: None,
+                // Las fechas de la segunda vuelta y de la impugnación se fijaron para el primer llamado
+                balotaje: None,
+                fin_impugnacion: None,
+                inscripcion: None,
+                ..primer_llamado.config.clone()
+            };
+            let id = self.registrar_eleccion(fecha_inicio, fecha_fin, config, None);
+            let mut segundo_llamado = self.elecciones.get(id).unwrap();
+            segundo_llamado.primer_llamado = Some(primer_llamado.get_id());
+            let candidatos: Vec<AccountId> = self
+                .get_candidatos(primer_llamado)
+                .into_iter()
+                .filter(|candidato| !self.is_retirado(primer_llamado.get_id(), *candidato))
+                .collect();
+            self.copiar_participantes(primer_llamado, &mut segundo_llamado, &candidatos);
+            self.elecciones.insert(id, &segundo_llamado);
+            id
+        }
+
+        /// Registra en `destino` los candidatos pasados por parametro y los votantes de `origen`
+        /// con sus pesos. No guarda `destino`
+        fn copiar_participantes(
+            &mut self,
+            origen: &Eleccion,
+            destino: &mut Eleccion,
+            candidatos: &[AccountId],
+        ) {
+            let id = destino.get_id();
+            for (posicion, candidato) in candidatos.iter().enumerate() {
+                self.participantes
+                    .insert((id, *candidato), &EstadoParticipante::Candidato);
+                self.candidatos.insert((id, posicion as u32), candidato);
+                self.registrar_participacion(id, *candidato);
+            }
+            let votantes = self.get_votantes(origen);
+            for (posicion, votante) in votantes.iter().enumerate() {
+                self.participantes
+                    .insert((id, *votante), &EstadoParticipante::Votante);
+                self.votantes.insert((id, posicion as u32), votante);
+                self.registrar_participacion(id, *votante);
+                if let Some(peso) = self.pesos.get((origen.get_id(), *votante)) {
+                    self.pesos.insert((id, *votante), &peso);
+                }
+            }
+            destino.cantidad_candidatos = candidatos.len() as u32;
+            destino.cantidad_votantes = votantes.len() as u32;
+            destino.peso_votantes = origen.get_peso_votantes();
+        }
+
+        /// Registra el voto en blanco o nulo del caller
+        fn votar_sin_candidato(&mut self, id_eleccion: u32, voto: VotoSinCandidato) -> Result<()> {
+            let caller = self.env().caller();
+            if self.get_usuario(caller).is_err() {
+                return Err(VotacionError::UsuarioNoEncontrado);
+            }
+
+            let timestamp = self.env().block_timestamp();
+
+            let mut eleccion = self
+                .get_eleccion(id_eleccion)
+                .ok_or(VotacionError::EleccionNoEncontrada)?;
+            eleccion.votar_sin_candidato(
+                self.get_estado_participante(id_eleccion, caller),
+                self.get_peso(id_eleccion, caller),
+                voto,
+                self.voto_registrado(id_eleccion, caller),
+                &Fecha::from_timestamp(timestamp),
+            )?;
+            self.registrar_voto(&mut eleccion, caller);
+            self.registrar_delegados(&mut eleccion, caller, DestinoVoto::SinCandidato(voto));
+            self.elecciones.insert(id_eleccion, &eleccion);
+
+            self.env().emit_event(VotoEmitido {
+                id_eleccion,
+                votante: caller,
+            });
+            Ok(())
+        }
+
+        /// Devuelve true si el contrato está en modo privado
+        #[ink(message)]
+        pub fn get_modo_privado(&self) -> bool {
+            self.modo_privado
+        }
+
+        /// Combina el dato pasado por parametro con el número y el timestamp del bloque actual
+        fn mezclar_semilla_desempate(&self, dato: impl ink::scale::Encode) -> [u8; 32] {
+            let mut semilla = [0u8; 32];
+            ink::env::hash_encoded::<ink::env::hash::Blake2x256, _>(
+                &(
+                    dato,
+                    self.env().block_number(),
+                    self.env().block_timestamp(),
+                ),
+                &mut semilla,
+            );
+            semilla
+        }
+
+        /// Registra que el votante votó en la elección y combina su voto con la semilla
+        /// de desempate. No guarda la elección
+        fn registrar_voto(&mut self, eleccion: &mut Eleccion, votante: AccountId) {
+            self.votantes_voto.insert((eleccion.get_id(), votante), &());
+            eleccion.semilla_desempate =
+                Some(self.mezclar_semilla_desempate((eleccion.semilla_desempate, votante)));
+        }
+
+        /// Calcula el hash de los datos personales de un usuario en modo privado
+        /// con la sal pasada por parametro
+        pub fn calcular_hash_datos(dni: String, direccion: String, sal: [u8; 32]) -> HashDatos {
+            let mut hash = HashDatos::default();
+            ink::env::hash_encoded::<ink::env::hash::Blake2x256, _>(
+                &(dni, direccion, sal),
+                &mut hash,
+            );
+            hash
+        }
+
+        /// Calcula el compromiso del voto secreto de un votante por un candidato con la sal pasada
+        /// por parametro. Incluye la eleccion y el votante para que el compromiso no se pueda copiar
+        /// ni vincular con los de otras elecciones que usen la misma sal
+        pub fn calcular_compromiso(
+            id_eleccion: u32,
+            votante: AccountId,
+            id_candidato: AccountId,
+            sal: [u8; 32],
+        ) -> Compromiso {
+            let mut compromiso = Compromiso::default();
+            ink::env::hash_encoded::<ink::env::hash::Blake2x256, _>(
+                &(id_eleccion, votante, id_candidato, sal),
+                &mut compromiso,
+            );
+            compromiso
+        }
+
+        /// Devuelve cada usuario junto a su estado en la elección
+        fn get_estados(
+            &self,
+            id_eleccion: u32,
+            usuarios: &[AccountId],
+        ) -> Vec<(AccountId, Option<EstadoParticipante>)> {
+            usuarios
+                .iter()
+                .map(|id| (*id, self.get_estado_participante(id_eleccion, *id)))
+                .collect()
+        }
+
+        /// Devuelve las boletas de una elección preferencial finalizada, en orden de emisión
+        ///
+        /// # Errores
+        /// - Devuelve un error si la elección no es preferencial
+        /// - Devuelve un error si la elección no finalizó
+        fn get_boletas(
+            &self,
+            eleccion: &Eleccion,
+            current_time: &Fecha,
+        ) -> Result<Vec<Vec<AccountId>>> {
+            if eleccion.get_tipo() != TipoEleccion::Preferencial {
+                return Err(VotacionError::TipoEleccionInvalido);
+            }
+
+            if !eleccion.get_finalizada(current_time) {
+                return Err(VotacionError::EleccionNoFinalizada);
+            }
+
+            Ok((0..eleccion.get_cantidad_votos())
+                .filter_map(|numero| self.boletas.get((eleccion.get_id(), numero)))
+                .collect())
+        }
+
+        /// Devuelve las listas de una elección por listas finalizada junto a sus votos
+        ///
+        /// # Errores
+        /// - Devuelve un error si la elección no es por listas
+        /// - Devuelve un error si la elección no finalizó
+        fn get_votos_listas(
+            &self,
+            eleccion: &Eleccion,
+            current_time: &Fecha,
+        ) -> Result<Vec<(Lista, u128)>> {
+            if eleccion.get_tipo() != TipoEleccion::Listas {
+                return Err(VotacionError::TipoEleccionInvalido);
+            }
+
+            if !eleccion.get_finalizada(current_time) {
+                return Err(VotacionError::EleccionNoFinalizada);
+            }
+
+            let id_eleccion = eleccion.get_id();
+            Ok((0..eleccion.get_cantidad_listas())
+                .filter_map(|id_lista| {
+                    let lista = self.listas.get((id_eleccion, id_lista))?;
+                    let votos = self.votos_lista.get((id_eleccion, id_lista)).unwrap_or(0);
+                    Some((lista, votos))
+                })
+                .collect())
+        }
+
+        /// Devuelve los votos de los candidatos de la elección que no se retiraron, almacenados por id
+        ///
+        /// # Errores
+        /// Devuelve un error si la elección no finalizó
+        fn get_votos(
+            &self,
+            eleccion: &Eleccion,
+            current_time: &Fecha,
+        ) -> Result<Vec<(AccountId, u128)>> {
+            eleccion.validar_resultado(current_time)?;
+
+            Ok(self
+                .get_candidatos(eleccion)
+                .into_iter()
+                .filter(|candidato| !self.is_retirado(eleccion.get_id(), *candidato))
+                .map(|candidato| {
+                    (
+                        candidato,
+                        self.get_votos_registrados(eleccion.get_id(), candidato),
+                    )
+                })
+                .collect())
+        }
+
+        /// Devuelve la suma de los votos de los candidatos retirados de la elección
+        /// si la política de retiro los conserva, o 0 si los anula
+        fn get_votos_conservados(&self, eleccion: &Eleccion) -> u128 {
+            if eleccion.config.retiro_candidatos != PoliticaRetiro::ConservarVotos {
+                return 0;
+            }
+
+            self.get_candidatos(eleccion)
+                .into_iter()
+                .filter(|candidato| self.is_retirado(eleccion.get_id(), *candidato))
+                .map(|candidato| self.get_votos_registrados(eleccion.get_id(), candidato))
+                .sum()
+        }
+
+        /// Ordena los votos de mayor a menor, con los empatados según la política de desempate
+        /// de la elección. Con `PoliticaDesempate::SegundaVuelta` quedan en orden de aceptación
+        fn ordenar_votos(&self, eleccion: &Eleccion, votos: &mut [(AccountId, u128)]) {
+            // Los votos llegan en orden de aceptación
+            match eleccion.config.desempate {
+                PoliticaDesempate::OrdenRegistro | PoliticaDesempate::SegundaVuelta => {}
+                PoliticaDesempate::MayorEdad => {
+                    // A igual fecha de nacimiento se conserva el orden de aceptación,
+                    // y los candidatos que no están registrados quedan últimos
+                    let mut con_nacimiento: Vec<_> = votos
+                        .iter()
+                        .map(|voto| {
+                            let usuario = self.usuarios.get(voto.0);
+                            (*voto, usuario.map(|usuario| usuario.get_fecha_nacimiento()))
+                        })
+                        .collect();
+                    con_nacimiento.sort_by(|(_, a), (_, b)| match (a, b) {
+                        (Some(a), Some(b)) if a.es_mayor(b) => Ordering::Greater,
+                        (Some(a), Some(b)) if b.es_mayor(a) => Ordering::Less,
+                        (Some(_), None) => Ordering::Less,
+                        (None, Some(_)) => Ordering::Greater,
+                        _ => Ordering::Equal,
+                    });
+                    for (voto, (ordenado, _)) in votos.iter_mut().zip(con_nacimiento) {
+                        *voto = ordenado;
+                    }
+                }
+                PoliticaDesempate::Aleatorio => {
+                    let semilla = eleccion.semilla_desempate.unwrap_or_default();
+                    votos.sort_by_cached_key(|(id, _)| {
+                        PoliticaDesempate::clave_aleatoria(&semilla, *id)
+                    });
+                }
+            }
+
+            // sort_by es estable, los empatados conservan el orden del desempate
+            votos.sort_by(|(_, a), (_, b)| b.cmp(a));
+        }
+    }
+
+    impl EleccionManager for Votacion {
+        /// Crea una elección y la agrega a la lista de elecciones
+        /// devuelve el id de la elección creada
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el admin
+        /// - Devuelve un error si hay un consejo configurado
+        /// - Devuelve un error si la fecha de inicio o fin no es válida
+        /// - Devuelve un error si la fecha de inicio es mayor que la fecha de fin
+        /// - Devuelve un error si la cantidad de cargos es cero, o mayor a uno en una elección preferencial
+        /// - Devuelve un error si una elección secreta no es simple
+        /// - Devuelve un error si la fecha de fin de revelación no es válida o es anterior a la fecha de fin
+        /// - Devuelve un error si una elección con balotaje no es simple, es secreta o elige más de un cargo
+        /// - Devuelve un error si la regla de balotaje no es válida
+        /// - Devuelve un error si la fecha de fin de impugnación no es válida
+        ///   o es anterior al fin de la votación o de la revelación
+        /// - Devuelve un error si la regla de quórum no es válida, o tiene segundo llamado
+        ///   en una elección por listas o secreta
+        #[ink(message)]
+        fn crear_eleccion(
+            &mut self,
+            fecha_inicio: Fecha,
+            fecha_fin: Fecha,
+            config: ConfigEleccion,
+        ) -> Result<u32> {
+            self.validar_accion_directa()?;
+            Self::validar_eleccion(&fecha_inicio, &fecha_fin, &config)?;
+            Ok(self.registrar_eleccion(fecha_inicio, fecha_fin, config, None))
+        }
+
+        /// Devuelve una elección por su ID, con su estado actual,
+        /// None si la elección no se encuentra
+        #[ink(message)]
+        fn get_eleccion(&self, id: u32) -> Option<Eleccion> {
+            let current_time = Fecha::from_timestamp(self.env().block_timestamp());
+            self.elecciones.get(id).map(|mut eleccion| {
+                eleccion.estado = eleccion.calcular_estado(&current_time);
+                eleccion
+            })
+        }
+
+        /// Crea una lista de candidatos en una elección por listas
+        /// Los candidatos ocupan los cargos de la lista en el orden pasado por parametro
+        /// Devuelve el id de la lista creada
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el admin
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion no es por listas
+        /// - Devuelve un error si la eleccion ya finalizo
+        /// - Devuelve un error si la eleccion ya inicio
+        /// - Devuelve un error si la lista no tiene candidatos
+        /// - Devuelve un error si algun candidato no está registrado como candidato
+        /// - Devuelve un error si algun candidato está repetido
+        /// - Devuelve un error si algun candidato ya integra otra lista
+        #[ink(message)]
+        fn crear_lista(
+            &mut self,
+            id_eleccion: u32,
+            nombre: String,
+            candidatos: Vec<AccountId>,
+        ) -> Result<u32> {
+            if !self.caller_is_admin() {
+                return Err(VotacionError::NoEsAdmin);
+            }
+
+            let timestamp = self.env().block_timestamp();
+
+            let mut eleccion = self
+                .get_eleccion(id_eleccion)
+                .ok_or(VotacionError::EleccionNoEncontrada)?;
+            let en_otra_lista = candidatos
+                .iter()
+                .any(|candidato| self.listas_candidato.contains((id_eleccion, *candidato)));
+            let id_lista = eleccion.agregar_lista(
+                &self.get_estados(id_eleccion, &candidatos),
+                en_otra_lista,
+                &Fecha::from_timestamp(timestamp),
+            )?;
+            for candidato in candidatos.iter() {
+                self.listas_candidato
+                    .insert((id_eleccion, *candidato), &id_lista);
+            }
+            self.listas.insert(
+                (id_eleccion, id_lista),
+                &Lista::new(id_lista, nombre, candidatos),
+            );
+            self.elecciones.insert(id_eleccion, &eleccion);
+
+            self.env().emit_event(ListaCreada {
+                id_eleccion,
+                id_lista,
+            });
+            Ok(id_lista)
+        }
+
+        /// Devuelve una lista de una elección por su ID, None si la lista no se encuentra
+        #[ink(message)]
+        fn get_lista(&self, id_eleccion: u32, id_lista: u32) -> Option<Lista> {
+            self.listas.get((id_eleccion, id_lista))
+        }
+
+        /// Aplica la regla de balotaje a una elección finalizada.
+        /// Si el más votado no cumple la regla, crea la segunda vuelta entre los dos
+        /// más votados con los mismos votantes. Los empates se resuelven según la política
+        /// de desempate de la elección
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el admin
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion no tiene regla de balotaje
+        /// - Devuelve un error si la eleccion no finalizó
+        /// - Devuelve un error si la regla ya se aplicó
+        /// - Devuelve un error si la eleccion no alcanzó el quórum
+        /// - Devuelve un error si no terminó el período de impugnación, o si hay impugnaciones
+        ///   pendientes o aceptadas
+        /// - Devuelve un error si la eleccion tiene menos de dos candidatos
+        /// - Devuelve un error si la eleccion desempata en segunda vuelta y hay un empate
+        ///   que no permite elegir a los finalistas
+        #[ink(message)]
+        fn aplicar_balotaje(&mut self, id_eleccion: u32) -> Result<ResultadoBalotaje> {
+            if !self.caller_is_admin() {
+                return Err(VotacionError::NoEsAdmin);
+            }
+
+            let timestamp = self.env().block_timestamp();
+            let current_time = Fecha::from_timestamp(timestamp);
+
+            let mut eleccion = self
+                .get_eleccion(id_eleccion)
+                .ok_or(VotacionError::EleccionNoEncontrada)?;
+            let mut votos = self.get_votos(&eleccion, &current_time)?;
+            self.ordenar_votos(&eleccion, &mut votos);
+
+            let votos_conservados = self.get_votos_conservados(&eleccion);
+            let resultado =
+                match eleccion.aplicar_balotaje(&votos, votos_conservados, &current_time)? {
+                    Some(ganador) => ResultadoBalotaje::Ganador(ganador),
+                    None => {
+                        // aplicar_balotaje valida que haya regla y al menos dos candidatos
+                        let regla = eleccion.config.balotaje.clone().unwrap();
+                        let finalistas = [votos[0].0, votos[1].0];
+                        ResultadoBalotaje::SegundaVuelta(
+                            self.crear_segunda_vuelta(&eleccion, regla, finalistas),
+                        )
+                    }
+                };
+            eleccion.resultado_balotaje = Some(resultado);
+            self.elecciones.insert(id_eleccion, &eleccion);
+
+            self.env().emit_event(BalotajeAplicado {
+                id_eleccion,
+                resultado,
+            });
+            Ok(resultado)
+        }
+
+        /// Aplica la regla de quórum a una elección finalizada que no lo alcanzó.
+        /// La elección queda inválida y, si la regla lo indica, se crea su segundo llamado
+        /// con los mismos candidatos y votantes
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el admin
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion está suspendida, cancelada, certificada o ya es inválida
+        /// - Devuelve un error si la eleccion no tiene regla de quórum
+        /// - Devuelve un error si la eleccion no finalizó o no terminó su revelación
+        /// - Devuelve un error si la eleccion alcanzó el quórum
+        /// - Devuelve un error si ya pasó la fecha de inicio del segundo llamado
+        #[ink(message)]
+        fn aplicar_quorum(&mut self, id_eleccion: u32) -> Result<ResultadoQuorum> {
+            if !self.caller_is_admin() {
+                return Err(VotacionError::NoEsAdmin);
+            }
+
+            self.cambiar_estado_eleccion(
+                id_eleccion,
+                String::from("Quórum no alcanzado"),
+                |eleccion, current_time| {
+                    eleccion.invalidar(current_time)?;
+                    Ok(EstadoEleccion::Invalida)
+                },
+            )?;
+
+            let mut eleccion = self.get_eleccion(id_eleccion).unwrap();
+            // invalidar valida que haya regla de quórum
+            let regla = eleccion.config.quorum.clone().unwrap();
+            let resultado = match regla.segundo_llamado {
+                Some((fecha_inicio, fecha_fin)) => ResultadoQuorum::SegundoLlamado(
+                    self.crear_segundo_llamado(&eleccion, fecha_inicio, fecha_fin),
+                ),
+                None => ResultadoQuorum::Invalida,
+            };
+            eleccion.resultado_quorum = Some(resultado);
+            self.elecciones.insert(id_eleccion, &eleccion);
+
+            self.env().emit_event(QuorumAplicado {
+                id_eleccion,
+                resultado,
+            });
+            Ok(resultado)
+        }
+
+        /// Cancela una elección con el motivo pasado por parametro. La elección deja de
+        /// admitir postulaciones y votos, no tiene resultado y sus participantes pueden darse de baja
+        /// Con un consejo configurado se realiza por medio de una propuesta
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el admin
+        /// - Devuelve un error si hay un consejo configurado
+        /// - Devuelve un error si el motivo está vacío
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion ya fue certificada o cancelada
+        #[ink(message)]
+        fn cancelar_eleccion(&mut self, id_eleccion: u32, motivo: String) -> Result<()> {
+            self.validar_accion_directa()?;
+            self.registrar_cambio_estado(id_eleccion, CambioEstado::Cancelar, motivo)
+        }
+
+        /// Suspende una elección en inscripción o abierta con el motivo pasado por parametro.
+        /// Mientras está suspendida no admite postulaciones, votos ni consultas de resultado
+        /// Con un consejo configurado se realiza por medio de una propuesta
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el admin
+        /// - Devuelve un error si hay un consejo configurado
+        /// - Devuelve un error si el motivo está vacío
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion no está en inscripción ni abierta
+        #[ink(message)]
+        fn suspender_eleccion(&mut self, id_eleccion: u32, motivo: String) -> Result<()> {
+            self.validar_accion_directa()?;
+            self.registrar_cambio_estado(id_eleccion, CambioEstado::Suspender, motivo)
+        }
+
+        /// Reanuda una elección suspendida con el motivo pasado por parametro.
+        /// La elección vuelve al estado que indican sus fechas
+        /// Con un consejo configurado se realiza por medio de una propuesta
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el admin
+        /// - Devuelve un error si hay un consejo configurado
+        /// - Devuelve un error si el motivo está vacío
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion no está suspendida
+        #[ink(message)]
+        fn reanudar_eleccion(&mut self, id_eleccion: u32, motivo: String) -> Result<()> {
+            self.validar_accion_directa()?;
+            self.registrar_cambio_estado(id_eleccion, CambioEstado::Reanudar, motivo)
+        }
+
+        /// Posterga la fecha de fin de una elección que todavia no cerró, o que está suspendida,
+        /// con el motivo pasado por parametro
+        /// Con un consejo configurado se realiza por medio de una propuesta
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el admin
+        /// - Devuelve un error si hay un consejo configurado
+        /// - Devuelve un error si el motivo está vacío
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion ya finalizó, fue certificada o cancelada
+        /// - Devuelve un error si la nueva fecha de fin no es válida o no es posterior a la actual
+        /// - Devuelve un error si la fecha de fin de revelación o la segunda vuelta
+        ///   no son posteriores a la nueva fecha de fin
+        #[ink(message)]
+        fn extender_eleccion(
+            &mut self,
+            id_eleccion: u32,
+            fecha_fin: Fecha,
+            motivo: String,
+        ) -> Result<()> {
+            self.validar_accion_directa()?;
+            self.registrar_extension(id_eleccion, fecha_fin, motivo)
+        }
+
+        /// Certifica el resultado de una elección cerrada, una vez terminado su período
+        /// de impugnación y resueltas sus impugnaciones
+        /// Con un consejo configurado se realiza por medio de una propuesta
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el admin
+        /// - Devuelve un error si hay un consejo configurado
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion no finalizó o no terminó su revelación
+        /// - Devuelve un error si la eleccion está suspendida, cancelada o ya fue certificada
+        /// - Devuelve un error si no terminó el período de impugnación
+        /// - Devuelve un error si hay impugnaciones pendientes o aceptadas
+        /// - Devuelve un error si la eleccion no alcanzó el quórum
+        /// - Devuelve un error si la eleccion tiene regla de balotaje y todavia no se aplicó
+        #[ink(message)]
+        fn certificar_eleccion(&mut self, id_eleccion: u32) -> Result<()> {
+            self.validar_accion_directa()?;
+            self.registrar_cambio_estado(
+                id_eleccion,
+                CambioEstado::Certificar,
+                String::from("Certificación del resultado"),
+            )
+        }
+
+        /// Impugna el resultado de una elección finalizada con el motivo pasado por parametro.
+        /// Solo los votantes y candidatos pueden impugnar, hasta la fecha de fin de impugnación
+        /// Devuelve el id de la impugnación
+        ///
+        /// # Errores
+        /// - Devuelve un error si el motivo está vacío o supera los 280 bytes
+        /// - Devuelve un error si el caller ya tiene una impugnación pendiente en la elección
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion no admite impugnaciones
+        /// - Devuelve un error si la eleccion no finalizó o no terminó su revelación
+        /// - Devuelve un error si la eleccion está suspendida, cancelada o ya fue certificada
+        /// - Devuelve un error si terminó el período de impugnación
+        /// - Devuelve un error si el caller no es votante ni candidato de la elección
+        #[ink(message)]
+        fn impugnar_resultado(&mut self, id_eleccion: u32, motivo: String) -> Result<u32> {
+            if motivo.is_empty() {
+                return Err(VotacionError::MotivoVacio);
+            }
+
+            if motivo.len() > LONGITUD_MAXIMA_MOTIVO {
+                return Err(VotacionError::MotivoDemasiadoLargo);
+            }
+
+            let caller = self.env().caller();
+            // Cada participante tiene a lo sumo una impugnación pendiente por elección
+            if self.impugnacion_pendiente.contains((id_eleccion, caller)) {
+                return Err(VotacionError::ImpugnacionYaPresentada);
+            }
+            let current_time = Fecha::from_timestamp(self.env().block_timestamp());
+
+            let mut eleccion = self
+                .get_eleccion(id_eleccion)
+                .ok_or(VotacionError::EleccionNoEncontrada)?;
+            let id_impugnacion = eleccion.impugnar(
+                self.get_estado_participante(id_eleccion, caller),
+                &current_time,
+            )?;
+            self.impugnacion_pendiente
+                .insert((id_eleccion, caller), &());
+            self.impugnaciones.insert(
+                (id_eleccion, id_impugnacion),
+                &Impugnacion {
+                    autor: caller,
+                    motivo,
+                    estado: EstadoImpugnacion::Pendiente,
+                    resolucion: None,
+                },
+            );
+            self.elecciones.insert(id_eleccion, &eleccion);
+
+            self.env().emit_event(ImpugnacionPresentada {
+                id_eleccion,
+                id_impugnacion,
+                autor: caller,
+            });
+            Ok(id_impugnacion)
+        }
+
+        /// Acepta o rechaza una impugnación pendiente con el fundamento pasado por parametro.
+        /// Con un consejo configurado se resuelve por medio de una propuesta
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el admin
+        /// - Devuelve un error si hay un consejo configurado
+        /// - Devuelve un error si el fundamento está vacío
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la impugnación no es encontrada
+        /// - Devuelve un error si la impugnación ya fue resuelta
+        #[ink(message)]
+        fn resolver_impugnacion(
+            &mut self,
+            id_eleccion: u32,
+            id_impugnacion: u32,
+            aceptada: bool,
+            resolucion: String,
+        ) -> Result<()> {
+            self.validar_accion_directa()?;
+            self.validar_resolucion(id_eleccion, id_impugnacion, &resolucion)?;
+            self.registrar_resolucion(id_eleccion, id_impugnacion, aceptada, resolucion)
+        }
+
+        /// Devuelve una impugnación de una elección por su ID
+        /// Devuelve None si la impugnación no se encuentra
+        #[ink(message)]
+        fn get_impugnacion(&self, id_eleccion: u32, id_impugnacion: u32) -> Option<Impugnacion> {
+            self.impugnaciones.get((id_eleccion, id_impugnacion))
+        }
+    }
+
+    impl UserManager for Votacion {
+        /// Devuelve true si el caller es el admin del contrato o tiene el rol Admin,
+        /// false en cualquier otro caso
+        #[ink(message)]
+        fn caller_is_admin(&self) -> bool {
+            let caller = self.env().caller();
+            self.admin == caller || self.roles.contains((Rol::Admin, caller))
+        }
+
+        /// Crea un usuario y lo agrega a la lista de usuarios_por_aceptar
+        /// Devuelve el usuario creado si no hay errores
+        ///
+        /// # Errores
+        /// - Devuelve un error si el contrato está en modo privado
+        /// - Devuelve un error si el usuario ya está registrado
+        /// - Devuelve un error si el usuario ya está postulado
+        /// - Devuelve un error si la fecha de nacimiento es inválida o posterior a la fecha actual
+        /// - Devuelve un error si el dni está vacío, no es numérico o no tiene 7 u 8 dígitos
+        /// - Devuelve un error si el dni ya está registrado por otro usuario
+        #[ink(message)]
+        fn postular_usuario(
+            &mut self,
+            nombre: String,
+            apellido: String,
+            direccion: String,
+            dni: String,
+            fecha_nacimiento: Fecha,
+        ) -> Result<Usuario> {
+            if self.modo_privado {
+                return Err(VotacionError::ModoPrivadoActivo);
+            }
+
+            let id = self.env().caller();
+            self.registrar_postulacion_usuario(Usuario::new(
+                id,
+                nombre,
+                apellido,
+                direccion,
+                dni,
+                fecha_nacimiento,
+            ))
+        }
+
+        /// Crea un usuario en modo privado y lo agrega a la lista de usuarios_por_aceptar.
+        /// En lugar del dni y la dirección se guarda el hash calculado con calcular_hash_datos,
+        /// que el registrador verifica fuera de la cadena junto a la unicidad del dni
+        /// Devuelve el usuario creado si no hay errores
+        ///
+        /// # Errores
+        /// - Devuelve un error si el contrato no está en modo privado
+        /// - Devuelve un error si el usuario ya está registrado
+        /// - Devuelve un error si el usuario ya está postulado
+        /// - Devuelve un error si la fecha de nacimiento es inválida o posterior a la fecha actual
+        #[ink(message)]
+        fn postular_usuario_privado(
+            &mut self,
+            nombre: String,
+            apellido: String,
+            hash_datos: HashDatos,
+            fecha_nacimiento: Fecha,
+        ) -> Result<Usuario> {
+            if !self.modo_privado {
+                return Err(VotacionError::ModoPrivadoInactivo);
+            }
+
+            let id = self.env().caller();
+            self.registrar_postulacion_usuario(Usuario::new_privado(
+                id,
+                nombre,
+                apellido,
+                hash_datos,
+                fecha_nacimiento,
+            ))
+        }
+
+        /// Acepta un usuario de la lista usuarios_por_aceptar y lo agrega a la lista de usuarios
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es admin ni registrador
+        /// - Devuelve un error si el usuario no se encuentra en la lista de usuarios postulados
+        #[ink(message)]
+        fn aceptar_usuario(&mut self, id: AccountId) -> Result<()> {
+            if !self.caller_tiene_rol(Rol::Registrador) {
+                return Err(VotacionError::NoTieneRol);
+            }
+
+            let usuario = self
+                .usuarios_sin_aceptar
+                .take(id)
+                .ok_or(VotacionError::UsuarioSinAceptarNoEncontrado)?;
+            self.usuarios.insert(id, &usuario);
+            self.env().emit_event(UsuarioAceptado { usuario: id });
+            Ok(())
+        }
+
+        /// Rechaza un usuario de la lista usuarios_por_aceptar con el motivo pasado por parametro
+        /// y libera su dni. El usuario puede consultar el motivo y volver a postularse
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es admin ni registrador
+        /// - Devuelve un error si el usuario no se encuentra en la lista de usuarios postulados
+        #[ink(message)]
+        fn rechazar_usuario(&mut self, id: AccountId, motivo: MotivoRechazo) -> Result<()> {
+            if !self.caller_tiene_rol(Rol::Registrador) {
+                return Err(VotacionError::NoTieneRol);
+            }
+
+            let usuario = self
+                .usuarios_sin_aceptar
+                .take(id)
+                .ok_or(VotacionError::UsuarioSinAceptarNoEncontrado)?;
+            self.liberar_dni(&usuario);
+            self.rechazos_usuario.insert(id, &motivo);
+            self.env().emit_event(UsuarioRechazado {
+                usuario: id,
+                motivo,
+            });
+            Ok(())
+        }
+
+        /// Devuelve el motivo por el que se rechazó la postulación del usuario,
+        /// None si no fue rechazado o si volvió a postularse
+        #[ink(message)]
+        fn get_rechazo_usuario(&self, id: AccountId) -> Option<MotivoRechazo> {
+            self.rechazos_usuario.get(id)
+        }
+
+        /// Obtiene un usuario sin aceptar por su id
+        ///
+        /// # Errores
+        /// Devuelve un error si no se encuentra el usuario
+        #[ink(message)]
+        fn get_usuario_sin_aceptar(&self, id: AccountId) -> Result<Usuario> {
+            self.usuarios_sin_aceptar
+                .get(id)
+                .ok_or(VotacionError::UsuarioSinAceptarNoEncontrado)
+        }
+
+        /// Obtiene un usuario por su id
+        ///
+        /// # Errores
+        /// Devuelve un error si no se encuentra el usuario
+        #[ink(message)]
+        fn get_usuario(&self, id: AccountId) -> Result<Usuario> {
+            self.usuarios
+                .get(id)
+                .ok_or(VotacionError::UsuarioNoEncontrado)
+        }
+
+        /// Actualiza los datos del usuario (caller) y devuelve el usuario actualizado.
+        /// Si el usuario ya fue aceptado y cambia su nombre, apellido, dni o fecha de nacimiento,
+        /// vuelve a la lista de usuarios_por_aceptar para ser validado otra vez
+        ///
+        /// # Errores
+        /// - Devuelve un error si el contrato está en modo privado
+        /// - Devuelve un error si el usuario no es encontrado
+        /// - Devuelve un error si cambian los datos de identidad de un candidato o votante
+        ///   de una elección que todavia no tiene resultado
+        /// - Devuelve un error si la fecha de nacimiento es inválida o posterior a la fecha actual
+        /// - Devuelve un error si el dni está vacío, no es numérico o no tiene 7 u 8 dígitos
+        /// - Devuelve un error si el dni ya está registrado por otro usuario
+        #[ink(message)]
+        fn actualizar_usuario(
+            &mut self,
+            nombre: String,
+            apellido: String,
+            direccion: String,
+            dni: String,
+            fecha_nacimiento: Fecha,
+        ) -> Result<Usuario> {
+            if self.modo_privado {
+                return Err(VotacionError::ModoPrivadoActivo);
+            }
+
+            let id = self.env().caller();
+            self.registrar_actualizacion_usuario(Usuario::new(
+                id,
+                nombre,
+                apellido,
+                direccion,
+                dni,
+                fecha_nacimiento,
+            ))
+        }
+
+        /// Actualiza los datos del usuario (caller) en modo privado y devuelve el usuario
+        /// actualizado. Si el usuario ya fue aceptado y cambia su nombre, apellido, fecha de
+        /// nacimiento o el hash de sus datos, vuelve a la lista de usuarios_por_aceptar
+        /// para ser validado otra vez
+        ///
+        /// # Errores
+        /// - Devuelve un error si el contrato no está en modo privado
+        /// - Devuelve un error si el usuario no es encontrado
+        /// - Devuelve un error si cambian los datos de identidad de un candidato o votante
+        ///   de una elección que todavia no tiene resultado
+        /// - Devuelve un error si la fecha de nacimiento es inválida o posterior a la fecha actual
+        #[ink(message)]
+        fn actualizar_usuario_privado(
+            &mut self,
+            nombre: String,
+            apellido: String,
+            hash_datos: HashDatos,
+            fecha_nacimiento: Fecha,
+        ) -> Result<Usuario> {
+            if !self.modo_privado {
+                return Err(VotacionError::ModoPrivadoInactivo);
+            }
+
+            let id = self.env().caller();
+            self.registrar_actualizacion_usuario(Usuario::new_privado(
+                id,
+                nombre,
+                apellido,
+                hash_datos,
+                fecha_nacimiento,
+            ))
+        }
+
+        /// Da de baja al usuario (caller), aceptado o postulado, libera su dni y retira
+        /// sus postulaciones a elecciones. El usuario puede volver a postularse
+        ///
+        /// # Errores
+        /// - Devuelve un error si el usuario no es encontrado
+        /// - Devuelve un error si el usuario es candidato o votante de una elección
+        ///   que todavia no tiene resultado
+        #[ink(message)]
+        fn darse_de_baja(&mut self) -> Result<()> {
+            let id = self.env().caller();
+
+            // Un usuario sin aceptar no puede ser candidato ni votante de una elección sin resultado
+            let usuario = match self.usuarios_sin_aceptar.take(id) {
+                Some(usuario) => usuario,
+                None => {
+                    let usuario = self.get_usuario(id)?;
+                    self.validar_sin_elecciones_activas(id)?;
+                    self.usuarios.remove(id);
+                    usuario
+                }
+            };
+            self.liberar_dni(&usuario);
+            self.retirar_postulaciones(id);
+
+            self.env().emit_event(UsuarioDadoDeBaja { usuario: id });
+            Ok(())
+        }
+    }
+
+    impl RolManager for Votacion {
+        /// Otorga el rol pasado por parametro a la cuenta
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el admin
+        /// - Devuelve un error si el rol es Admin y hay un consejo configurado
+        /// - Devuelve un error si la eleccion de una autoridad no es encontrada
+        /// - Devuelve un error si la cuenta ya tiene el rol
+        #[ink(message)]
+        fn otorgar_rol(&mut self, cuenta: AccountId, rol: Rol) -> Result<()> {
+            self.validar_cambio_rol(rol)?;
+            self.validar_otorgar_rol(cuenta, rol)?;
+            self.registrar_rol(cuenta, rol);
+            Ok(())
+        }
+
+        /// Revoca el rol pasado por parametro a la cuenta
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el admin
+        /// - Devuelve un error si el rol es Admin y hay un consejo configurado
+        /// - Devuelve un error si la cuenta no tiene el rol
+        #[ink(message)]
+        fn revocar_rol(&mut self, cuenta: AccountId, rol: Rol) -> Result<()> {
+            self.validar_cambio_rol(rol)?;
+            self.validar_revocar_rol(cuenta, rol)?;
+            self.quitar_rol(cuenta, rol);
+            Ok(())
+        }
+
+        /// Devuelve true si la cuenta tiene el rol pasado por parametro
+        #[ink(message)]
+        fn tiene_rol(&self, cuenta: AccountId, rol: Rol) -> bool {
+            self.roles.contains((rol, cuenta))
+        }
+
+        /// Devuelve las cuentas que tienen el rol pasado por parametro, en el orden en que se otorgó
+        #[ink(message)]
+        fn get_cuentas_rol(&self, rol: Rol) -> Vec<AccountId> {
+            self.cuentas_rol.get(rol).unwrap_or_default()
+        }
+    }
+
+    impl ConsejoManager for Votacion {
+        /// Configura el consejo que aprueba las acciones del admin.
+        /// Desde ese momento set_admin, set_reporte, crear_eleccion y otorgar o revocar
+        /// el rol Admin solo se ejecutan mediante propuestas, y el consejo solo se cambia con una propuesta
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el admin
+        /// - Devuelve un error si el consejo ya está configurado
+        /// - Devuelve un error si hay miembros repetidos, o si las aprobaciones necesarias
+        ///   son cero o más que los miembros
+        #[ink(message)]
+        fn configurar_consejo(&mut self, miembros: Vec<AccountId>, umbral: u32) -> Result<()> {
+            if !self.caller_is_admin() {
+                return Err(VotacionError::NoEsAdmin);
+            }
+
+            if self.umbral > 0 {
+                return Err(VotacionError::ConsejoYaConfigurado);
+            }
+
+            Self::validar_consejo(&miembros, umbral)?;
+            self.cambiar_consejo(miembros, umbral);
+            Ok(())
+        }
+
+        /// Crea una propuesta con la acción pasada por parametro, aprobada por el caller.
+        /// Si alcanza las aprobaciones necesarias se ejecuta en el momento
+        /// Devuelve el id de la propuesta creada
+        ///
+        /// # Errores
+        /// - Devuelve un error si el consejo no está configurado
+        /// - Devuelve un error si el caller no es miembro del consejo
+        /// - Devuelve un error si la acción no es válida
+        #[ink(message)]
+        fn proponer(&mut self, accion: Accion) -> Result<u32> {
+            if self.umbral == 0 {
+                return Err(VotacionError::ConsejoNoConfigurado);
+            }
+
+            let caller = self.env().caller();
+            if !self.consejo.contains(&caller) {
+                return Err(VotacionError::NoEsMiembroConsejo);
+            }
+
+            self.validar_accion(&accion)?;
+
+            let id = self.cantidad_propuestas;
+            let mut propuesta = Propuesta {
+                accion: accion.clone(),
+                proponente: caller,
+                aprobaciones: Vec::from([caller]),
+                vencimiento: self.env().block_timestamp() + VIGENCIA_PROPUESTA,
+                version_consejo: self.version_consejo,
+                ejecutada: false,
+            };
+            self.cantidad_propuestas += 1;
+            self.env().emit_event(PropuestaCreada {
+                id_propuesta: id,
+                proponente: caller,
+                accion,
+            });
+
+            if self.umbral == 1 {
+                propuesta.ejecutada = true;
+                self.ejecutar_accion(propuesta.accion.clone())?;
+                self.env()
+                    .emit_event(PropuestaEjecutada { id_propuesta: id });
+            }
+            self.propuestas.insert(id, &propuesta);
+            Ok(id)
+        }
+
+        /// Aprueba la propuesta con el id pasado por parametro y la ejecuta
+        /// si alcanza las aprobaciones necesarias.
+        /// Devuelve true si la propuesta se ejecutó
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es miembro del consejo
+        /// - Devuelve un error si la propuesta no es encontrada
+        /// - Devuelve un error si la propuesta ya se ejecutó
+        /// - Devuelve un error si la propuesta venció o la creó un consejo anterior
+        /// - Devuelve un error si el caller ya aprobó la propuesta
+        /// - Devuelve un error si la acción deja de ser válida al ejecutarse
+        #[ink(message)]
+        fn aprobar_propuesta(&mut self, id_propuesta: u32) -> Result<bool> {
+            let caller = self.env().caller();
+            if !self.consejo.contains(&caller) {
+                return Err(VotacionError::NoEsMiembroConsejo);
+            }
+
+            let mut propuesta = self
+                .get_propuesta(id_propuesta)
+                .ok_or(VotacionError::PropuestaNoEncontrada)?;
+
+            if propuesta.ejecutada {
+                return Err(VotacionError::PropuestaYaEjecutada);
+            }
+
+            if propuesta.version_consejo != self.version_consejo
+                || self.env().block_timestamp() >= propuesta.vencimiento
+            {
+                return Err(VotacionError::PropuestaVencida);
+            }
+
+            if propuesta.aprobaciones.contains(&caller) {
+                return Err(VotacionError::PropuestaYaAprobada);
+            }
+
+            propuesta.aprobaciones.push(caller);
+            self.env().emit_event(PropuestaAprobada {
+                id_propuesta,
+                miembro: caller,
+            });
+
+            if propuesta.aprobaciones.len() as u32 >= self.umbral {
+                propuesta.ejecutada = true;
+                self.ejecutar_accion(propuesta.accion.clone())?;
+                self.env().emit_event(PropuestaEjecutada { id_propuesta });
+            }
+            self.propuestas.insert(id_propuesta, &propuesta);
+            Ok(propuesta.ejecutada)
+        }
+
+        /// Devuelve una propuesta por su ID, None si la propuesta no se encuentra
+        #[ink(message)]
+        fn get_propuesta(&self, id_propuesta: u32) -> Option<Propuesta> {
+            self.propuestas.get(id_propuesta)
+        }
+
+        /// Devuelve los miembros del consejo y las aprobaciones necesarias,
+        /// 0 si el consejo no está configurado
+        #[ink(message)]
+        fn get_consejo(&self) -> (Vec<AccountId>, u32) {
+            (self.consejo.clone(), self.umbral)
+        }
+    }
+
+    impl EleccionSystemInk for Votacion {
+        /// Postula un candidato a la eleccion con el id pasado por parametro
+        ///
+        /// # Errores
+        /// - Devuelve un error si el usuario no esta aceptado
+        /// - Devuelve un error si el usuario no es encontrado
+        /// - Devuelve un error si la eleccion no se encuentra
+        /// - Devuelve un error si la eleccion ya finalizo
+        /// - Devuelve un error si la eleccion ya inicio
+        /// - Devuelve un error si el periodo de inscripcion no inició o ya cerró
+        /// - Devuelve un error si el usuario ya está registrado como candidato
+        /// - Devuelve un error si el usuario ya está postulado como candidato
+        /// - Devuelve un error si el usuario ya está registrado como votante
+        /// - Devuelve un error si el usuario ya está postulado como votante
+        /// - Devuelve un error si la eleccion solo admite usuarios habilitados
+        ///   y el usuario no fue habilitado
+        /// - Devuelve un error si la edad del usuario no cumple las reglas de la eleccion
+        #[ink(message)]
+        fn postular_candidato(&mut self, id_eleccion: u32) -> Result<()> {
+            let usuario_actual = self.env().caller();
+
+            if self.get_usuario_sin_aceptar(usuario_actual).is_ok() {
+                return Err(VotacionError::UsuarioNoAceptado);
+            }
+
+            let usuario = self
+                .get_usuario(usuario_actual)
+                .map_err(|_| VotacionError::UsuarioNoEncontrado)?;
+
+            let timestamp = self.env().block_timestamp();
+
+            let eleccion = self
+                .get_eleccion(id_eleccion)
+                .ok_or(VotacionError::EleccionNoEncontrada)?;
+            eleccion.postular_candidato(
+                self.get_estado_participante(id_eleccion, usuario_actual),
+                &usuario,
+                self.get_habilitado(id_eleccion, usuario_actual),
+                &Fecha::from_timestamp(timestamp),
+            )?;
+            self.participantes.insert(
+                (id_eleccion, usuario_actual),
+                &EstadoParticipante::PostuladoCandidato,
+            );
+            self.rechazos_postulacion
+                .remove((id_eleccion, usuario_actual));
+            self.registrar_participacion(id_eleccion, usuario_actual);
+
+            self.env().emit_event(CandidatoPostulado {
+                id_eleccion,
+                candidato: usuario_actual,
+            });
+            Ok(())
+        }
+
+        /// Postula un votante a la eleccion con el id pasado por parametro
+        ///
+        /// # Errores
+        /// - Devuelve un error si el usuario no esta aceptado
+        /// - Devuelve un error si el usuario no es encontrado
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion ya finalizo
+        /// - Devuelve un error si la eleccion ya inicio
+        /// - Devuelve un error si el periodo de inscripcion no inició o ya cerró
+        /// - Devuelve un error si el usuario ya está registrado como candidato
+        /// - Devuelve un error si el usuario ya está postulado como candidato
+        /// - Devuelve un error si el usuario ya está registrado como votante
+        /// - Devuelve un error si el usuario ya está postulado como votante
+        /// - Devuelve un error si la eleccion solo admite usuarios habilitados
+        ///   y el usuario no fue habilitado
+        /// - Devuelve un error si la edad del usuario no cumple las reglas de la eleccion
+        #[ink(message)]
+        fn postular_votante(&mut self, id_eleccion: u32) -> Result<()> {
+            let usuario_actual = self.env().caller();
+
+            if self.get_usuario_sin_aceptar(usuario_actual).is_ok() {
+                return Err(VotacionError::UsuarioNoAceptado);
+            }
+
+            let usuario = self
+                .get_usuario(usuario_actual)
+                .map_err(|_| VotacionError::UsuarioNoEncontrado)?;
+
+            let timestamp = self.env().block_timestamp();
+
+            let eleccion = self
+                .get_eleccion(id_eleccion)
+                .ok_or(VotacionError::EleccionNoEncontrada)?;
+            eleccion.postular_votante(
+                self.get_estado_participante(id_eleccion, usuario_actual),
+                &usuario,
+                self.get_habilitado(id_eleccion, usuario_actual),
+                &Fecha::from_timestamp(timestamp),
+            )?;
+            self.participantes.insert(
+                (id_eleccion, usuario_actual),
+                &EstadoParticipante::PostuladoVotante,
+            );
+            self.rechazos_postulacion
+                .remove((id_eleccion, usuario_actual));
+            self.registrar_participacion(id_eleccion, usuario_actual);
+
+            self.env().emit_event(VotantePostulado {
+                id_eleccion,
+                votante: usuario_actual,
+            });
+            Ok(())
+        }
+
+        /// Acepta un candidato postulado a la eleccion con el id pasado por parametro
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es admin ni autoridad de la eleccion
+        /// - Devuelve un error si el usuario no es encontrado
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion ya finalizo
+        /// - Devuelve un error si la eleccion ya inicio
+        /// - Devuelve un error si el usuario no está postulado como candidato
+        #[ink(message)]
+        fn agregar_candidato(&mut self, id_eleccion: u32, id_candidato: AccountId) -> Result<()> {
+            if !self.caller_tiene_rol(Rol::Autoridad(id_eleccion)) {
+                return Err(VotacionError::NoTieneRol);
+            }
+
+            if self.get_usuario(id_candidato).is_err() {
+                return Err(VotacionError::UsuarioNoEncontrado);
+            }
+
+            let timestamp = self.env().block_timestamp();
+
+            let mut eleccion = self
+                .get_eleccion(id_eleccion)
+                .ok_or(VotacionError::EleccionNoEncontrada)?;
+            let posicion = eleccion.agregar_candidato(
+                self.get_estado_participante(id_eleccion, id_candidato),
+                &Fecha::from_timestamp(timestamp),
+            )?;
+            self.participantes
+                .insert((id_eleccion, id_candidato), &EstadoParticipante::Candidato);
+            self.candidatos
+                .insert((id_eleccion, posicion), &id_candidato);
+            self.elecciones.insert(id_eleccion, &eleccion);
+
+            self.env().emit_event(CandidatoAgregado {
+                id_eleccion,
+                candidato: id_candidato,
+            });
+            Ok(())
+        }
+
+        /// Acepta un votante postulado a la eleccion con el id pasado por parametro,
+        /// con el peso que tendrá su voto
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es admin ni autoridad de la eleccion
+        /// - Devuelve un error si el usuario no es encontrado
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion ya finalizo
+        /// - Devuelve un error si la eleccion ya inicio
+        /// - Devuelve un error si el usuario no está postulado como votante
+        /// - Devuelve un error si el peso es 0, o distinto de 1 en una eleccion preferencial
+        #[ink(message)]
+        fn agregar_votante(
+            &mut self,
+            id_eleccion: u32,
+            id_votante: AccountId,
+            peso: u64,
+        ) -> Result<()> {
+            if !self.caller_tiene_rol(Rol::Autoridad(id_eleccion)) {
+                return Err(VotacionError::NoTieneRol);
+            }
+
+            if self.get_usuario(id_votante).is_err() {
+                return Err(VotacionError::UsuarioNoEncontrado);
+            }
+
+            let timestamp = self.env().block_timestamp();
+
+            let mut eleccion = self
+                .get_eleccion(id_eleccion)
+                .ok_or(VotacionError::EleccionNoEncontrada)?;
+            let posicion = eleccion.agregar_votante(
+                self.get_estado_participante(id_eleccion, id_votante),
+                peso,
+                &Fecha::from_timestamp(timestamp),
+            )?;
+            self.participantes
+                .insert((id_eleccion, id_votante), &EstadoParticipante::Votante);
+            self.votantes.insert((id_eleccion, posicion), &id_votante);
+            if peso != 1 {
+                self.pesos.insert((id_eleccion, id_votante), &peso);
+            }
+            self.elecciones.insert(id_eleccion, &eleccion);
+
+            self.env().emit_event(VotanteAgregado {
+                id_eleccion,
+                votante: id_votante,
+            });
+            Ok(())
+        }
+
+        /// Rechaza un candidato postulado a la eleccion con el id pasado por parametro,
+        /// con el motivo pasado por parametro. El usuario puede volver a postularse
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es admin ni autoridad de la eleccion
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si el usuario no está postulado como candidato
+        #[ink(message)]
+        fn rechazar_candidato(
+            &mut self,
+            id_eleccion: u32,
+            id_candidato: AccountId,
+            motivo: MotivoRechazo,
+        ) -> Result<()> {
+            if !self.caller_tiene_rol(Rol::Autoridad(id_eleccion)) {
+                return Err(VotacionError::NoTieneRol);
+            }
+
+            let eleccion = self
+                .get_eleccion(id_eleccion)
+                .ok_or(VotacionError::EleccionNoEncontrada)?;
+            eleccion.rechazar_candidato(self.get_estado_participante(id_eleccion, id_candidato))?;
+            self.participantes.remove((id_eleccion, id_candidato));
+            self.rechazos_postulacion
+                .insert((id_eleccion, id_candidato), &motivo);
+
+            self.env().emit_event(CandidatoRechazado {
+                id_eleccion,
+                candidato: id_candidato,
+                motivo,
+            });
+            Ok(())
+        }
+
+        /// Rechaza un votante postulado a la eleccion con el id pasado por parametro,
+        /// con el motivo pasado por parametro. El usuario puede volver a postularse
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es admin ni autoridad de la eleccion
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si el usuario no está postulado como votante
+        #[ink(message)]
+        fn rechazar_votante(
+            &mut self,
+            id_eleccion: u32,
+            id_votante: AccountId,
+            motivo: MotivoRechazo,
+        ) -> Result<()> {
+            if !self.caller_tiene_rol(Rol::Autoridad(id_eleccion)) {
+                return Err(VotacionError::NoTieneRol);
+            }
+
+            let eleccion = self
+                .get_eleccion(id_eleccion)
+                .ok_or(VotacionError::EleccionNoEncontrada)?;
+            eleccion.rechazar_votante(self.get_estado_participante(id_eleccion, id_votante))?;
+            self.participantes.remove((id_eleccion, id_votante));
+            self.rechazos_postulacion
+                .insert((id_eleccion, id_votante), &motivo);
+
+            self.env().emit_event(VotanteRechazado {
+                id_eleccion,
+                votante: id_votante,
+                motivo,
+            });
+            Ok(())
+        }
+
+        /// Devuelve el motivo por el que se rechazó la postulación del usuario a la eleccion,
+        /// None si no fue rechazado o si volvió a postularse
+        #[ink(message)]
+        fn get_rechazo_postulacion(
+            &self,
+            id_eleccion: u32,
+            id: AccountId,
+        ) -> Option<MotivoRechazo> {
+            self.rechazos_postulacion.get((id_eleccion, id))
+        }
+
+        /// El candidato (caller) se retira de la eleccion con el id pasado por parametro.
+        /// Si se retira durante la votación ya no puede recibir votos, y los que recibió
+        /// se anulan o se conservan según la configuración de la eleccion. En ningun caso
+        /// puede resultar electo
+        ///
+        /// # Errores
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion ya finalizo
+        /// - Devuelve un error si la eleccion está suspendida, cancelada o ya fue certificada
+        /// - Devuelve un error si el caller no está registrado como candidato
+        /// - Devuelve un error si el caller integra una lista
+        /// - Devuelve un error si la eleccion ya inicio y no es simple ni por aprobacion
+        /// - Devuelve un error si la eleccion ya inicio y no quedarían candidatos para todos los cargos
+        #[ink(message)]
+        fn retirar_candidato(&mut self, id_eleccion: u32) -> Result<()> {
+            let caller = self.env().caller();
+            let timestamp = self.env().block_timestamp();
+
+            let mut eleccion = self
+                .get_eleccion(id_eleccion)
+                .ok_or(VotacionError::EleccionNoEncontrada)?;
+            eleccion.retirar_candidato(
+                self.get_estado_participante(id_eleccion, caller),
+                self.listas_candidato.contains((id_eleccion, caller)),
+                &Fecha::from_timestamp(timestamp),
+            )?;
+            self.participantes.insert(
+                (id_eleccion, caller),
+                &EstadoParticipante::CandidatoRetirado,
+            );
+            self.elecciones.insert(id_eleccion, &eleccion);
+
+            self.env().emit_event(CandidatoRetirado {
+                id_eleccion,
+                candidato: caller,
+            });
+            Ok(())
+        }
+
+        /// El votante (caller) se retira de la eleccion con el id pasado por parametro
+        /// antes de que inicie. Se revoca su delegación y quienes delegaron en él
+        /// vuelven a votar por sí mismos
+        ///
+        /// # Errores
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion ya finalizo
+        /// - Devuelve un error si la eleccion ya inicio
+        /// - Devuelve un error si la eleccion está suspendida o cancelada
+        /// - Devuelve un error si la eleccion es una segunda vuelta
+        /// - Devuelve un error si el caller no está registrado como votante
+        #[ink(message)]
+        fn retirar_votante(&mut self, id_eleccion: u32) -> Result<()> {
+            let caller = self.env().caller();
+            let timestamp = self.env().block_timestamp();
+
+            let mut eleccion = self
+                .get_eleccion(id_eleccion)
+                .ok_or(VotacionError::EleccionNoEncontrada)?;
+            eleccion.retirar_votante(
+                self.get_estado_participante(id_eleccion, caller),
+                self.get_peso(id_eleccion, caller),
+                &Fecha::from_timestamp(timestamp),
+            )?;
+            self.participantes
+                .insert((id_eleccion, caller), &EstadoParticipante::VotanteRetirado);
+
+            if let Some(delegado) = self.delegaciones.take((id_eleccion, caller)) {
+                self.quitar_delegante(id_eleccion, delegado, caller);
+            }
+            for delegante in self
+                .delegantes
+                .take((id_eleccion, caller))
+                .unwrap_or_default()
+            {
+                self.delegaciones.remove((id_eleccion, delegante));
+            }
+            self.elecciones.insert(id_eleccion, &eleccion);
+
+            self.env().emit_event(VotanteRetirado {
+                id_eleccion,
+                votante: caller,
+            });
+            Ok(())
+        }
+
+        /// El votante (caller) vota por un candidato en una eleccion con el id pasado por parametro.
+        /// El candidato suma el peso del votante y el de quienes delegaron en él
+        ///
+        /// # Errores
+        /// - Devuelve un error si el votante no es encontrado
+        /// - Devuelve un error si el candidato no es encontrado
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion ya finalizo
+        /// - Devuelve un error si la eleccion no inicio
+        /// - Devuelve un error si el votante ya voto
+        /// - Devuelve un error si el votante no está registrado como votante
+        /// - Devuelve un error si el candidato no está registrado como candidato
+        /// - Devuelve un error si la eleccion no es simple
+        #[ink(message)]
+        fn votar(&mut self, id_eleccion: u32, id_candidato: AccountId) -> Result<()> {
+            let caller = self.env().caller();
+            if self.get_usuario(id_candidato).is_err() || self.get_usuario(caller).is_err() {
+                return Err(VotacionError::UsuarioNoEncontrado);
+            }
+
+            let timestamp = self.env().block_timestamp();
+
+            let mut eleccion = self
+                .get_eleccion(id_eleccion)
+                .ok_or(VotacionError::EleccionNoEncontrada)?;
+            let peso = self.get_peso(id_eleccion, caller);
+            eleccion.votar(
+                self.get_estado_participante(id_eleccion, caller),
+                peso,
+                self.get_estado_participante(id_eleccion, id_candidato),
+                self.voto_registrado(id_eleccion, caller),
+                &Fecha::from_timestamp(timestamp),
+            )?;
+            self.registrar_voto(&mut eleccion, caller);
+            self.sumar_votos(id_eleccion, id_candidato, peso as u128);
+            self.registrar_delegados(&mut eleccion, caller, DestinoVoto::Candidato(id_candidato));
+            self.elecciones.insert(id_eleccion, &eleccion);
+
+            self.env().emit_event(VotoEmitido {
+                id_eleccion,
+                votante: caller,
+            });
+            Ok(())
+        }
+
+        /// El votante (caller) vota en una eleccion preferencial con el id pasado por parametro,
+        /// ordenando a los candidatos de mayor a menor preferencia.
+        /// No es necesario ordenar a todos los candidatos
+        ///
+        /// # Errores
+        /// - Devuelve un error si el votante no es encontrado
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion no es preferencial
+        /// - Devuelve un error si la eleccion ya finalizo
+        /// - Devuelve un error si la eleccion no inicio
+        /// - Devuelve un error si el votante no está registrado como votante
+        /// - Devuelve un error si no se ordena ningun candidato
+        /// - Devuelve un error si algun candidato no está registrado como candidato
+        /// - Devuelve un error si algun candidato está repetido
+        /// - Devuelve un error si el votante ya voto
+        #[ink(message)]
+        fn votar_preferencial(
+            &mut self,
+            id_eleccion: u32,
+            preferencias: Vec<AccountId>,
+        ) -> Result<()> {
+            let caller = self.env().caller();
+            if self.get_usuario(caller).is_err() {
+                return Err(VotacionError::UsuarioNoEncontrado);
+            }
+
+            let timestamp = self.env().block_timestamp();
+
+            let mut eleccion = self
+                .get_eleccion(id_eleccion)
+                .ok_or(VotacionError::EleccionNoEncontrada)?;
+            let numero_boleta = eleccion.votar_preferencial(
+                self.get_estado_participante(id_eleccion, caller),
+                &self.get_estados(id_eleccion, &preferencias),
+                self.voto_registrado(id_eleccion, caller),
+                &Fecha::from_timestamp(timestamp),
+            )?;
+            self.registrar_voto(&mut eleccion, caller);
+            // La primera preferencia se cuenta como voto del candidato para el resultado inicial
+            self.sumar_votos(id_eleccion, preferencias[0], 1);
+            self.boletas
+                .insert((id_eleccion, numero_boleta), &preferencias);
+            self.elecciones.insert(id_eleccion, &eleccion);
+
+            self.env().emit_event(VotoEmitido {
+                id_eleccion,
+                votante: caller,
+            });
+            Ok(())
+        }
+
+        /// El votante (caller) vota en una eleccion por aprobacion con el id pasado por parametro,
+        /// aprobando a cualquier cantidad de candidatos. Cada candidato aprobado suma el peso del votante
+        ///
+        /// # Errores
+        /// - Devuelve un error si el votante no es encontrado
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion no es por aprobacion
+        /// - Devuelve un error si la eleccion ya finalizo
+        /// - Devuelve un error si la eleccion no inicio
+        /// - Devuelve un error si el votante no está registrado como votante
+        /// - Devuelve un error si algun candidato no está registrado como candidato
+        /// - Devuelve un error si algun candidato está repetido
+        /// - Devuelve un error si el votante ya voto
+        #[ink(message)]
+        fn votar_aprobacion(&mut self, id_eleccion: u32, aprobados: Vec<AccountId>) -> Result<()> {
+            let caller = self.env().caller();
+            if self.get_usuario(caller).is_err() {
+                return Err(VotacionError::UsuarioNoEncontrado);
+            }
+
+            let timestamp = self.env().block_timestamp();
+
+            let mut eleccion = self
+                .get_eleccion(id_eleccion)
+                .ok_or(VotacionError::EleccionNoEncontrada)?;
+            let peso = self.get_peso(id_eleccion, caller);
+            eleccion.votar_aprobacion(
+                self.get_estado_participante(id_eleccion, caller),
+                peso,
+                &self.get_estados(id_eleccion, &aprobados),
+                self.voto_registrado(id_eleccion, caller),
+                &Fecha::from_timestamp(timestamp),
+            )?;
+            self.registrar_voto(&mut eleccion, caller);
+            for candidato in aprobados {
+                self.sumar_votos(id_eleccion, candidato, peso as u128);
+            }
+            self.elecciones.insert(id_eleccion, &eleccion);
+
+            self.env().emit_event(VotoEmitido {
+                id_eleccion,
+                votante: caller,
+            });
+            Ok(())
+        }
+
+        /// El votante (caller) vota por una lista en una eleccion por listas
+        ///
+        /// # Errores
+        /// - Devuelve un error si el votante no es encontrado
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion no es por listas
+        /// - Devuelve un error si la eleccion ya finalizo
+        /// - Devuelve un error si la eleccion no inicio
+        /// - Devuelve un error si el votante no está registrado como votante
+        /// - Devuelve un error si la lista no es encontrada
+        /// - Devuelve un error si el votante ya voto
+        #[ink(message)]
+        fn votar_lista(&mut self, id_eleccion: u32, id_lista: u32) -> Result<()> {
+            let caller = self.env().caller();
+            if self.get_usuario(caller).is_err() {
+                return Err(VotacionError::UsuarioNoEncontrado);
+            }
+
+            let timestamp = self.env().block_timestamp();
+
+            let mut eleccion = self
+                .get_eleccion(id_eleccion)
+                .ok_or(VotacionError::EleccionNoEncontrada)?;
+            let peso = self.get_peso(id_eleccion, caller);
+            eleccion.votar_lista(
+                self.get_estado_participante(id_eleccion, caller),
+                peso,
+                id_lista,
+                self.voto_registrado(id_eleccion, caller),
+                &Fecha::from_timestamp(timestamp),
+            )?;
+            self.registrar_voto(&mut eleccion, caller);
+            let votos = self.votos_lista.get((id_eleccion, id_lista)).unwrap_or(0);
+            self.votos_lista
+                .insert((id_eleccion, id_lista), &(votos + peso as u128));
+            self.elecciones.insert(id_eleccion, &eleccion);
+
+            self.env().emit_event(VotoEmitido {
+                id_eleccion,
+                votante: caller,
+            });
+            Ok(())
+        }
+
+        /// El votante (caller) vota en blanco en una eleccion con el id pasado por parametro
+        ///
+        /// # Errores
+        /// - Devuelve un error si el votante no es encontrado
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion es secreta
+        /// - Devuelve un error si la eleccion ya finalizo
+        /// - Devuelve un error si la eleccion no inicio
+        /// - Devuelve un error si el votante no está registrado como votante
+        /// - Devuelve un error si el votante ya voto
+        #[ink(message)]
+        fn votar_en_blanco(&mut self, id_eleccion: u32) -> Result<()> {
+            self.votar_sin_candidato(id_eleccion, VotoSinCandidato::Blanco)
+        }
+
+        /// El votante (caller) anula su voto en una eleccion con el id pasado por parametro
+        ///
+        /// # Errores
+        /// - Devuelve un error si el votante no es encontrado
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion es secreta
+        /// - Devuelve un error si la eleccion ya finalizo
+        /// - Devuelve un error si la eleccion no inicio
+        /// - Devuelve un error si el votante no está registrado como votante
+        /// - Devuelve un error si el votante ya voto
+        #[ink(message)]
+        fn votar_nulo(&mut self, id_eleccion: u32) -> Result<()> {
+            self.votar_sin_candidato(id_eleccion, VotoSinCandidato::Nulo)
+        }
+
+        /// El votante (caller) compromete su voto en una eleccion secreta con el hash de la eleccion,
+        /// su cuenta, el candidato elegido y una sal, calculado como en `Votacion::calcular_compromiso`
+        ///
+        /// # Errores
+        /// - Devuelve un error si el votante no es encontrado
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion no es secreta
+        /// - Devuelve un error si la eleccion ya finalizo
+        /// - Devuelve un error si la eleccion no inicio
+        /// - Devuelve un error si el votante no está registrado como votante
+        /// - Devuelve un error si el votante ya voto
+        #[ink(message)]
+        fn votar_secreto(&mut self, id_eleccion: u32, compromiso: Compromiso) -> Result<()> {
+            let caller = self.env().caller();
+            if self.get_usuario(caller).is_err() {
+                return Err(VotacionError::UsuarioNoEncontrado);
+            }
+
+            let timestamp = self.env().block_timestamp();
+
+            let mut eleccion = self
+                .get_eleccion(id_eleccion)
+                .ok_or(VotacionError::EleccionNoEncontrada)?;
+            eleccion.comprometer_voto(
+                self.get_estado_participante(id_eleccion, caller),
+                self.get_peso(id_eleccion, caller),
+                self.voto_registrado(id_eleccion, caller),
+                &Fecha::from_timestamp(timestamp),
+            )?;
+            self.registrar_voto(&mut eleccion, caller);
+            self.compromisos.insert((id_eleccion, caller), &compromiso);
+            self.elecciones.insert(id_eleccion, &eleccion);
+
+            self.env().emit_event(VotoEmitido {
+                id_eleccion,
+                votante: caller,
+            });
+            Ok(())
+        }
+
+        /// El votante (caller) revela el candidato y la sal de su voto secreto,
+        /// luego de la fecha de fin y hasta la fecha de fin de revelación.
+        /// Solo los votos revelados se cuentan en el resultado
+        ///
+        /// # Errores
+        /// - Devuelve un error si el votante no es encontrado
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion no es secreta
+        /// - Devuelve un error si la eleccion no finalizó
+        /// - Devuelve un error si terminó el periodo de revelación
+        /// - Devuelve un error si el votante no comprometió su voto o ya lo reveló
+        /// - Devuelve un error si el candidato y la sal no corresponden al compromiso
+        /// - Devuelve un error si el candidato no está registrado como candidato
+        #[ink(message)]
+        fn revelar_voto(
+            &mut self,
+            id_eleccion: u32,
+            id_candidato: AccountId,
+            sal: [u8; 32],
+        ) -> Result<()> {
+            let caller = self.env().caller();
+            if self.get_usuario(caller).is_err() {
+                return Err(VotacionError::UsuarioNoEncontrado);
+            }
+
+            let timestamp = self.env().block_timestamp();
+
+            let mut eleccion = self
+                .get_eleccion(id_eleccion)
+                .ok_or(VotacionError::EleccionNoEncontrada)?;
+            eleccion.revelar_voto(
+                self.compromisos.get((id_eleccion, caller)),
+                self.voto_registrado(id_eleccion, caller),
+                Votacion::calcular_compromiso(id_eleccion, caller, id_candidato, sal),
+                self.get_estado_participante(id_eleccion, id_candidato),
+                &Fecha::from_timestamp(timestamp),
+            )?;
+            self.compromisos.remove((id_eleccion, caller));
+            let peso = self.get_peso(id_eleccion, caller) as u128;
+            self.sumar_votos(id_eleccion, id_candidato, peso);
+            self.elecciones.insert(id_eleccion, &eleccion);
+
+            self.env().emit_event(VotoRevelado {
+                id_eleccion,
+                votante: caller,
+            });
+            Ok(())
+        }
+
+        /// Devuelve true si el usuario ya voto en la eleccion con el id pasado como parametro
+        /// false en cualquier otro caso
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el admin
+        /// - Devuelve un error si el usuario no es encontrado
+        /// - Devuelve un error si la eleccion no es encontrada
+        #[ink(message)]
+        fn ya_voto(&self, id_eleccion: u32, id_votante: AccountId) -> Result<bool> {
+            if !self.caller_is_admin() {
+                return Err(VotacionError::NoEsAdmin);
+            }
+
+            if self.get_usuario(id_votante).is_err() {
+                return Err(VotacionError::UsuarioNoEncontrado);
+            }
+
+            if self.get_eleccion(id_eleccion).is_some() {
+                Ok(self.voto_registrado(id_eleccion, id_votante))
+            } else {
+                Err(VotacionError::EleccionNoEncontrada)
+            }
+        }
+
+        /// Devuelve true si la elección ya inició
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el admin
+        /// - Devuelve un error si la eleccion no es encontrada
+        #[ink(message)]
+        fn get_iniciada(&self, id_eleccion: u32) -> Result<bool> {
+            if !self.caller_is_admin() {
+                return Err(VotacionError::NoEsAdmin);
+            }
+
+            let timestamp = self.env().block_timestamp();
+
+            if let Some(eleccion) = self.get_eleccion(id_eleccion) {
+                Ok(eleccion.get_inicio(&Fecha::from_timestamp(timestamp)))
+            } else {
+                Err(VotacionError::EleccionNoEncontrada)
+            }
+        }
+
+        /// Devuelve true si la elección con el id pasado como parametro ya finalizó
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el admin
+        /// - Devuelve un error si la eleccion no es encontrada
+        #[ink(message)]
+        fn get_finalizada(&self, id_eleccion: u32) -> Result<bool> {
+            if !self.caller_is_admin() {
+                return Err(VotacionError::NoEsAdmin);
+            }
+
+            let timestamp = self.env().block_timestamp();
+
+            if let Some(eleccion) = self.get_eleccion(id_eleccion) {
+                Ok(eleccion.get_finalizada(&Fecha::from_timestamp(timestamp)))
+            } else {
+                Err(VotacionError::EleccionNoEncontrada)
+            }
+        }
+
+        /// Devuelve la cantidad de votos del candidato en la eleccion con el id pasado como parametro
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el admin
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion no finalizó
+        /// - Devuelve un error si el candidato no es encontrado
+        #[ink(message)]
+        fn get_votos_candidato(&self, id_eleccion: u32, id_candidato: AccountId) -> Result<u128> {
+            if !self.caller_is_admin() {
+                return Err(VotacionError::NoEsAdmin);
+            }
+
+            let timestamp = self.env().block_timestamp();
+
+            let eleccion = self
+                .get_eleccion(id_eleccion)
+                .ok_or(VotacionError::EleccionNoEncontrada)?;
+
+            eleccion.validar_resultado(&Fecha::from_timestamp(timestamp))?;
+
+            if self.is_candidato(id_eleccion, id_candidato) {
+                Ok(self.get_votos_registrados(id_eleccion, id_candidato))
+            } else {
+                Err(VotacionError::UsuarioNoEsCandidato)
+            }
+        }
+
+        /// El votante (caller) delega su voto en otro votante de la eleccion antes de que inicie.
+        /// La delegación es transitiva: el delegado vota con los votos de quienes delegaron en él
+        /// y todavia no votaron. Si el caller ya había delegado su voto, la delegación se reemplaza.
+        /// Si el caller vota directamente, su voto reemplaza a la delegación
+        ///
+        /// # Errores
+        /// - Devuelve un error si el votante o el delegado no son encontrados
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion no es simple o es secreta
+        /// - Devuelve un error si la eleccion ya inició
+        /// - Devuelve un error si el caller no está registrado como votante
+        /// - Devuelve un error si el delegado no está registrado como votante
+        /// - Devuelve un error si la delegación genera un ciclo
+        #[ink(message)]
+        fn delegar_voto(&mut self, id_eleccion: u32, delegado: AccountId) -> Result<()> {
+            let caller = self.env().caller();
+            if self.get_usuario(caller).is_err() || self.get_usuario(delegado).is_err() {
+                return Err(VotacionError::UsuarioNoEncontrado);
+            }
+
+            let timestamp = self.env().block_timestamp();
+
+            let eleccion = self
+                .get_eleccion(id_eleccion)
+                .ok_or(VotacionError::EleccionNoEncontrada)?;
+            eleccion.delegar_voto(
+                self.get_estado_participante(id_eleccion, caller),
+                self.get_estado_participante(id_eleccion, delegado),
+                self.genera_ciclo(id_eleccion, caller, delegado),
+                &Fecha::from_timestamp(timestamp),
+            )?;
+
+            if let Some(anterior) = self.delegaciones.get((id_eleccion, caller)) {
+                self.quitar_delegante(id_eleccion, anterior, caller);
+            }
+            self.delegaciones.insert((id_eleccion, caller), &delegado);
+            let mut delegantes = self
+                .delegantes
+                .get((id_eleccion, delegado))
+                .unwrap_or_default();
+            delegantes.push(caller);
+            self.delegantes.insert((id_eleccion, delegado), &delegantes);
+
+            self.env().emit_event(VotoDelegado {
+                id_eleccion,
+                votante: caller,
+                delegado,
+            });
+            Ok(())
+        }
+
+        /// El votante (caller) revoca la delegación de su voto antes de que inicie la eleccion
+        ///
+        /// # Errores
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion no es simple o es secreta
+        /// - Devuelve un error si la eleccion ya inició
+        /// - Devuelve un error si el caller no delegó su voto
+        #[ink(message)]
+        fn revocar_delegacion(&mut self, id_eleccion: u32) -> Result<()> {
+            let caller = self.env().caller();
+            let timestamp = self.env().block_timestamp();
+
+            let eleccion = self
+                .get_eleccion(id_eleccion)
+                .ok_or(VotacionError::EleccionNoEncontrada)?;
+            eleccion.revocar_delegacion(
+                self.delegaciones.contains((id_eleccion, caller)),
+                &Fecha::from_timestamp(timestamp),
+            )?;
+
+            if let Some(delegado) = self.delegaciones.take((id_eleccion, caller)) {
+                self.quitar_delegante(id_eleccion, delegado, caller);
+            }
+
+            self.env().emit_event(DelegacionRevocada {
+                id_eleccion,
+                votante: caller,
+            });
+            Ok(())
+        }
+
+        /// Devuelve el votante en el que delegó su voto el votante pasado por parametro
+        #[ink(message)]
+        fn get_delegacion(&self, id_eleccion: u32, votante: AccountId) -> Option<AccountId> {
+            self.delegaciones.get((id_eleccion, votante))
+        }
+
+        /// Habilita a los usuarios pasados por parametro para postularse a una eleccion
+        /// que solo admite usuarios habilitados
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es admin ni autoridad de la eleccion
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion ya finalizo
+        /// - Devuelve un error si la eleccion ya inicio
+        /// - Devuelve un error si la eleccion no solo admite usuarios habilitados
+        #[ink(message)]
+        fn habilitar_usuarios(&mut self, id_eleccion: u32, usuarios: Vec<AccountId>) -> Result<()> {
+            if !self.caller_tiene_rol(Rol::Autoridad(id_eleccion)) {
+                return Err(VotacionError::NoTieneRol);
+            }
+
+            let timestamp = self.env().block_timestamp();
+
+            let eleccion = self
+                .get_eleccion(id_eleccion)
+                .ok_or(VotacionError::EleccionNoEncontrada)?;
+            eleccion.habilitar_usuarios(&Fecha::from_timestamp(timestamp))?;
+            for usuario in usuarios.iter() {
+                self.habilitados.insert((id_eleccion, *usuario), &());
+            }
+
+            self.env().emit_event(UsuariosHabilitados {
+                id_eleccion,
+                usuarios,
+            });
+            Ok(())
+        }
+
+        /// Devuelve true si el usuario fue habilitado para postularse a la eleccion
+        #[ink(message)]
+        fn get_habilitado(&self, id_eleccion: u32, usuario: AccountId) -> bool {
+            self.habilitados.contains((id_eleccion, usuario))
+        }
+    }
+
+    impl ReportMessage for Votacion {
+        /// Devuelve un vector con los id de los votantes aceptados
+        /// si no hay votantes devuelve un vector vacio
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el reporte
+        /// - Devuelve un error si la eleccion no es encontrada
+        #[ink(message)]
+        fn reporte_registro_votantes(&self, eleccion_id: u32) -> Result<Vec<AccountId>> {
+            if !self.caller_is_reporte() {
+                return Err(VotacionError::SoloReportes);
+            }
+
+            let eleccion = self
+                .get_eleccion(eleccion_id)
+                .ok_or(VotacionError::EleccionNoEncontrada)?;
+            let id_votantes = self.get_votantes(&eleccion); // -> AccountId de votantes aceptados y aprobados para esa eleccion
+
+            Ok(id_votantes)
+        }
+
+        /// Devuelve la cantidad de votantes y la cantidad de votos, incluidos los votos en blanco
+        /// y nulos. En las elecciones secretas tambien la cantidad de votos revelados
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el reporte
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion no finalizó o no terminó su revelación
+        /// - Devuelve un error si la eleccion está suspendida o cancelada
+        #[ink(message)]
+        fn reporte_participacion(&self, eleccion_id: u32) -> Result<Participacion> {
+            if !self.caller_is_reporte() {
+                return Err(VotacionError::SoloReportes);
+            }
+
+            let timestamp = self.env().block_timestamp();
+            let eleccion = self
+                .get_eleccion(eleccion_id)
+                .ok_or(VotacionError::EleccionNoEncontrada)?;
+
+            eleccion.reporte_participacion(&Fecha::from_timestamp(timestamp))
+        }
+
+        /// Devuelve un vector que contiene para cada posicion el AccountId
+        /// de un candidato y la cantidad de votos que obtuvo.
+        /// En las elecciones por aprobacion se cuentan las aprobaciones de cada candidato
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el reporte
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion no finalizó
+        #[ink(message)]
+        fn reporte_resultado(&self, eleccion_id: u32) -> Result<Vec<(AccountId, u128)>> {
+            if !self.caller_is_reporte() {
+                return Err(VotacionError::SoloReportes);
+            }
+
+            let timestamp = self.env().block_timestamp();
+
+            let eleccion = self
+                .get_eleccion(eleccion_id)
+                .ok_or(VotacionError::EleccionNoEncontrada)?;
+            self.get_votos(&eleccion, &Fecha::from_timestamp(timestamp))
+        }
+
+        /// Devuelve las boletas de una eleccion preferencial, cada una con los candidatos
+        /// ordenados de mayor a menor preferencia
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el reporte
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion no es preferencial
+        /// - Devuelve un error si la eleccion no finalizó
+        #[ink(message)]
+        fn reporte_boletas(&self, eleccion_id: u32) -> Result<Vec<Vec<AccountId>>> {
+            if !self.caller_is_reporte() {
+                return Err(VotacionError::SoloReportes);
+            }
+
+            let timestamp = self.env().block_timestamp();
+
+            let eleccion = self
+                .get_eleccion(eleccion_id)
+                .ok_or(VotacionError::EleccionNoEncontrada)?;
+            self.get_boletas(&eleccion, &Fecha::from_timestamp(timestamp))
+        }
+
+        /// Devuelve la configuración con la que se creó una eleccion
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el reporte
+        /// - Devuelve un error si la eleccion no es encontrada
+        #[ink(message)]
+        fn reporte_config(&self, eleccion_id: u32) -> Result<ConfigEleccion> {
+            if !self.caller_is_reporte() {
+                return Err(VotacionError::SoloReportes);
+            }
+
+            let eleccion = self
+                .get_eleccion(eleccion_id)
+                .ok_or(VotacionError::EleccionNoEncontrada)?;
+            Ok(eleccion.config)
+        }
+
+        /// Devuelve las listas de una eleccion por listas junto a la cantidad de votos que obtuvo cada una
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el reporte
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion no es por listas
+        /// - Devuelve un error si la eleccion no finalizó
+        #[ink(message)]
+        fn reporte_listas(&self, eleccion_id: u32) -> Result<Vec<(Lista, u128)>> {
+            if !self.caller_is_reporte() {
+                return Err(VotacionError::SoloReportes);
+            }
+
+            let timestamp = self.env().block_timestamp();
+
+            let eleccion = self
+                .get_eleccion(eleccion_id)
+                .ok_or(VotacionError::EleccionNoEncontrada)?;
+            self.get_votos_listas(&eleccion, &Fecha::from_timestamp(timestamp))
+        }
+
+        /// Devuelve el id de la primera vuelta, si la eleccion es una segunda vuelta,
+        /// y el resultado de la regla de balotaje, si ya se aplicó
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el reporte
+        /// - Devuelve un error si la eleccion no es encontrada
+        #[ink(message)]
+        fn reporte_balotaje(
+            &self,
+            eleccion_id: u32,
+        ) -> Result<(Option<u32>, Option<ResultadoBalotaje>)> {
+            if !self.caller_is_reporte() {
+                return Err(VotacionError::SoloReportes);
+            }
+
+            let eleccion = self
+                .get_eleccion(eleccion_id)
+                .ok_or(VotacionError::EleccionNoEncontrada)?;
+            Ok((
+                eleccion.get_primera_vuelta(),
+                eleccion.get_resultado_balotaje(),
+            ))
+        }
+
+        /// Devuelve el estado actual de una eleccion, que indica si su resultado ya fue certificado
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el reporte
+        /// - Devuelve un error si la eleccion no es encontrada
+        #[ink(message)]
+        fn reporte_estado(&self, eleccion_id: u32) -> Result<EstadoEleccion> {
+            if !self.caller_is_reporte() {
+                return Err(VotacionError::SoloReportes);
+            }
+
+            let eleccion = self
+                .get_eleccion(eleccion_id)
+                .ok_or(VotacionError::EleccionNoEncontrada)?;
+            Ok(eleccion.get_estado())
+        }
+
+        /// Devuelve los candidatos que se retiraron de una eleccion junto a la cantidad de votos
+        /// que recibieron, que se anulan o se conservan según la configuración de la eleccion
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el reporte
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion no finalizó
+        #[ink(message)]
+        fn reporte_retirados(&self, eleccion_id: u32) -> Result<Vec<(AccountId, u128)>> {
+            if !self.caller_is_reporte() {
+                return Err(VotacionError::SoloReportes);
+            }
+
+            let timestamp = self.env().block_timestamp();
+
+            let eleccion = self
+                .get_eleccion(eleccion_id)
+                .ok_or(VotacionError::EleccionNoEncontrada)?;
+            eleccion.validar_resultado(&Fecha::from_timestamp(timestamp))?;
+
+            Ok(self
+                .get_candidatos(&eleccion)
+                .into_iter()
+                .filter(|candidato| self.is_retirado(eleccion_id, *candidato))
+                .map(|candidato| {
+                    (
+                        candidato,
+                        self.get_votos_registrados(eleccion_id, candidato),
+                    )
+                })
+                .collect())
+        }
+
+        /// Devuelve la semilla con la que se desempata en forma aleatoria el resultado de una eleccion,
+        /// None mientras no terminó la votación y su revelación, porque cada voto la modifica
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el reporte
+        /// - Devuelve un error si la eleccion no es encontrada
+        #[ink(message)]
+        fn reporte_semilla_desempate(&self, eleccion_id: u32) -> Result<Option<[u8; 32]>> {
+            if !self.caller_is_reporte() {
+                return Err(VotacionError::SoloReportes);
+            }
+
+            let eleccion = self
+                .get_eleccion(eleccion_id)
+                .ok_or(VotacionError::EleccionNoEncontrada)?;
+            let current_time = Fecha::from_timestamp(self.env().block_timestamp());
+            Ok(eleccion
+                .validar_resultado(&current_time)
+                .ok()
+                .and(eleccion.semilla_desempate))
+        }
+
+        /// Devuelve la fecha de inicio de una eleccion, a la que los reportes calculan
+        /// la edad de los votantes
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el reporte
+        /// - Devuelve un error si la eleccion no es encontrada
+        #[ink(message)]
+        fn reporte_fecha_inicio(&self, eleccion_id: u32) -> Result<Fecha> {
+            if !self.caller_is_reporte() {
+                return Err(VotacionError::SoloReportes);
+            }
+
+            let eleccion = self
                 .get_eleccion(eleccion_id)
                 .ok_or(VotacionError::EleccionNoEncontrada)?;
-            eleccion.reporte_resultado(&Fecha::from_timestamp(timestamp))
+            Ok(eleccion.get_fecha_inicio())
+        }
+    }
+
+    #[ink::trait_definition]
+    pub trait ReportMessage {
+        /// Devuelve un vector con los id de los votantes aceptados
+        /// si no hay votantes devuelve un vector vacio
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el reporte
+        /// - Devuelve un error si la eleccion no es encontrada
+        #[ink(message)]
+        fn reporte_registro_votantes(&self, eleccion_id: u32) -> Result<Vec<AccountId>>;
+        /// Devuelve la cantidad de votantes y la cantidad de votos, incluidos los votos en blanco
+        /// y nulos. En las elecciones secretas tambien la cantidad de votos revelados
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el reporte
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion no finalizó o no terminó su revelación
+        /// - Devuelve un error si la eleccion está suspendida o cancelada
+        #[ink(message)]
+        fn reporte_participacion(&self, eleccion_id: u32) -> Result<Participacion>;
+        /// Devuelve un vector que contiene para cada posicion el AccountId
+        /// de un candidato y la cantidad de votos que obtuvo.
+        /// En las elecciones por aprobacion se cuentan las aprobaciones de cada candidato
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el reporte
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion no finalizó
+        #[ink(message)]
+        fn reporte_resultado(&self, eleccion_id: u32) -> Result<Vec<(AccountId, u128)>>;
+        /// Devuelve las boletas de una eleccion preferencial, cada una con los candidatos
+        /// ordenados de mayor a menor preferencia
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el reporte
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion no es preferencial
+        /// - Devuelve un error si la eleccion no finalizó
+        #[ink(message)]
+        fn reporte_boletas(&self, eleccion_id: u32) -> Result<Vec<Vec<AccountId>>>;
+        /// Devuelve la configuración con la que se creó una eleccion
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el reporte
+        /// - Devuelve un error si la eleccion no es encontrada
+        #[ink(message)]
+        fn reporte_config(&self, eleccion_id: u32) -> Result<ConfigEleccion>;
+        /// Devuelve las listas de una eleccion por listas junto a la cantidad de votos que obtuvo cada una
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el reporte
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion no es por listas
+        /// - Devuelve un error si la eleccion no finalizó
+        #[ink(message)]
+        fn reporte_listas(&self, eleccion_id: u32) -> Result<Vec<(Lista, u128)>>;
+        /// Devuelve el id de la primera vuelta, si la eleccion es una segunda vuelta,
+        /// y el resultado de la regla de balotaje, si ya se aplicó
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el reporte
+        /// - Devuelve un error si la eleccion no es encontrada
+        #[ink(message)]
+        fn reporte_balotaje(
+            &self,
+            eleccion_id: u32,
+        ) -> Result<(Option<u32>, Option<ResultadoBalotaje>)>;
+        /// Devuelve el estado actual de una eleccion, que indica si su resultado ya fue certificado
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el reporte
+        /// - Devuelve un error si la eleccion no es encontrada
+        #[ink(message)]
+        fn reporte_estado(&self, eleccion_id: u32) -> Result<EstadoEleccion>;
+        /// Devuelve los candidatos que se retiraron de una eleccion junto a la cantidad de votos
+        /// que recibieron, que se anulan o se conservan según la configuración de la eleccion
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el reporte
+        /// - Devuelve un error si la eleccion no es encontrada
+        /// - Devuelve un error si la eleccion no finalizó
+        #[ink(message)]
+        fn reporte_retirados(&self, eleccion_id: u32) -> Result<Vec<(AccountId, u128)>>;
+        /// Devuelve la semilla con la que se desempata en forma aleatoria el resultado de una eleccion,
+        /// None mientras no terminó la votación y su revelación, porque cada voto la modifica
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el reporte
+        /// - Devuelve un error si la eleccion no es encontrada
+        #[ink(message)]
+        fn reporte_semilla_desempate(&self, eleccion_id: u32) -> Result<Option<[u8; 32]>>;
+        /// Devuelve la fecha de inicio de una eleccion, a la que los reportes calculan
+        /// la edad de los votantes
+        ///
+        /// # Errores
+        /// - Devuelve un error si el caller no es el reporte
+        /// - Devuelve un error si la eleccion no es encontrada
+        #[ink(message)]
+        fn reporte_fecha_inicio(&self, eleccion_id: u32) -> Result<Fecha>;
+    }
+
+    trait ReportMessageEleccion {
+        /// Reporte de participación
+        fn reporte_participacion(&self, current_time: &Fecha) -> Result<Participacion>;
+    }
+
+    #[cfg(test)]
+    pub mod tests {
+        use super::*;
+        use ink::env::{
+            test::{default_accounts, recorded_events, set_block_timestamp, set_caller},
+            DefaultEnvironment,
+        };
+
+        /// Funcion auxiliar para los tests de reportes.
+        pub fn default_with_data() -> Votacion {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2023).timestamp().unwrap());
+            let mut votacion = Votacion::new();
+            //Creo dos elecciones nuevas (admin)
+            votacion
+                .crear_eleccion(
+                    Fecha::new(1, 1, 2024),
+                    Fecha::new(31, 12, 2024),
+                    ConfigEleccion::default(),
+                )
+                .unwrap();
+            votacion
+                .crear_eleccion(
+                    Fecha::new(1, 1, 2024),
+                    Fecha::new(31, 12, 2024),
+                    ConfigEleccion::default(),
+                )
+                .unwrap();
+            //Agrego usuarios a la lista de usuarios_por_aceptar
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            votacion
+                .postular_usuario(
+                    "Alice".to_string(),
+                    "Cooper".to_string(),
+                    "EEUU".to_string(),
+                    "11111111".to_string(),
+                    Fecha::new(1, 1, 1994),
+                )
+                .unwrap();
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            votacion
+                .postular_usuario(
+                    "Bob".to_string(),
+                    "Marley".to_string(),
+                    "Jamaica".to_string(),
+                    "22222222".to_string(),
+                    Fecha::new(1, 1, 1994),
+                )
+                .unwrap();
+            set_caller::<DefaultEnvironment>(accounts.charlie);
+            votacion
+                .postular_usuario(
+                    "Charlie".to_string(),
+                    "Chaplin".to_string(),
+                    "Inglaterra".to_string(),
+                    "33333333".to_string(),
+                    Fecha::new(1, 1, 1994),
+                )
+                .unwrap();
+            set_caller::<DefaultEnvironment>(accounts.django);
+            votacion
+                .postular_usuario(
+                    "Django".to_string(),
+                    "Unchained".to_string(),
+                    "EEUU".to_string(),
+                    "44444444".to_string(),
+                    Fecha::new(1, 1, 1994),
+                )
+                .unwrap();
+            //Acepto a los usuarios (admin)
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            votacion.aceptar_usuario(accounts.alice).unwrap();
+            votacion.aceptar_usuario(accounts.bob).unwrap();
+            votacion.aceptar_usuario(accounts.charlie).unwrap();
+            votacion.aceptar_usuario(accounts.django).unwrap();
+            //Eleccion id = 0:
+            //Postulo a alice y a bob como y candidatos a la eleccion
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            votacion.postular_candidato(0).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            votacion.postular_candidato(0).unwrap();
+            //Postulo a charlie y a django como y votantes a la eleccion
+            set_caller::<DefaultEnvironment>(accounts.charlie);
+            votacion.postular_votante(0).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.django);
+            votacion.postular_votante(0).unwrap();
+            //Acepto a los votantes y candidatos postulados (admin)
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            votacion.agregar_candidato(0, accounts.alice).unwrap();
+            votacion.agregar_candidato(0, accounts.bob).unwrap();
+            votacion.agregar_votante(0, accounts.charlie, 1).unwrap();
+            votacion.agregar_votante(0, accounts.django, 1).unwrap();
+
+            //Eleccion id = 1
+            //Postulo a alice como candidato a la eleccion
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            votacion.postular_candidato(1).unwrap();
+            //Postulo a bob, charlie, y django como votantes de la eleccion
+            set_caller::<DefaultEnvironment>(accounts.charlie);
+            votacion.postular_votante(1).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.django);
+            votacion.postular_votante(1).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            votacion.postular_votante(1).unwrap();
+            //Acepto a los votantes y candidatos postulados (admin)
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            votacion.agregar_candidato(1, accounts.alice).unwrap();
+            votacion.agregar_votante(1, accounts.bob, 1).unwrap();
+            votacion.agregar_votante(1, accounts.charlie, 1).unwrap();
+            votacion.agregar_votante(1, accounts.django, 1).unwrap();
+            //Realizo votos para eleccion 0 (alice = 2, bob = 0)
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(15, 6, 2024).timestamp().unwrap());
+            set_caller::<DefaultEnvironment>(accounts.django);
+            votacion.votar(0, accounts.alice).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.charlie);
+            votacion.votar(0, accounts.alice).unwrap();
+            //Realizo votos para eleccion 0 (alice = 2)
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(15, 6, 2024).timestamp().unwrap());
+            set_caller::<DefaultEnvironment>(accounts.django);
+            votacion.votar(1, accounts.alice).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            votacion.votar(1, accounts.alice).unwrap();
+
+            votacion
+        }
+
+        #[ink::test]
+        fn test_set_admin() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            let mut votacion = Votacion::new();
+            votacion.set_admin(accounts.bob).unwrap();
+            assert_eq!(votacion.admin, accounts.alice);
+            assert_eq!(votacion.get_admin_nominado(), Some(accounts.bob));
+
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            votacion.aceptar_admin().unwrap();
+            assert_eq!(votacion.admin, accounts.bob);
+            assert_eq!(votacion.get_admin_nominado(), None);
+        }
+
+        #[ink::test]
+        fn test_set_admin_reemplaza_nominacion() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            let mut votacion = Votacion::new();
+            votacion.set_admin(accounts.bob).unwrap();
+            votacion.set_admin(accounts.charlie).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                votacion.aceptar_admin(),
+                Err(VotacionError::NoEsAdminNominado)
+            );
+            set_caller::<DefaultEnvironment>(accounts.charlie);
+            votacion.aceptar_admin().unwrap();
+            assert_eq!(votacion.admin, accounts.charlie);
+        }
+
+        #[ink::test]
+        fn test_aceptar_admin_error_sin_admin_nominado() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            let mut votacion = Votacion::new();
+            assert_eq!(
+                votacion.aceptar_admin(),
+                Err(VotacionError::SinAdminNominado)
+            );
+        }
+
+        #[ink::test]
+        fn test_cancelar_nominacion_admin() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            let mut votacion = Votacion::new();
+            // cancela el admin
+            votacion.set_admin(accounts.bob).unwrap();
+            votacion.cancelar_nominacion_admin().unwrap();
+            assert_eq!(votacion.get_admin_nominado(), None);
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                votacion.aceptar_admin(),
+                Err(VotacionError::SinAdminNominado)
+            );
+
+            // cancela el nominado
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            votacion.set_admin(accounts.bob).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            votacion.cancelar_nominacion_admin().unwrap();
+            assert_eq!(votacion.get_admin_nominado(), None);
+            assert_eq!(votacion.admin, accounts.alice);
+            assert_eq!(
+                votacion.cancelar_nominacion_admin(),
+                Err(VotacionError::SinAdminNominado)
+            );
+        }
+
+        #[ink::test]
+        fn test_cancelar_nominacion_admin_error_no_admin() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            let mut votacion = Votacion::new();
+            votacion.set_admin(accounts.bob).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.charlie);
+            assert_eq!(
+                votacion.cancelar_nominacion_admin(),
+                Err(VotacionError::NoEsAdmin)
+            );
+            assert_eq!(votacion.get_admin_nominado(), Some(accounts.bob));
+        }
+
+        // Tests de GettersUsuario
+        #[test]
+        fn test_getters_usuario() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let usuario = Usuario {
+                addres: accounts.bob,
+                nombre: "Juan".to_string(),
+                apellido: "Perez".to_string(),
+                direccion: "Calle Falsa 123".to_string(),
+                dni: "12345678".to_string(),
+                fecha_nacimiento: Fecha::new(1, 1, 1994),
+                hash_datos: None,
+            };
+
+            assert_eq!(usuario.get_addres(), accounts.bob);
+            assert_eq!(usuario.get_nombre(), "Juan".to_string());
+            assert_eq!(usuario.get_apellido(), "Perez".to_string());
+            assert_eq!(usuario.get_direccion(), "Calle Falsa 123".to_string());
+            assert_eq!(usuario.get_dni(), "12345678".to_string());
+            assert_eq!(usuario.get_fecha_nacimiento(), Fecha::new(1, 1, 1994));
+        }
+
+        // Tests de GettersEleccion
+        #[test]
+        fn test_getters_eleccion() {
+            let eleccion = Eleccion {
+                id: 0,
+                cantidad_votantes: 2,
+                cantidad_candidatos: 3,
+                votantes_retirados: 0,
+                candidatos_retirados: 0,
+                cantidad_votos: 1,
+                cantidad_revelados: 0,
+                cantidad_listas: 0,
+                votos_blanco: 0,
+                votos_nulos: 0,
+                votos_delegados: 0,
+                peso_votantes: 0,
+                votos_ponderados: 0,
+                fecha_inicio: Fecha::new(19, 6, 2024),
+                fecha_fin: Fecha::new(20, 6, 2024),
+                config: ConfigEleccion {
+                    cantidad_cargos: 3,
+                    ..Default::default()
+                },
+                primera_vuelta: None,
+                resultado_balotaje: None,
+                primer_llamado: None,
+                resultado_quorum: None,
+                semilla_desempate: None,
+                estado: EstadoEleccion::Borrador,
+                cantidad_impugnaciones: 0,
+                impugnaciones_pendientes: 0,
+                impugnaciones_aceptadas: 0,
+            };
+
+            assert_eq!(eleccion.get_id(), 0);
+            assert_eq!(eleccion.get_cantidad_votantes(), 2);
+            assert_eq!(eleccion.get_cantidad_candidatos(), 3);
+            assert_eq!(eleccion.get_cantidad_votos(), 1);
+            assert_eq!(eleccion.get_fecha_inicio(), Fecha::new(19, 6, 2024));
+            assert_eq!(eleccion.get_fecha_fin(), Fecha::new(20, 6, 2024));
+            assert_eq!(eleccion.get_tipo(), TipoEleccion::Simple);
+            assert_eq!(eleccion.get_cantidad_cargos(), 3);
+        }
+
+        #[ink::test]
+        fn test_get_estado_participante() {
+            let votacion = default_with_data();
+            let accounts = default_accounts::<DefaultEnvironment>();
+            assert_eq!(
+                votacion.get_estado_participante(0, accounts.alice),
+                Some(EstadoParticipante::Candidato)
+            );
+            assert_eq!(
+                votacion.get_estado_participante(0, accounts.charlie),
+                Some(EstadoParticipante::Votante)
+            );
+            assert_eq!(
+                votacion.get_estado_participante(1, accounts.bob),
+                Some(EstadoParticipante::Votante)
+            );
+            assert_eq!(votacion.get_estado_participante(0, accounts.eve), None);
+            assert_eq!(votacion.get_estado_participante(2, accounts.alice), None);
+        }
+
+        // Tests de VotacionImpl
+        #[ink::test]
+        fn test_crear_eleccion() {
+            let mut votacion = Votacion::new();
+            let fecha_inicio = Fecha::new(1, 1, 2024);
+            let fecha_fin = Fecha::new(31, 12, 2024);
+            let id_eleccion = votacion
+                .crear_eleccion(
+                    fecha_inicio.clone(),
+                    fecha_fin.clone(),
+                    ConfigEleccion::default(),
+                )
+                .unwrap();
+            let eleccion = votacion.get_eleccion(id_eleccion).unwrap();
+            assert_eq!(eleccion.get_id(), 0);
+            assert_eq!(eleccion.get_fecha_inicio(), fecha_inicio);
+            assert_eq!(eleccion.get_fecha_fin(), fecha_fin);
+        }
+
+        #[ink::test]
+        fn test_crear_eleccion_error_no_admin() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            let mut votacion = Votacion::new();
+            let fecha_inicio = Fecha::new(1, 1, 2024);
+            let fecha_fin = Fecha::new(31, 12, 2024);
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            let eleccion =
+                votacion.crear_eleccion(fecha_inicio, fecha_fin, ConfigEleccion::default());
+            assert_eq!(eleccion, Err(VotacionError::NoEsAdmin));
+        }
+
+        #[ink::test]
+        fn test_crear_eleccion_error_fecha_inicio_mayor_que_fin() {
+            let mut votacion = Votacion::new();
+            let fecha_inicio = Fecha::new(1, 1, 2024);
+            let fecha_fin = Fecha::new(31, 12, 2023);
+            let eleccion =
+                votacion.crear_eleccion(fecha_inicio, fecha_fin, ConfigEleccion::default());
+            assert_eq!(eleccion, Err(VotacionError::FechaInicioMayorQueFin));
+        }
+
+        #[ink::test]
+        fn test_crear_eleccion_error_cantidad_cargos_invalida() {
+            let mut votacion = Votacion::new();
+            let sin_cargos = ConfigEleccion {
+                cantidad_cargos: 0,
+                ..Default::default()
+            };
+            let preferencial_varios_cargos = ConfigEleccion {
+                tipo: TipoEleccion::Preferencial,
+                cantidad_cargos: 2,
+                ..Default::default()
+            };
+            assert_eq!(
+                votacion.crear_eleccion(
+                    Fecha::new(1, 1, 2024),
+                    Fecha::new(31, 12, 2024),
+                    sin_cargos
+                ),
+                Err(VotacionError::CantidadCargosInvalida)
+            );
+            assert_eq!(
+                votacion.crear_eleccion(
+                    Fecha::new(1, 1, 2024),
+                    Fecha::new(31, 12, 2024),
+                    preferencial_varios_cargos
+                ),
+                Err(VotacionError::CantidadCargosInvalida)
+            );
+            assert!(votacion.get_eleccion(0).is_none());
+        }
+
+        #[ink::test]
+        fn test_crear_eleccion_varios_cargos() {
+            let mut votacion = Votacion::new();
+            let config = ConfigEleccion {
+                tipo: TipoEleccion::Aprobacion,
+                cantidad_cargos: 3,
+                ..Default::default()
+            };
+            votacion
+                .crear_eleccion(
+                    Fecha::new(1, 1, 2024),
+                    Fecha::new(31, 12, 2024),
+                    config.clone(),
+                )
+                .unwrap();
+            assert_eq!(votacion.get_eleccion(0).unwrap().get_cantidad_cargos(), 3);
+
+            set_caller::<DefaultEnvironment>(AccountId::from([0x10; 32]));
+            assert_eq!(votacion.reporte_config(0), Ok(config));
+            assert_eq!(
+                votacion.reporte_config(1),
+                Err(VotacionError::EleccionNoEncontrada)
+            );
+        }
+
+        #[ink::test]
+        fn test_reporte_config_no_es_reporte() {
+            let votacion = default_with_data();
+            assert_eq!(votacion.reporte_config(0), Err(VotacionError::SoloReportes));
+        }
+
+        #[ink::test]
+        fn test_get_eleccion() {
+            let mut votacion = Votacion::new();
+            let eleccion = votacion
+                .crear_eleccion(
+                    Fecha::new(1, 1, 2024),
+                    Fecha::new(31, 12, 2024),
+                    ConfigEleccion::default(),
+                )
+                .unwrap();
+            assert_eq!(votacion.get_eleccion(0).unwrap().get_id(), eleccion);
+        }
+
+        // Tests de UserManager
+        #[ink::test]
+        fn test_crear_usuario() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            let mut votacion = Votacion::new();
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2023).timestamp().unwrap());
+            let usuario = votacion
+                .postular_usuario(
+                    "Juan".to_string(),
+                    "Perez".to_string(),
+                    "Calle Falsa 123".to_string(),
+                    "12345678".to_string(),
+                    Fecha::new(1, 1, 1994),
+                )
+                .unwrap();
+            assert_eq!(usuario.get_addres(), accounts.bob);
+            assert_eq!(usuario.get_nombre(), "Juan".to_string());
+            assert_eq!(usuario.get_apellido(), "Perez".to_string());
+            assert_eq!(usuario.get_direccion(), "Calle Falsa 123".to_string());
+            assert_eq!(usuario.get_dni(), "12345678".to_string());
+            assert_eq!(usuario.get_fecha_nacimiento(), Fecha::new(1, 1, 1994));
+        }
+
+        #[ink::test]
+        fn test_crear_usuario_error_usuario_no_aceptado() {
+            let mut votacion = Votacion::new();
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2023).timestamp().unwrap());
+            votacion
+                .postular_usuario(
+                    "Juan".to_string(),
+                    "Perez".to_string(),
+                    "Calle Falsa 123".to_string(),
+                    "12345678".to_string(),
+                    Fecha::new(1, 1, 1994),
+                )
+                .unwrap();
+            let usuario = votacion.postular_usuario(
+                "Juan".to_string(),
+                "Perez".to_string(),
+                "Calle Falsa 123".to_string(),
+                "12345678".to_string(),
+                Fecha::new(1, 1, 1994),
+            );
+            assert_eq!(usuario, Err(VotacionError::UsuarioNoAceptado));
+        }
+
+        #[ink::test]
+        fn test_crear_usuario_error_usuario_ya_registrado() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            let mut votacion = Votacion::new();
+            votacion.usuarios.insert(
+                accounts.bob,
+                &Usuario::new(
+                    accounts.bob,
+                    "Juan".to_string(),
+                    "Perez".to_string(),
+                    "Calle Falsa 123".to_string(),
+                    "12345678".to_string(),
+                    Fecha::new(1, 1, 1994),
+                ),
+            );
+            let usuario = votacion.postular_usuario(
+                "Juan".to_string(),
+                "Perez".to_string(),
+                "Calle Falsa 123".to_string(),
+                "12345678".to_string(),
+                Fecha::new(1, 1, 1994),
+            );
+            assert_eq!(usuario, Err(VotacionError::UsuarioYaRegistrado));
+        }
+
+        #[ink::test]
+        fn test_aceptar_usuario() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            let mut votacion = Votacion::new();
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2023).timestamp().unwrap());
+            votacion
+                .postular_usuario(
+                    "Juan".to_string(),
+                    "Perez".to_string(),
+                    "Calle Falsa 123".to_string(),
+                    "12345678".to_string(),
+                    Fecha::new(1, 1, 1994),
+                )
+                .unwrap();
+            votacion.aceptar_usuario(accounts.bob).unwrap();
+            assert!(!votacion.usuarios_sin_aceptar.contains(accounts.bob));
+            assert!(votacion.usuarios.contains(accounts.bob));
+        }
+
+        #[ink::test]
+        fn test_aceptar_usuario_error_sin_rol() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.charlie);
+            let mut votacion = Votacion::new();
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2023).timestamp().unwrap());
+            votacion
+                .postular_usuario(
+                    "Juan".to_string(),
+                    "Perez".to_string(),
+                    "Calle Falsa 123".to_string(),
+                    "12345678".to_string(),
+                    Fecha::new(1, 1, 1994),
+                )
+                .unwrap();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            let usuario = votacion.aceptar_usuario(accounts.bob);
+            assert_eq!(usuario, Err(VotacionError::NoTieneRol));
+        }
+
+        #[ink::test]
+        fn test_aceptar_usuario_error_usuario_sin_aceptar_no_encontrado() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = Votacion::new();
+            let usuario = votacion.aceptar_usuario(accounts.bob);
+            assert_eq!(usuario, Err(VotacionError::UsuarioSinAceptarNoEncontrado));
+        }
+
+        #[ink::test]
+        fn test_get_usuario_sin_aceptar() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            let mut votacion = Votacion::new();
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2023).timestamp().unwrap());
+            votacion
+                .postular_usuario(
+                    "Juan".to_string(),
+                    "Perez".to_string(),
+                    "Calle Falsa 123".to_string(),
+                    "12345678".to_string(),
+                    Fecha::new(1, 1, 1994),
+                )
+                .unwrap();
+            assert_eq!(
+                votacion
+                    .get_usuario_sin_aceptar(accounts.bob)
+                    .unwrap()
+                    .get_addres(),
+                accounts.bob
+            );
+        }
+
+        #[ink::test]
+        fn test_get_usuario_sin_aceptar_error_usuario_sin_aceptar_no_encontrado() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let votacion = Votacion::new();
+            let usuario = votacion.get_usuario_sin_aceptar(accounts.bob);
+            assert_eq!(usuario, Err(VotacionError::UsuarioSinAceptarNoEncontrado));
+        }
+
+        #[ink::test]
+        fn test_get_usuario() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            let mut votacion = Votacion::new();
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2023).timestamp().unwrap());
+            votacion
+                .postular_usuario(
+                    "Juan".to_string(),
+                    "Perez".to_string(),
+                    "Calle Falsa 123".to_string(),
+                    "12345678".to_string(),
+                    Fecha::new(1, 1, 1994),
+                )
+                .unwrap();
+            votacion.aceptar_usuario(accounts.bob).unwrap();
+            assert_eq!(
+                votacion.get_usuario(accounts.bob).unwrap().get_addres(),
+                accounts.bob
+            );
+        }
+
+        #[ink::test]
+        fn test_get_usuario_error_usuario_no_encontrado() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let votacion = Votacion::new();
+            let usuario = votacion.get_usuario(accounts.bob);
+            assert_eq!(usuario, Err(VotacionError::UsuarioNoEncontrado));
+        }
+
+        //impl test de EleccionImpl
+        #[ink::test]
+        fn test_get_inicio_eleccion() {
+            // Ya terminó
+            let eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2023),
+                Fecha::new(31, 12, 2023),
+                ConfigEleccion::default(),
+            );
+            assert!(eleccion.get_inicio(&Fecha::new(1, 1, 2024)));
+
+            // Ya empezó y no terminó
+            let eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2024),
+                Fecha::new(31, 12, 2024),
+                ConfigEleccion::default(),
+            );
+            assert!(eleccion.get_inicio(&Fecha::new(15, 6, 2024)));
+
+            // No empezó
+            let eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2025),
+                Fecha::new(31, 12, 2025),
+                ConfigEleccion::default(),
+            );
+            assert!(!eleccion.get_inicio(&Fecha::new(1, 1, 2024)));
+        }
+
+        #[test]
+        fn test_get_finalizada_eleccion() {
+            // Ya terminó
+            let eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2023),
+                Fecha::new(31, 12, 2023),
+                ConfigEleccion::default(),
+            );
+            assert!(eleccion.get_finalizada(&Fecha::new(1, 1, 2024)));
+
+            // Ya empezó y no terminó
+            let eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2024),
+                Fecha::new(31, 12, 2024),
+                ConfigEleccion::default(),
+            );
+            assert!(!eleccion.get_finalizada(&Fecha::new(15, 6, 2024)));
+
+            // No empezó
+            let eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2025),
+                Fecha::new(31, 12, 2025),
+                ConfigEleccion::default(),
+            );
+            assert!(!eleccion.get_inicio(&Fecha::new(1, 1, 2024)));
+        }
+
+        /// Usuario de 30 años al inicio de las elecciones de los tests
+        fn usuario_test() -> Usuario {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            Usuario::new(
+                accounts.bob,
+                "Juan".to_string(),
+                "Perez".to_string(),
+                "Calle Falsa 123".to_string(),
+                "12345678".to_string(),
+                Fecha::new(1, 1, 1994),
+            )
+        }
+
+        #[test]
+        fn test_postular_candidato_eleccion() {
+            let eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2024),
+                Fecha::new(31, 12, 2024),
+                ConfigEleccion::default(),
+            );
+
+            assert!(eleccion
+                .postular_candidato(None, &usuario_test(), false, &Fecha::new(1, 1, 2023))
+                .is_ok());
+            //Intento postularlo como votante una vez postulado como candidato
+            assert_eq!(
+                eleccion.postular_votante(
+                    Some(EstadoParticipante::PostuladoCandidato),
+                    &usuario_test(),
+                    false,
+                    &Fecha::new(1, 1, 2023)
+                ),
+                Err(VotacionError::UsuarioEsPostuladoCandidato)
+            );
+        }
+
+        #[test]
+        fn test_postular_candidato_eleccion_error_eleccion_ya_finalizada() {
+            let eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2024),
+                Fecha::new(31, 12, 2024),
+                ConfigEleccion::default(),
+            );
+
+            assert_eq!(
+                eleccion.postular_candidato(None, &usuario_test(), false, &Fecha::new(1, 1, 2025)),
+                Err(VotacionError::EleccionYaFinalizada)
+            );
+        }
+
+        #[test]
+        fn test_postular_eleccion_reglas_de_edad() {
+            let eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2024),
+                Fecha::new(31, 12, 2024),
+                ConfigEleccion {
+                    elegibilidad: ReglasElegibilidad {
+                        edad_minima_votante: Some(18),
+                        edad_maxima_votante: Some(70),
+                        edad_minima_candidato: Some(25),
+                        solo_habilitados: false,
+                    },
+                    ..Default::default()
+                },
+            );
+            let antes_de_postular = Fecha::new(1, 6, 2023);
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let nacido_el = |fecha: Fecha| {
+                Usuario::new(
+                    accounts.bob,
+                    "Juan".to_string(),
+                    "Perez".to_string(),
+                    "Calle Falsa 123".to_string(),
+                    "12345678".to_string(),
+                    fecha,
+                )
+            };
+
+            // la edad se calcula a la fecha de inicio de la elección
+            assert!(eleccion
+                .postular_votante(
+                    None,
+                    &nacido_el(Fecha::new(1, 1, 2006)),
+                    false,
+                    &antes_de_postular
+                )
+                .is_ok());
+            assert_eq!(
+                eleccion.postular_votante(
+                    None,
+                    &nacido_el(Fecha::new(2, 1, 2006)),
+                    false,
+                    &antes_de_postular
+                ),
+                Err(VotacionError::EdadMenorAMinima)
+            );
+            assert_eq!(
+                eleccion.postular_votante(
+                    None,
+                    &nacido_el(Fecha::new(1, 1, 1953)),
+                    false,
+                    &antes_de_postular
+                ),
+                Err(VotacionError::EdadMayorAMaxima)
+            );
+            assert_eq!(
+                eleccion.postular_candidato(
+                    None,
+                    &nacido_el(Fecha::new(1, 1, 2006)),
+                    false,
+                    &antes_de_postular
+                ),
+                Err(VotacionError::EdadMenorAMinimaCandidato)
+            );
+            assert!(eleccion
+                .postular_candidato(
+                    None,
+                    &nacido_el(Fecha::new(1, 1, 1953)),
+                    false,
+                    &antes_de_postular
+                )
+                .is_ok());
+        }
+
+        #[test]
+        fn test_postular_candidato_eleccion_error_eleccion_ya_iniciada() {
+            let eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2024),
+                Fecha::new(31, 12, 2024),
+                ConfigEleccion::default(),
+            );
+
+            assert_eq!(
+                eleccion.postular_candidato(None, &usuario_test(), false, &Fecha::new(1, 1, 2024)),
+                Err(VotacionError::EleccionYaIniciada)
+            );
+        }
+
+        #[test]
+        fn test_postular_candidato_eleccion_error_es_postulado_candidato() {
+            let eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2024),
+                Fecha::new(31, 12, 2024),
+                ConfigEleccion::default(),
+            );
+
+            assert_eq!(
+                eleccion.postular_candidato(
+                    Some(EstadoParticipante::PostuladoCandidato),
+                    &usuario_test(),
+                    false,
+                    &Fecha::new(1, 1, 2023)
+                ),
+                Err(VotacionError::UsuarioEsPostuladoCandidato)
+            );
+        }
+
+        #[test]
+        fn test_postular_candidato_eleccion_error_es_postulado_votante() {
+            let eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2024),
+                Fecha::new(31, 12, 2024),
+                ConfigEleccion::default(),
+            );
+
+            assert_eq!(
+                eleccion.postular_candidato(
+                    Some(EstadoParticipante::PostuladoVotante),
+                    &usuario_test(),
+                    false,
+                    &Fecha::new(1, 1, 2023)
+                ),
+                Err(VotacionError::UsuarioEsPostuladoVotante)
+            );
+        }
+
+        #[test]
+        fn test_postular_votante_eleccion() {
+            let eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2024),
+                Fecha::new(31, 12, 2024),
+                ConfigEleccion::default(),
+            );
+
+            assert!(eleccion
+                .postular_votante(None, &usuario_test(), false, &Fecha::new(1, 1, 2023))
+                .is_ok());
+            //Intento postularlo como candidato una vez postulado como votante
+            assert_eq!(
+                eleccion.postular_candidato(
+                    Some(EstadoParticipante::PostuladoVotante),
+                    &usuario_test(),
+                    false,
+                    &Fecha::new(1, 1, 2023)
+                ),
+                Err(VotacionError::UsuarioEsPostuladoVotante)
+            );
+        }
+
+        #[test]
+        fn test_postular_votante_eleccion_error_eleccion_ya_finalizada() {
+            let eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2024),
+                Fecha::new(31, 12, 2024),
+                ConfigEleccion::default(),
+            );
+
+            assert_eq!(
+                eleccion.postular_votante(None, &usuario_test(), false, &Fecha::new(1, 1, 2025)),
+                Err(VotacionError::EleccionYaFinalizada)
+            );
+        }
+
+        #[test]
+        fn test_postular_votante_eleccion_ya_iniciada() {
+            let eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2024),
+                Fecha::new(31, 12, 2024),
+                ConfigEleccion::default(),
+            );
+
+            assert_eq!(
+                eleccion.postular_votante(None, &usuario_test(), false, &Fecha::new(1, 1, 2024)),
+                Err(VotacionError::EleccionYaIniciada)
+            );
+        }
+
+        #[test]
+        fn test_postular_votante_eleccion_error_es_postulado_votante() {
+            let eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2024),
+                Fecha::new(31, 12, 2024),
+                ConfigEleccion::default(),
+            );
+
+            assert_eq!(
+                eleccion.postular_votante(
+                    Some(EstadoParticipante::PostuladoVotante),
+                    &usuario_test(),
+                    false,
+                    &Fecha::new(1, 1, 2023)
+                ),
+                Err(VotacionError::UsuarioEsPostuladoVotante)
+            );
+        }
+
+        #[test]
+        fn test_agregar_candidato_eleccion() {
+            let mut eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2024),
+                Fecha::new(31, 12, 2024),
+                ConfigEleccion::default(),
+            );
+
+            assert_eq!(
+                eleccion.agregar_candidato(
+                    Some(EstadoParticipante::PostuladoCandidato),
+                    &Fecha::new(1, 1, 2023)
+                ),
+                Ok(0)
+            );
+            assert_eq!(
+                eleccion.agregar_candidato(
+                    Some(EstadoParticipante::PostuladoCandidato),
+                    &Fecha::new(1, 1, 2023)
+                ),
+                Ok(1)
+            );
+            assert_eq!(eleccion.get_cantidad_candidatos(), 2);
+        }
+
+        #[test]
+        fn test_agregar_candidato_eleccion_error_usuario_no_postulado_candidato() {
+            let mut eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2024),
+                Fecha::new(31, 12, 2024),
+                ConfigEleccion::default(),
+            );
+
+            assert_eq!(
+                eleccion.agregar_candidato(None, &Fecha::new(1, 1, 2023)),
+                Err(VotacionError::UsuarioNoPostuladoCandidato)
+            );
+            assert_eq!(eleccion.get_cantidad_candidatos(), 0);
+        }
+
+        #[test]
+        fn test_agregar_candidato_eleccion_error_eleccion_ya_iniciada() {
+            let mut eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2024),
+                Fecha::new(31, 12, 2024),
+                ConfigEleccion::default(),
+            );
+
+            assert_eq!(
+                eleccion.agregar_candidato(
+                    Some(EstadoParticipante::PostuladoCandidato),
+                    &Fecha::new(15, 6, 2024)
+                ),
+                Err(VotacionError::EleccionYaIniciada)
+            );
+        }
+
+        #[test]
+        fn test_agregar_candidato_eleccion_error_eleccion_ya_finalizada() {
+            let mut eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2024),
+                Fecha::new(1, 1, 2024),
+                ConfigEleccion::default(),
+            );
+
+            assert_eq!(
+                eleccion.agregar_candidato(
+                    Some(EstadoParticipante::PostuladoCandidato),
+                    &Fecha::new(2, 1, 2024)
+                ),
+                Err(VotacionError::EleccionYaFinalizada)
+            );
+        }
+
+        #[test]
+        fn test_agregar_candidato_eleccion_error_usuario_es_candidato() {
+            let eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2025),
+                Fecha::new(1, 1, 2025),
+                ConfigEleccion::default(),
+            );
+
+            assert_eq!(
+                eleccion.postular_candidato(
+                    Some(EstadoParticipante::Candidato),
+                    &usuario_test(),
+                    false,
+                    &Fecha::new(1, 1, 2023)
+                ),
+                Err(VotacionError::UsuarioEsCandidato)
+            );
+        }
+
+        #[test]
+        fn test_agregar_candidato_eleccion_error_usuario_es_votante() {
+            let eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2025),
+                Fecha::new(1, 1, 2025),
+                ConfigEleccion::default(),
+            );
+
+            assert_eq!(
+                eleccion.postular_candidato(
+                    Some(EstadoParticipante::Votante),
+                    &usuario_test(),
+                    false,
+                    &Fecha::new(1, 1, 2024)
+                ),
+                Err(VotacionError::UsuarioEsVotante)
+            );
+        }
+
+        #[test]
+        fn test_agregar_candidato_eleccion_error_usuario_es_votanteno_postulado() {
+            let mut eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2025),
+                Fecha::new(1, 1, 2025),
+                ConfigEleccion::default(),
+            );
+
+            assert_eq!(
+                eleccion.agregar_candidato(
+                    Some(EstadoParticipante::PostuladoVotante),
+                    &Fecha::new(31, 12, 2024)
+                ),
+                Err(VotacionError::UsuarioNoPostuladoCandidato)
+            );
+        }
+
+        #[test]
+        fn test_agregar_votante_eleccion() {
+            let mut eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2025),
+                Fecha::new(1, 1, 2025),
+                ConfigEleccion::default(),
+            );
+
+            assert_eq!(
+                eleccion.agregar_votante(
+                    Some(EstadoParticipante::PostuladoVotante),
+                    1,
+                    &Fecha::new(31, 12, 2024)
+                ),
+                Ok(0)
+            );
+            assert_eq!(eleccion.get_cantidad_votantes(), 1);
+        }
+
+        #[test]
+        fn test_periodo_inscripcion_eleccion() {
+            let mut eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2025),
+                Fecha::new(31, 1, 2025),
+                ConfigEleccion {
+                    inscripcion: Some(PeriodoInscripcion {
+                        apertura: Fecha::new(1, 11, 2024),
+                        cierre: Fecha::new(30, 11, 2024),
+                    }),
+                    ..Default::default()
+                },
+            );
+
+            assert_eq!(
+                eleccion.postular_votante(None, &usuario_test(), false, &Fecha::new(31, 10, 2024)),
+                Err(VotacionError::InscripcionNoIniciada)
+            );
+            assert!(eleccion
+                .postular_votante(None, &usuario_test(), false, &Fecha::new(30, 11, 2024))
+                .is_ok());
+            assert_eq!(
+                eleccion.postular_candidato(None, &usuario_test(), false, &Fecha::new(1, 12, 2024)),
+                Err(VotacionError::InscripcionFinalizada)
+            );
+            // las postulaciones se pueden aceptar hasta el inicio de la votación
+            assert_eq!(
+                eleccion.agregar_votante(
+                    Some(EstadoParticipante::PostuladoVotante),
+                    1,
+                    &Fecha::new(31, 12, 2024)
+                ),
+                Ok(0)
+            );
+        }
+
+        #[test]
+        fn test_calcular_estado_eleccion() {
+            let mut eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2025),
+                Fecha::new(31, 1, 2025),
+                ConfigEleccion {
+                    inscripcion: Some(PeriodoInscripcion {
+                        apertura: Fecha::new(1, 11, 2024),
+                        cierre: Fecha::new(30, 11, 2024),
+                    }),
+                    ..Default::default()
+                },
+            );
+
+            for (fecha, estado) in [
+                (Fecha::new(31, 10, 2024), EstadoEleccion::Borrador),
+                (Fecha::new(1, 11, 2024), EstadoEleccion::Inscripcion),
+                // después del cierre de la inscripción se revisan las postulaciones
+                (Fecha::new(15, 12, 2024), EstadoEleccion::Inscripcion),
+                (Fecha::new(1, 1, 2025), EstadoEleccion::Abierta),
+                (Fecha::new(1, 2, 2025), EstadoEleccion::Cerrada),
+            ] {
+                assert_eq!(eleccion.calcular_estado(&fecha), estado);
+            }
+
+            // los estados fijados por el admin no dependen de las fechas
+            eleccion.suspender(&Fecha::new(15, 1, 2025)).unwrap();
+            assert_eq!(
+                eleccion.calcular_estado(&Fecha::new(1, 2, 2025)),
+                EstadoEleccion::Suspendida
+            );
+            assert_eq!(
+                eleccion.reanudar(&Fecha::new(1, 2, 2025)),
+                Ok(EstadoEleccion::Cerrada)
+            );
+            eleccion.certificar(&Fecha::new(1, 2, 2025)).unwrap();
+            assert_eq!(
+                eleccion.calcular_estado(&Fecha::new(1, 2, 2025)),
+                EstadoEleccion::Certificada
+            );
+        }
+
+        #[test]
+        fn test_agregar_votante_eleccion_error_eleccion_ya_iniciada() {
+            let mut eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2024),
+                Fecha::new(31, 12, 2024),
+                ConfigEleccion::default(),
+            );
+
+            assert_eq!(
+                eleccion.agregar_votante(
+                    Some(EstadoParticipante::PostuladoVotante),
+                    1,
+                    &Fecha::new(15, 6, 2024)
+                ),
+                Err(VotacionError::EleccionYaIniciada)
+            );
+        }
+
+        #[test]
+        fn test_agregar_votante_eleccion_error_eleccion_ya_finalizada() {
+            let mut eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2024),
+                Fecha::new(31, 12, 2024),
+                ConfigEleccion::default(),
+            );
+
+            assert_eq!(
+                eleccion.agregar_votante(
+                    Some(EstadoParticipante::PostuladoVotante),
+                    1,
+                    &Fecha::new(1, 1, 2025)
+                ),
+                Err(VotacionError::EleccionYaFinalizada)
+            );
+        }
+
+        #[test]
+        fn test_agregar_votante_eleccion_error_usuario_no_postulado_votante() {
+            let mut eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2024),
+                Fecha::new(31, 12, 2024),
+                ConfigEleccion::default(),
+            );
+
+            assert_eq!(
+                eleccion.agregar_votante(None, 1, &Fecha::new(1, 1, 2023)),
+                Err(VotacionError::UsuarioNoPostuladoVotante)
+            );
+            assert_eq!(eleccion.get_cantidad_votantes(), 0);
+        }
+
+        #[test]
+        fn test_agregar_votante_eleccion_error_usuario_es_candidato() {
+            let eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2024),
+                Fecha::new(31, 12, 2024),
+                ConfigEleccion::default(),
+            );
+
+            assert_eq!(
+                eleccion.postular_votante(
+                    Some(EstadoParticipante::Candidato),
+                    &usuario_test(),
+                    false,
+                    &Fecha::new(31, 12, 2023)
+                ),
+                Err(VotacionError::UsuarioEsCandidato)
+            );
+        }
+
+        #[test]
+        fn test_agregar_votante_eleccion_error_usuario_es_votante() {
+            let eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2024),
+                Fecha::new(31, 12, 2024),
+                ConfigEleccion::default(),
+            );
+
+            assert_eq!(
+                eleccion.postular_votante(
+                    Some(EstadoParticipante::Votante),
+                    &usuario_test(),
+                    false,
+                    &Fecha::new(31, 12, 2023)
+                ),
+                Err(VotacionError::UsuarioEsVotante)
+            );
+        }
+
+        #[test]
+        fn test_votar_eleccion() {
+            let mut eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2024),
+                Fecha::new(31, 12, 2024),
+                ConfigEleccion::default(),
+            );
+
+            assert!(eleccion
+                .votar(
+                    Some(EstadoParticipante::Votante),
+                    1,
+                    Some(EstadoParticipante::Candidato),
+                    false,
+                    &Fecha::new(15, 6, 2024)
+                )
+                .is_ok());
+            assert_eq!(eleccion.get_cantidad_votos(), 1);
+        }
+
+        #[test]
+        fn test_votar_eleccion_error_eleccion_no_iniciada() {
+            let mut eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2024),
+                Fecha::new(31, 12, 2024),
+                ConfigEleccion::default(),
+            );
+
+            assert_eq!(
+                eleccion.votar(
+                    Some(EstadoParticipante::Votante),
+                    1,
+                    Some(EstadoParticipante::Candidato),
+                    false,
+                    &Fecha::new(31, 12, 2023)
+                ),
+                Err(VotacionError::EleccionNoIniciada)
+            );
+        }
+
+        #[test]
+        fn test_votar_eleccion_error_eleccion_ya_finalizada() {
+            let mut eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2024),
+                Fecha::new(31, 12, 2024),
+                ConfigEleccion::default(),
+            );
+
+            assert_eq!(
+                eleccion.votar(
+                    Some(EstadoParticipante::Votante),
+                    1,
+                    Some(EstadoParticipante::Candidato),
+                    false,
+                    &Fecha::new(1, 1, 2025)
+                ),
+                Err(VotacionError::EleccionYaFinalizada)
+            );
+        }
+
+        #[test]
+        fn test_votar_eleccion_error_usuario_no_es_votante_1() {
+            let mut eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2024),
+                Fecha::new(31, 12, 2024),
+                ConfigEleccion::default(),
+            );
+
+            assert_eq!(
+                eleccion.votar(
+                    None,
+                    1,
+                    Some(EstadoParticipante::Candidato),
+                    false,
+                    &Fecha::new(15, 6, 2024)
+                ),
+                Err(VotacionError::UsuarioNoEsVotante)
+            );
+        }
+
+        #[test]
+        fn test_votar_eleccion_error_usuario_no_es_votante_2() {
+            let mut eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2024),
+                Fecha::new(31, 12, 2024),
+                ConfigEleccion::default(),
+            );
+
+            //El votante se postulo pero no lo acepto el admin
+            assert_eq!(
+                eleccion.votar(
+                    Some(EstadoParticipante::PostuladoVotante),
+                    1,
+                    Some(EstadoParticipante::Candidato),
+                    false,
+                    &Fecha::new(15, 6, 2024)
+                ),
+                Err(VotacionError::UsuarioNoEsVotante)
+            );
+        }
+
+        #[test]
+        fn test_votar_eleccion_error_usuario_no_es_candidato_1() {
+            let mut eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2024),
+                Fecha::new(31, 12, 2024),
+                ConfigEleccion::default(),
+            );
+
+            assert_eq!(
+                eleccion.votar(
+                    Some(EstadoParticipante::Votante),
+                    1,
+                    None,
+                    false,
+                    &Fecha::new(15, 6, 2024)
+                ),
+                Err(VotacionError::UsuarioNoEsCandidato)
+            );
+        }
+
+        #[test]
+        fn test_votar_eleccion_error_usuario_no_es_candidato_2() {
+            let mut eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2024),
+                Fecha::new(31, 12, 2024),
+                ConfigEleccion::default(),
+            );
+
+            //El candidato se postulo pero no lo acepto el admin
+            assert_eq!(
+                eleccion.votar(
+                    Some(EstadoParticipante::Votante),
+                    1,
+                    Some(EstadoParticipante::PostuladoCandidato),
+                    false,
+                    &Fecha::new(15, 6, 2024)
+                ),
+                Err(VotacionError::UsuarioNoEsCandidato)
+            );
+        }
+
+        #[test]
+        fn test_votar_eleccion_error_usuario_ya_voto() {
+            let mut eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2024),
+                Fecha::new(31, 12, 2024),
+                ConfigEleccion::default(),
+            );
+
+            assert_eq!(
+                eleccion.votar(
+                    Some(EstadoParticipante::Votante),
+                    1,
+                    Some(EstadoParticipante::Candidato),
+                    true,
+                    &Fecha::new(15, 6, 2024)
+                ),
+                Err(VotacionError::UsuarioYaVoto)
+            );
+            assert_eq!(eleccion.get_cantidad_votos(), 0);
+        }
+
+        #[test]
+        fn test_votar_eleccion_error_tipo_eleccion_invalido() {
+            let mut eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2024),
+                Fecha::new(31, 12, 2024),
+                ConfigEleccion {
+                    tipo: TipoEleccion::Preferencial,
+                    ..Default::default()
+                },
+            );
+
+            assert_eq!(
+                eleccion.votar(
+                    Some(EstadoParticipante::Votante),
+                    1,
+                    Some(EstadoParticipante::Candidato),
+                    false,
+                    &Fecha::new(15, 6, 2024)
+                ),
+                Err(VotacionError::TipoEleccionInvalido)
+            );
+        }
+
+        #[test]
+        fn test_votar_preferencial_eleccion() {
+            let mut eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2024),
+                Fecha::new(31, 12, 2024),
+                ConfigEleccion {
+                    tipo: TipoEleccion::Preferencial,
+                    ..Default::default()
+                },
+            );
+            let preferencias = [
+                (
+                    AccountId::from([0x1; 32]),
+                    Some(EstadoParticipante::Candidato),
+                ),
+                (
+                    AccountId::from([0x2; 32]),
+                    Some(EstadoParticipante::Candidato),
+                ),
+            ];
+
+            assert_eq!(
+                eleccion.votar_preferencial(
+                    Some(EstadoParticipante::Votante),
+                    &preferencias,
+                    false,
+                    &Fecha::new(15, 6, 2024)
+                ),
+                Ok(0)
+            );
+            // No es necesario ordenar a todos los candidatos
+            assert_eq!(
+                eleccion.votar_preferencial(
+                    Some(EstadoParticipante::Votante),
+                    &preferencias[1..],
+                    false,
+                    &Fecha::new(15, 6, 2024)
+                ),
+                Ok(1)
+            );
+            assert_eq!(eleccion.get_cantidad_votos(), 2);
+        }
+
+        #[test]
+        fn test_votar_preferencial_eleccion_error_tipo_eleccion_invalido() {
+            let mut eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2024),
+                Fecha::new(31, 12, 2024),
+                ConfigEleccion::default(),
+            );
+
+            assert_eq!(
+                eleccion.votar_preferencial(
+                    Some(EstadoParticipante::Votante),
+                    &[(
+                        AccountId::from([0x1; 32]),
+                        Some(EstadoParticipante::Candidato)
+                    )],
+                    false,
+                    &Fecha::new(15, 6, 2024)
+                ),
+                Err(VotacionError::TipoEleccionInvalido)
+            );
+        }
+
+        #[test]
+        fn test_votar_preferencial_eleccion_error_preferencias_vacias() {
+            let mut eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2024),
+                Fecha::new(31, 12, 2024),
+                ConfigEleccion {
+                    tipo: TipoEleccion::Preferencial,
+                    ..Default::default()
+                },
+            );
+
+            assert_eq!(
+                eleccion.votar_preferencial(
+                    Some(EstadoParticipante::Votante),
+                    &[],
+                    false,
+                    &Fecha::new(15, 6, 2024)
+                ),
+                Err(VotacionError::PreferenciasVacias)
+            );
+        }
+
+        #[test]
+        fn test_votar_preferencial_eleccion_error_usuario_no_es_candidato() {
+            let mut eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2024),
+                Fecha::new(31, 12, 2024),
+                ConfigEleccion {
+                    tipo: TipoEleccion::Preferencial,
+                    ..Default::default()
+                },
+            );
+
+            assert_eq!(
+                eleccion.votar_preferencial(
+                    Some(EstadoParticipante::Votante),
+                    &[
+                        (
+                            AccountId::from([0x1; 32]),
+                            Some(EstadoParticipante::Candidato)
+                        ),
+                        (
+                            AccountId::from([0x2; 32]),
+                            Some(EstadoParticipante::PostuladoCandidato)
+                        ),
+                    ],
+                    false,
+                    &Fecha::new(15, 6, 2024)
+                ),
+                Err(VotacionError::UsuarioNoEsCandidato)
+            );
+        }
+
+        #[test]
+        fn test_votar_preferencial_eleccion_error_candidato_repetido() {
+            let mut eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2024),
+                Fecha::new(31, 12, 2024),
+                ConfigEleccion {
+                    tipo: TipoEleccion::Preferencial,
+                    ..Default::default()
+                },
+            );
+
+            assert_eq!(
+                eleccion.votar_preferencial(
+                    Some(EstadoParticipante::Votante),
+                    &[
+                        (
+                            AccountId::from([0x1; 32]),
+                            Some(EstadoParticipante::Candidato)
+                        ),
+                        (
+                            AccountId::from([0x2; 32]),
+                            Some(EstadoParticipante::Candidato)
+                        ),
+                        (
+                            AccountId::from([0x1; 32]),
+                            Some(EstadoParticipante::Candidato)
+                        ),
+                    ],
+                    false,
+                    &Fecha::new(15, 6, 2024)
+                ),
+                Err(VotacionError::CandidatoRepetido)
+            );
+            assert_eq!(eleccion.get_cantidad_votos(), 0);
+        }
+
+        #[test]
+        fn test_votar_preferencial_eleccion_error_usuario_ya_voto() {
+            let mut eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2024),
+                Fecha::new(31, 12, 2024),
+                ConfigEleccion {
+                    tipo: TipoEleccion::Preferencial,
+                    ..Default::default()
+                },
+            );
+
+            assert_eq!(
+                eleccion.votar_preferencial(
+                    Some(EstadoParticipante::Votante),
+                    &[(
+                        AccountId::from([0x1; 32]),
+                        Some(EstadoParticipante::Candidato)
+                    )],
+                    true,
+                    &Fecha::new(15, 6, 2024)
+                ),
+                Err(VotacionError::UsuarioYaVoto)
+            );
+        }
+
+        // tests de EleccionSystemInk
+        #[ink::test]
+        fn test_postular_candidato_votacion() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            let mut votacion = Votacion::new();
+            let id_eleccion = votacion
+                .crear_eleccion(
+                    Fecha::new(1, 1, 2024),
+                    Fecha::new(31, 12, 2024),
+                    ConfigEleccion::default(),
+                )
+                .unwrap();
+
+            set_block_timestamp::<DefaultEnvironment>(
+                Fecha::new(31, 12, 2023).timestamp().unwrap(),
+            );
+
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            votacion
+                .postular_usuario(
+                    "Juan".to_string(),
+                    "Perez".to_string(),
+                    "Calle Falsa 123".to_string(),
+                    "12345678".to_string(),
+                    Fecha::new(1, 1, 1994),
+                )
+                .unwrap();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            votacion.aceptar_usuario(accounts.bob).unwrap();
+
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            assert!(votacion.postular_candidato(id_eleccion).is_ok());
+            assert_eq!(
+                votacion.get_estado_participante(id_eleccion, accounts.bob),
+                Some(EstadoParticipante::PostuladoCandidato)
+            );
+        }
+
+        #[ink::test]
+        fn test_postular_candidato_votacion_error_usuario_no_aceptado() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = Votacion::new();
+            let id_eleccion = votacion
+                .crear_eleccion(
+                    Fecha::new(1, 1, 2024),
+                    Fecha::new(31, 12, 2024),
+                    ConfigEleccion::default(),
+                )
+                .unwrap();
+
+            set_block_timestamp::<DefaultEnvironment>(
+                Fecha::new(31, 12, 2023).timestamp().unwrap(),
+            );
+
+            //Creo el usuario pero no es aceptado
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            votacion
+                .postular_usuario(
+                    "Juan".to_string(),
+                    "Perez".to_string(),
+                    "Calle Falsa 123".to_string(),
+                    "12345678".to_string(),
+                    Fecha::new(1, 1, 1994),
+                )
+                .unwrap();
+            //votacion.aceptar_usuario(accounts.bob).unwrap();
+
+            assert_eq!(
+                votacion.postular_candidato(id_eleccion),
+                Err(VotacionError::UsuarioNoAceptado)
+            );
+        }
+
+        #[ink::test]
+        fn test_postular_candidato_votacion_error_usuario_no_encontrado() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            let mut votacion = Votacion::new();
+            let id_eleccion = votacion
+                .crear_eleccion(
+                    Fecha::new(1, 1, 2024),
+                    Fecha::new(31, 12, 2024),
+                    ConfigEleccion::default(),
+                )
+                .unwrap();
+
+            set_block_timestamp::<DefaultEnvironment>(
+                Fecha::new(31, 12, 2023).timestamp().unwrap(),
+            );
+
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            let resultado = votacion.postular_candidato(id_eleccion);
+            assert_eq!(resultado, Err(VotacionError::UsuarioNoEncontrado));
+        }
+
+        #[ink::test]
+        fn test_agregar_candidato_votacion() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            let mut votacion = Votacion::new();
+            let id_eleccion = votacion
+                .crear_eleccion(
+                    Fecha::new(1, 1, 2024),
+                    Fecha::new(31, 12, 2024),
+                    ConfigEleccion::default(),
+                )
+                .unwrap();
+
+            set_block_timestamp::<DefaultEnvironment>(
+                Fecha::new(31, 12, 2023).timestamp().unwrap(),
+            );
+
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            votacion
+                .postular_usuario(
+                    "Juan".to_string(),
+                    "Perez".to_string(),
+                    "Calle Falsa 123".to_string(),
+                    "12345678".to_string(),
+                    Fecha::new(1, 1, 1994),
+                )
+                .unwrap();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            votacion.aceptar_usuario(accounts.bob).unwrap();
+
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            assert!(votacion.postular_candidato(id_eleccion).is_ok());
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            assert!(votacion
+                .agregar_candidato(id_eleccion, accounts.bob)
+                .is_ok());
+            assert_eq!(
+                votacion.get_estado_participante(id_eleccion, accounts.bob),
+                Some(EstadoParticipante::Candidato)
+            );
+        }
+
+        #[ink::test]
+        fn test_agregar_candidato_votacion_error_sin_rol() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            let mut votacion = Votacion::new();
+            let id_eleccion = votacion
+                .crear_eleccion(
+                    Fecha::new(1, 1, 2024),
+                    Fecha::new(31, 12, 2024),
+                    ConfigEleccion::default(),
+                )
+                .unwrap();
+
+            set_block_timestamp::<DefaultEnvironment>(
+                Fecha::new(31, 12, 2023).timestamp().unwrap(),
+            );
+
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            votacion
+                .postular_usuario(
+                    "Juan".to_string(),
+                    "Perez".to_string(),
+                    "Calle Falsa 123".to_string(),
+                    "12345678".to_string(),
+                    Fecha::new(1, 1, 1994),
+                )
+                .unwrap();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            votacion.aceptar_usuario(accounts.bob).unwrap();
+
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            assert!(votacion.postular_candidato(id_eleccion).is_ok());
+            let resultado = votacion.agregar_candidato(id_eleccion, accounts.bob);
+            assert_eq!(resultado, Err(VotacionError::NoTieneRol));
+        }
+
+        #[ink::test]
+        fn test_postular_votante_votacion() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            let mut votacion = Votacion::new();
+            let id_eleccion = votacion
+                .crear_eleccion(
+                    Fecha::new(1, 1, 2024),
+                    Fecha::new(31, 12, 2024),
+                    ConfigEleccion::default(),
+                )
+                .unwrap();
+
+            set_block_timestamp::<DefaultEnvironment>(
+                Fecha::new(31, 12, 2023).timestamp().unwrap(),
+            );
+
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            votacion
+                .postular_usuario(
+                    "Juan".to_string(),
+                    "Perez".to_string(),
+                    "Calle Falsa 123".to_string(),
+                    "12345678".to_string(),
+                    Fecha::new(1, 1, 1994),
+                )
+                .unwrap();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            votacion.aceptar_usuario(accounts.bob).unwrap();
+
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            assert!(votacion.postular_votante(id_eleccion).is_ok());
+            assert_eq!(
+                votacion.get_estado_participante(id_eleccion, accounts.bob),
+                Some(EstadoParticipante::PostuladoVotante)
+            );
+        }
+
+        #[ink::test]
+        fn test_postular_votante_votacion_error_usuario_no_aceptado() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = Votacion::new();
+            let id_eleccion = votacion
+                .crear_eleccion(
+                    Fecha::new(1, 1, 2024),
+                    Fecha::new(31, 12, 2024),
+                    ConfigEleccion::default(),
+                )
+                .unwrap();
+
+            set_block_timestamp::<DefaultEnvironment>(
+                Fecha::new(31, 12, 2023).timestamp().unwrap(),
+            );
+
+            //Creo el usuario pero no es aceptado
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            votacion
+                .postular_usuario(
+                    "Juan".to_string(),
+                    "Perez".to_string(),
+                    "Calle Falsa 123".to_string(),
+                    "12345678".to_string(),
+                    Fecha::new(1, 1, 1994),
+                )
+                .unwrap();
+            //votacion.aceptar_usuario(accounts.bob).unwrap();
+
+            assert_eq!(
+                votacion.postular_votante(id_eleccion),
+                Err(VotacionError::UsuarioNoAceptado)
+            );
+        }
+
+        #[ink::test]
+        fn test_postular_votante_votacion_error_usuario_no_encontrado() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = Votacion::new();
+            let id_eleccion = votacion
+                .crear_eleccion(
+                    Fecha::new(1, 1, 2024),
+                    Fecha::new(31, 12, 2024),
+                    ConfigEleccion::default(),
+                )
+                .unwrap();
+
+            set_block_timestamp::<DefaultEnvironment>(
+                Fecha::new(31, 12, 2023).timestamp().unwrap(),
+            );
+
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            let resultado = votacion.postular_votante(id_eleccion);
+            assert_eq!(resultado, Err(VotacionError::UsuarioNoEncontrado));
+        }
+
+        #[ink::test]
+        fn test_agregar_votante_votacion() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            let mut votacion = Votacion::new();
+            let id_eleccion = votacion
+                .crear_eleccion(
+                    Fecha::new(1, 1, 2024),
+                    Fecha::new(31, 12, 2024),
+                    ConfigEleccion::default(),
+                )
+                .unwrap();
+
+            set_block_timestamp::<DefaultEnvironment>(
+                Fecha::new(31, 12, 2023).timestamp().unwrap(),
+            );
+
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            votacion
+                .postular_usuario(
+                    "Juan".to_string(),
+                    "Perez".to_string(),
+                    "Calle Falsa 123".to_string(),
+                    "12345678".to_string(),
+                    Fecha::new(1, 1, 1994),
+                )
+                .unwrap();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            votacion.aceptar_usuario(accounts.bob).unwrap();
+
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            assert!(votacion.postular_votante(id_eleccion).is_ok());
+
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            assert!(votacion
+                .agregar_votante(id_eleccion, accounts.bob, 1)
+                .is_ok());
+            assert_eq!(
+                votacion.get_estado_participante(id_eleccion, accounts.bob),
+                Some(EstadoParticipante::Votante)
+            );
+        }
+
+        #[ink::test]
+        fn test_agregar_votante_votacion_error_sin_rol() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            let mut votacion = Votacion::new();
+            let id_eleccion = votacion
+                .crear_eleccion(
+                    Fecha::new(1, 1, 2024),
+                    Fecha::new(31, 12, 2024),
+                    ConfigEleccion::default(),
+                )
+                .unwrap();
+
+            set_block_timestamp::<DefaultEnvironment>(
+                Fecha::new(31, 12, 2023).timestamp().unwrap(),
+            );
+
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            votacion
+                .postular_usuario(
+                    "Juan".to_string(),
+                    "Perez".to_string(),
+                    "Calle Falsa 123".to_string(),
+                    "12345678".to_string(),
+                    Fecha::new(1, 1, 1994),
+                )
+                .unwrap();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            votacion.aceptar_usuario(accounts.bob).unwrap();
+
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            assert!(votacion.postular_votante(id_eleccion).is_ok());
+            let resultado = votacion.agregar_votante(id_eleccion, accounts.bob, 1);
+            assert_eq!(resultado, Err(VotacionError::NoTieneRol));
+        }
+
+        #[ink::test]
+        fn test_votar_votacion() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = Votacion::new();
+            let id_eleccion = votacion
+                .crear_eleccion(
+                    Fecha::new(1, 1, 2024),
+                    Fecha::new(31, 12, 2024),
+                    ConfigEleccion::default(),
+                )
+                .unwrap();
+
+            set_block_timestamp::<DefaultEnvironment>(
+                Fecha::new(31, 12, 2023).timestamp().unwrap(),
+            );
+
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            votacion
+                .postular_usuario(
+                    "Bob".to_string(),
+                    "Perez".to_string(),
+                    "Calle Falsa 123".to_string(),
+                    "12345678".to_string(),
+                    Fecha::new(1, 1, 1994),
+                )
+                .unwrap();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            votacion.aceptar_usuario(accounts.bob).unwrap();
+
+            votacion
+                .postular_usuario(
+                    "Alice".to_string(),
+                    "Perez".to_string(),
+                    "Calle Falsa 123".to_string(),
+                    "12345679".to_string(),
+                    Fecha::new(1, 1, 1994),
+                )
+                .unwrap();
+            votacion.aceptar_usuario(accounts.alice).unwrap();
+
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            votacion.postular_votante(id_eleccion).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            votacion.postular_candidato(id_eleccion).unwrap();
+            votacion
+                .agregar_votante(id_eleccion, accounts.bob, 1)
+                .unwrap();
+            votacion
+                .agregar_candidato(id_eleccion, accounts.alice)
+                .unwrap();
+
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(15, 6, 2024).timestamp().unwrap());
+            set_caller::<DefaultEnvironment>(accounts.bob);
+
+            assert!(votacion.votar(id_eleccion, accounts.alice).is_ok());
+            assert!(votacion.voto_registrado(id_eleccion, accounts.bob));
+            assert_eq!(
+                votacion.get_votos_registrados(id_eleccion, accounts.alice),
+                1
+            );
+            assert_eq!(
+                votacion
+                    .get_eleccion(id_eleccion)
+                    .unwrap()
+                    .get_cantidad_votos(),
+                1
+            );
+        }
+
+        #[ink::test]
+        fn test_votar_votacion_error_usuario_no_encontrado() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            let mut votacion = Votacion::new();
+            let id_eleccion = votacion
+                .crear_eleccion(
+                    Fecha::new(1, 1, 2024),
+                    Fecha::new(31, 12, 2024),
+                    ConfigEleccion::default(),
+                )
+                .unwrap();
+            set_block_timestamp::<DefaultEnvironment>(
+                Fecha::new(31, 12, 2023).timestamp().unwrap(),
+            );
+
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            votacion
+                .postular_usuario(
+                    "Bob".to_string(),
+                    "Perez".to_string(),
+                    "Calle Falsa 123".to_string(),
+                    "12345678".to_string(),
+                    Fecha::new(1, 1, 1994),
+                )
+                .unwrap();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            votacion.aceptar_usuario(accounts.bob).unwrap();
+
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            votacion.postular_votante(id_eleccion).unwrap();
+
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(15, 6, 2024).timestamp().unwrap());
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                votacion.votar(id_eleccion, accounts.alice),
+                Err(VotacionError::UsuarioNoEncontrado)
+            );
+        }
+
+        #[ink::test]
+        fn test_ya_voto_votacion() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            let mut votacion = Votacion::new();
+            let id_eleccion = votacion
+                .crear_eleccion(
+                    Fecha::new(1, 1, 2024),
+                    Fecha::new(31, 12, 2024),
+                    ConfigEleccion::default(),
+                )
+                .unwrap();
+            set_block_timestamp::<DefaultEnvironment>(
+                Fecha::new(31, 12, 2023).timestamp().unwrap(),
+            );
+
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            votacion
+                .postular_usuario(
+                    "Bob".to_string(),
+                    "Perez".to_string(),
+                    "Calle Falsa 123".to_string(),
+                    "12345678".to_string(),
+                    Fecha::new(1, 1, 1994),
+                )
+                .unwrap();
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            votacion.aceptar_usuario(accounts.bob).unwrap();
+
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            votacion
+                .postular_usuario(
+                    "Alice".to_string(),
+                    "Perez".to_string(),
+                    "Calle Falsa 123".to_string(),
+                    "12345679".to_string(),
+                    Fecha::new(1, 1, 1994),
+                )
+                .unwrap();
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            votacion.aceptar_usuario(accounts.alice).unwrap();
+
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            votacion.postular_votante(id_eleccion).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            votacion.postular_candidato(id_eleccion).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            votacion
+                .agregar_votante(id_eleccion, accounts.bob, 1)
+                .unwrap();
+            votacion
+                .agregar_candidato(id_eleccion, accounts.alice)
+                .unwrap();
+
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(15, 6, 2024).timestamp().unwrap());
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            votacion.votar(id_eleccion, accounts.alice).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            assert!(votacion.ya_voto(id_eleccion, accounts.bob).unwrap());
+        }
+
+        #[ink::test]
+        fn test_ya_voto_votacion_error_usuario_no_encontrado() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            let mut votacion = Votacion::new();
+            let id_eleccion = votacion
+                .crear_eleccion(
+                    Fecha::new(1, 1, 2024),
+                    Fecha::new(31, 12, 2024),
+                    ConfigEleccion::default(),
+                )
+                .unwrap();
+            set_block_timestamp::<DefaultEnvironment>(
+                Fecha::new(31, 12, 2023).timestamp().unwrap(),
+            );
+
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            votacion
+                .postular_usuario(
+                    "Bob".to_string(),
+                    "Perez".to_string(),
+                    "Calle Falsa 123".to_string(),
+                    "12345678".to_string(),
+                    Fecha::new(1, 1, 1994),
+                )
+                .unwrap();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            votacion.aceptar_usuario(accounts.bob).unwrap();
+
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            votacion.postular_votante(id_eleccion).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            votacion
+                .agregar_votante(id_eleccion, accounts.bob, 1)
+                .unwrap();
+
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(15, 6, 2024).timestamp().unwrap());
+            assert_eq!(
+                votacion.ya_voto(id_eleccion, accounts.alice),
+                Err(VotacionError::UsuarioNoEncontrado)
+            );
+        }
+
+        #[ink::test]
+        fn test_ya_voto_votacion_error_no_es_admin() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            let mut votacion = Votacion::new();
+            let id_eleccion = votacion
+                .crear_eleccion(
+                    Fecha::new(1, 1, 2024),
+                    Fecha::new(31, 12, 2024),
+                    ConfigEleccion::default(),
+                )
+                .unwrap();
+            set_block_timestamp::<DefaultEnvironment>(
+                Fecha::new(31, 12, 2023).timestamp().unwrap(),
+            );
+
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            votacion
+                .postular_usuario(
+                    "Bob".to_string(),
+                    "Perez".to_string(),
+                    "Calle Falsa 123".to_string(),
+                    "12345678".to_string(),
+                    Fecha::new(1, 1, 1994),
+                )
+                .unwrap();
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            votacion.aceptar_usuario(accounts.bob).unwrap();
+
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            votacion.postular_votante(id_eleccion).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            votacion
+                .agregar_votante(id_eleccion, accounts.bob, 1)
+                .unwrap();
+
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(15, 6, 2024).timestamp().unwrap());
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            assert_eq!(
+                votacion.ya_voto(id_eleccion, accounts.bob),
+                Err(VotacionError::NoEsAdmin)
+            );
+        }
+
+        #[ink::test]
+        fn test_get_votos_candidato_votacion() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            let mut votacion = Votacion::new();
+            let id_eleccion = votacion
+                .crear_eleccion(
+                    Fecha::new(1, 1, 2024),
+                    Fecha::new(31, 12, 2024),
+                    ConfigEleccion::default(),
+                )
+                .unwrap();
+            set_block_timestamp::<DefaultEnvironment>(
+                Fecha::new(31, 12, 2023).timestamp().unwrap(),
+            );
+
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            votacion
+                .postular_usuario(
+                    "Bob".to_string(),
+                    "Perez".to_string(),
+                    "Calle Falsa 123".to_string(),
+                    "12345678".to_string(),
+                    Fecha::new(1, 1, 1994),
+                )
+                .unwrap();
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            votacion.aceptar_usuario(accounts.bob).unwrap();
+
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            votacion
+                .postular_usuario(
+                    "Alice".to_string(),
+                    "Perez".to_string(),
+                    "Calle Falsa 123".to_string(),
+                    "12345679".to_string(),
+                    Fecha::new(1, 1, 1994),
+                )
+                .unwrap();
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            votacion.aceptar_usuario(accounts.alice).unwrap();
+
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            votacion.postular_votante(id_eleccion).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            votacion.postular_candidato(id_eleccion).unwrap();
+
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            votacion
+                .agregar_votante(id_eleccion, accounts.bob, 1)
+                .unwrap();
+            votacion
+                .agregar_candidato(id_eleccion, accounts.alice)
+                .unwrap();
+
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(15, 6, 2024).timestamp().unwrap());
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            votacion.votar(id_eleccion, accounts.alice).unwrap();
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2025).timestamp().unwrap());
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            assert_eq!(
+                votacion
+                    .get_votos_candidato(id_eleccion, accounts.alice)
+                    .unwrap(),
+                1
+            );
+        }
+
+        #[ink::test]
+        fn test_get_votos_candidato_votacion_error_no_es_admin() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            let mut votacion = Votacion::new();
+            let id_eleccion = votacion
+                .crear_eleccion(
+                    Fecha::new(1, 1, 2024),
+                    Fecha::new(31, 12, 2024),
+                    ConfigEleccion::default(),
+                )
+                .unwrap();
+            set_block_timestamp::<DefaultEnvironment>(
+                Fecha::new(31, 12, 2023).timestamp().unwrap(),
+            );
+
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            votacion
+                .postular_usuario(
+                    "Bob".to_string(),
+                    "Perez".to_string(),
+                    "Calle Falsa 123".to_string(),
+                    "12345678".to_string(),
+                    Fecha::new(1, 1, 1994),
+                )
+                .unwrap();
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            votacion.aceptar_usuario(accounts.bob).unwrap();
+
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            votacion
+                .postular_usuario(
+                    "Alice".to_string(),
+                    "Perez".to_string(),
+                    "Calle Falsa 123".to_string(),
+                    "12345679".to_string(),
+                    Fecha::new(1, 1, 1994),
+                )
+                .unwrap();
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            votacion.aceptar_usuario(accounts.alice).unwrap();
+
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            votacion.postular_votante(id_eleccion).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            votacion.postular_candidato(id_eleccion).unwrap();
+
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            votacion
+                .agregar_votante(id_eleccion, accounts.bob, 1)
+                .unwrap();
+            votacion
+                .agregar_candidato(id_eleccion, accounts.alice)
+                .unwrap();
+
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(15, 6, 2024).timestamp().unwrap());
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            votacion.votar(id_eleccion, accounts.alice).unwrap();
+
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2025).timestamp().unwrap());
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            assert_eq!(
+                votacion.get_votos_candidato(id_eleccion, accounts.alice),
+                Err(VotacionError::NoEsAdmin)
+            );
+        }
+
+        #[ink::test]
+        fn test_get_votos_candidato_votacion_error_eleccion_no_encontrada() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let votacion = Votacion::new();
+            assert_eq!(
+                votacion.get_votos_candidato(0, accounts.bob),
+                Err(VotacionError::EleccionNoEncontrada)
+            );
+        }
+
+        #[ink::test]
+        fn test_get_votos_candidato_votacion_error_eleccion_no_finalizada() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            let mut votacion = Votacion::new();
+            let id_eleccion = votacion
+                .crear_eleccion(
+                    Fecha::new(1, 1, 2024),
+                    Fecha::new(31, 12, 2024),
+                    ConfigEleccion::default(),
+                )
+                .unwrap();
+            set_block_timestamp::<DefaultEnvironment>(
+                Fecha::new(31, 12, 2023).timestamp().unwrap(),
+            );
+
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            votacion
+                .postular_usuario(
+                    "Alice".to_string(),
+                    "Perez".to_string(),
+                    "Calle Falsa 123".to_string(),
+                    "12345679".to_string(),
+                    Fecha::new(1, 1, 1994),
+                )
+                .unwrap();
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            votacion.aceptar_usuario(accounts.alice).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            votacion.postular_candidato(id_eleccion).unwrap();
+
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            votacion
+                .agregar_candidato(id_eleccion, accounts.alice)
+                .unwrap();
+
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(5, 5, 2024).timestamp().unwrap());
+
+            assert_eq!(
+                votacion.get_votos_candidato(id_eleccion, accounts.alice),
+                Err(VotacionError::EleccionNoFinalizada)
+            );
+        }
+
+        #[ink::test]
+        fn test_get_votos_candidato_votacion_error_usuario_no_es_candidato() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            let mut votacion = Votacion::new();
+            let id_eleccion = votacion
+                .crear_eleccion(
+                    Fecha::new(1, 1, 2024),
+                    Fecha::new(31, 12, 2024),
+                    ConfigEleccion::default(),
+                )
+                .unwrap();
+            set_block_timestamp::<DefaultEnvironment>(
+                Fecha::new(31, 12, 2023).timestamp().unwrap(),
+            );
+
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            votacion
+                .postular_usuario(
+                    "Alice".to_string(),
+                    "Perez".to_string(),
+                    "Calle Falsa 123".to_string(),
+                    "12345679".to_string(),
+                    Fecha::new(1, 1, 1994),
+                )
+                .unwrap();
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            votacion.aceptar_usuario(accounts.alice).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            votacion.postular_candidato(id_eleccion).unwrap();
+
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            votacion
+                .agregar_candidato(id_eleccion, accounts.alice)
+                .unwrap();
+
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(5, 5, 2025).timestamp().unwrap());
+
+            assert_eq!(
+                votacion.get_votos_candidato(id_eleccion, accounts.bob),
+                Err(VotacionError::UsuarioNoEsCandidato)
+            );
+        }
+
+        #[ink::test]
+        fn test_get_iniciada_votacion() {
+            let mut votacion = Votacion::new();
+            let id_eleccion = votacion
+                .crear_eleccion(
+                    Fecha::new(1, 1, 2024),
+                    Fecha::new(31, 12, 2024),
+                    ConfigEleccion::default(),
+                )
+                .unwrap();
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(15, 6, 2024).timestamp().unwrap());
+            assert!(votacion.get_iniciada(id_eleccion).unwrap());
+        }
+
+        #[ink::test]
+        fn test_get_iniciada_votacion_error_eleccion_no_encontrada() {
+            let votacion = Votacion::new();
+            assert_eq!(
+                votacion.get_iniciada(0),
+                Err(VotacionError::EleccionNoEncontrada)
+            );
+        }
+
+        #[ink::test]
+        fn test_get_finalizada_votacion() {
+            let mut votacion = Votacion::new();
+            let id_eleccion = votacion
+                .crear_eleccion(
+                    Fecha::new(1, 1, 2024),
+                    Fecha::new(31, 12, 2024),
+                    ConfigEleccion::default(),
+                )
+                .unwrap();
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2023).timestamp().unwrap());
+            assert!(!votacion.get_finalizada(id_eleccion).unwrap());
+
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(15, 6, 2024).timestamp().unwrap());
+            assert!(!votacion.get_finalizada(id_eleccion).unwrap());
+
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2025).timestamp().unwrap());
+            assert!(votacion.get_finalizada(id_eleccion).unwrap());
+        }
+
+        #[ink::test]
+        fn test_get_finalizada_votacion_error_eleccion_no_encontrada() {
+            let votacion = Votacion::new();
+            assert_eq!(
+                votacion.get_finalizada(0),
+                Err(VotacionError::EleccionNoEncontrada)
+            );
+        }
+
+        #[ink::test]
+        fn test_reporte_registro_votantes_eleccion() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = Votacion::new();
+            let eleccion = Eleccion {
+                id: 0,
+                cantidad_votantes: 3,
+                cantidad_candidatos: 0,
+                votantes_retirados: 0,
+                candidatos_retirados: 0,
+                cantidad_votos: 0,
+                cantidad_revelados: 0,
+                cantidad_listas: 0,
+                votos_blanco: 0,
+                votos_nulos: 0,
+                votos_delegados: 0,
+                peso_votantes: 0,
+                votos_ponderados: 0,
+                fecha_inicio: Fecha::new(1, 1, 2024),
+                fecha_fin: Fecha::new(31, 12, 2024),
+                config: ConfigEleccion::default(),
+                primera_vuelta: None,
+                resultado_balotaje: None,
+                primer_llamado: None,
+                resultado_quorum: None,
+                semilla_desempate: None,
+                estado: EstadoEleccion::Borrador,
+                cantidad_impugnaciones: 0,
+                impugnaciones_pendientes: 0,
+                impugnaciones_aceptadas: 0,
+            };
+            votacion.votantes.insert((0, 0), &accounts.alice);
+            votacion.votantes.insert((0, 1), &accounts.bob);
+            votacion.votantes.insert((0, 2), &accounts.charlie);
+
+            let votantes = votacion.get_votantes(&eleccion);
+            assert_eq!(votantes.len(), 3);
+            assert_eq!(votantes.first().unwrap(), &accounts.alice);
+            assert_eq!(votantes.get(1).unwrap(), &accounts.bob);
+            assert_eq!(votantes.get(2).unwrap(), &accounts.charlie);
+        }
+
+        #[ink::test]
+        fn test_reporte_participacion_eleccion() {
+            let eleccion = Eleccion {
+                id: 0,
+                cantidad_votantes: 3,
+                cantidad_candidatos: 0,
+                votantes_retirados: 0,
+                candidatos_retirados: 0,
+                cantidad_votos: 2,
+                cantidad_revelados: 0,
+                cantidad_listas: 0,
+                votos_blanco: 0,
+                votos_nulos: 0,
+                votos_delegados: 0,
+                peso_votantes: 0,
+                votos_ponderados: 0,
+                fecha_inicio: Fecha::new(1, 1, 2024),
+                fecha_fin: Fecha::new(31, 12, 2024),
+                config: ConfigEleccion::default(),
+                primera_vuelta: None,
+                resultado_balotaje: None,
+                primer_llamado: None,
+                resultado_quorum: None,
+                semilla_desempate: None,
+                estado: EstadoEleccion::Borrador,
+                cantidad_impugnaciones: 0,
+                impugnaciones_pendientes: 0,
+                impugnaciones_aceptadas: 0,
+            };
+
+            let participacion = eleccion
+                .reporte_participacion(&Fecha::new(1, 1, 2025))
+                .unwrap();
+            assert_eq!(participacion.votantes, 3);
+            assert_eq!(participacion.votos, 2);
+        }
+
+        #[ink::test]
+        fn test_reporte_participacion_eleccion_error_eleccion_no_finalizada() {
+            let eleccion = Eleccion {
+                id: 0,
+                cantidad_votantes: 3,
+                cantidad_candidatos: 0,
+                votantes_retirados: 0,
+                candidatos_retirados: 0,
+                cantidad_votos: 2,
+                cantidad_revelados: 0,
+                cantidad_listas: 0,
+                votos_blanco: 0,
+                votos_nulos: 0,
+                votos_delegados: 0,
+                peso_votantes: 0,
+                votos_ponderados: 0,
+                fecha_inicio: Fecha::new(1, 1, 2024),
+                fecha_fin: Fecha::new(31, 12, 2024),
+                config: ConfigEleccion::default(),
+                primera_vuelta: None,
+                resultado_balotaje: None,
+                primer_llamado: None,
+                resultado_quorum: None,
+                semilla_desempate: None,
+                estado: EstadoEleccion::Borrador,
+                cantidad_impugnaciones: 0,
+                impugnaciones_pendientes: 0,
+                impugnaciones_aceptadas: 0,
+            };
+
+            assert_eq!(
+                eleccion.reporte_participacion(&Fecha::new(1, 1, 2024)),
+                Err(VotacionError::EleccionNoFinalizada)
+            );
+        }
+
+        #[ink::test]
+        fn test_reporte_resultado_eleccion() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = Votacion::new();
+            let eleccion = Eleccion {
+                id: 0,
+                cantidad_votantes: 3,
+                cantidad_candidatos: 2,
+                votantes_retirados: 0,
+                candidatos_retirados: 0,
+                cantidad_votos: 2,
+                cantidad_revelados: 0,
+                cantidad_listas: 0,
+                votos_blanco: 0,
+                votos_nulos: 0,
+                votos_delegados: 0,
+                peso_votantes: 0,
+                votos_ponderados: 0,
+                fecha_inicio: Fecha::new(1, 1, 2024),
+                fecha_fin: Fecha::new(31, 12, 2024),
+                config: ConfigEleccion::default(),
+                primera_vuelta: None,
+                resultado_balotaje: None,
+                primer_llamado: None,
+                resultado_quorum: None,
+                semilla_desempate: None,
+                estado: EstadoEleccion::Borrador,
+                cantidad_impugnaciones: 0,
+                impugnaciones_pendientes: 0,
+                impugnaciones_aceptadas: 0,
+            };
+            votacion.candidatos.insert((0, 0), &accounts.alice);
+            votacion.candidatos.insert((0, 1), &accounts.bob);
+            votacion.votos.insert((0, accounts.alice), &2);
+
+            let mut resultado = votacion
+                .get_votos(&eleccion, &Fecha::new(1, 1, 2025))
+                .unwrap();
+            assert_eq!(resultado.len(), 2);
+            assert_eq!(resultado.pop().unwrap(), (accounts.bob, 0));
+            assert_eq!(resultado.pop().unwrap(), (accounts.alice, 2));
+        }
+
+        #[ink::test]
+        fn test_reporte_resultado_eleccion_error_eleccion_no_finalizada() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = Votacion::new();
+            let eleccion = Eleccion {
+                id: 0,
+                cantidad_votantes: 3,
+                cantidad_candidatos: 2,
+                votantes_retirados: 0,
+                candidatos_retirados: 0,
+                cantidad_votos: 2,
+                cantidad_revelados: 0,
+                cantidad_listas: 0,
+                votos_blanco: 0,
+                votos_nulos: 0,
+                votos_delegados: 0,
+                peso_votantes: 0,
+                votos_ponderados: 0,
+                fecha_inicio: Fecha::new(1, 1, 2024),
+                fecha_fin: Fecha::new(31, 12, 2024),
+                config: ConfigEleccion::default(),
+                primera_vuelta: None,
+                resultado_balotaje: None,
+                primer_llamado: None,
+                resultado_quorum: None,
+                semilla_desempate: None,
+                estado: EstadoEleccion::Borrador,
+                cantidad_impugnaciones: 0,
+                impugnaciones_pendientes: 0,
+                impugnaciones_aceptadas: 0,
+            };
+            votacion.candidatos.insert((0, 0), &accounts.alice);
+            votacion.candidatos.insert((0, 1), &accounts.bob);
+            votacion.votos.insert((0, accounts.alice), &2);
+
+            assert_eq!(
+                votacion.get_votos(&eleccion, &Fecha::new(1, 1, 2024)),
+                Err(VotacionError::EleccionNoFinalizada)
+            );
+        }
+
+        // tests de ReportMessage
+        #[ink::test]
+        fn test_reporte_registro_votantes() {
+            let id_reporte = AccountId::from([0x10; 32]);
+            let votacion = default_with_data(); //Eleccion de id = 0: 2 votantes aprobados/ eleccion de id = 1: 3 votantes aprobados
+            let accounts = default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(id_reporte);
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2026).timestamp().unwrap());
+            let mut reporte = votacion.reporte_registro_votantes(0).unwrap();
+            assert_eq!(reporte.len(), 2);
+            assert_eq!(reporte.pop().unwrap(), accounts.django);
+            assert_eq!(reporte.pop().unwrap(), accounts.charlie);
+        }
+
+        #[ink::test]
+        fn test_reporte_participacion_votacion() {
+            let id_reporte = AccountId::from([0x10; 32]);
+            let votacion = default_with_data(); //Eleccion de id = 0: 2 votantes aprobados/ eleccion de id = 1: 3 votantes aprobados
+            set_caller::<DefaultEnvironment>(id_reporte);
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2026).timestamp().unwrap());
+            let reporte = votacion.reporte_participacion(0).unwrap();
+            assert_eq!(reporte.votantes, 2);
+            assert_eq!(reporte.votos, 2);
+        }
+
+        #[ink::test]
+        fn test_reporte_resultado() {
+            let id_reporte = AccountId::from([0x10; 32]);
+            let votacion = default_with_data(); //Eleccion de id = 0: Alice = 2 votos y Bob = 0 votos
+            let accounts = default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(id_reporte);
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2026).timestamp().unwrap());
+            let mut reporte = votacion.reporte_resultado(0).unwrap();
+            let primero = reporte.pop().unwrap();
+            let segundo = reporte.pop().unwrap();
+            assert_eq!(primero.0, accounts.bob);
+            assert_eq!(primero.1, 0);
+            assert_eq!(segundo.0, accounts.alice);
+            assert_eq!(segundo.1, 2);
+        }
+
+        #[ink::test]
+        fn test_reporte_registro_votantes_no_es_reporte() {
+            let id_no_reporte = AccountId::from([0x11; 32]);
+            let votacion = Votacion::new();
+            set_caller::<DefaultEnvironment>(id_no_reporte);
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2026).timestamp().unwrap());
+            let result = votacion.reporte_registro_votantes(0);
+            assert!(result.is_err());
+            assert_eq!(result.unwrap_err(), VotacionError::SoloReportes);
+        }
+        #[ink::test]
+        fn test_reporte_registro_votantes_eleccion_no_encontrada() {
+            let id_reporte = AccountId::from([0x10; 32]);
+            let votacion = Votacion::new();
+            set_caller::<DefaultEnvironment>(id_reporte);
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2026).timestamp().unwrap());
+            let result = votacion.reporte_registro_votantes(99);
+            assert!(result.is_err());
+            assert_eq!(result.unwrap_err(), VotacionError::EleccionNoEncontrada);
+        }
+
+        #[ink::test]
+        fn test_reporte_participacion_no_es_reporte() {
+            let id_no_reporte = AccountId::from([0x11; 32]);
+            let votacion = Votacion::new();
+            set_caller::<DefaultEnvironment>(id_no_reporte);
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2026).timestamp().unwrap());
+            let result = votacion.reporte_participacion(0);
+            assert!(result.is_err());
+            assert_eq!(result.unwrap_err(), VotacionError::SoloReportes);
+        }
+
+        #[ink::test]
+        fn test_reporte_participacion_eleccion_no_encontrada() {
+            let id_reporte = AccountId::from([0x10; 32]);
+            let votacion = Votacion::new();
+            set_caller::<DefaultEnvironment>(id_reporte);
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2026).timestamp().unwrap());
+            let result = votacion.reporte_participacion(99);
+            assert!(result.is_err());
+            assert_eq!(result.unwrap_err(), VotacionError::EleccionNoEncontrada);
+        }
+
+        #[ink::test]
+        fn test_reporte_resultado_no_es_reporte() {
+            let id_no_reporte = AccountId::from([0x11; 32]);
+            let votacion = Votacion::new();
+            set_caller::<DefaultEnvironment>(id_no_reporte);
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2026).timestamp().unwrap());
+            let result = votacion.reporte_resultado(0);
+            assert!(result.is_err());
+            assert_eq!(result.unwrap_err(), VotacionError::SoloReportes);
+        }
+
+        #[ink::test]
+        fn test_reporte_resultado_eleccion_no_encontrada() {
+            let id_reporte = AccountId::from([0x10; 32]);
+            let votacion = Votacion::new();
+            set_caller::<DefaultEnvironment>(id_reporte);
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2026).timestamp().unwrap());
+            let result = votacion.reporte_resultado(99);
+            assert!(result.is_err());
+            assert_eq!(result.unwrap_err(), VotacionError::EleccionNoEncontrada);
+        }
+
+        #[ink::test]
+        fn test_reporte_participacion_eleccion_no_finalizada() {
+            let eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2026),
+                Fecha::new(1, 2, 2026),
+                ConfigEleccion::default(),
+            );
+
+            let current_time = Fecha::new(1, 1, 2026);
+            let result = eleccion.reporte_participacion(&current_time);
+            assert!(result.is_err());
+            assert_eq!(result.unwrap_err(), VotacionError::EleccionNoFinalizada);
+        }
+
+        #[ink::test]
+        fn test_reporte_participacion_error_estado() {
+            let mut eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2024),
+                Fecha::new(31, 12, 2024),
+                config_secreta(),
+            );
+
+            let current_time = Fecha::new(15, 1, 2025);
+            assert_eq!(
+                eleccion.reporte_participacion(&current_time),
+                Err(VotacionError::RevelacionNoFinalizada)
+            );
+
+            let current_time = Fecha::new(1, 2, 2025);
+            assert!(eleccion.reporte_participacion(&current_time).is_ok());
+            eleccion.estado = EstadoEleccion::Suspendida;
+            assert_eq!(
+                eleccion.reporte_participacion(&current_time),
+                Err(VotacionError::EleccionSuspendida)
+            );
+            eleccion.estado = EstadoEleccion::Cancelada;
+            assert_eq!(
+                eleccion.reporte_participacion(&current_time),
+                Err(VotacionError::EleccionCancelada)
+            );
+        }
+
+        #[ink::test]
+        fn test_reporte_participacion_eleccion_finalizada() {
+            let mut eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2026),
+                Fecha::new(1, 2, 2026),
+                ConfigEleccion::default(),
+            );
+            eleccion.cantidad_votantes = 2;
+            eleccion.cantidad_votos = 1;
+
+            let current_time = Fecha::new(2, 2, 2026);
+            let result = eleccion.reporte_participacion(&current_time).unwrap();
+            assert_eq!(result.votantes, 2);
+            assert_eq!(result.votos, 1);
+        }
+
+        #[ink::test]
+        fn test_reporte_resultado_eleccion_no_finalizada() {
+            let votacion = Votacion::new();
+            let eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2026),
+                Fecha::new(1, 2, 2026),
+                ConfigEleccion::default(),
+            );
+
+            let current_time = Fecha::new(1, 1, 2026);
+            let result = votacion.get_votos(&eleccion, &current_time);
+            assert!(result.is_err());
+            assert_eq!(result.unwrap_err(), VotacionError::EleccionNoFinalizada);
+        }
+
+        #[ink::test]
+        fn test_reporte_resultado_eleccion_finalizada() {
+            let mut votacion = Votacion::new();
+            let mut eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2026),
+                Fecha::new(1, 2, 2026),
+                ConfigEleccion::default(),
+            );
+            eleccion.cantidad_candidatos = 2;
+            votacion
+                .candidatos
+                .insert((0, 0), &AccountId::from([0x01; 32]));
+            votacion
+                .candidatos
+                .insert((0, 1), &AccountId::from([0x02; 32]));
+            votacion.votos.insert((0, AccountId::from([0x01; 32])), &2);
+            votacion.votos.insert((0, AccountId::from([0x02; 32])), &1);
+
+            let current_time = Fecha::new(2, 2, 2026);
+            let result = votacion.get_votos(&eleccion, &current_time).unwrap();
+            assert_eq!(result.len(), 2);
+            assert_eq!(result[0].0, AccountId::from([0x01; 32]));
+            assert_eq!(result[0].1, 2);
+            assert_eq!(result[1].0, AccountId::from([0x02; 32]));
+            assert_eq!(result[1].1, 1);
+        }
+
+        /// Funcion auxiliar para los tests de elecciones que no son simples.
+        /// Crea una eleccion del tipo pasado por parametro con alice, bob y charlie
+        /// como candidatos y django, eve y frank como votantes, con frank como admin
+        fn default_con_tipo(tipo: TipoEleccion) -> Votacion {
+            default_con_config(ConfigEleccion {
+                tipo,
+                ..Default::default()
+            })
+        }
+
+        /// Igual que `default_con_tipo` pero con la configuracion pasada por parametro
+        fn default_con_config(config: ConfigEleccion) -> Votacion {
+            default_con_pesos(config, [1, 1, 1])
+        }
+
+        /// Como `default_con_config`, con los pesos de django, eve y frank pasados por parametro
+        fn default_con_pesos(config: ConfigEleccion, pesos: [u64; 3]) -> Votacion {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2023).timestamp().unwrap());
+            let mut votacion = Votacion::new();
+            votacion
+                .crear_eleccion(Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024), config)
+                .unwrap();
+
+            let usuarios = [
+                (accounts.alice, "Alice", "11111111"),
+                (accounts.bob, "Bob", "22222222"),
+                (accounts.charlie, "Charlie", "33333333"),
+                (accounts.django, "Django", "44444444"),
+                (accounts.eve, "Eve", "55555555"),
+                (accounts.frank, "Frank", "66666666"),
+            ];
+            for (cuenta, nombre, dni) in usuarios {
+                set_caller::<DefaultEnvironment>(cuenta);
+                votacion
+                    .postular_usuario(
+                        nombre.to_string(),
+                        "Test".to_string(),
+                        "Calle Falsa 123".to_string(),
+                        dni.to_string(),
+                        Fecha::new(1, 1, 1994),
+                    )
+                    .unwrap();
+            }
+
+            set_block_timestamp::<DefaultEnvironment>(
+                Fecha::new(31, 12, 2023).timestamp().unwrap(),
+            );
+            for candidato in [accounts.alice, accounts.bob, accounts.charlie] {
+                set_caller::<DefaultEnvironment>(accounts.frank);
+                votacion.aceptar_usuario(candidato).unwrap();
+                set_caller::<DefaultEnvironment>(candidato);
+                votacion.postular_candidato(0).unwrap();
+                set_caller::<DefaultEnvironment>(accounts.frank);
+                votacion.agregar_candidato(0, candidato).unwrap();
+            }
+            for (votante, peso) in [accounts.django, accounts.eve, accounts.frank]
+                .into_iter()
+                .zip(pesos)
+            {
+                set_caller::<DefaultEnvironment>(accounts.frank);
+                votacion.aceptar_usuario(votante).unwrap();
+                set_caller::<DefaultEnvironment>(votante);
+                votacion.postular_votante(0).unwrap();
+                set_caller::<DefaultEnvironment>(accounts.frank);
+                votacion.agregar_votante(0, votante, peso).unwrap();
+            }
+
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(15, 6, 2024).timestamp().unwrap());
+            votacion
+        }
+
+        // tests de elecciones preferenciales
+        #[ink::test]
+        fn test_votar_preferencial_votacion() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_tipo(TipoEleccion::Preferencial);
+
+            set_caller::<DefaultEnvironment>(accounts.django);
+            votacion
+                .votar_preferencial(0, vec![accounts.bob, accounts.alice])
+                .unwrap();
+            set_caller::<DefaultEnvironment>(accounts.eve);
+            votacion
+                .votar_preferencial(0, vec![accounts.bob, accounts.charlie, accounts.alice])
+                .unwrap();
+
+            assert!(votacion.voto_registrado(0, accounts.django));
+            assert!(votacion.voto_registrado(0, accounts.eve));
+            assert!(!votacion.voto_registrado(0, accounts.frank));
+            // Se cuenta la primera preferencia de cada boleta
+            assert_eq!(votacion.get_votos_registrados(0, accounts.bob), 2);
+            assert_eq!(votacion.get_votos_registrados(0, accounts.alice), 0);
+            assert_eq!(
+                votacion.boletas.get((0, 1)),
+                Some(vec![accounts.bob, accounts.charlie, accounts.alice])
+            );
+            assert_eq!(
+                ultimo_evento::<VotoEmitido>(),
+                VotoEmitido {
+                    id_eleccion: 0,
+                    votante: accounts.eve
+                }
+            );
+        }
+
+        #[ink::test]
+        fn test_votar_preferencial_votacion_error_usuario_ya_voto() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_tipo(TipoEleccion::Preferencial);
+
+            set_caller::<DefaultEnvironment>(accounts.django);
+            votacion.votar_preferencial(0, vec![accounts.bob]).unwrap();
+            assert_eq!(
+                votacion.votar_preferencial(0, vec![accounts.alice]),
+                Err(VotacionError::UsuarioYaVoto)
+            );
+            assert_eq!(votacion.get_votos_registrados(0, accounts.alice), 0);
+        }
+
+        #[ink::test]
+        fn test_votar_preferencial_votacion_error_no_es_candidato() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_tipo(TipoEleccion::Preferencial);
+
+            set_caller::<DefaultEnvironment>(accounts.django);
+            assert_eq!(
+                votacion.votar_preferencial(0, vec![accounts.alice, accounts.eve]),
+                Err(VotacionError::UsuarioNoEsCandidato)
+            );
+            assert!(!votacion.voto_registrado(0, accounts.django));
+        }
+
+        #[ink::test]
+        fn test_votar_votacion_error_eleccion_preferencial() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_tipo(TipoEleccion::Preferencial);
+
+            set_caller::<DefaultEnvironment>(accounts.django);
+            assert_eq!(
+                votacion.votar(0, accounts.alice),
+                Err(VotacionError::TipoEleccionInvalido)
+            );
+        }
+
+        #[ink::test]
+        fn test_reporte_boletas() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_tipo(TipoEleccion::Preferencial);
+
+            set_caller::<DefaultEnvironment>(accounts.django);
+            votacion
+                .votar_preferencial(0, vec![accounts.bob, accounts.alice])
+                .unwrap();
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            votacion
+                .votar_preferencial(0, vec![accounts.charlie])
+                .unwrap();
+
+            set_caller::<DefaultEnvironment>(AccountId::from([0x10; 32]));
+            assert_eq!(
+                votacion.reporte_boletas(0),
+                Err(VotacionError::EleccionNoFinalizada)
+            );
+
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2025).timestamp().unwrap());
+            assert_eq!(
+                votacion.reporte_boletas(0),
+                Ok(vec![
+                    vec![accounts.bob, accounts.alice],
+                    vec![accounts.charlie]
+                ])
+            );
+            assert_eq!(
+                votacion.reporte_resultado(0),
+                Ok(vec![
+                    (accounts.alice, 0),
+                    (accounts.bob, 1),
+                    (accounts.charlie, 1)
+                ])
+            );
+        }
+
+        #[ink::test]
+        fn test_reporte_boletas_error_tipo_eleccion_invalido() {
+            let id_reporte = AccountId::from([0x10; 32]);
+            let votacion = default_with_data();
+            set_caller::<DefaultEnvironment>(id_reporte);
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2026).timestamp().unwrap());
+            assert_eq!(
+                votacion.reporte_boletas(0),
+                Err(VotacionError::TipoEleccionInvalido)
+            );
+        }
+
+        #[ink::test]
+        fn test_reporte_boletas_no_es_reporte() {
+            let votacion = default_con_tipo(TipoEleccion::Preferencial);
+            assert_eq!(
+                votacion.reporte_boletas(0),
+                Err(VotacionError::SoloReportes)
+            );
+        }
+
+        // tests de elecciones por aprobacion
+        #[ink::test]
+        fn test_votar_aprobacion_votacion() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_tipo(TipoEleccion::Aprobacion);
+
+            set_caller::<DefaultEnvironment>(accounts.django);
+            votacion
+                .votar_aprobacion(0, vec![accounts.alice, accounts.charlie])
+                .unwrap();
+            set_caller::<DefaultEnvironment>(accounts.eve);
+            votacion
+                .votar_aprobacion(0, vec![accounts.charlie])
+                .unwrap();
+            // Un votante puede no aprobar a ningun candidato
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            votacion.votar_aprobacion(0, vec![]).unwrap();
+
+            assert!(votacion.voto_registrado(0, accounts.frank));
+            assert_eq!(votacion.get_eleccion(0).unwrap().get_cantidad_votos(), 3);
+            assert_eq!(
+                ultimo_evento::<VotoEmitido>(),
+                VotoEmitido {
+                    id_eleccion: 0,
+                    votante: accounts.frank
+                }
+            );
+
+            set_caller::<DefaultEnvironment>(AccountId::from([0x10; 32]));
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2025).timestamp().unwrap());
+            assert_eq!(
+                votacion.reporte_resultado(0),
+                Ok(vec![
+                    (accounts.alice, 1),
+                    (accounts.bob, 0),
+                    (accounts.charlie, 2)
+                ])
+            );
+        }
+
+        #[ink::test]
+        fn test_votar_aprobacion_votacion_error_candidato_repetido() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_tipo(TipoEleccion::Aprobacion);
+
+            set_caller::<DefaultEnvironment>(accounts.django);
+            assert_eq!(
+                votacion.votar_aprobacion(0, vec![accounts.alice, accounts.bob, accounts.alice]),
+                Err(VotacionError::CandidatoRepetido)
+            );
+            assert!(!votacion.voto_registrado(0, accounts.django));
+            assert_eq!(votacion.get_votos_registrados(0, accounts.alice), 0);
+        }
+
+        #[ink::test]
+        fn test_votar_aprobacion_votacion_error_no_es_candidato() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_tipo(TipoEleccion::Aprobacion);
+
+            set_caller::<DefaultEnvironment>(accounts.django);
+            assert_eq!(
+                votacion.votar_aprobacion(0, vec![accounts.alice, accounts.eve]),
+                Err(VotacionError::UsuarioNoEsCandidato)
+            );
+        }
+
+        #[ink::test]
+        fn test_votar_aprobacion_votacion_error_usuario_ya_voto() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_tipo(TipoEleccion::Aprobacion);
+
+            set_caller::<DefaultEnvironment>(accounts.django);
+            votacion.votar_aprobacion(0, vec![accounts.alice]).unwrap();
+            assert_eq!(
+                votacion.votar_aprobacion(0, vec![accounts.bob]),
+                Err(VotacionError::UsuarioYaVoto)
+            );
+            assert_eq!(votacion.get_votos_registrados(0, accounts.bob), 0);
+        }
+
+        #[ink::test]
+        fn test_votar_aprobacion_votacion_error_tipo_eleccion_invalido() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_tipo(TipoEleccion::Preferencial);
+
+            set_caller::<DefaultEnvironment>(accounts.django);
+            assert_eq!(
+                votacion.votar_aprobacion(0, vec![accounts.alice]),
+                Err(VotacionError::TipoEleccionInvalido)
+            );
+        }
+
+        // tests de elecciones por listas
+
+        /// Crea una eleccion por listas con la lista 0 formada por alice y bob
+        /// y la lista 1 formada por charlie
+        fn default_listas() -> Votacion {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_tipo(TipoEleccion::Listas);
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            set_block_timestamp::<DefaultEnvironment>(
+                Fecha::new(31, 12, 2023).timestamp().unwrap(),
+            );
+            votacion
+                .crear_lista(0, "Lista A".to_string(), vec![accounts.alice, accounts.bob])
+                .unwrap();
+            votacion
+                .crear_lista(0, "Lista B".to_string(), vec![accounts.charlie])
+                .unwrap();
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(15, 6, 2024).timestamp().unwrap());
+            votacion
+        }
+
+        #[ink::test]
+        fn test_crear_lista() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let votacion = default_listas();
+
+            let lista = votacion.get_lista(0, 0).unwrap();
+            assert_eq!(lista.get_id(), 0);
+            assert_eq!(lista.get_nombre(), "Lista A".to_string());
+            assert_eq!(lista.get_candidatos(), vec![accounts.alice, accounts.bob]);
+            assert_eq!(votacion.get_eleccion(0).unwrap().get_cantidad_listas(), 2);
+            assert!(votacion.get_lista(0, 2).is_none());
+            assert_eq!(
+                ultimo_evento::<ListaCreada>(),
+                ListaCreada {
+                    id_eleccion: 0,
+                    id_lista: 1
+                }
+            );
+        }
+
+        #[ink::test]
+        fn test_crear_lista_errores() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_listas();
+            set_block_timestamp::<DefaultEnvironment>(
+                Fecha::new(31, 12, 2023).timestamp().unwrap(),
+            );
+
+            assert_eq!(
+                votacion.crear_lista(0, "Vacia".to_string(), vec![]),
+                Err(VotacionError::ListaVacia)
+            );
+            assert_eq!(
+                votacion.crear_lista(0, "Lista C".to_string(), vec![accounts.django]),
+                Err(VotacionError::UsuarioNoEsCandidato)
+            );
+            assert_eq!(
+                votacion.crear_lista(0, "Lista C".to_string(), vec![accounts.bob]),
+                Err(VotacionError::CandidatoEnOtraLista)
+            );
+            assert_eq!(
+                votacion.crear_lista(1, "Lista C".to_string(), vec![accounts.bob]),
+                Err(VotacionError::EleccionNoEncontrada)
+            );
+
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            assert_eq!(
+                votacion.crear_lista(0, "Lista C".to_string(), vec![accounts.alice]),
+                Err(VotacionError::NoEsAdmin)
+            );
+
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(15, 6, 2024).timestamp().unwrap());
+            assert_eq!(
+                votacion.crear_lista(0, "Lista C".to_string(), vec![accounts.alice]),
+                Err(VotacionError::EleccionYaIniciada)
+            );
+            assert_eq!(votacion.get_eleccion(0).unwrap().get_cantidad_listas(), 2);
+        }
+
+        #[ink::test]
+        fn test_crear_lista_error_tipo_eleccion_invalido() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_tipo(TipoEleccion::Aprobacion);
+            set_block_timestamp::<DefaultEnvironment>(
+                Fecha::new(31, 12, 2023).timestamp().unwrap(),
+            );
+            assert_eq!(
+                votacion.crear_lista(0, "Lista A".to_string(), vec![accounts.alice]),
+                Err(VotacionError::TipoEleccionInvalido)
+            );
+        }
+
+        #[ink::test]
+        fn test_votar_lista() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_listas();
+
+            set_caller::<DefaultEnvironment>(accounts.django);
+            votacion.votar_lista(0, 1).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.eve);
+            votacion.votar_lista(0, 1).unwrap();
+            assert_eq!(
+                votacion.votar_lista(0, 0),
+                Err(VotacionError::UsuarioYaVoto)
+            );
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            assert_eq!(
+                votacion.votar_lista(0, 2),
+                Err(VotacionError::ListaNoEncontrada)
+            );
+            votacion.votar_lista(0, 0).unwrap();
+            assert_eq!(
+                votacion.votar(0, accounts.alice),
+                Err(VotacionError::TipoEleccionInvalido)
+            );
+
+            set_caller::<DefaultEnvironment>(AccountId::from([0x10; 32]));
+            assert_eq!(
+                votacion.reporte_listas(0),
+                Err(VotacionError::EleccionNoFinalizada)
+            );
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2025).timestamp().unwrap());
+            assert_eq!(
+                votacion.reporte_listas(0),
+                Ok(vec![
+                    (votacion.get_lista(0, 0).unwrap(), 1),
+                    (votacion.get_lista(0, 1).unwrap(), 2)
+                ])
+            );
+        }
+
+        #[ink::test]
+        fn test_reporte_listas_errores() {
+            let votacion = default_with_data();
+            assert_eq!(votacion.reporte_listas(0), Err(VotacionError::SoloReportes));
+
+            set_caller::<DefaultEnvironment>(AccountId::from([0x10; 32]));
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2026).timestamp().unwrap());
+            assert_eq!(
+                votacion.reporte_listas(0),
+                Err(VotacionError::TipoEleccionInvalido)
+            );
+        }
+
+        // tests de elecciones secretas
+
+        /// Configuracion de una eleccion secreta cuyos votos se revelan hasta el 31/1/2025
+        fn config_secreta() -> ConfigEleccion {
+            ConfigEleccion {
+                fin_revelacion: Some(Fecha::new(31, 1, 2025)),
+                ..Default::default()
+            }
+        }
+
+        #[ink::test]
+        fn test_crear_eleccion_secreta_errores() {
+            let mut votacion = Votacion::new();
+            assert_eq!(
+                votacion.crear_eleccion(
+                    Fecha::new(1, 1, 2024),
+                    Fecha::new(31, 12, 2024),
+                    ConfigEleccion {
+                        fin_revelacion: Some(Fecha::new(30, 12, 2024)),
+                        ..Default::default()
+                    }
+                ),
+                Err(VotacionError::FechaRevelacionInvalida)
+            );
+            assert_eq!(
+                votacion.crear_eleccion(
+                    Fecha::new(1, 1, 2024),
+                    Fecha::new(31, 12, 2024),
+                    ConfigEleccion {
+                        tipo: TipoEleccion::Aprobacion,
+                        ..config_secreta()
+                    }
+                ),
+                Err(VotacionError::TipoEleccionInvalido)
+            );
+        }
+
+        #[ink::test]
+        fn test_votar_secreto() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_config(config_secreta());
+
+            set_caller::<DefaultEnvironment>(accounts.django);
+            assert_eq!(
+                votacion.votar(0, accounts.alice),
+                Err(VotacionError::TipoEleccionInvalido)
+            );
+            let compromiso =
+                Votacion::calcular_compromiso(0, accounts.django, accounts.alice, [1; 32]);
+            votacion.votar_secreto(0, compromiso).unwrap();
+            assert_eq!(
+                votacion.votar_secreto(0, compromiso),
+                Err(VotacionError::UsuarioYaVoto)
+            );
+            assert_eq!(
+                votacion.compromisos.get((0, accounts.django)),
+                Some(compromiso)
+            );
+            assert_eq!(
+                ultimo_evento::<VotoEmitido>(),
+                VotoEmitido {
+                    id_eleccion: 0,
+                    votante: accounts.django
+                }
+            );
+            // El voto no se cuenta hasta que se revela
+            assert_eq!(votacion.get_votos_registrados(0, accounts.alice), 0);
+            assert_eq!(
+                votacion.revelar_voto(0, accounts.alice, [1; 32]),
+                Err(VotacionError::EleccionNoFinalizada)
+            );
+        }
+
+        #[ink::test]
+        fn test_revelar_voto_error_compromiso_copiado() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_config(config_secreta());
+
+            let compromiso =
+                Votacion::calcular_compromiso(0, accounts.django, accounts.alice, [1; 32]);
+            set_caller::<DefaultEnvironment>(accounts.django);
+            votacion.votar_secreto(0, compromiso).unwrap();
+            // Otro votante copia el compromiso y quiere revelarlo con la apertura de django
+            set_caller::<DefaultEnvironment>(accounts.eve);
+            votacion.votar_secreto(0, compromiso).unwrap();
+
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(15, 1, 2025).timestamp().unwrap());
+            set_caller::<DefaultEnvironment>(accounts.django);
+            votacion.revelar_voto(0, accounts.alice, [1; 32]).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.eve);
+            assert_eq!(
+                votacion.revelar_voto(0, accounts.alice, [1; 32]),
+                Err(VotacionError::RevelacionInvalida)
+            );
+        }
+
+        #[ink::test]
+        fn test_votar_secreto_error_eleccion_no_secreta() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_tipo(TipoEleccion::Simple);
+
+            set_caller::<DefaultEnvironment>(accounts.django);
+            assert_eq!(
+                votacion.votar_secreto(0, [0; 32]),
+                Err(VotacionError::TipoEleccionInvalido)
+            );
+        }
+
+        #[ink::test]
+        fn test_revelar_voto() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let id_reporte = AccountId::from([0x10; 32]);
+            let mut votacion = default_con_config(config_secreta());
+
+            for (votante, candidato, sal) in [
+                (accounts.django, accounts.alice, [1; 32]),
+                (accounts.eve, accounts.bob, [2; 32]),
+                (accounts.frank, accounts.alice, [3; 32]),
+            ] {
+                set_caller::<DefaultEnvironment>(votante);
+                votacion
+                    .votar_secreto(0, Votacion::calcular_compromiso(0, votante, candidato, sal))
+                    .unwrap();
+            }
+
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(15, 1, 2025).timestamp().unwrap());
+            set_caller::<DefaultEnvironment>(accounts.django);
+            votacion.revelar_voto(0, accounts.alice, [1; 32]).unwrap();
+            assert_eq!(
+                ultimo_evento::<VotoRevelado>(),
+                VotoRevelado {
+                    id_eleccion: 0,
+                    votante: accounts.django
+                }
+            );
+            assert_eq!(
+                votacion.revelar_voto(0, accounts.alice, [1; 32]),
+                Err(VotacionError::VotoYaRevelado)
+            );
+
+            set_caller::<DefaultEnvironment>(accounts.eve);
+            assert_eq!(
+                votacion.revelar_voto(0, accounts.bob, [1; 32]),
+                Err(VotacionError::RevelacionInvalida)
+            );
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            assert_eq!(
+                votacion.revelar_voto(0, accounts.alice, [1; 32]),
+                Err(VotacionError::VotoNoComprometido)
+            );
+
+            // Durante la revelación no se informan la participación ni el resultado
+            set_caller::<DefaultEnvironment>(id_reporte);
+            assert_eq!(
+                votacion.reporte_participacion(0),
+                Err(VotacionError::RevelacionNoFinalizada)
+            );
+            assert_eq!(
+                votacion.reporte_resultado(0),
+                Err(VotacionError::RevelacionNoFinalizada)
+            );
+
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 2, 2025).timestamp().unwrap());
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            assert_eq!(
+                votacion.revelar_voto(0, accounts.alice, [3; 32]),
+                Err(VotacionError::RevelacionFinalizada)
+            );
+            set_caller::<DefaultEnvironment>(id_reporte);
+            assert_eq!(
+                votacion.reporte_resultado(0),
+                Ok(vec![
+                    (accounts.alice, 1),
+                    (accounts.bob, 0),
+                    (accounts.charlie, 0)
+                ])
+            );
+            // la participación distingue los votos comprometidos de los revelados
+            assert_eq!(
+                votacion.reporte_participacion(0),
+                Ok(Participacion {
+                    votantes: 3,
+                    votos: 3,
+                    votos_revelados: Some(1),
+                    votos_blanco: 0,
+                    votos_nulos: 0,
+                    votos_delegados: 0,
+                    peso_votantes: 3,
+                    votos_ponderados: 3,
+                    quorum_alcanzado: None
+                })
+            );
+        }
+
+        #[test]
+        fn test_revelar_voto_eleccion_error_no_es_candidato() {
+            let mut eleccion = Eleccion::new(
+                0,
+                Fecha::new(1, 1, 2024),
+                Fecha::new(31, 12, 2024),
+                config_secreta(),
+            );
+            let compromiso = [7; 32];
+            assert_eq!(
+                eleccion.revelar_voto(
+                    Some(compromiso),
+                    true,
+                    compromiso,
+                    Some(EstadoParticipante::Votante),
+                    &Fecha::new(15, 1, 2025)
+                ),
+                Err(VotacionError::UsuarioNoEsCandidato)
+            );
+            assert_eq!(eleccion.get_cantidad_revelados(), 0);
+        }
+
+        // tests de votos en blanco y nulos
+        #[ink::test]
+        fn test_votar_en_blanco_y_nulo() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_tipo(TipoEleccion::Simple);
+
+            set_caller::<DefaultEnvironment>(accounts.django);
+            votacion.votar_en_blanco(0).unwrap();
+            assert_eq!(
+                votacion.votar(0, accounts.alice),
+                Err(VotacionError::UsuarioYaVoto)
+            );
+            set_caller::<DefaultEnvironment>(accounts.eve);
+            votacion.votar_nulo(0).unwrap();
+            assert_eq!(
+                votacion.votar_en_blanco(0),
+                Err(VotacionError::UsuarioYaVoto)
+            );
+            assert_eq!(
+                ultimo_evento::<VotoEmitido>(),
+                VotoEmitido {
+                    id_eleccion: 0,
+                    votante: accounts.eve
+                }
+            );
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            votacion.votar(0, accounts.alice).unwrap();
+            assert_eq!(votacion.ya_voto(0, accounts.django), Ok(true));
+            assert_eq!(votacion.ya_voto(0, accounts.eve), Ok(true));
+
+            let eleccion = votacion.get_eleccion(0).unwrap();
+            assert_eq!(eleccion.get_votos_blanco(), 1);
+            assert_eq!(eleccion.get_votos_nulos(), 1);
+
+            set_caller::<DefaultEnvironment>(AccountId::from([0x10; 32]));
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2025).timestamp().unwrap());
+            assert_eq!(
+                votacion.reporte_participacion(0),
+                Ok(Participacion {
+                    votantes: 3,
+                    votos: 3,
+                    votos_revelados: None,
+                    votos_blanco: 1,
+                    votos_nulos: 1,
+                    votos_delegados: 0,
+                    peso_votantes: 3,
+                    votos_ponderados: 3,
+                    quorum_alcanzado: None
+                })
+            );
+            assert_eq!(
+                votacion.reporte_resultado(0),
+                Ok(vec![
+                    (accounts.alice, 1),
+                    (accounts.bob, 0),
+                    (accounts.charlie, 0)
+                ])
+            );
+        }
+
+        #[ink::test]
+        fn test_votar_en_blanco_error_eleccion_secreta() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_config(config_secreta());
+
+            set_caller::<DefaultEnvironment>(accounts.django);
+            assert_eq!(
+                votacion.votar_en_blanco(0),
+                Err(VotacionError::TipoEleccionInvalido)
+            );
+        }
+
+        #[ink::test]
+        fn test_votar_nulo_errores() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_tipo(TipoEleccion::Listas);
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            assert_eq!(
+                votacion.votar_nulo(0),
+                Err(VotacionError::UsuarioNoEsVotante)
+            );
+            set_caller::<DefaultEnvironment>(accounts.django);
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2025).timestamp().unwrap());
+            assert_eq!(
+                votacion.votar_nulo(0),
+                Err(VotacionError::EleccionYaFinalizada)
+            );
+        }
+
+        // tests de balotaje
+        fn regla_balotaje(
+            porcentaje_ganador: u32,
+            porcentaje_con_diferencia: Option<(u32, u32)>,
+        ) -> ReglaBalotaje {
+            ReglaBalotaje {
+                porcentaje_ganador,
+                porcentaje_con_diferencia,
+                fecha_inicio: Fecha::new(1, 2, 2025),
+                fecha_fin: Fecha::new(28, 2, 2025),
+            }
+        }
+
+        fn config_balotaje(regla: ReglaBalotaje) -> ConfigEleccion {
+            ConfigEleccion {
+                balotaje: Some(regla),
+                ..Default::default()
+            }
+        }
+
+        #[test]
+        fn test_regla_balotaje_gana_primera_vuelta() {
+            let regla = regla_balotaje(45, Some((40, 10)));
+            assert!(regla.gana_primera_vuelta(45, 30, 100));
+            assert!(regla.gana_primera_vuelta(40, 30, 100));
+            assert!(!regla.gana_primera_vuelta(40, 31, 100));
+            assert!(!regla.gana_primera_vuelta(39, 10, 100));
+            assert!(!regla.gana_primera_vuelta(0, 0, 0));
+
+            let regla = regla_balotaje(50, None);
+            assert!(!regla.gana_primera_vuelta(49, 0, 100));
+            assert!(regla.gana_primera_vuelta(
+                u32::MAX as u128 * u64::MAX as u128 / 2 + 1,
+                0,
+                u32::MAX as u128 * u64::MAX as u128
+            ));
+        }
+
+        #[ink::test]
+        fn test_crear_eleccion_balotaje_errores() {
+            let mut votacion = Votacion::new();
+            let crear = |votacion: &mut Votacion, config: ConfigEleccion| {
+                votacion.crear_eleccion(Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024), config)
+            };
+
+            assert_eq!(
+                crear(
+                    &mut votacion,
+                    ConfigEleccion {
+                        tipo: TipoEleccion::Aprobacion,
+                        ..config_balotaje(regla_balotaje(50, None))
+                    }
+                ),
+                Err(VotacionError::TipoEleccionInvalido)
+            );
+            assert_eq!(
+                crear(
+                    &mut votacion,
+                    ConfigEleccion {
+                        fin_revelacion: Some(Fecha::new(31, 1, 2025)),
+                        ..config_balotaje(regla_balotaje(50, None))
+                    }
+                ),
+                Err(VotacionError::TipoEleccionInvalido)
+            );
+            assert_eq!(
+                crear(
+                    &mut votacion,
+                    ConfigEleccion {
+                        cantidad_cargos: 2,
+                        ..config_balotaje(regla_balotaje(50, None))
+                    }
+                ),
+                Err(VotacionError::CantidadCargosInvalida)
+            );
+            assert_eq!(
+                crear(&mut votacion, config_balotaje(regla_balotaje(0, None))),
+                Err(VotacionError::ReglaBalotajeInvalida)
+            );
+            assert_eq!(
+                crear(&mut votacion, config_balotaje(regla_balotaje(101, None))),
+                Err(VotacionError::ReglaBalotajeInvalida)
+            );
+            assert_eq!(
+                crear(
+                    &mut votacion,
+                    config_balotaje(regla_balotaje(45, Some((50, 10))))
+                ),
+                Err(VotacionError::ReglaBalotajeInvalida)
+            );
+            assert_eq!(
+                crear(
+                    &mut votacion,
+                    config_balotaje(ReglaBalotaje {
+                        fecha_inicio: Fecha::new(31, 12, 2024),
+                        ..regla_balotaje(50, None)
+                    })
+                ),
+                Err(VotacionError::ReglaBalotajeInvalida)
+            );
+            assert_eq!(
+                crear(
+                    &mut votacion,
+                    config_balotaje(ReglaBalotaje {
+                        fecha_fin: Fecha::new(31, 1, 2025),
+                        ..regla_balotaje(50, None)
+                    })
+                ),
+                Err(VotacionError::ReglaBalotajeInvalida)
+            );
+            assert_eq!(
+                crear(
+                    &mut votacion,
+                    config_balotaje(regla_balotaje(45, Some((40, 10))))
+                ),
+                Ok(0)
+            );
+        }
+
+        #[ink::test]
+        fn test_aplicar_balotaje_ganador() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_config(config_balotaje(regla_balotaje(45, None)));
+
+            for votante in [accounts.django, accounts.eve] {
+                set_caller::<DefaultEnvironment>(votante);
+                votacion.votar(0, accounts.alice).unwrap();
+            }
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            votacion.votar(0, accounts.bob).unwrap();
+
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2025).timestamp().unwrap());
+            assert_eq!(
+                votacion.aplicar_balotaje(0),
+                Ok(ResultadoBalotaje::Ganador(accounts.alice))
+            );
+            assert_eq!(
+                ultimo_evento::<BalotajeAplicado>(),
+                BalotajeAplicado {
+                    id_eleccion: 0,
+                    resultado: ResultadoBalotaje::Ganador(accounts.alice)
+                }
+            );
+            assert!(votacion.get_eleccion(1).is_none());
+            assert_eq!(
+                votacion.aplicar_balotaje(0),
+                Err(VotacionError::BalotajeYaAplicado)
+            );
+
+            set_caller::<DefaultEnvironment>(AccountId::from([0x10; 32]));
+            assert_eq!(
+                votacion.reporte_balotaje(0),
+                Ok((None, Some(ResultadoBalotaje::Ganador(accounts.alice))))
+            );
+        }
+
+        #[ink::test]
+        fn test_aplicar_balotaje_ganador_por_diferencia() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion =
+                default_con_config(config_balotaje(regla_balotaje(70, Some((60, 30)))));
+
+            set_caller::<DefaultEnvironment>(accounts.django);
+            votacion.votar(0, accounts.bob).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.eve);
+            votacion.votar(0, accounts.bob).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            votacion.votar(0, accounts.charlie).unwrap();
+
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2025).timestamp().unwrap());
+            assert_eq!(
+                votacion.aplicar_balotaje(0),
+                Ok(ResultadoBalotaje::Ganador(accounts.bob))
+            );
+        }
+
+        #[ink::test]
+        fn test_aplicar_balotaje_segunda_vuelta() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_config(config_balotaje(regla_balotaje(70, None)));
+
+            // los votos en blanco no cuentan para el porcentaje
+            set_caller::<DefaultEnvironment>(accounts.django);
+            votacion.votar(0, accounts.charlie).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.eve);
+            votacion.votar(0, accounts.alice).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            votacion.votar_en_blanco(0).unwrap();
+
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2025).timestamp().unwrap());
+            assert_eq!(
+                votacion.aplicar_balotaje(0),
+                Ok(ResultadoBalotaje::SegundaVuelta(1))
+            );
+            assert_eq!(
+                ultimo_evento::<BalotajeAplicado>(),
+                BalotajeAplicado {
+                    id_eleccion: 0,
+                    resultado: ResultadoBalotaje::SegundaVuelta(1)
+                }
+            );
+
+            // empate entre alice y charlie, ambos pasan por delante de bob
+            let segunda_vuelta = votacion.get_eleccion(1).unwrap();
+            assert_eq!(segunda_vuelta.get_primera_vuelta(), Some(0));
+            assert_eq!(segunda_vuelta.get_fecha_inicio(), Fecha::new(1, 2, 2025));
+            assert_eq!(segunda_vuelta.get_fecha_fin(), Fecha::new(28, 2, 2025));
+            assert_eq!(segunda_vuelta.config.balotaje, None);
+            assert_eq!(
+                votacion.get_candidatos(&segunda_vuelta),
+                vec![accounts.alice, accounts.charlie]
+            );
+            assert_eq!(
+                votacion.get_votantes(&segunda_vuelta),
+                vec![accounts.django, accounts.eve, accounts.frank]
+            );
+            assert_eq!(votacion.get_estado_participante(1, accounts.bob), None);
+
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                votacion.postular_votante(1),
+                Err(VotacionError::EleccionEsSegundaVuelta)
+            );
+
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(15, 2, 2025).timestamp().unwrap());
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            votacion.votar(1, accounts.charlie).unwrap();
+            assert_eq!(
+                votacion.votar(1, accounts.alice),
+                Err(VotacionError::UsuarioYaVoto)
+            );
+
+            set_caller::<DefaultEnvironment>(AccountId::from([0x10; 32]));
+            assert_eq!(
+                votacion.reporte_balotaje(0),
+                Ok((None, Some(ResultadoBalotaje::SegundaVuelta(1))))
+            );
+            assert_eq!(votacion.reporte_balotaje(1), Ok((Some(0), None)));
+        }
+
+        #[ink::test]
+        fn test_certificar_eleccion_con_balotaje() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_config(config_balotaje(regla_balotaje(70, None)));
+            set_caller::<DefaultEnvironment>(accounts.django);
+            votacion.votar(0, accounts.charlie).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.eve);
+            votacion.votar(0, accounts.alice).unwrap();
+
+            // no se certifica una primera vuelta sin saber si tiene ganador o segunda vuelta
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2025).timestamp().unwrap());
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            assert_eq!(
+                votacion.certificar_eleccion(0),
+                Err(VotacionError::BalotajeNoAplicado)
+            );
+            assert_eq!(
+                votacion.get_eleccion(0).unwrap().get_estado(),
+                EstadoEleccion::Cerrada
+            );
+
+            assert_eq!(
+                votacion.aplicar_balotaje(0),
+                Ok(ResultadoBalotaje::SegundaVuelta(1))
+            );
+            assert_eq!(votacion.certificar_eleccion(0), Ok(()));
+            assert_eq!(
+                votacion.get_eleccion(0).unwrap().resultado_balotaje,
+                Some(ResultadoBalotaje::SegundaVuelta(1))
+            );
+        }
+
+        #[ink::test]
+        fn test_aplicar_balotaje_errores() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_config(config_balotaje(regla_balotaje(50, None)));
+
+            assert_eq!(
+                votacion.aplicar_balotaje(0),
+                Err(VotacionError::EleccionNoFinalizada)
+            );
+            assert_eq!(
+                votacion.aplicar_balotaje(5),
+                Err(VotacionError::EleccionNoEncontrada)
+            );
+            votacion
+                .crear_eleccion(
+                    Fecha::new(1, 1, 2024),
+                    Fecha::new(31, 12, 2024),
+                    ConfigEleccion::default(),
+                )
+                .unwrap();
+            votacion
+                .crear_eleccion(
+                    Fecha::new(1, 1, 2024),
+                    Fecha::new(31, 12, 2024),
+                    config_balotaje(regla_balotaje(50, None)),
+                )
+                .unwrap();
+
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2025).timestamp().unwrap());
+            assert_eq!(
+                votacion.aplicar_balotaje(1),
+                Err(VotacionError::EleccionSinBalotaje)
+            );
+            assert_eq!(
+                votacion.aplicar_balotaje(2),
+                Err(VotacionError::CandidatosInsuficientes)
+            );
+
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            assert_eq!(votacion.aplicar_balotaje(0), Err(VotacionError::NoEsAdmin));
+            assert_eq!(
+                votacion.reporte_balotaje(0),
+                Err(VotacionError::SoloReportes)
+            );
+        }
+
+        /// Crea la eleccion 0 con balotaje y la politica de retiro pasada por parametro,
+        /// donde alice, bob y charlie reciben un voto cada uno y charlie se retira
+        fn default_con_retiro_votado(retiro_candidatos: PoliticaRetiro) -> Votacion {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_config(ConfigEleccion {
+                retiro_candidatos,
+                ..config_balotaje(regla_balotaje(50, None))
+            });
+            for (votante, candidato) in [
+                (accounts.django, accounts.alice),
+                (accounts.eve, accounts.bob),
+                (accounts.frank, accounts.charlie),
+            ] {
+                set_caller::<DefaultEnvironment>(votante);
+                votacion.votar(0, candidato).unwrap();
+            }
+            set_caller::<DefaultEnvironment>(accounts.charlie);
+            votacion.retirar_candidato(0).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2025).timestamp().unwrap());
+            votacion
+        }
+
+        #[ink::test]
+        fn test_aplicar_balotaje_retiro_anula_votos() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_retiro_votado(PoliticaRetiro::AnularVotos);
+
+            // el voto a charlie no cuenta, alice tiene el 50% de los votos
+            assert_eq!(
+                votacion.aplicar_balotaje(0),
+                Ok(ResultadoBalotaje::Ganador(accounts.alice))
+            );
+        }
+
+        #[ink::test]
+        fn test_aplicar_balotaje_retiro_conserva_votos() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_retiro_votado(PoliticaRetiro::ConservarVotos);
+
+            // el voto a charlie cuenta para el total, alice no alcanza el 50%,
+            // y charlie no puede pasar a la segunda vuelta
+            assert_eq!(
+                votacion.aplicar_balotaje(0),
+                Ok(ResultadoBalotaje::SegundaVuelta(1))
+            );
+            assert_eq!(
+                votacion.get_candidatos(&votacion.get_eleccion(1).unwrap()),
+                vec![accounts.alice, accounts.bob]
+            );
+        }
+
+        /// Crea la eleccion 0 con balotaje y la politica de desempate pasada por parametro,
+        /// donde alice gana sin alcanzar la regla y bob y charlie empatan en el segundo puesto
+        fn default_con_empate_segundo_puesto(desempate: PoliticaDesempate) -> Votacion {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_pesos(
+                ConfigEleccion {
+                    desempate,
+                    ..config_balotaje(regla_balotaje(70, None))
+                },
+                [2, 1, 1],
+            );
+            for (votante, candidato) in [
+                (accounts.django, accounts.alice),
+                (accounts.eve, accounts.bob),
+                (accounts.frank, accounts.charlie),
+            ] {
+                set_caller::<DefaultEnvironment>(votante);
+                votacion.votar(0, candidato).unwrap();
+            }
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2025).timestamp().unwrap());
+            votacion
+        }
+
+        #[ink::test]
+        fn test_aplicar_balotaje_empate_segundo_puesto_orden_registro() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_empate_segundo_puesto(PoliticaDesempate::OrdenRegistro);
+
+            // se prioriza al aceptado primero
+            assert_eq!(
+                votacion.aplicar_balotaje(0),
+                Ok(ResultadoBalotaje::SegundaVuelta(1))
+            );
+            assert_eq!(
+                votacion.get_candidatos(&votacion.get_eleccion(1).unwrap()),
+                vec![accounts.alice, accounts.bob]
+            );
+        }
+
+        #[ink::test]
+        fn test_aplicar_balotaje_empate_segundo_puesto_mayor_edad() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_empate_segundo_puesto(PoliticaDesempate::MayorEdad);
+            let mut charlie = votacion.usuarios.get(accounts.charlie).unwrap();
+            charlie.fecha_nacimiento = Fecha::new(1, 1, 1980);
+            votacion.usuarios.insert(accounts.charlie, &charlie);
+
+            // charlie es mayor que bob
+            assert_eq!(
+                votacion.aplicar_balotaje(0),
+                Ok(ResultadoBalotaje::SegundaVuelta(1))
+            );
+            assert_eq!(
+                votacion.get_candidatos(&votacion.get_eleccion(1).unwrap()),
+                vec![accounts.alice, accounts.charlie]
+            );
+        }
+
+        #[ink::test]
+        fn test_aplicar_balotaje_empate_segundo_puesto_aleatorio() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_empate_segundo_puesto(PoliticaDesempate::Aleatorio);
+
+            // pasa el empatado con la menor clave derivada de la semilla
+            let semilla = votacion.get_eleccion(0).unwrap().semilla_desempate.unwrap();
+            let finalista = [accounts.bob, accounts.charlie]
+                .into_iter()
+                .min_by_key(|id| PoliticaDesempate::clave_aleatoria(&semilla, *id))
+                .unwrap();
+            assert_eq!(
+                votacion.aplicar_balotaje(0),
+                Ok(ResultadoBalotaje::SegundaVuelta(1))
+            );
+            assert_eq!(
+                votacion.get_candidatos(&votacion.get_eleccion(1).unwrap()),
+                vec![accounts.alice, finalista]
+            );
+        }
+
+        #[ink::test]
+        fn test_aplicar_balotaje_empate_segundo_puesto_segunda_vuelta() {
+            let mut votacion = default_con_empate_segundo_puesto(PoliticaDesempate::SegundaVuelta);
+
+            // el empate no permite elegir al segundo finalista
+            assert_eq!(
+                votacion.aplicar_balotaje(0),
+                Err(VotacionError::EmpateFinalistas)
+            );
+            assert!(votacion.get_eleccion(1).is_none());
+            assert_eq!(votacion.get_eleccion(0).unwrap().resultado_balotaje, None);
+        }
+
+        #[ink::test]
+        fn test_aplicar_balotaje_empate_primer_puesto_segunda_vuelta() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_config(ConfigEleccion {
+                desempate: PoliticaDesempate::SegundaVuelta,
+                ..config_balotaje(regla_balotaje(45, None))
+            });
+            set_caller::<DefaultEnvironment>(accounts.django);
+            votacion.votar(0, accounts.alice).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.eve);
+            votacion.votar(0, accounts.bob).unwrap();
+
+            // alice y bob alcanzan la regla con el 50% de los votos, pero comparten
+            // el primer puesto y van a la segunda vuelta
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2025).timestamp().unwrap());
+            assert_eq!(
+                votacion.aplicar_balotaje(0),
+                Ok(ResultadoBalotaje::SegundaVuelta(1))
+            );
+            assert_eq!(
+                votacion.get_candidatos(&votacion.get_eleccion(1).unwrap()),
+                vec![accounts.alice, accounts.bob]
+            );
+        }
+
+        // tests de delegacion de votos
+        /// Registra las delegaciones pasadas por parametro antes del inicio de la eleccion 0
+        /// y vuelve a la fecha de la votacion
+        fn delegar(votacion: &mut Votacion, delegaciones: &[(AccountId, AccountId)]) {
+            set_block_timestamp::<DefaultEnvironment>(
+                Fecha::new(31, 12, 2023).timestamp().unwrap(),
+            );
+            for (votante, delegado) in delegaciones {
+                set_caller::<DefaultEnvironment>(*votante);
+                votacion.delegar_voto(0, *delegado).unwrap();
+            }
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(15, 6, 2024).timestamp().unwrap());
+        }
+
+        /// Devuelve los votos de cada candidato y la participacion de la eleccion 0 finalizada
+        fn resultado_delegado(votacion: &Votacion) -> (Vec<(AccountId, u128)>, Participacion) {
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2025).timestamp().unwrap());
+            set_caller::<DefaultEnvironment>(AccountId::from([0x10; 32]));
+            (
+                votacion.reporte_resultado(0).unwrap(),
+                votacion.reporte_participacion(0).unwrap(),
+            )
+        }
+
+        #[ink::test]
+        fn test_delegar_voto_transitivo() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_tipo(TipoEleccion::Simple);
+            delegar(
+                &mut votacion,
+                &[
+                    (accounts.eve, accounts.django),
+                    (accounts.frank, accounts.eve),
+                ],
+            );
+            assert_eq!(
+                votacion.get_delegacion(0, accounts.frank),
+                Some(accounts.eve)
+            );
+
+            set_caller::<DefaultEnvironment>(accounts.django);
+            votacion.votar(0, accounts.alice).unwrap();
+
+            let (resultado, participacion) = resultado_delegado(&votacion);
+            assert_eq!(
+                resultado,
+                vec![
+                    (accounts.alice, 3),
+                    (accounts.bob, 0),
+                    (accounts.charlie, 0)
+                ]
+            );
+            assert_eq!(participacion.votos, 1);
+            assert_eq!(participacion.votos_delegados, 2);
         }
-    }
 
-    #[ink::trait_definition]
-    pub trait ReportMessage {
-        /// Devuelve un vector con los id de los votantes aceptados
-        /// si no hay votantes devuelve un vector vacio
-        ///
-        /// # Errores
-        /// - Devuelve un error si el caller no es el reporte
-        /// - Devuelve un error si la eleccion no es encontrada
-        #[ink(message)]
-        fn reporte_registro_votantes(&self, eleccion_id: u32) -> Result<Vec<AccountId>>;
-        /// Devuelve un tuple que contiene la cantidad de votantes y la cantidad de votos, en ese orden
-        ///
-        /// # Errores
-        /// - Devuelve un error si el caller no es el reporte
-        /// - Devuelve un error si la eleccion no es encontrada
-        /// - Devuelve un error si la eleccion no finalizó
-        #[ink(message)]
-        fn reporte_participacion(&self, eleccion_id: u32) -> Result<(u128, u128)>;
-        /// Devuelve un vector que contiene para cada posicion el AccountId
-        /// de un candidato y la cantidad de votos que obtuvo
-        ///
-        /// # Errores
-        /// - Devuelve un error si el caller no es el reporte
-        /// - Devuelve un error si la eleccion no es encontrada
-        /// - Devuelve un error si la eleccion no finalizó
-        #[ink(message)]
-        fn reporte_resultado(&self, eleccion_id: u32) -> Result<Vec<(AccountId, u32)>>;
-    }
+        #[ink::test]
+        fn test_voto_directo_reemplaza_delegacion() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_tipo(TipoEleccion::Simple);
+            delegar(
+                &mut votacion,
+                &[
+                    (accounts.eve, accounts.django),
+                    (accounts.frank, accounts.eve),
+                ],
+            );
 
-    trait ReportMessageEleccion {
-        /// Reporte de registro de votantes
-        fn reporte_registro_votantes(&self) -> Vec<AccountId>;
+            set_caller::<DefaultEnvironment>(accounts.django);
+            votacion.votar(0, accounts.alice).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.eve);
+            votacion.votar(0, accounts.bob).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            votacion.votar(0, accounts.charlie).unwrap();
 
-        /// Reporte de participación
-        fn reporte_participacion(&self, current_time: &Fecha) -> Result<(u128, u128)>;
+            let (resultado, participacion) = resultado_delegado(&votacion);
+            assert_eq!(
+                resultado,
+                vec![
+                    (accounts.alice, 1),
+                    (accounts.bob, 1),
+                    (accounts.charlie, 1)
+                ]
+            );
+            assert_eq!(participacion.votos, 3);
+            assert_eq!(participacion.votos_delegados, 0);
+        }
 
-        /// Reporte de resultados finales
-        fn reporte_resultado(&self, current_time: &Fecha) -> Result<Vec<(AccountId, u32)>>;
-    }
+        #[ink::test]
+        fn test_delegado_vota_despues_del_delegante() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_tipo(TipoEleccion::Simple);
+            delegar(
+                &mut votacion,
+                &[
+                    (accounts.eve, accounts.django),
+                    (accounts.frank, accounts.eve),
+                ],
+            );
 
-    #[cfg(test)]
-    pub mod tests {
-        use super::*;
-        use ink::env::{
-            test::{default_accounts, set_block_timestamp, set_caller},
-            DefaultEnvironment,
-        };
+            // eve ya votó con el voto de frank, django solo suma el propio
+            set_caller::<DefaultEnvironment>(accounts.eve);
+            votacion.votar(0, accounts.bob).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.django);
+            votacion.votar(0, accounts.alice).unwrap();
 
-        /// Funcion auxiliar para los tests de reportes.
-        pub fn default_with_data() -> Votacion {
+            let (resultado, participacion) = resultado_delegado(&votacion);
+            assert_eq!(
+                resultado,
+                vec![
+                    (accounts.alice, 1),
+                    (accounts.bob, 2),
+                    (accounts.charlie, 0)
+                ]
+            );
+            assert_eq!(participacion.votos, 2);
+            assert_eq!(participacion.votos_delegados, 1);
+        }
+
+        #[ink::test]
+        fn test_delegar_voto_en_blanco() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_tipo(TipoEleccion::Simple);
+            delegar(
+                &mut votacion,
+                &[
+                    (accounts.eve, accounts.django),
+                    (accounts.frank, accounts.django),
+                ],
+            );
+
+            set_caller::<DefaultEnvironment>(accounts.django);
+            votacion.votar_en_blanco(0).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.eve);
+            votacion.votar(0, accounts.alice).unwrap();
+
+            let (resultado, participacion) = resultado_delegado(&votacion);
+            assert_eq!(
+                resultado,
+                vec![
+                    (accounts.alice, 1),
+                    (accounts.bob, 0),
+                    (accounts.charlie, 0)
+                ]
+            );
+            assert_eq!(participacion.votos, 2);
+            assert_eq!(participacion.votos_delegados, 1);
+            assert_eq!(participacion.votos_blanco, 2);
+        }
+
+        #[ink::test]
+        fn test_cambiar_y_revocar_delegacion() {
             let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_tipo(TipoEleccion::Simple);
+            delegar(
+                &mut votacion,
+                &[
+                    (accounts.eve, accounts.django),
+                    (accounts.eve, accounts.frank),
+                    (accounts.django, accounts.frank),
+                ],
+            );
+            assert_eq!(
+                votacion.get_delegacion(0, accounts.eve),
+                Some(accounts.frank)
+            );
+
+            set_block_timestamp::<DefaultEnvironment>(
+                Fecha::new(31, 12, 2023).timestamp().unwrap(),
+            );
+            set_caller::<DefaultEnvironment>(accounts.django);
+            votacion.revocar_delegacion(0).unwrap();
+            assert_eq!(
+                ultimo_evento::<DelegacionRevocada>(),
+                DelegacionRevocada {
+                    id_eleccion: 0,
+                    votante: accounts.django
+                }
+            );
+            assert_eq!(votacion.get_delegacion(0, accounts.django), None);
+            assert_eq!(
+                votacion.revocar_delegacion(0),
+                Err(VotacionError::VotoNoDelegado)
+            );
+
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(15, 6, 2024).timestamp().unwrap());
+            votacion.votar(0, accounts.alice).unwrap();
             set_caller::<DefaultEnvironment>(accounts.frank);
-            let mut votacion = Votacion::new();
-            //Creo dos elecciones nuevas (admin)
-            votacion
-                .crear_eleccion(Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024))
-                .unwrap();
-            votacion
-                .crear_eleccion(Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024))
-                .unwrap();
-            //Agrego usuarios a la lista de usuarios_por_aceptar
+            votacion.votar(0, accounts.bob).unwrap();
+
+            let (resultado, _) = resultado_delegado(&votacion);
+            assert_eq!(
+                resultado,
+                vec![
+                    (accounts.alice, 1),
+                    (accounts.bob, 2),
+                    (accounts.charlie, 0)
+                ]
+            );
+        }
+
+        #[ink::test]
+        fn test_delegar_voto_errores() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_tipo(TipoEleccion::Simple);
+            set_block_timestamp::<DefaultEnvironment>(
+                Fecha::new(31, 12, 2023).timestamp().unwrap(),
+            );
+
+            set_caller::<DefaultEnvironment>(accounts.eve);
+            assert_eq!(
+                votacion.delegar_voto(0, accounts.eve),
+                Err(VotacionError::DelegacionCiclica)
+            );
+            assert_eq!(
+                votacion.delegar_voto(0, accounts.alice),
+                Err(VotacionError::DelegadoNoEsVotante)
+            );
+            assert_eq!(
+                votacion.delegar_voto(1, accounts.django),
+                Err(VotacionError::EleccionNoEncontrada)
+            );
+            votacion.delegar_voto(0, accounts.django).unwrap();
+            assert_eq!(
+                ultimo_evento::<VotoDelegado>(),
+                VotoDelegado {
+                    id_eleccion: 0,
+                    votante: accounts.eve,
+                    delegado: accounts.django
+                }
+            );
+
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            votacion.delegar_voto(0, accounts.eve).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.django);
+            assert_eq!(
+                votacion.delegar_voto(0, accounts.frank),
+                Err(VotacionError::DelegacionCiclica)
+            );
+
             set_caller::<DefaultEnvironment>(accounts.alice);
+            assert_eq!(
+                votacion.delegar_voto(0, accounts.django),
+                Err(VotacionError::UsuarioNoEsVotante)
+            );
+
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(15, 6, 2024).timestamp().unwrap());
+            set_caller::<DefaultEnvironment>(accounts.django);
+            assert_eq!(
+                votacion.delegar_voto(0, accounts.eve),
+                Err(VotacionError::EleccionYaIniciada)
+            );
+            set_caller::<DefaultEnvironment>(accounts.eve);
+            assert_eq!(
+                votacion.revocar_delegacion(0),
+                Err(VotacionError::EleccionYaIniciada)
+            );
+        }
+
+        #[ink::test]
+        fn test_delegar_voto_error_eleccion_no_simple() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_tipo(TipoEleccion::Aprobacion);
+            set_block_timestamp::<DefaultEnvironment>(
+                Fecha::new(31, 12, 2023).timestamp().unwrap(),
+            );
+            set_caller::<DefaultEnvironment>(accounts.eve);
+            assert_eq!(
+                votacion.delegar_voto(0, accounts.django),
+                Err(VotacionError::TipoEleccionInvalido)
+            );
+        }
+
+        // tests de votos ponderados
+        #[ink::test]
+        fn test_votar_ponderado() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_pesos(ConfigEleccion::default(), [10, 3, 1]);
+
+            set_caller::<DefaultEnvironment>(accounts.django);
+            votacion.votar(0, accounts.alice).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.eve);
+            votacion.votar_en_blanco(0).unwrap();
+
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2025).timestamp().unwrap());
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            assert_eq!(votacion.get_votos_candidato(0, accounts.alice), Ok(10));
+
+            set_caller::<DefaultEnvironment>(AccountId::from([0x10; 32]));
+            assert_eq!(
+                votacion.reporte_participacion(0),
+                Ok(Participacion {
+                    votantes: 3,
+                    votos: 2,
+                    votos_delegados: 0,
+                    peso_votantes: 14,
+                    votos_ponderados: 13,
+                    votos_revelados: None,
+                    votos_blanco: 3,
+                    votos_nulos: 0,
+                    quorum_alcanzado: None
+                })
+            );
+        }
+
+        #[ink::test]
+        fn test_votar_ponderado_aprobacion_y_delegacion() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_pesos(
+                ConfigEleccion {
+                    tipo: TipoEleccion::Aprobacion,
+                    ..Default::default()
+                },
+                [u64::MAX, u64::MAX, 2],
+            );
+
+            // Dos pesos máximos no desbordan el acumulador
+            for votante in [accounts.django, accounts.eve] {
+                set_caller::<DefaultEnvironment>(votante);
+                votacion
+                    .votar_aprobacion(0, vec![accounts.alice, accounts.bob])
+                    .unwrap();
+            }
+
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2025).timestamp().unwrap());
+            set_caller::<DefaultEnvironment>(AccountId::from([0x10; 32]));
+            assert_eq!(
+                votacion.reporte_resultado(0),
+                Ok(vec![
+                    (accounts.alice, u64::MAX as u128 * 2),
+                    (accounts.bob, u64::MAX as u128 * 2),
+                    (accounts.charlie, 0)
+                ])
+            );
+        }
+
+        #[ink::test]
+        fn test_delegar_voto_ponderado() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_pesos(ConfigEleccion::default(), [1, 5, 7]);
+            delegar(
+                &mut votacion,
+                &[
+                    (accounts.eve, accounts.django),
+                    (accounts.frank, accounts.eve),
+                ],
+            );
+
+            set_caller::<DefaultEnvironment>(accounts.django);
+            votacion.votar(0, accounts.alice).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.eve);
+            votacion.votar(0, accounts.bob).unwrap();
+
+            let (resultado, participacion) = resultado_delegado(&votacion);
+            assert_eq!(
+                resultado,
+                vec![
+                    (accounts.alice, 1),
+                    (accounts.bob, 12),
+                    (accounts.charlie, 0)
+                ]
+            );
+            assert_eq!(participacion.votos_delegados, 1);
+            assert_eq!(participacion.votos_ponderados, 13);
+        }
+
+        #[ink::test]
+        fn test_agregar_votante_peso_invalido() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_tipo(TipoEleccion::Preferencial);
             votacion
-                .postular_usuario(
-                    "Alice".to_string(),
-                    "Cooper".to_string(),
-                    "EEUU".to_string(),
-                    "111".to_string(),
-                    30,
-                )
-                .unwrap();
-            set_caller::<DefaultEnvironment>(accounts.bob);
-            votacion
-                .postular_usuario(
-                    "Bob".to_string(),
-                    "Marley".to_string(),
-                    "Jamaica".to_string(),
-                    "222".to_string(),
-                    30,
-                )
-                .unwrap();
-            set_caller::<DefaultEnvironment>(accounts.charlie);
-            votacion
-                .postular_usuario(
-                    "Charlie".to_string(),
-                    "Chaplin".to_string(),
-                    "Inglaterra".to_string(),
-                    "333".to_string(),
-                    30,
+                .crear_eleccion(
+                    Fecha::new(1, 1, 2025),
+                    Fecha::new(31, 12, 2025),
+                    ConfigEleccion {
+                        tipo: TipoEleccion::Preferencial,
+                        ..Default::default()
+                    },
                 )
                 .unwrap();
-            set_caller::<DefaultEnvironment>(accounts.django);
             votacion
-                .postular_usuario(
-                    "Django".to_string(),
-                    "Unchained".to_string(),
-                    "EEUU".to_string(),
-                    "444".to_string(),
-                    30,
+                .crear_eleccion(
+                    Fecha::new(1, 1, 2025),
+                    Fecha::new(31, 12, 2025),
+                    ConfigEleccion::default(),
                 )
                 .unwrap();
-            //Acepto a los usuarios (admin)
-            set_caller::<DefaultEnvironment>(accounts.frank);
-            votacion.aceptar_usuario(accounts.alice).unwrap();
-            votacion.aceptar_usuario(accounts.bob).unwrap();
-            votacion.aceptar_usuario(accounts.charlie).unwrap();
-            votacion.aceptar_usuario(accounts.django).unwrap();
-            //Eleccion id = 0:
-            //Postulo a alice y a bob como y candidatos a la eleccion
-            set_caller::<DefaultEnvironment>(accounts.alice);
-            votacion.postular_candidato(0).unwrap();
-            set_caller::<DefaultEnvironment>(accounts.bob);
-            votacion.postular_candidato(0).unwrap();
-            //Postulo a charlie y a django como y votantes a la eleccion
-            set_caller::<DefaultEnvironment>(accounts.charlie);
-            votacion.postular_votante(0).unwrap();
-            set_caller::<DefaultEnvironment>(accounts.django);
-            votacion.postular_votante(0).unwrap();
-            //Acepto a los votantes y candidatos postulados (admin)
-            set_caller::<DefaultEnvironment>(accounts.frank);
-            votacion.agregar_candidato(0, accounts.alice).unwrap();
-            votacion.agregar_candidato(0, accounts.bob).unwrap();
-            votacion.agregar_votante(0, accounts.charlie).unwrap();
-            votacion.agregar_votante(0, accounts.django).unwrap();
-
-            //Eleccion id = 1
-            //Postulo a alice como candidato a la eleccion
-            set_caller::<DefaultEnvironment>(accounts.alice);
-            votacion.postular_candidato(1).unwrap();
-            //Postulo a bob, charlie, y django como votantes de la eleccion
-            set_caller::<DefaultEnvironment>(accounts.charlie);
-            votacion.postular_votante(1).unwrap();
             set_caller::<DefaultEnvironment>(accounts.django);
             votacion.postular_votante(1).unwrap();
-            set_caller::<DefaultEnvironment>(accounts.bob);
-            votacion.postular_votante(1).unwrap();
-            //Acepto a los votantes y candidatos postulados (admin)
+            votacion.postular_votante(2).unwrap();
+
             set_caller::<DefaultEnvironment>(accounts.frank);
-            votacion.agregar_candidato(1, accounts.alice).unwrap();
-            votacion.agregar_votante(1, accounts.bob).unwrap();
-            votacion.agregar_votante(1, accounts.charlie).unwrap();
-            votacion.agregar_votante(1, accounts.django).unwrap();
-            //Realizo votos para eleccion 0 (alice = 2, bob = 0)
-            set_block_timestamp::<DefaultEnvironment>(Fecha::new(15, 6, 2024).timestamp().unwrap());
-            set_caller::<DefaultEnvironment>(accounts.django);
-            votacion.votar(0, accounts.alice).unwrap();
-            set_caller::<DefaultEnvironment>(accounts.charlie);
-            votacion.votar(0, accounts.alice).unwrap();
-            //Realizo votos para eleccion 0 (alice = 2)
-            set_block_timestamp::<DefaultEnvironment>(Fecha::new(15, 6, 2024).timestamp().unwrap());
-            set_caller::<DefaultEnvironment>(accounts.django);
-            votacion.votar(1, accounts.alice).unwrap();
-            set_caller::<DefaultEnvironment>(accounts.bob);
-            votacion.votar(1, accounts.alice).unwrap();
+            assert_eq!(
+                votacion.agregar_votante(1, accounts.django, 2),
+                Err(VotacionError::PesoInvalido)
+            );
+            assert_eq!(
+                votacion.agregar_votante(2, accounts.django, 0),
+                Err(VotacionError::PesoInvalido)
+            );
+            votacion.agregar_votante(2, accounts.django, 2).unwrap();
+            assert_eq!(votacion.get_eleccion(2).unwrap().get_peso_votantes(), 2);
+        }
+
+        /// Decodifica el ultimo evento emitido por el contrato
+        /// Crea dos elecciones con alice como admin y postula a bob, charlie y django
+        /// al sistema, a charlie como candidato y a django como votante de ambas elecciones
+        fn default_con_roles() -> Votacion {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2023).timestamp().unwrap());
+            let mut votacion = Votacion::new();
+            for _ in 0..2 {
+                votacion
+                    .crear_eleccion(
+                        Fecha::new(1, 1, 2024),
+                        Fecha::new(31, 12, 2024),
+                        ConfigEleccion::default(),
+                    )
+                    .unwrap();
+            }
+            for (cuenta, nombre, dni) in [
+                (accounts.bob, "Bob", "22222222"),
+                (accounts.charlie, "Charlie", "33333333"),
+                (accounts.django, "Django", "44444444"),
+            ] {
+                set_caller::<DefaultEnvironment>(cuenta);
+                votacion
+                    .postular_usuario(
+                        nombre.to_string(),
+                        "Test".to_string(),
+                        "Calle Falsa 123".to_string(),
+                        dni.to_string(),
+                        Fecha::new(1, 1, 1994),
+                    )
+                    .unwrap();
+            }
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            votacion.aceptar_usuario(accounts.charlie).unwrap();
+            votacion.aceptar_usuario(accounts.django).unwrap();
+            for id_eleccion in 0..2 {
+                set_caller::<DefaultEnvironment>(accounts.charlie);
+                votacion.postular_candidato(id_eleccion).unwrap();
+                set_caller::<DefaultEnvironment>(accounts.django);
+                votacion.postular_votante(id_eleccion).unwrap();
+            }
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            votacion
+        }
 
+        // tests del ciclo de vida de una elección
+        /// Devuelve default_con_roles con charlie como candidato y django como votante
+        /// aceptados en la elección 0
+        fn default_con_participantes() -> Votacion {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_roles();
+            votacion.agregar_candidato(0, accounts.charlie).unwrap();
+            votacion.agregar_votante(0, accounts.django, 1).unwrap();
             votacion
         }
 
         #[ink::test]
-        fn test_set_admin() {
-            let accounts = default_accounts::<DefaultEnvironment>();
-            let mut votacion = Votacion::new();
-            votacion.set_admin(accounts.bob).unwrap();
-            assert_eq!(votacion.admin, accounts.bob);
+        fn test_get_eleccion_estado() {
+            let mut votacion = default_con_participantes();
+            assert_eq!(
+                votacion.get_eleccion(0).unwrap().get_estado(),
+                EstadoEleccion::Inscripcion
+            );
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 6, 2024).timestamp().unwrap());
+            assert_eq!(
+                votacion.get_eleccion(0).unwrap().get_estado(),
+                EstadoEleccion::Abierta
+            );
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2025).timestamp().unwrap());
+            assert_eq!(
+                votacion.get_eleccion(0).unwrap().get_estado(),
+                EstadoEleccion::Cerrada
+            );
+            votacion.certificar_eleccion(0).unwrap();
+            assert_eq!(
+                votacion.get_eleccion(0).unwrap().get_estado(),
+                EstadoEleccion::Certificada
+            );
         }
 
-        // Tests de GettersUsuario
-        #[test]
-        fn test_getters_usuario() {
+        #[ink::test]
+        fn test_reporte_fecha_inicio() {
             let accounts = default_accounts::<DefaultEnvironment>();
-            let usuario = Usuario {
-                addres: accounts.bob,
-                nombre: "Juan".to_string(),
-                apellido: "Perez".to_string(),
-                direccion: "Calle Falsa 123".to_string(),
-                dni: "12345678".to_string(),
-                edad: 30,
-            };
-
-            assert_eq!(usuario.get_addres(), accounts.bob);
-            assert_eq!(usuario.get_nombre(), "Juan".to_string());
-            assert_eq!(usuario.get_apellido(), "Perez".to_string());
-            assert_eq!(usuario.get_direccion(), "Calle Falsa 123".to_string());
-            assert_eq!(usuario.get_dni(), "12345678".to_string());
-            assert_eq!(usuario.get_edad(), 30);
+            let votacion = default_con_roles();
+            set_caller::<DefaultEnvironment>(AccountId::from([0x10; 32]));
+            assert_eq!(votacion.reporte_fecha_inicio(0), Ok(Fecha::new(1, 1, 2024)));
+            assert_eq!(
+                votacion.reporte_fecha_inicio(5),
+                Err(VotacionError::EleccionNoEncontrada)
+            );
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            assert_eq!(
+                votacion.reporte_fecha_inicio(0),
+                Err(VotacionError::SoloReportes)
+            );
         }
 
-        // Tests de GettersEleccion
-        #[test]
-        fn test_getters_eleccion() {
+        #[ink::test]
+        fn test_semilla_desempate_fija_al_terminar_votacion() {
             let accounts = default_accounts::<DefaultEnvironment>();
-            let votantes = vec![accounts.bob, accounts.alice];
-            let candidatos = vec![accounts.charlie, accounts.django];
+            let id_reporte = AccountId::from([0x10; 32]);
+            let mut votacion = default_con_participantes();
+            let semilla_inicial = votacion.get_eleccion(0).unwrap().semilla_desempate;
+            assert!(semilla_inicial.is_some());
+            // la semilla depende de la elección aunque se creen en el mismo bloque
+            assert_ne!(
+                votacion.get_eleccion(1).unwrap().semilla_desempate,
+                semilla_inicial
+            );
 
-            let eleccion = Eleccion {
-                id: 0,
-                votantes_sin_aceptar: vec![],
-                candidatos_sin_aceptar: vec![],
-                votantes: votantes.clone(),
-                candidatos: candidatos.clone(),
-                votos: vec![],
-                votantes_voto: vec![],
-                fecha_inicio: Fecha::new(19, 6, 2024),
-                fecha_fin: Fecha::new(20, 6, 2024),
-            };
+            // cada voto modifica la semilla, que no se informa mientras la votación sigue abierta
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(15, 6, 2024).timestamp().unwrap());
+            set_caller::<DefaultEnvironment>(accounts.django);
+            votacion.votar(0, accounts.charlie).unwrap();
+            let semilla = votacion.get_eleccion(0).unwrap().semilla_desempate;
+            assert_ne!(semilla, semilla_inicial);
+            set_caller::<DefaultEnvironment>(id_reporte);
+            assert_eq!(votacion.reporte_semilla_desempate(0), Ok(None));
 
-            assert_eq!(eleccion.get_id(), 0);
-            assert!(eleccion.is_votante(&accounts.bob));
-            assert!(!eleccion.is_votante(&accounts.charlie));
-            assert!(eleccion.is_candidato(&accounts.django));
-            assert!(!eleccion.is_candidato(&accounts.alice));
-            assert_eq!(eleccion.get_fecha_inicio(), Fecha::new(19, 6, 2024));
-            assert_eq!(eleccion.get_fecha_fin(), Fecha::new(20, 6, 2024));
-        }
+            // el bloque en el que se certifica no cambia la semilla
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2025).timestamp().unwrap());
+            assert_eq!(votacion.reporte_semilla_desempate(0), Ok(semilla));
+            ink::env::test::advance_block::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            votacion.certificar_eleccion(0).unwrap();
+            set_caller::<DefaultEnvironment>(id_reporte);
+            assert_eq!(votacion.reporte_semilla_desempate(0), Ok(semilla));
+            assert_eq!(
+                votacion.reporte_semilla_desempate(5),
+                Err(VotacionError::EleccionNoEncontrada)
+            );
 
-        // Tests de VotacionImpl
-        #[ink::test]
-        fn test_crear_eleccion() {
-            let mut votacion = Votacion::new();
-            let fecha_inicio = Fecha::new(1, 1, 2024);
-            let fecha_fin = Fecha::new(31, 12, 2024);
-            let id_eleccion = votacion
-                .crear_eleccion(fecha_inicio.clone(), fecha_fin.clone())
-                .unwrap();
-            let eleccion = votacion.get_eleccion(id_eleccion).unwrap();
-            assert_eq!(eleccion.get_id(), 0);
-            assert_eq!(eleccion.get_fecha_inicio(), fecha_inicio);
-            assert_eq!(eleccion.get_fecha_fin(), fecha_fin);
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            assert_eq!(
+                votacion.reporte_semilla_desempate(0),
+                Err(VotacionError::SoloReportes)
+            );
         }
 
         #[ink::test]
-        fn test_crear_eleccion_error_no_admin() {
+        fn test_cambiar_estado_eleccion_con_consejo() {
             let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_participantes();
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 6, 2024).timestamp().unwrap());
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            votacion
+                .configurar_consejo(vec![accounts.alice, accounts.bob, accounts.charlie], 2)
+                .unwrap();
+
+            // con un consejo configurado el admin no cambia el estado de una elección por si solo
+            assert_eq!(
+                votacion.cancelar_eleccion(0, "Motivo".to_string()),
+                Err(VotacionError::RequiereAprobacionConsejo)
+            );
+            assert_eq!(
+                votacion.suspender_eleccion(0, "Motivo".to_string()),
+                Err(VotacionError::RequiereAprobacionConsejo)
+            );
+            assert_eq!(
+                votacion.reanudar_eleccion(0, "Motivo".to_string()),
+                Err(VotacionError::RequiereAprobacionConsejo)
+            );
+            assert_eq!(
+                votacion.extender_eleccion(0, Fecha::new(31, 1, 2025), "Motivo".to_string()),
+                Err(VotacionError::RequiereAprobacionConsejo)
+            );
+            assert_eq!(
+                votacion.certificar_eleccion(0),
+                Err(VotacionError::RequiereAprobacionConsejo)
+            );
+
+            // las propuestas se validan al crearlas
+            assert_eq!(
+                votacion.proponer(Accion::CambiarEstadoEleccion(
+                    0,
+                    CambioEstado::Suspender,
+                    String::new()
+                )),
+                Err(VotacionError::MotivoVacio)
+            );
+            assert_eq!(
+                votacion.proponer(Accion::CambiarEstadoEleccion(
+                    0,
+                    CambioEstado::Reanudar,
+                    "Motivo".to_string()
+                )),
+                Err(VotacionError::EleccionNoSuspendida)
+            );
+            assert_eq!(
+                votacion.proponer(Accion::ExtenderEleccion(
+                    5,
+                    Fecha::new(31, 1, 2025),
+                    "Motivo".to_string()
+                )),
+                Err(VotacionError::EleccionNoEncontrada)
+            );
 
+            let id_propuesta = votacion
+                .proponer(Accion::CambiarEstadoEleccion(
+                    0,
+                    CambioEstado::Suspender,
+                    "Denuncia de fraude".to_string(),
+                ))
+                .unwrap();
             set_caller::<DefaultEnvironment>(accounts.bob);
-            let mut votacion = Votacion::new();
-            let fecha_inicio = Fecha::new(1, 1, 2024);
-            let fecha_fin = Fecha::new(31, 12, 2024);
+            assert_eq!(votacion.aprobar_propuesta(id_propuesta), Ok(true));
+            assert_eq!(
+                votacion.get_eleccion(0).unwrap().get_estado(),
+                EstadoEleccion::Suspendida
+            );
+
+            let id_propuesta = votacion
+                .proponer(Accion::ExtenderEleccion(
+                    0,
+                    Fecha::new(31, 1, 2025),
+                    "Recuperar los días suspendida".to_string(),
+                ))
+                .unwrap();
+            set_caller::<DefaultEnvironment>(accounts.charlie);
+            assert_eq!(votacion.aprobar_propuesta(id_propuesta), Ok(true));
+            assert_eq!(
+                votacion.get_eleccion(0).unwrap().get_fecha_fin(),
+                Fecha::new(31, 1, 2025)
+            );
+
+            let id_propuesta = votacion
+                .proponer(Accion::CambiarEstadoEleccion(
+                    0,
+                    CambioEstado::Reanudar,
+                    "Denuncia desestimada".to_string(),
+                ))
+                .unwrap();
             set_caller::<DefaultEnvironment>(accounts.alice);
-            let eleccion = votacion.crear_eleccion(fecha_inicio, fecha_fin);
-            assert_eq!(eleccion, Err(VotacionError::NoEsAdmin));
+            assert_eq!(votacion.aprobar_propuesta(id_propuesta), Ok(true));
+            assert_eq!(
+                votacion.get_eleccion(0).unwrap().get_estado(),
+                EstadoEleccion::Abierta
+            );
+
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 2, 2025).timestamp().unwrap());
+            let id_propuesta = votacion
+                .proponer(Accion::CambiarEstadoEleccion(
+                    0,
+                    CambioEstado::Certificar,
+                    "Certificación del resultado".to_string(),
+                ))
+                .unwrap();
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(votacion.aprobar_propuesta(id_propuesta), Ok(true));
+            assert_eq!(
+                votacion.get_eleccion(0).unwrap().get_estado(),
+                EstadoEleccion::Certificada
+            );
+
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            assert_eq!(
+                votacion.proponer(Accion::CambiarEstadoEleccion(
+                    0,
+                    CambioEstado::Cancelar,
+                    "Motivo".to_string()
+                )),
+                Err(VotacionError::EleccionCertificada)
+            );
         }
 
         #[ink::test]
-        fn test_crear_eleccion_error_fecha_inicio_mayor_que_fin() {
-            let mut votacion = Votacion::new();
-            let fecha_inicio = Fecha::new(1, 1, 2024);
-            let fecha_fin = Fecha::new(31, 12, 2023);
-            let eleccion = votacion.crear_eleccion(fecha_inicio, fecha_fin);
-            assert_eq!(eleccion, Err(VotacionError::FechaInicioMayorQueFin));
+        fn test_cancelar_eleccion() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_participantes();
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 6, 2024).timestamp().unwrap());
+            votacion
+                .cancelar_eleccion(0, "Padrón con errores".to_string())
+                .unwrap();
+            assert_eq!(
+                ultimo_evento::<EstadoEleccionCambiado>(),
+                EstadoEleccionCambiado {
+                    id_eleccion: 0,
+                    estado_anterior: EstadoEleccion::Abierta,
+                    estado_nuevo: EstadoEleccion::Cancelada,
+                    motivo: "Padrón con errores".to_string(),
+                }
+            );
+            assert_eq!(
+                votacion.get_eleccion(0).unwrap().get_estado(),
+                EstadoEleccion::Cancelada
+            );
+            assert_eq!(
+                votacion.cancelar_eleccion(0, "Otra vez".to_string()),
+                Err(VotacionError::EleccionCancelada)
+            );
+
+            set_caller::<DefaultEnvironment>(accounts.django);
+            assert_eq!(
+                votacion.votar(0, accounts.charlie),
+                Err(VotacionError::EleccionCancelada)
+            );
+            // una elección cancelada no retiene a sus participantes
+            votacion.darse_de_baja().unwrap();
         }
 
         #[ink::test]
-        fn test_get_eleccion() {
-            let mut votacion = Votacion::new();
-            let eleccion = votacion
-                .crear_eleccion(Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024))
+        fn test_suspender_extender_y_reanudar_eleccion() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_participantes();
+            set_block_timestamp::<DefaultEnvironment>(
+                Fecha::new(30, 12, 2024).timestamp().unwrap(),
+            );
+            votacion
+                .suspender_eleccion(0, "Falla en el padrón".to_string())
                 .unwrap();
-            assert_eq!(votacion.get_eleccion(0).unwrap().get_id(), eleccion);
+            set_caller::<DefaultEnvironment>(accounts.django);
+            assert_eq!(
+                votacion.votar(0, accounts.charlie),
+                Err(VotacionError::EleccionSuspendida)
+            );
+
+            // la suspensión se extiende después de la fecha de fin original
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(2, 1, 2025).timestamp().unwrap());
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            votacion
+                .extender_eleccion(0, Fecha::new(5, 1, 2025), "Días perdidos".to_string())
+                .unwrap();
+            assert_eq!(
+                ultimo_evento::<EleccionExtendida>(),
+                EleccionExtendida {
+                    id_eleccion: 0,
+                    fecha_fin_anterior: Fecha::new(31, 12, 2024),
+                    fecha_fin_nueva: Fecha::new(5, 1, 2025),
+                    motivo: "Días perdidos".to_string(),
+                }
+            );
+            votacion
+                .reanudar_eleccion(0, "Padrón corregido".to_string())
+                .unwrap();
+            assert_eq!(
+                ultimo_evento::<EstadoEleccionCambiado>(),
+                EstadoEleccionCambiado {
+                    id_eleccion: 0,
+                    estado_anterior: EstadoEleccion::Suspendida,
+                    estado_nuevo: EstadoEleccion::Abierta,
+                    motivo: "Padrón corregido".to_string(),
+                }
+            );
+
+            set_caller::<DefaultEnvironment>(accounts.django);
+            votacion.votar(0, accounts.charlie).unwrap();
         }
 
-        // Tests de UserManager
         #[ink::test]
-        fn test_crear_usuario() {
+        fn test_transiciones_eleccion_errores() {
             let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_participantes();
             set_caller::<DefaultEnvironment>(accounts.bob);
-            let mut votacion = Votacion::new();
-            let usuario = votacion
-                .postular_usuario(
-                    "Juan".to_string(),
-                    "Perez".to_string(),
-                    "Calle Falsa 123".to_string(),
-                    "12345678".to_string(),
-                    30,
+            assert_eq!(
+                votacion.suspender_eleccion(0, "Motivo".to_string()),
+                Err(VotacionError::NoEsAdmin)
+            );
+
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            assert_eq!(
+                votacion.cancelar_eleccion(0, String::new()),
+                Err(VotacionError::MotivoVacio)
+            );
+            assert_eq!(
+                votacion.suspender_eleccion(5, "Motivo".to_string()),
+                Err(VotacionError::EleccionNoEncontrada)
+            );
+            assert_eq!(
+                votacion.reanudar_eleccion(0, "Motivo".to_string()),
+                Err(VotacionError::EleccionNoSuspendida)
+            );
+            assert_eq!(
+                votacion.extender_eleccion(0, Fecha::new(30, 12, 2024), "Motivo".to_string()),
+                Err(VotacionError::ExtensionInvalida)
+            );
+            assert_eq!(
+                votacion.certificar_eleccion(0),
+                Err(VotacionError::EleccionNoFinalizada)
+            );
+
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2025).timestamp().unwrap());
+            assert_eq!(
+                votacion.suspender_eleccion(0, "Motivo".to_string()),
+                Err(VotacionError::TransicionEstadoInvalida)
+            );
+            assert_eq!(
+                votacion.extender_eleccion(0, Fecha::new(5, 1, 2025), "Motivo".to_string()),
+                Err(VotacionError::EleccionYaFinalizada)
+            );
+            votacion.certificar_eleccion(0).unwrap();
+            assert_eq!(
+                votacion.certificar_eleccion(0),
+                Err(VotacionError::EleccionCertificada)
+            );
+            assert_eq!(
+                votacion.cancelar_eleccion(0, "Motivo".to_string()),
+                Err(VotacionError::EleccionCertificada)
+            );
+        }
+
+        // tests de impugnaciones
+        /// Devuelve default_con_roles con una tercera elección, de id 2, que admite impugnaciones
+        /// hasta el 15/1/2025, con charlie como candidato y django como votante
+        fn default_con_impugnaciones() -> Votacion {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_roles();
+            let id_eleccion = votacion
+                .crear_eleccion(
+                    Fecha::new(1, 1, 2024),
+                    Fecha::new(31, 12, 2024),
+                    ConfigEleccion {
+                        fin_impugnacion: Some(Fecha::new(15, 1, 2025)),
+                        ..Default::default()
+                    },
                 )
                 .unwrap();
-            assert_eq!(usuario.get_addres(), accounts.bob);
-            assert_eq!(usuario.get_nombre(), "Juan".to_string());
-            assert_eq!(usuario.get_apellido(), "Perez".to_string());
-            assert_eq!(usuario.get_direccion(), "Calle Falsa 123".to_string());
-            assert_eq!(usuario.get_dni(), "12345678".to_string());
-            assert_eq!(usuario.get_edad(), 30);
+            set_caller::<DefaultEnvironment>(accounts.charlie);
+            votacion.postular_candidato(id_eleccion).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.django);
+            votacion.postular_votante(id_eleccion).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            votacion
+                .agregar_candidato(id_eleccion, accounts.charlie)
+                .unwrap();
+            votacion
+                .agregar_votante(id_eleccion, accounts.django, 1)
+                .unwrap();
+            votacion
         }
 
         #[ink::test]
-        fn test_crear_usuario_error_usuario_no_aceptado() {
+        fn test_crear_eleccion_error_periodo_impugnacion_invalido() {
             let mut votacion = Votacion::new();
+            for (fin_revelacion, fin_impugnacion) in [
+                // termina antes del fin de la votación
+                (None, Fecha::new(30, 12, 2024)),
+                // fecha inválida
+                (None, Fecha::new(32, 1, 2025)),
+                // termina antes del fin de la revelación
+                (Some(Fecha::new(10, 1, 2025)), Fecha::new(5, 1, 2025)),
+            ] {
+                assert_eq!(
+                    votacion.crear_eleccion(
+                        Fecha::new(1, 1, 2024),
+                        Fecha::new(31, 12, 2024),
+                        ConfigEleccion {
+                            fin_revelacion,
+                            fin_impugnacion: Some(fin_impugnacion),
+                            ..Default::default()
+                        }
+                    ),
+                    Err(VotacionError::PeriodoImpugnacionInvalido)
+                );
+            }
+        }
+
+        #[ink::test]
+        fn test_impugnar_una_pendiente_por_autor() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_impugnaciones();
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2025).timestamp().unwrap());
+            set_caller::<DefaultEnvironment>(accounts.django);
+            assert_eq!(
+                votacion.impugnar_resultado(2, "Faltan votos".to_string()),
+                Ok(0)
+            );
+            assert_eq!(
+                votacion.impugnar_resultado(2, "Sobran votos".to_string()),
+                Err(VotacionError::ImpugnacionYaPresentada)
+            );
+            // la limitación es por elección y por autor
+            set_caller::<DefaultEnvironment>(accounts.charlie);
+            assert_eq!(
+                votacion.impugnar_resultado(2, "Sobran votos".to_string()),
+                Ok(1)
+            );
+
+            // una vez resuelta su impugnación, el autor puede presentar otra
+            set_caller::<DefaultEnvironment>(accounts.alice);
             votacion
-                .postular_usuario(
-                    "Juan".to_string(),
-                    "Perez".to_string(),
-                    "Calle Falsa 123".to_string(),
-                    "12345678".to_string(),
-                    30,
-                )
+                .resolver_impugnacion(2, 0, false, "Los votos coinciden".to_string())
                 .unwrap();
-            let usuario = votacion.postular_usuario(
-                "Juan".to_string(),
-                "Perez".to_string(),
-                "Calle Falsa 123".to_string(),
-                "12345678".to_string(),
-                30,
+            set_caller::<DefaultEnvironment>(accounts.django);
+            assert_eq!(
+                votacion.impugnar_resultado(2, "Sobran votos".to_string()),
+                Ok(2)
             );
-            assert_eq!(usuario, Err(VotacionError::UsuarioNoAceptado));
         }
 
         #[ink::test]
-        fn test_crear_usuario_error_usuario_ya_registrado() {
+        fn test_impugnar_error_motivo_demasiado_largo() {
             let accounts = default_accounts::<DefaultEnvironment>();
-            set_caller::<DefaultEnvironment>(accounts.bob);
-            let mut votacion = Votacion::new();
-            votacion.usuarios.push(Usuario::new(
-                accounts.bob,
-                "Juan".to_string(),
-                "Perez".to_string(),
-                "Calle Falsa 123".to_string(),
-                "12345678".to_string(),
-                30,
-            ));
-            let usuario = votacion.postular_usuario(
-                "Juan".to_string(),
-                "Perez".to_string(),
-                "Calle Falsa 123".to_string(),
-                "12345678".to_string(),
-                30,
+            let mut votacion = default_con_impugnaciones();
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2025).timestamp().unwrap());
+            set_caller::<DefaultEnvironment>(accounts.django);
+            assert_eq!(
+                votacion.impugnar_resultado(2, "a".repeat(LONGITUD_MAXIMA_MOTIVO + 1)),
+                Err(VotacionError::MotivoDemasiadoLargo)
+            );
+            assert_eq!(
+                votacion.impugnar_resultado(2, "a".repeat(LONGITUD_MAXIMA_MOTIVO)),
+                Ok(0)
             );
-            assert_eq!(usuario, Err(VotacionError::UsuarioYaRegistrado));
         }
 
         #[ink::test]
-        fn test_aceptar_usuario() {
+        fn test_impugnar_y_certificar() {
             let accounts = default_accounts::<DefaultEnvironment>();
-            set_caller::<DefaultEnvironment>(accounts.bob);
-            let mut votacion = Votacion::new();
+            let mut votacion = default_con_impugnaciones();
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2025).timestamp().unwrap());
+            set_caller::<DefaultEnvironment>(accounts.django);
+            assert_eq!(
+                votacion.impugnar_resultado(2, "Faltan votos".to_string()),
+                Ok(0)
+            );
+            assert_eq!(
+                ultimo_evento::<ImpugnacionPresentada>(),
+                ImpugnacionPresentada {
+                    id_eleccion: 2,
+                    id_impugnacion: 0,
+                    autor: accounts.django
+                }
+            );
+            assert_eq!(
+                votacion.get_impugnacion(2, 0),
+                Some(Impugnacion {
+                    autor: accounts.django,
+                    motivo: "Faltan votos".to_string(),
+                    estado: EstadoImpugnacion::Pendiente,
+                    resolucion: None
+                })
+            );
+            assert_eq!(
+                votacion
+                    .get_eleccion(2)
+                    .unwrap()
+                    .get_impugnaciones_pendientes(),
+                1
+            );
+
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            assert_eq!(
+                votacion.certificar_eleccion(2),
+                Err(VotacionError::PeriodoImpugnacionNoFinalizado)
+            );
+
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(16, 1, 2025).timestamp().unwrap());
+            assert_eq!(
+                votacion.certificar_eleccion(2),
+                Err(VotacionError::ImpugnacionesPendientes)
+            );
+            set_caller::<DefaultEnvironment>(accounts.charlie);
+            assert_eq!(
+                votacion.impugnar_resultado(2, "Otro motivo".to_string()),
+                Err(VotacionError::PeriodoImpugnacionFinalizado)
+            );
+
+            set_caller::<DefaultEnvironment>(accounts.alice);
             votacion
-                .postular_usuario(
-                    "Juan".to_string(),
-                    "Perez".to_string(),
-                    "Calle Falsa 123".to_string(),
-                    "12345678".to_string(),
-                    30,
-                )
+                .resolver_impugnacion(2, 0, false, "Los votos están completos".to_string())
                 .unwrap();
-            votacion.aceptar_usuario(accounts.bob).unwrap();
-            assert_eq!(votacion.usuarios_sin_aceptar.len(), 0);
-            assert_eq!(votacion.usuarios.len(), 1);
+            assert_eq!(
+                ultimo_evento::<ImpugnacionResuelta>(),
+                ImpugnacionResuelta {
+                    id_eleccion: 2,
+                    id_impugnacion: 0,
+                    aceptada: false
+                }
+            );
+            let impugnacion = votacion.get_impugnacion(2, 0).unwrap();
+            assert_eq!(impugnacion.estado, EstadoImpugnacion::Rechazada);
+            assert_eq!(
+                impugnacion.resolucion,
+                Some("Los votos están completos".to_string())
+            );
+            assert_eq!(
+                votacion.resolver_impugnacion(2, 0, true, "Motivo".to_string()),
+                Err(VotacionError::ImpugnacionYaResuelta)
+            );
+
+            votacion.certificar_eleccion(2).unwrap();
+            assert_eq!(
+                votacion.get_eleccion(2).unwrap().get_estado(),
+                EstadoEleccion::Certificada
+            );
+        }
+
+        #[ink::test]
+        fn test_impugnar_resultado_errores() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_impugnaciones();
+            set_caller::<DefaultEnvironment>(accounts.django);
+            assert_eq!(
+                votacion.impugnar_resultado(2, "Motivo".to_string()),
+                Err(VotacionError::EleccionNoFinalizada)
+            );
+
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2025).timestamp().unwrap());
+            assert_eq!(
+                votacion.impugnar_resultado(2, String::new()),
+                Err(VotacionError::MotivoVacio)
+            );
+            assert_eq!(
+                votacion.impugnar_resultado(5, "Motivo".to_string()),
+                Err(VotacionError::EleccionNoEncontrada)
+            );
+            assert_eq!(
+                votacion.impugnar_resultado(0, "Motivo".to_string()),
+                Err(VotacionError::EleccionSinImpugnaciones)
+            );
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                votacion.impugnar_resultado(2, "Motivo".to_string()),
+                Err(VotacionError::UsuarioNoEsParticipante)
+            );
+            assert_eq!(votacion.get_impugnacion(2, 0), None);
         }
 
         #[ink::test]
-        fn test_aceptar_usuario_error_no_admin() {
+        fn test_impugnacion_aceptada_impide_certificar() {
             let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_impugnaciones();
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2025).timestamp().unwrap());
             set_caller::<DefaultEnvironment>(accounts.charlie);
-            let mut votacion = Votacion::new();
             votacion
-                .postular_usuario(
-                    "Juan".to_string(),
-                    "Perez".to_string(),
-                    "Calle Falsa 123".to_string(),
-                    "12345678".to_string(),
-                    30,
-                )
+                .impugnar_resultado(2, "Votos duplicados".to_string())
                 .unwrap();
+
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                votacion.resolver_impugnacion(2, 0, true, "Motivo".to_string()),
+                Err(VotacionError::NoEsAdmin)
+            );
             set_caller::<DefaultEnvironment>(accounts.alice);
-            let usuario = votacion.aceptar_usuario(accounts.bob);
-            assert_eq!(usuario, Err(VotacionError::NoEsAdmin));
-        }
+            assert_eq!(
+                votacion.resolver_impugnacion(2, 0, true, String::new()),
+                Err(VotacionError::MotivoVacio)
+            );
+            assert_eq!(
+                votacion.resolver_impugnacion(2, 1, true, "Motivo".to_string()),
+                Err(VotacionError::ImpugnacionNoEncontrada)
+            );
+            votacion
+                .resolver_impugnacion(2, 0, true, "Se comprobaron votos duplicados".to_string())
+                .unwrap();
 
-        #[ink::test]
-        fn test_aceptar_usuario_error_usuario_sin_aceptar_no_encontrado() {
-            let accounts = default_accounts::<DefaultEnvironment>();
-            let mut votacion = Votacion::new();
-            let usuario = votacion.aceptar_usuario(accounts.bob);
-            assert_eq!(usuario, Err(VotacionError::UsuarioSinAceptarNoEncontrado));
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(16, 1, 2025).timestamp().unwrap());
+            assert_eq!(
+                votacion.certificar_eleccion(2),
+                Err(VotacionError::ResultadoImpugnado)
+            );
+            votacion
+                .cancelar_eleccion(2, "Resultado impugnado".to_string())
+                .unwrap();
+            assert_eq!(
+                votacion.get_eleccion(2).unwrap().get_estado(),
+                EstadoEleccion::Cancelada
+            );
         }
 
         #[ink::test]
-        fn test_get_usuario_sin_aceptar() {
+        fn test_resolver_impugnacion_con_consejo() {
             let accounts = default_accounts::<DefaultEnvironment>();
-            set_caller::<DefaultEnvironment>(accounts.bob);
-            let mut votacion = Votacion::new();
+            let mut votacion = default_con_impugnaciones();
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2025).timestamp().unwrap());
+            set_caller::<DefaultEnvironment>(accounts.django);
             votacion
-                .postular_usuario(
-                    "Juan".to_string(),
-                    "Perez".to_string(),
-                    "Calle Falsa 123".to_string(),
-                    "12345678".to_string(),
-                    30,
-                )
+                .impugnar_resultado(2, "Faltan votos".to_string())
+                .unwrap();
+
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            votacion
+                .configurar_consejo(vec![accounts.alice, accounts.bob, accounts.charlie], 2)
+                .unwrap();
+            assert_eq!(
+                votacion.resolver_impugnacion(2, 0, false, "Motivo".to_string()),
+                Err(VotacionError::RequiereAprobacionConsejo)
+            );
+            assert_eq!(
+                votacion.proponer(Accion::ResolverImpugnacion(
+                    2,
+                    1,
+                    false,
+                    "Motivo".to_string()
+                )),
+                Err(VotacionError::ImpugnacionNoEncontrada)
+            );
+            let id_propuesta = votacion
+                .proponer(Accion::ResolverImpugnacion(
+                    2,
+                    0,
+                    false,
+                    "Los votos están completos".to_string(),
+                ))
                 .unwrap();
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(votacion.aprobar_propuesta(id_propuesta), Ok(true));
+            assert_eq!(
+                votacion.get_impugnacion(2, 0).unwrap().estado,
+                EstadoImpugnacion::Rechazada
+            );
             assert_eq!(
                 votacion
-                    .get_usuario_sin_aceptar(accounts.bob)
+                    .get_eleccion(2)
                     .unwrap()
-                    .get_addres(),
-                accounts.bob
+                    .get_impugnaciones_pendientes(),
+                0
             );
         }
 
         #[ink::test]
-        fn test_get_usuario_sin_aceptar_error_usuario_sin_aceptar_no_encontrado() {
-            let accounts = default_accounts::<DefaultEnvironment>();
-            let votacion = Votacion::new();
-            let usuario = votacion.get_usuario_sin_aceptar(accounts.bob);
-            assert_eq!(usuario, Err(VotacionError::UsuarioSinAceptarNoEncontrado));
+        fn test_reporte_estado() {
+            let mut votacion = default_con_participantes();
+            assert_eq!(votacion.reporte_estado(0), Err(VotacionError::SoloReportes));
+
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2025).timestamp().unwrap());
+            votacion.certificar_eleccion(0).unwrap();
+            set_caller::<DefaultEnvironment>(AccountId::from([0x10; 32]));
+            assert_eq!(votacion.reporte_estado(0), Ok(EstadoEleccion::Certificada));
+            assert_eq!(votacion.reporte_estado(1), Ok(EstadoEleccion::Cerrada));
+            assert_eq!(
+                votacion.reporte_estado(5),
+                Err(VotacionError::EleccionNoEncontrada)
+            );
+        }
+
+        // tests de quorum
+        fn config_quorum(
+            minimo: Quorum,
+            segundo_llamado: Option<(Fecha, Fecha)>,
+        ) -> ConfigEleccion {
+            ConfigEleccion {
+                quorum: Some(ReglaQuorum {
+                    minimo,
+                    segundo_llamado,
+                }),
+                ..Default::default()
+            }
+        }
+
+        #[test]
+        fn test_regla_quorum_alcanzado() {
+            let porcentaje = config_quorum(Quorum::Porcentaje(50), None).quorum.unwrap();
+            assert!(porcentaje.alcanzado(2, 4));
+            assert!(!porcentaje.alcanzado(1, 3));
+            assert!(porcentaje.alcanzado(0, 0));
+            let cantidad = config_quorum(Quorum::Cantidad(2), None).quorum.unwrap();
+            assert!(cantidad.alcanzado(2, 10));
+            assert!(!cantidad.alcanzado(1, 1));
         }
 
         #[ink::test]
-        fn test_get_usuario() {
-            let accounts = default_accounts::<DefaultEnvironment>();
-            set_caller::<DefaultEnvironment>(accounts.bob);
+        fn test_crear_eleccion_error_regla_quorum_invalida() {
             let mut votacion = Votacion::new();
-            votacion
-                .postular_usuario(
-                    "Juan".to_string(),
-                    "Perez".to_string(),
-                    "Calle Falsa 123".to_string(),
-                    "12345678".to_string(),
-                    30,
-                )
-                .unwrap();
-            votacion.aceptar_usuario(accounts.bob).unwrap();
+            for (minimo, segundo_llamado) in [
+                (Quorum::Porcentaje(0), None),
+                (Quorum::Porcentaje(101), None),
+                (Quorum::Cantidad(0), None),
+                // empieza antes del fin de la votación
+                (
+                    Quorum::Cantidad(1),
+                    Some((Fecha::new(30, 12, 2024), Fecha::new(31, 1, 2025))),
+                ),
+                // termina antes de empezar
+                (
+                    Quorum::Cantidad(1),
+                    Some((Fecha::new(1, 2, 2025), Fecha::new(31, 1, 2025))),
+                ),
+            ] {
+                assert_eq!(
+                    votacion.crear_eleccion(
+                        Fecha::new(1, 1, 2024),
+                        Fecha::new(31, 12, 2024),
+                        config_quorum(minimo, segundo_llamado)
+                    ),
+                    Err(VotacionError::ReglaQuorumInvalida)
+                );
+            }
             assert_eq!(
-                votacion.get_usuario(accounts.bob).unwrap().get_addres(),
-                accounts.bob
+                votacion.crear_eleccion(
+                    Fecha::new(1, 1, 2024),
+                    Fecha::new(31, 12, 2024),
+                    ConfigEleccion {
+                        tipo: TipoEleccion::Listas,
+                        ..config_quorum(
+                            Quorum::Cantidad(1),
+                            Some((Fecha::new(1, 2, 2025), Fecha::new(28, 2, 2025)))
+                        )
+                    }
+                ),
+                Err(VotacionError::TipoEleccionInvalido)
             );
         }
 
         #[ink::test]
-        fn test_get_usuario_error_usuario_no_encontrado() {
+        fn test_quorum_alcanzado() {
             let accounts = default_accounts::<DefaultEnvironment>();
-            let votacion = Votacion::new();
-            let usuario = votacion.get_usuario(accounts.bob);
-            assert_eq!(usuario, Err(VotacionError::UsuarioNoEncontrado));
+            let mut votacion = default_con_config(config_quorum(Quorum::Porcentaje(60), None));
+            delegar(&mut votacion, &[(accounts.eve, accounts.django)]);
+            set_caller::<DefaultEnvironment>(accounts.django);
+            votacion.votar(0, accounts.alice).unwrap();
+
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2025).timestamp().unwrap());
+            set_caller::<DefaultEnvironment>(AccountId::from([0x10; 32]));
+            assert_eq!(
+                votacion.reporte_participacion(0).unwrap().quorum_alcanzado,
+                Some(true)
+            );
+
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            assert_eq!(
+                votacion.aplicar_quorum(0),
+                Err(VotacionError::QuorumAlcanzado)
+            );
+            assert_eq!(votacion.certificar_eleccion(0), Ok(()));
         }
 
-        //impl test de EleccionImpl
         #[ink::test]
-        fn test_get_inicio_eleccion() {
-            // Ya terminó
-            let eleccion = Eleccion::new(0, Fecha::new(1, 1, 2023), Fecha::new(31, 12, 2023));
-            assert!(eleccion.get_inicio(&Fecha::new(1, 1, 2024)));
+        fn test_quorum_no_alcanzado_invalida() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_config(config_quorum(Quorum::Cantidad(2), None));
+            set_caller::<DefaultEnvironment>(accounts.django);
+            votacion.votar(0, accounts.alice).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            assert_eq!(
+                votacion.aplicar_quorum(0),
+                Err(VotacionError::EleccionNoFinalizada)
+            );
+            votacion
+                .crear_eleccion(
+                    Fecha::new(1, 1, 2024),
+                    Fecha::new(31, 12, 2024),
+                    ConfigEleccion::default(),
+                )
+                .unwrap();
 
-            // Ya empezó y no terminó
-            let eleccion = Eleccion::new(0, Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024));
-            assert!(eleccion.get_inicio(&Fecha::new(15, 6, 2024)));
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2025).timestamp().unwrap());
+            assert_eq!(
+                votacion.aplicar_quorum(1),
+                Err(VotacionError::EleccionSinQuorum)
+            );
+            assert_eq!(
+                votacion.certificar_eleccion(0),
+                Err(VotacionError::QuorumNoAlcanzado)
+            );
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            assert_eq!(votacion.aplicar_quorum(0), Err(VotacionError::NoEsAdmin));
 
-            // No empezó
-            let eleccion = Eleccion::new(0, Fecha::new(1, 1, 2025), Fecha::new(31, 12, 2025));
-            assert!(!eleccion.get_inicio(&Fecha::new(1, 1, 2024)));
-        }
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            assert_eq!(votacion.aplicar_quorum(0), Ok(ResultadoQuorum::Invalida));
+            assert_eq!(
+                ultimo_evento::<QuorumAplicado>(),
+                QuorumAplicado {
+                    id_eleccion: 0,
+                    resultado: ResultadoQuorum::Invalida
+                }
+            );
+            assert_eq!(
+                votacion.get_eleccion(0).unwrap().get_estado(),
+                EstadoEleccion::Invalida
+            );
+            assert!(votacion.get_eleccion(2).is_none());
+            assert_eq!(
+                votacion.aplicar_quorum(0),
+                Err(VotacionError::EleccionInvalida)
+            );
+            assert_eq!(
+                votacion.certificar_eleccion(0),
+                Err(VotacionError::EleccionInvalida)
+            );
+            assert_eq!(
+                votacion.cancelar_eleccion(0, "Sin quórum".to_string()),
+                Err(VotacionError::EleccionInvalida)
+            );
 
-        #[test]
-        fn test_get_finalizada_eleccion() {
-            // Ya terminó
-            let eleccion = Eleccion::new(0, Fecha::new(1, 1, 2023), Fecha::new(31, 12, 2023));
-            assert!(eleccion.get_finalizada(&Fecha::new(1, 1, 2024)));
+            set_caller::<DefaultEnvironment>(AccountId::from([0x10; 32]));
+            let participacion = votacion.reporte_participacion(0).unwrap();
+            assert_eq!(participacion.votos, 1);
+            assert_eq!(participacion.quorum_alcanzado, Some(false));
+        }
 
-            // Ya empezó y no terminó
-            let eleccion = Eleccion::new(0, Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024));
-            assert!(!eleccion.get_finalizada(&Fecha::new(15, 6, 2024)));
+        #[ink::test]
+        fn test_quorum_segundo_llamado() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_config(config_quorum(
+                Quorum::Porcentaje(100),
+                Some((Fecha::new(1, 2, 2025), Fecha::new(28, 2, 2025))),
+            ));
+            set_caller::<DefaultEnvironment>(accounts.django);
+            votacion.votar(0, accounts.alice).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            votacion.retirar_candidato(0).unwrap();
 
-            // No empezó
-            let eleccion = Eleccion::new(0, Fecha::new(1, 1, 2025), Fecha::new(31, 12, 2025));
-            assert!(!eleccion.get_inicio(&Fecha::new(1, 1, 2024)));
-        }
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2025).timestamp().unwrap());
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            assert_eq!(
+                votacion.aplicar_quorum(0),
+                Ok(ResultadoQuorum::SegundoLlamado(1))
+            );
+            assert_eq!(
+                votacion.get_eleccion(0).unwrap().resultado_quorum,
+                Some(ResultadoQuorum::SegundoLlamado(1))
+            );
 
-        #[test]
-        fn test_postular_candidato_eleccion() {
-            let mut eleccion = Eleccion::new(0, Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024));
-            let id_candidato = AccountId::from([0x1; 32]);
+            // el candidato retirado no participa del segundo llamado
+            let segundo_llamado = votacion.get_eleccion(1).unwrap();
+            assert_eq!(segundo_llamado.primer_llamado, Some(0));
+            assert_eq!(segundo_llamado.get_fecha_inicio(), Fecha::new(1, 2, 2025));
+            assert_eq!(segundo_llamado.config.quorum, None);
+            assert_eq!(
+                votacion.get_candidatos(&segundo_llamado),
+                vec![accounts.bob, accounts.charlie]
+            );
+            assert_eq!(
+                votacion.get_votantes(&segundo_llamado),
+                vec![accounts.django, accounts.eve, accounts.frank]
+            );
 
-            assert!(eleccion
-                .postular_candidato(id_candidato, &Fecha::new(1, 1, 2023))
-                .is_ok());
-            assert!(eleccion.is_postulado_candidato(&id_candidato));
-            //Intento postularlo como votante
+            set_caller::<DefaultEnvironment>(accounts.alice);
             assert_eq!(
-                eleccion.postular_votante(id_candidato, &Fecha::new(1, 1, 2023)),
-                Err(VotacionError::UsuarioEsPostuladoCandidato)
+                votacion.postular_votante(1),
+                Err(VotacionError::EleccionEsSegundoLlamado)
             );
-            //Chequeo que no se haya postulado como votante
-            assert!(!eleccion.is_postulado_votante(&id_candidato));
+
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(15, 2, 2025).timestamp().unwrap());
+            set_caller::<DefaultEnvironment>(accounts.eve);
+            votacion.votar(1, accounts.bob).unwrap();
+
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 3, 2025).timestamp().unwrap());
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            assert_eq!(votacion.certificar_eleccion(1), Ok(()));
         }
 
-        #[test]
-        fn test_postular_candidato_eleccion_error_eleccion_ya_finalizada() {
-            let mut eleccion = Eleccion::new(0, Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024));
-            let id_candidato = AccountId::from([0x1; 32]);
+        #[ink::test]
+        fn test_aplicar_quorum_error_segundo_llamado_vencido() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_config(config_quorum(
+                Quorum::Porcentaje(100),
+                Some((Fecha::new(1, 2, 2025), Fecha::new(28, 2, 2025))),
+            ));
 
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(2, 2, 2025).timestamp().unwrap());
+            set_caller::<DefaultEnvironment>(accounts.frank);
             assert_eq!(
-                eleccion.postular_candidato(id_candidato, &Fecha::new(1, 1, 2025)),
-                Err(VotacionError::EleccionYaFinalizada)
+                votacion.aplicar_quorum(0),
+                Err(VotacionError::SegundoLlamadoVencido)
+            );
+            assert_eq!(
+                votacion.get_eleccion(0).unwrap().get_estado(),
+                EstadoEleccion::Cerrada
             );
+            assert!(votacion.get_eleccion(1).is_none());
         }
 
-        #[test]
-        fn test_postular_candidato_eleccion_error_eleccion_ya_iniciada() {
-            let mut eleccion = Eleccion::new(0, Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024));
-            let id_candidato = AccountId::from([0x1; 32]);
+        #[ink::test]
+        fn test_aplicar_balotaje_error_impugnaciones() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_config(ConfigEleccion {
+                fin_impugnacion: Some(Fecha::new(15, 1, 2025)),
+                ..config_balotaje(regla_balotaje(45, None))
+            });
+            set_caller::<DefaultEnvironment>(accounts.django);
+            votacion.votar(0, accounts.alice).unwrap();
 
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2025).timestamp().unwrap());
+            set_caller::<DefaultEnvironment>(accounts.frank);
             assert_eq!(
-                eleccion.postular_candidato(id_candidato, &Fecha::new(1, 1, 2024)),
-                Err(VotacionError::EleccionYaIniciada)
+                votacion.aplicar_balotaje(0),
+                Err(VotacionError::PeriodoImpugnacionNoFinalizado)
             );
-        }
-
-        #[test]
-        fn test_postular_candidato_eleccion_error_es_postulado_candidato() {
-            let mut eleccion = Eleccion::new(0, Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024));
-            let id_candidato = AccountId::from([0x1; 32]);
+            set_caller::<DefaultEnvironment>(accounts.eve);
+            votacion
+                .impugnar_resultado(0, "Faltan votos".to_string())
+                .unwrap();
 
-            assert!(eleccion
-                .postular_candidato(id_candidato, &Fecha::new(1, 1, 2023))
-                .is_ok());
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(16, 1, 2025).timestamp().unwrap());
+            set_caller::<DefaultEnvironment>(accounts.frank);
             assert_eq!(
-                eleccion.postular_candidato(id_candidato, &Fecha::new(1, 1, 2023)),
-                Err(VotacionError::UsuarioEsPostuladoCandidato)
+                votacion.aplicar_balotaje(0),
+                Err(VotacionError::ImpugnacionesPendientes)
+            );
+            votacion
+                .resolver_impugnacion(0, 0, true, "Se confirmó el reclamo".to_string())
+                .unwrap();
+            assert_eq!(
+                votacion.aplicar_balotaje(0),
+                Err(VotacionError::ResultadoImpugnado)
             );
+            assert!(votacion.get_eleccion(1).is_none());
         }
 
-        #[test]
-        fn test_postular_candidato_eleccion_error_es_postulado_votante() {
-            let mut eleccion = Eleccion::new(0, Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024));
-            let id_candidato = AccountId::from([0x1; 32]);
-
-            assert!(eleccion
-                .postular_votante(id_candidato, &Fecha::new(1, 1, 2023))
-                .is_ok());
+        #[ink::test]
+        fn test_crear_eleccion_error_balotaje_antes_de_fin_impugnacion() {
+            let mut votacion = default_con_roles();
             assert_eq!(
-                eleccion.postular_candidato(id_candidato, &Fecha::new(1, 1, 2023)),
-                Err(VotacionError::UsuarioEsPostuladoVotante)
+                votacion.crear_eleccion(
+                    Fecha::new(1, 1, 2024),
+                    Fecha::new(31, 12, 2024),
+                    ConfigEleccion {
+                        fin_impugnacion: Some(Fecha::new(15, 2, 2025)),
+                        ..config_balotaje(regla_balotaje(50, None))
+                    }
+                ),
+                Err(VotacionError::ReglaBalotajeInvalida)
             );
         }
 
-        #[test]
-        fn test_postular_votante_eleccion() {
-            let mut eleccion = Eleccion::new(0, Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024));
-            let id_votante = AccountId::from([0x1; 32]);
-
-            assert!(eleccion
-                .postular_votante(id_votante, &Fecha::new(1, 1, 2023))
-                .is_ok());
-            assert!(eleccion.is_postulado_votante(&id_votante));
-            //Intento postularlo como candidato
+        #[ink::test]
+        fn test_aplicar_balotaje_error_quorum_no_alcanzado() {
+            let mut votacion = default_con_config(ConfigEleccion {
+                quorum: config_quorum(Quorum::Cantidad(1), None).quorum,
+                ..config_balotaje(regla_balotaje(50, None))
+            });
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2025).timestamp().unwrap());
             assert_eq!(
-                eleccion.postular_votante(id_votante, &Fecha::new(1, 1, 2023)),
-                Err(VotacionError::UsuarioEsPostuladoVotante)
+                votacion.aplicar_balotaje(0),
+                Err(VotacionError::QuorumNoAlcanzado)
             );
-            //Chequeo que no se haya postulado como candidato
-            assert!(!eleccion.is_postulado_candidato(&id_votante));
         }
 
-        #[test]
-        fn test_postular_votante_eleccion_error_eleccion_ya_finalizada() {
-            let mut eleccion = Eleccion::new(0, Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024));
-            let id_candidato = AccountId::from([0x1; 32]);
+        // tests de retiro de participantes
+        #[ink::test]
+        fn test_retirar_candidato_antes_de_votacion() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_participantes();
+            set_caller::<DefaultEnvironment>(accounts.charlie);
+            assert_eq!(votacion.retirar_candidato(0), Ok(()));
+            assert_eq!(
+                ultimo_evento::<CandidatoRetirado>(),
+                CandidatoRetirado {
+                    id_eleccion: 0,
+                    candidato: accounts.charlie
+                }
+            );
+            assert_eq!(
+                votacion.get_estado_participante(0, accounts.charlie),
+                Some(EstadoParticipante::CandidatoRetirado)
+            );
+            assert_eq!(
+                votacion.get_eleccion(0).unwrap().get_cantidad_candidatos(),
+                0
+            );
+            assert_eq!(
+                votacion.retirar_candidato(0),
+                Err(VotacionError::UsuarioNoEsCandidato)
+            );
+            // no puede volver a postularse en la misma elección
+            assert_eq!(
+                votacion.postular_votante(0),
+                Err(VotacionError::UsuarioRetirado)
+            );
 
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 6, 2024).timestamp().unwrap());
+            set_caller::<DefaultEnvironment>(accounts.django);
             assert_eq!(
-                eleccion.postular_votante(id_candidato, &Fecha::new(1, 1, 2025)),
-                Err(VotacionError::EleccionYaFinalizada)
+                votacion.votar(0, accounts.charlie),
+                Err(VotacionError::UsuarioNoEsCandidato)
             );
         }
 
-        #[test]
-        fn test_postular_votante_eleccion_ya_iniciada() {
-            let mut eleccion = Eleccion::new(0, Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024));
-            let id_votante = AccountId::from([0x1; 32]);
+        #[ink::test]
+        fn test_retirar_candidato_durante_votacion() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_tipo(TipoEleccion::Simple);
+            set_caller::<DefaultEnvironment>(accounts.django);
+            votacion.votar(0, accounts.charlie).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.eve);
+            votacion.votar(0, accounts.alice).unwrap();
 
+            set_caller::<DefaultEnvironment>(accounts.charlie);
+            votacion.retirar_candidato(0).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.frank);
             assert_eq!(
-                eleccion.postular_votante(id_votante, &Fecha::new(1, 1, 2024)),
-                Err(VotacionError::EleccionYaIniciada)
+                votacion.votar(0, accounts.charlie),
+                Err(VotacionError::UsuarioNoEsCandidato)
             );
-        }
-
-        #[test]
-        fn test_postular_votante_eleccion_error_es_postulado_votante() {
-            let mut eleccion = Eleccion::new(0, Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024));
-            let id_votante = AccountId::from([0x1; 32]);
+            votacion.votar(0, accounts.bob).unwrap();
 
-            assert!(eleccion
-                .postular_votante(id_votante, &Fecha::new(1, 1, 2023))
-                .is_ok());
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2025).timestamp().unwrap());
+            set_caller::<DefaultEnvironment>(AccountId::from([0x10; 32]));
             assert_eq!(
-                eleccion.postular_votante(id_votante, &Fecha::new(1, 1, 2023)),
-                Err(VotacionError::UsuarioEsPostuladoVotante)
+                votacion.reporte_resultado(0),
+                Ok(vec![(accounts.alice, 1), (accounts.bob, 1)])
             );
+            assert_eq!(
+                votacion.reporte_retirados(0),
+                Ok(vec![(accounts.charlie, 1)])
+            );
+            assert_eq!(votacion.reporte_participacion(0).unwrap().votos, 3);
         }
 
-        #[test]
-        fn test_agregar_candidato_eleccion() {
-            let mut eleccion = Eleccion::new(0, Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024));
-            let id_candidato = AccountId::from([0x1; 32]);
-
-            assert!(eleccion
-                .postular_candidato(id_candidato, &Fecha::new(1, 1, 2023))
-                .is_ok());
-            assert!(eleccion
-                .agregar_candidato(id_candidato, &Fecha::new(1, 1, 2023))
-                .is_ok());
-            assert!(eleccion.is_candidato(&id_candidato));
-        }
+        #[ink::test]
+        fn test_retirar_candidato_errores() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_tipo(TipoEleccion::Simple);
+            set_caller::<DefaultEnvironment>(accounts.django);
+            assert_eq!(
+                votacion.retirar_candidato(0),
+                Err(VotacionError::UsuarioNoEsCandidato)
+            );
+            assert_eq!(
+                votacion.retirar_candidato(5),
+                Err(VotacionError::EleccionNoEncontrada)
+            );
 
-        #[test]
-        fn test_agregar_candidato_eleccion_error_usuario_no_postulado_candidato() {
-            let mut eleccion = Eleccion::new(0, Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024));
-            let id_candidato = AccountId::from([0x1; 32]);
+            // durante la votación deben quedar candidatos para el cargo
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            votacion.retirar_candidato(0).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            votacion.retirar_candidato(0).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.charlie);
+            assert_eq!(
+                votacion.retirar_candidato(0),
+                Err(VotacionError::CandidatosInsuficientes)
+            );
 
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2025).timestamp().unwrap());
             assert_eq!(
-                eleccion.agregar_candidato(id_candidato, &Fecha::new(1, 1, 2023)),
-                Err(VotacionError::UsuarioNoPostuladoCandidato)
+                votacion.retirar_candidato(0),
+                Err(VotacionError::EleccionYaFinalizada)
+            );
+            set_caller::<DefaultEnvironment>(AccountId::from([0x10; 32]));
+            assert_eq!(
+                votacion.reporte_retirados(0),
+                Ok(vec![(accounts.alice, 0), (accounts.bob, 0)])
+            );
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            assert_eq!(
+                votacion.reporte_retirados(0),
+                Err(VotacionError::SoloReportes)
             );
         }
 
-        #[test]
-        fn test_agregar_candidato_eleccion_error_eleccion_ya_iniciada() {
-            let mut eleccion = Eleccion::new(0, Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024));
-            let id_candidato = AccountId::from([0x1; 32]);
-
-            assert!(eleccion
-                .postular_candidato(id_candidato, &Fecha::new(1, 1, 2023))
-                .is_ok());
+        #[ink::test]
+        fn test_retirar_candidato_error_tipo_eleccion_invalido() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_tipo(TipoEleccion::Preferencial);
+            set_caller::<DefaultEnvironment>(accounts.alice);
             assert_eq!(
-                eleccion.agregar_candidato(id_candidato, &Fecha::new(15, 6, 2024)),
-                Err(VotacionError::EleccionYaIniciada)
+                votacion.retirar_candidato(0),
+                Err(VotacionError::TipoEleccionInvalido)
             );
         }
 
-        #[test]
-        fn test_agregar_candidato_eleccion_error_eleccion_ya_finalizada() {
-            let mut eleccion = Eleccion::new(0, Fecha::new(1, 1, 2024), Fecha::new(1, 1, 2024));
-            let id_candidato = AccountId::from([0x1; 32]);
-
-            assert!(eleccion
-                .postular_candidato(id_candidato, &Fecha::new(1, 1, 2023))
-                .is_ok());
+        #[ink::test]
+        fn test_retirar_candidato_error_en_lista() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_listas();
+            set_block_timestamp::<DefaultEnvironment>(
+                Fecha::new(31, 12, 2023).timestamp().unwrap(),
+            );
+            set_caller::<DefaultEnvironment>(accounts.alice);
             assert_eq!(
-                eleccion.agregar_candidato(id_candidato, &Fecha::new(2, 1, 2024)),
-                Err(VotacionError::EleccionYaFinalizada)
+                votacion.retirar_candidato(0),
+                Err(VotacionError::CandidatoEnLista)
             );
         }
 
-        #[test]
-        fn test_agregar_candidato_eleccion_error_usuario_es_candidato() {
-            let mut eleccion = Eleccion::new(0, Fecha::new(1, 1, 2025), Fecha::new(1, 1, 2025));
-            let id_candidato = AccountId::from([0x1; 32]);
+        #[ink::test]
+        fn test_retirar_votante() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_pesos(ConfigEleccion::default(), [1, 2, 1]);
+            delegar(
+                &mut votacion,
+                &[
+                    (accounts.eve, accounts.django),
+                    (accounts.django, accounts.frank),
+                ],
+            );
 
-            assert!(eleccion
-                .postular_candidato(id_candidato, &Fecha::new(1, 1, 2023))
-                .is_ok());
-            assert!(eleccion
-                .agregar_candidato(id_candidato, &Fecha::new(31, 12, 2024))
-                .is_ok());
+            set_block_timestamp::<DefaultEnvironment>(
+                Fecha::new(31, 12, 2023).timestamp().unwrap(),
+            );
+            set_caller::<DefaultEnvironment>(accounts.django);
+            assert_eq!(votacion.retirar_votante(0), Ok(()));
             assert_eq!(
-                eleccion.postular_candidato(id_candidato, &Fecha::new(1, 1, 2023)),
-                Err(VotacionError::UsuarioEsCandidato)
+                ultimo_evento::<VotanteRetirado>(),
+                VotanteRetirado {
+                    id_eleccion: 0,
+                    votante: accounts.django
+                }
+            );
+            assert_eq!(votacion.get_delegacion(0, accounts.django), None);
+            assert_eq!(votacion.get_delegacion(0, accounts.eve), None);
+            assert_eq!(
+                votacion.retirar_votante(0),
+                Err(VotacionError::UsuarioNoEsVotante)
             );
-        }
 
-        #[test]
-        fn test_agregar_candidato_eleccion_error_usuario_es_votante() {
-            let mut eleccion = Eleccion::new(0, Fecha::new(1, 1, 2025), Fecha::new(1, 1, 2025));
-            let id_candidato = AccountId::from([0x1; 32]);
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(15, 6, 2024).timestamp().unwrap());
+            assert_eq!(
+                votacion.votar(0, accounts.alice),
+                Err(VotacionError::UsuarioNoEsVotante)
+            );
+            set_caller::<DefaultEnvironment>(accounts.eve);
+            assert_eq!(
+                votacion.retirar_votante(0),
+                Err(VotacionError::EleccionYaIniciada)
+            );
+            votacion.votar(0, accounts.bob).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            votacion.votar(0, accounts.alice).unwrap();
 
-            assert!(eleccion
-                .postular_votante(id_candidato, &Fecha::new(1, 1, 2024))
-                .is_ok());
-            assert!(eleccion
-                .agregar_votante(id_candidato, &Fecha::new(31, 12, 2024))
-                .is_ok());
+            let (resultado, participacion) = resultado_delegado(&votacion);
             assert_eq!(
-                eleccion.postular_candidato(id_candidato, &Fecha::new(1, 1, 2024)),
-                Err(VotacionError::UsuarioEsVotante)
+                resultado,
+                vec![
+                    (accounts.alice, 1),
+                    (accounts.bob, 2),
+                    (accounts.charlie, 0)
+                ]
+            );
+            assert_eq!(participacion.votantes, 2);
+            assert_eq!(participacion.peso_votantes, 3);
+            assert_eq!(participacion.votos_delegados, 0);
+            assert_eq!(
+                votacion.reporte_registro_votantes(0),
+                Ok(vec![accounts.eve, accounts.frank])
             );
         }
 
-        #[test]
-        fn test_agregar_candidato_eleccion_error_usuario_es_votanteno_postulado() {
-            let mut eleccion = Eleccion::new(0, Fecha::new(1, 1, 2025), Fecha::new(1, 1, 2025));
-            let id_candidato = AccountId::from([0x1; 32]);
+        // tests de periodo de inscripcion
+        #[ink::test]
+        fn test_crear_eleccion_error_periodo_inscripcion_invalido() {
+            let mut votacion = Votacion::new();
+            for (apertura, cierre) in [
+                // cierra después del inicio de la votación
+                (Fecha::new(1, 11, 2023), Fecha::new(1, 1, 2024)),
+                // abre después de cerrar
+                (Fecha::new(1, 12, 2023), Fecha::new(1, 11, 2023)),
+                // fecha inválida
+                (Fecha::new(31, 11, 2023), Fecha::new(1, 12, 2023)),
+            ] {
+                assert_eq!(
+                    votacion.crear_eleccion(
+                        Fecha::new(1, 1, 2024),
+                        Fecha::new(31, 12, 2024),
+                        ConfigEleccion {
+                            inscripcion: Some(PeriodoInscripcion { apertura, cierre }),
+                            ..Default::default()
+                        }
+                    ),
+                    Err(VotacionError::PeriodoInscripcionInvalido)
+                );
+            }
+        }
+
+        #[ink::test]
+        fn test_postular_fuera_del_periodo_inscripcion() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_roles();
+            votacion
+                .crear_eleccion(
+                    Fecha::new(1, 1, 2024),
+                    Fecha::new(31, 12, 2024),
+                    ConfigEleccion {
+                        inscripcion: Some(PeriodoInscripcion {
+                            apertura: Fecha::new(1, 11, 2023),
+                            cierre: Fecha::new(30, 11, 2023),
+                        }),
+                        ..Default::default()
+                    },
+                )
+                .unwrap();
 
+            set_block_timestamp::<DefaultEnvironment>(
+                Fecha::new(15, 10, 2023).timestamp().unwrap(),
+            );
+            set_caller::<DefaultEnvironment>(accounts.django);
             assert_eq!(
-                eleccion.agregar_candidato(id_candidato, &Fecha::new(31, 12, 2024)),
-                Err(VotacionError::UsuarioNoPostuladoCandidato)
+                votacion.postular_votante(2),
+                Err(VotacionError::InscripcionNoIniciada)
             );
-        }
 
-        #[test]
-        fn test_agregar_votante_eleccion() {
-            let mut eleccion = Eleccion::new(0, Fecha::new(1, 1, 2025), Fecha::new(1, 1, 2025));
-            let votante_id = AccountId::from([0x2; 32]);
+            set_block_timestamp::<DefaultEnvironment>(
+                Fecha::new(15, 11, 2023).timestamp().unwrap(),
+            );
+            votacion.postular_votante(2).unwrap();
 
-            assert!(eleccion
-                .postular_votante(votante_id, &Fecha::new(31, 12, 2024))
-                .is_ok());
-            assert!(eleccion
-                .agregar_votante(votante_id, &Fecha::new(31, 12, 2024))
-                .is_ok());
-            assert!(eleccion.is_votante(&votante_id));
+            set_block_timestamp::<DefaultEnvironment>(
+                Fecha::new(15, 12, 2023).timestamp().unwrap(),
+            );
+            set_caller::<DefaultEnvironment>(accounts.charlie);
+            assert_eq!(
+                votacion.postular_candidato(2),
+                Err(VotacionError::InscripcionFinalizada)
+            );
+            // la autoridad revisa las postulaciones después del cierre de la inscripción
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            votacion.agregar_votante(2, accounts.django, 1).unwrap();
+            assert_eq!(
+                votacion.get_estado_participante(2, accounts.django),
+                Some(EstadoParticipante::Votante)
+            );
         }
 
-        #[test]
-        fn test_agregar_votante_eleccion_error_eleccion_ya_iniciada() {
-            let mut eleccion = Eleccion::new(0, Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024));
-            let votante_id = AccountId::from([0x2; 32]);
-
-            assert!(eleccion
-                .postular_votante(votante_id, &Fecha::new(1, 1, 2023))
-                .is_ok());
+        // tests de elegibilidad
+        #[ink::test]
+        fn test_crear_eleccion_error_reglas_elegibilidad_invalidas() {
+            let mut votacion = Votacion::new();
             assert_eq!(
-                eleccion.agregar_votante(votante_id, &Fecha::new(15, 6, 2024)),
-                Err(VotacionError::EleccionYaIniciada)
+                votacion.crear_eleccion(
+                    Fecha::new(1, 1, 2024),
+                    Fecha::new(31, 12, 2024),
+                    ConfigEleccion {
+                        elegibilidad: ReglasElegibilidad {
+                            edad_minima_votante: Some(30),
+                            edad_maxima_votante: Some(29),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    }
+                ),
+                Err(VotacionError::ReglasElegibilidadInvalidas)
             );
         }
 
-        #[test]
-        fn test_agregar_votante_eleccion_error_eleccion_ya_finalizada() {
-            let mut eleccion = Eleccion::new(0, Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024));
-            let votante_id = AccountId::from([0x1; 32]);
+        #[ink::test]
+        fn test_postular_usuario_error_fecha_nacimiento_invalida() {
+            let mut votacion = Votacion::new();
+            assert_eq!(
+                votacion.postular_usuario(
+                    "Juan".to_string(),
+                    "Perez".to_string(),
+                    "Calle Falsa 123".to_string(),
+                    "12345678".to_string(),
+                    Fecha::new(31, 2, 1994),
+                ),
+                Err(VotacionError::FechaNacimientoInvalida)
+            );
 
-            assert!(eleccion
-                .postular_votante(votante_id, &Fecha::new(1, 1, 2023))
-                .is_ok());
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2023).timestamp().unwrap());
             assert_eq!(
-                eleccion.agregar_votante(votante_id, &Fecha::new(1, 1, 2025)),
-                Err(VotacionError::EleccionYaFinalizada)
+                votacion.postular_usuario(
+                    "Juan".to_string(),
+                    "Perez".to_string(),
+                    "Calle Falsa 123".to_string(),
+                    "12345678".to_string(),
+                    Fecha::new(2, 1, 2023),
+                ),
+                Err(VotacionError::FechaNacimientoInvalida)
             );
         }
 
-        #[test]
-        fn test_agregar_votante_eleccion_error_usuario_no_postulado_votante() {
-            let mut eleccion = Eleccion::new(0, Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024));
-            let votante_id = AccountId::from([0x1; 32]);
+        #[ink::test]
+        fn test_habilitar_usuarios() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_roles();
+            votacion
+                .crear_eleccion(
+                    Fecha::new(1, 1, 2024),
+                    Fecha::new(31, 12, 2024),
+                    ConfigEleccion {
+                        elegibilidad: ReglasElegibilidad {
+                            solo_habilitados: true,
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    },
+                )
+                .unwrap();
 
+            set_caller::<DefaultEnvironment>(accounts.charlie);
             assert_eq!(
-                eleccion.agregar_votante(votante_id, &Fecha::new(1, 1, 2023)),
-                Err(VotacionError::UsuarioNoPostuladoVotante)
+                votacion.postular_candidato(2),
+                Err(VotacionError::UsuarioNoHabilitado)
+            );
+            assert_eq!(
+                votacion.habilitar_usuarios(2, vec![accounts.charlie]),
+                Err(VotacionError::NoTieneRol)
             );
-        }
-
-        #[test]
-        fn test_agregar_votante_eleccion_error_usuario_es_candidato() {
-            let mut eleccion = Eleccion::new(0, Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024));
-            let votante_id = AccountId::from([0x1; 32]);
 
-            assert!(eleccion
-                .postular_candidato(votante_id, &Fecha::new(31, 12, 2023))
-                .is_ok());
-            assert!(eleccion
-                .agregar_candidato(votante_id, &Fecha::new(31, 12, 2023))
-                .is_ok());
+            set_caller::<DefaultEnvironment>(accounts.alice);
             assert_eq!(
-                eleccion.postular_votante(votante_id, &Fecha::new(31, 12, 2023)),
-                Err(VotacionError::UsuarioEsCandidato)
+                votacion.habilitar_usuarios(0, vec![accounts.charlie]),
+                Err(VotacionError::EleccionSinHabilitados)
             );
-        }
+            votacion
+                .habilitar_usuarios(2, vec![accounts.charlie, accounts.django])
+                .unwrap();
+            assert_eq!(
+                ultimo_evento::<UsuariosHabilitados>(),
+                UsuariosHabilitados {
+                    id_eleccion: 2,
+                    usuarios: vec![accounts.charlie, accounts.django],
+                }
+            );
+            assert!(votacion.get_habilitado(2, accounts.charlie));
+            assert!(!votacion.get_habilitado(0, accounts.charlie));
 
-        #[test]
-        fn test_agregar_votante_eleccion_error_usuario_es_votante() {
-            let mut eleccion = Eleccion::new(0, Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024));
-            let votante_id = AccountId::from([0x1; 32]);
+            set_caller::<DefaultEnvironment>(accounts.charlie);
+            votacion.postular_candidato(2).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.django);
+            votacion.postular_votante(2).unwrap();
 
-            assert!(eleccion
-                .postular_votante(votante_id, &Fecha::new(31, 12, 2023))
-                .is_ok());
-            assert!(eleccion
-                .agregar_votante(votante_id, &Fecha::new(31, 12, 2023))
-                .is_ok());
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 6, 2024).timestamp().unwrap());
+            set_caller::<DefaultEnvironment>(accounts.alice);
             assert_eq!(
-                eleccion.postular_votante(votante_id, &Fecha::new(31, 12, 2023)),
-                Err(VotacionError::UsuarioEsVotante)
+                votacion.habilitar_usuarios(2, vec![accounts.bob]),
+                Err(VotacionError::EleccionYaIniciada)
             );
         }
 
-        #[test]
-        fn test_votar_eleccion() {
-            let votante_id = AccountId::from([0x2; 32]);
-            let id_candidato = AccountId::from([0x1; 32]);
-            let mut eleccion = Eleccion::new(0, Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024));
-
-            eleccion
-                .postular_votante(votante_id, &Fecha::new(31, 12, 2023))
-                .unwrap();
-            eleccion
-                .postular_candidato(id_candidato, &Fecha::new(31, 12, 2023))
-                .unwrap();
-            eleccion
-                .agregar_candidato(id_candidato, &Fecha::new(31, 12, 2023))
-                .unwrap();
-            eleccion
-                .agregar_votante(votante_id, &Fecha::new(31, 12, 2023))
+        #[ink::test]
+        fn test_postular_votante_error_edad_minima() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_roles();
+            votacion
+                .crear_eleccion(
+                    Fecha::new(1, 1, 2024),
+                    Fecha::new(31, 12, 2024),
+                    ConfigEleccion {
+                        elegibilidad: ReglasElegibilidad {
+                            edad_minima_votante: Some(31),
+                            edad_minima_candidato: Some(35),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    },
+                )
                 .unwrap();
 
-            assert!(eleccion
-                .votar(&votante_id, &id_candidato, &Fecha::new(15, 6, 2024))
-                .is_ok());
+            // charlie y django cumplen 30 años el día de inicio de la elección
+            set_caller::<DefaultEnvironment>(accounts.django);
             assert_eq!(
-                eleccion.get_votos_candidato(&id_candidato, &Fecha::new(1, 1, 2025)),
-                Ok(1)
+                votacion.postular_votante(2),
+                Err(VotacionError::EdadMenorAMinima)
             );
+            set_caller::<DefaultEnvironment>(accounts.charlie);
+            assert_eq!(
+                votacion.postular_candidato(2),
+                Err(VotacionError::EdadMenorAMinimaCandidato)
+            );
+            assert_eq!(votacion.get_estado_participante(2, accounts.charlie), None);
         }
 
-        #[test]
-        fn test_votar_eleccion_error_eleccion_no_iniciada() {
-            let mut eleccion = Eleccion::new(0, Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024));
-            let votante_id = AccountId::from([0x2; 32]);
-            let id_candidato = AccountId::from([0x1; 32]);
-
-            eleccion
-                .postular_votante(votante_id, &Fecha::new(31, 12, 2023))
-                .unwrap();
-            eleccion
-                .postular_candidato(id_candidato, &Fecha::new(31, 12, 2023))
-                .unwrap();
-            eleccion
-                .agregar_votante(votante_id, &Fecha::new(31, 12, 2023))
+        // tests de roles
+        #[ink::test]
+        fn test_otorgar_rol() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_roles();
+            assert!(!votacion.tiene_rol(accounts.bob, Rol::Registrador));
+            votacion
+                .otorgar_rol(accounts.bob, Rol::Registrador)
                 .unwrap();
-            eleccion
-                .agregar_candidato(id_candidato, &Fecha::new(31, 12, 2023))
+            votacion
+                .otorgar_rol(accounts.eve, Rol::Registrador)
                 .unwrap();
-
+            assert!(votacion.tiene_rol(accounts.bob, Rol::Registrador));
+            assert!(!votacion.tiene_rol(accounts.bob, Rol::Admin));
             assert_eq!(
-                eleccion.votar(&votante_id, &id_candidato, &Fecha::new(31, 12, 2023)),
-                Err(VotacionError::EleccionNoIniciada)
+                votacion.get_cuentas_rol(Rol::Registrador),
+                vec![accounts.bob, accounts.eve]
+            );
+            assert_eq!(
+                votacion.otorgar_rol(accounts.bob, Rol::Registrador),
+                Err(VotacionError::RolYaOtorgado)
             );
         }
 
-        #[test]
-        fn test_votar_eleccion_error_eleccion_ya_finalizada() {
-            let mut eleccion = Eleccion::new(0, Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024));
-            let votante_id = AccountId::from([0x2; 32]);
-            let id_candidato = AccountId::from([0x1; 32]);
-
-            eleccion
-                .postular_votante(votante_id, &Fecha::new(31, 12, 2023))
-                .unwrap();
-            eleccion
-                .postular_candidato(id_candidato, &Fecha::new(31, 12, 2023))
-                .unwrap();
-            eleccion
-                .agregar_votante(votante_id, &Fecha::new(31, 12, 2023))
-                .unwrap();
-            eleccion
-                .agregar_candidato(id_candidato, &Fecha::new(31, 12, 2023))
-                .unwrap();
-
+        #[ink::test]
+        fn test_otorgar_rol_error_no_admin() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_roles();
+            set_caller::<DefaultEnvironment>(accounts.bob);
             assert_eq!(
-                eleccion.votar(&votante_id, &id_candidato, &Fecha::new(1, 1, 2025)),
-                Err(VotacionError::EleccionYaFinalizada)
+                votacion.otorgar_rol(accounts.bob, Rol::Admin),
+                Err(VotacionError::NoEsAdmin)
             );
+            assert!(votacion.get_cuentas_rol(Rol::Admin).is_empty());
         }
 
-        #[test]
-        fn test_votar_eleccion_error_usuario_no_es_votante_1() {
-            let id_candidato = AccountId::from([0x1; 32]);
-            let votante_id = AccountId::from([0x2; 32]);
-
-            let mut eleccion = Eleccion::new(0, Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024));
-            eleccion
-                .postular_candidato(id_candidato, &Fecha::new(31, 12, 2023))
-                .unwrap();
-            eleccion
-                .agregar_candidato(id_candidato, &Fecha::new(31, 12, 2023))
-                .unwrap();
-
+        #[ink::test]
+        fn test_otorgar_rol_autoridad_error_eleccion_no_encontrada() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_roles();
             assert_eq!(
-                eleccion.votar(&votante_id, &id_candidato, &Fecha::new(15, 6, 2024)),
-                Err(VotacionError::UsuarioNoEsVotante)
+                votacion.otorgar_rol(accounts.bob, Rol::Autoridad(2)),
+                Err(VotacionError::EleccionNoEncontrada)
             );
         }
 
-        #[test]
-        fn test_votar_eleccion_error_usuario_no_es_votante_2() {
-            let id_candidato = AccountId::from([0x1; 32]);
-            let votante_id = AccountId::from([0x2; 32]);
-
-            let mut eleccion = Eleccion::new(0, Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024));
-            eleccion
-                .postular_candidato(id_candidato, &Fecha::new(31, 12, 2023))
+        #[ink::test]
+        fn test_revocar_rol() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_roles();
+            votacion
+                .otorgar_rol(accounts.bob, Rol::Registrador)
                 .unwrap();
-            eleccion
-                .agregar_candidato(id_candidato, &Fecha::new(31, 12, 2023))
+            votacion
+                .otorgar_rol(accounts.eve, Rol::Registrador)
                 .unwrap();
-            //Postulo un al votante pero no lo acepta el admin
-            eleccion
-                .postular_votante(votante_id, &Fecha::new(31, 12, 2023))
+            votacion
+                .revocar_rol(accounts.bob, Rol::Registrador)
                 .unwrap();
+            assert!(!votacion.tiene_rol(accounts.bob, Rol::Registrador));
+            assert_eq!(
+                votacion.get_cuentas_rol(Rol::Registrador),
+                vec![accounts.eve]
+            );
+            assert_eq!(
+                votacion.revocar_rol(accounts.bob, Rol::Registrador),
+                Err(VotacionError::RolNoOtorgado)
+            );
 
+            set_caller::<DefaultEnvironment>(accounts.bob);
             assert_eq!(
-                eleccion.votar(&votante_id, &id_candidato, &Fecha::new(15, 6, 2024)),
-                Err(VotacionError::UsuarioNoEsVotante)
+                votacion.aceptar_usuario(accounts.bob),
+                Err(VotacionError::NoTieneRol)
+            );
+            assert_eq!(
+                votacion.revocar_rol(accounts.eve, Rol::Registrador),
+                Err(VotacionError::NoEsAdmin)
             );
         }
 
-        #[test]
-        fn test_votar_eleccion_error_usuario_no_es_candidato_1() {
-            let id_candidato = AccountId::from([0x1; 32]);
-            let votante_id = AccountId::from([0x2; 32]);
-
-            let mut eleccion = Eleccion::new(0, Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024));
-            eleccion
-                .postular_votante(votante_id, &Fecha::new(31, 12, 2023))
-                .unwrap();
-            eleccion
-                .agregar_votante(votante_id, &Fecha::new(31, 12, 2023))
+        #[ink::test]
+        fn test_registrador_acepta_usuarios() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_roles();
+            votacion
+                .otorgar_rol(accounts.eve, Rol::Registrador)
                 .unwrap();
-
+            set_caller::<DefaultEnvironment>(accounts.eve);
+            votacion.aceptar_usuario(accounts.bob).unwrap();
+            assert!(votacion.get_usuario(accounts.bob).is_ok());
             assert_eq!(
-                eleccion.votar(&votante_id, &id_candidato, &Fecha::new(15, 6, 2024)),
-                Err(VotacionError::UsuarioNoEsCandidato)
+                votacion.agregar_candidato(0, accounts.charlie),
+                Err(VotacionError::NoTieneRol)
+            );
+            assert_eq!(
+                votacion.set_reporte(accounts.eve),
+                Err(VotacionError::NoEsAdmin)
             );
         }
 
-        #[test]
-        fn test_votar_eleccion_error_usuario_no_es_candidato_2() {
-            let id_candidato = AccountId::from([0x1; 32]);
-            let votante_id = AccountId::from([0x2; 32]);
-
-            let mut eleccion = Eleccion::new(0, Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024));
-            eleccion
-                .postular_votante(votante_id, &Fecha::new(31, 12, 2023))
-                .unwrap();
-            eleccion
-                .agregar_votante(votante_id, &Fecha::new(31, 12, 2023))
-                .unwrap();
-            //Postulo un candidato pero no lo acepta el admin
-            eleccion
-                .postular_candidato(id_candidato, &Fecha::new(31, 12, 2023))
+        #[ink::test]
+        fn test_autoridad_agrega_participantes_de_su_eleccion() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_roles();
+            votacion
+                .otorgar_rol(accounts.eve, Rol::Autoridad(0))
                 .unwrap();
-
+            set_caller::<DefaultEnvironment>(accounts.eve);
+            votacion.agregar_candidato(0, accounts.charlie).unwrap();
+            votacion.agregar_votante(0, accounts.django, 1).unwrap();
             assert_eq!(
-                eleccion.votar(&votante_id, &id_candidato, &Fecha::new(15, 6, 2024)),
-                Err(VotacionError::UsuarioNoEsCandidato)
+                votacion.agregar_candidato(1, accounts.charlie),
+                Err(VotacionError::NoTieneRol)
+            );
+            assert_eq!(
+                votacion.agregar_votante(1, accounts.django, 1),
+                Err(VotacionError::NoTieneRol)
+            );
+            assert_eq!(
+                votacion.aceptar_usuario(accounts.bob),
+                Err(VotacionError::NoTieneRol)
             );
         }
 
-        #[test]
-        fn test_votar_eleccion_error_usuario_ya_voto() {
-            let votante_id = AccountId::from([0x2; 32]);
-            let id_candidato = AccountId::from([0x1; 32]);
-            let mut eleccion = Eleccion::new(0, Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024));
-
-            eleccion
-                .postular_votante(votante_id, &Fecha::new(31, 12, 2023))
-                .unwrap();
-            eleccion
-                .postular_candidato(id_candidato, &Fecha::new(31, 12, 2023))
-                .unwrap();
-            eleccion
-                .agregar_candidato(id_candidato, &Fecha::new(31, 12, 2023))
-                .unwrap();
-            eleccion
-                .agregar_votante(votante_id, &Fecha::new(31, 12, 2023))
-                .unwrap();
-
-            eleccion
-                .votar(&votante_id, &id_candidato, &Fecha::new(15, 6, 2024))
+        #[ink::test]
+        fn test_rol_admin() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_roles();
+            votacion.otorgar_rol(accounts.eve, Rol::Admin).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.eve);
+            assert!(votacion.caller_is_admin());
+            votacion.aceptar_usuario(accounts.bob).unwrap();
+            votacion.agregar_candidato(1, accounts.charlie).unwrap();
+            votacion
+                .otorgar_rol(accounts.bob, Rol::Autoridad(1))
                 .unwrap();
 
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            votacion.revocar_rol(accounts.eve, Rol::Admin).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.eve);
+            assert!(!votacion.caller_is_admin());
             assert_eq!(
-                eleccion.votar(&votante_id, &id_candidato, &Fecha::new(15, 6, 2024)),
-                Err(VotacionError::UsuarioYaVoto)
+                votacion.revocar_rol(accounts.bob, Rol::Autoridad(1)),
+                Err(VotacionError::NoEsAdmin)
             );
         }
 
-        #[test]
-        fn test_ya_voto_eleccion() {
-            let votante_id = AccountId::from([0x2; 32]);
-            let id_candidato = AccountId::from([0x1; 32]);
-            let mut eleccion = Eleccion::new(0, Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024));
-
-            eleccion
-                .postular_votante(votante_id, &Fecha::new(31, 12, 2023))
-                .unwrap();
-            eleccion
-                .postular_candidato(id_candidato, &Fecha::new(31, 12, 2023))
-                .unwrap();
-            eleccion
-                .agregar_candidato(id_candidato, &Fecha::new(31, 12, 2023))
-                .unwrap();
-            eleccion
-                .agregar_votante(votante_id, &Fecha::new(31, 12, 2023))
+        /// Crea un contrato con alice como admin y un consejo de alice, bob y charlie
+        /// que necesita dos aprobaciones
+        fn default_con_consejo() -> Votacion {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            let mut votacion = Votacion::new();
+            votacion
+                .configurar_consejo(vec![accounts.alice, accounts.bob, accounts.charlie], 2)
                 .unwrap();
+            votacion
+        }
 
-            assert!(!eleccion.ya_voto(&votante_id));
-            eleccion
-                .votar(&votante_id, &id_candidato, &Fecha::new(15, 6, 2024))
-                .unwrap();
-            assert!(eleccion.ya_voto(&votante_id));
+        // tests de dni
+        #[test]
+        fn test_validar_formato_dni() {
+            assert_eq!(Usuario::validar_formato_dni("12345678"), Ok(()));
+            assert_eq!(Usuario::validar_formato_dni("1234567"), Ok(()));
+            assert_eq!(
+                Usuario::validar_formato_dni(""),
+                Err(VotacionError::DniVacio)
+            );
+            assert_eq!(
+                Usuario::validar_formato_dni("12.345.678"),
+                Err(VotacionError::DniNoNumerico)
+            );
+            assert_eq!(
+                Usuario::validar_formato_dni("1234567A"),
+                Err(VotacionError::DniNoNumerico)
+            );
+            assert_eq!(
+                Usuario::validar_formato_dni("123456"),
+                Err(VotacionError::DniLongitudInvalida)
+            );
+            assert_eq!(
+                Usuario::validar_formato_dni("123456789"),
+                Err(VotacionError::DniLongitudInvalida)
+            );
+            assert_eq!(Usuario::validar_formato_dni("01234567"), Ok(()));
+            assert_eq!(
+                Usuario::validar_formato_dni("00123456"),
+                Err(VotacionError::DniLongitudInvalida)
+            );
         }
 
-        // tests de EleccionSystemInk
         #[ink::test]
-        fn test_postular_candidato_votacion() {
+        fn test_postular_usuario_error_dni_invalido() {
             let accounts = default_accounts::<DefaultEnvironment>();
             set_caller::<DefaultEnvironment>(accounts.alice);
             let mut votacion = Votacion::new();
-            let id_eleccion = votacion
-                .crear_eleccion(Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024))
-                .unwrap();
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2023).timestamp().unwrap());
+            for (dni, error) in [
+                ("", VotacionError::DniVacio),
+                ("12-345-678", VotacionError::DniNoNumerico),
+                ("123", VotacionError::DniLongitudInvalida),
+            ] {
+                assert_eq!(
+                    votacion.postular_usuario(
+                        "Alice".to_string(),
+                        "Test".to_string(),
+                        "Calle Falsa 123".to_string(),
+                        dni.to_string(),
+                        Fecha::new(1, 1, 1994),
+                    ),
+                    Err(error)
+                );
+            }
+            assert!(votacion.get_usuario_sin_aceptar(accounts.alice).is_err());
+        }
 
-            set_block_timestamp::<DefaultEnvironment>(
-                Fecha::new(31, 12, 2023).timestamp().unwrap(),
-            );
+        #[ink::test]
+        fn test_postular_usuario_error_dni_duplicado() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_roles();
+            // dni de un usuario sin aceptar (bob) y de uno aceptado (charlie)
+            for dni in ["22222222", "33333333"] {
+                set_caller::<DefaultEnvironment>(accounts.eve);
+                assert_eq!(
+                    votacion.postular_usuario(
+                        "Eve".to_string(),
+                        "Test".to_string(),
+                        "Calle Falsa 123".to_string(),
+                        dni.to_string(),
+                        Fecha::new(1, 1, 1994),
+                    ),
+                    Err(VotacionError::DniDuplicado)
+                );
+            }
 
-            set_caller::<DefaultEnvironment>(accounts.bob);
+            // el dni se libera al rechazar al usuario
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            votacion
+                .rechazar_usuario(accounts.bob, MotivoRechazo::DocumentoDuplicado)
+                .unwrap();
+            set_caller::<DefaultEnvironment>(accounts.eve);
             votacion
                 .postular_usuario(
-                    "Juan".to_string(),
-                    "Perez".to_string(),
+                    "Eve".to_string(),
+                    "Test".to_string(),
                     "Calle Falsa 123".to_string(),
-                    "12345678".to_string(),
-                    30,
+                    "22222222".to_string(),
+                    Fecha::new(1, 1, 1994),
                 )
                 .unwrap();
-            set_caller::<DefaultEnvironment>(accounts.alice);
-            votacion.aceptar_usuario(accounts.bob).unwrap();
-
-            set_caller::<DefaultEnvironment>(accounts.bob);
-            assert!(votacion.postular_candidato(id_eleccion).is_ok());
-            let eleccion = votacion.get_eleccion(id_eleccion).unwrap();
-            assert!(eleccion.is_postulado_candidato(&accounts.bob));
         }
 
         #[ink::test]
-        fn test_postular_candidato_votacion_error_usuario_no_aceptado() {
+        fn test_postular_usuario_error_dni_duplicado_con_ceros() {
             let accounts = default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.alice);
             let mut votacion = Votacion::new();
-            let id_eleccion = votacion
-                .crear_eleccion(Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024))
-                .unwrap();
-
-            set_block_timestamp::<DefaultEnvironment>(
-                Fecha::new(31, 12, 2023).timestamp().unwrap(),
-            );
-
-            //Creo el usuario pero no es aceptado
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2023).timestamp().unwrap());
             set_caller::<DefaultEnvironment>(accounts.bob);
             votacion
                 .postular_usuario(
-                    "Juan".to_string(),
-                    "Perez".to_string(),
+                    "Bob".to_string(),
+                    "Test".to_string(),
                     "Calle Falsa 123".to_string(),
-                    "12345678".to_string(),
-                    30,
+                    "1234567".to_string(),
+                    Fecha::new(1, 1, 1994),
                 )
                 .unwrap();
-            //votacion.aceptar_usuario(accounts.bob).unwrap();
-
+            set_caller::<DefaultEnvironment>(accounts.eve);
             assert_eq!(
-                votacion.postular_candidato(id_eleccion),
-                Err(VotacionError::UsuarioNoAceptado)
+                votacion.postular_usuario(
+                    "Eve".to_string(),
+                    "Test".to_string(),
+                    "Calle Falsa 123".to_string(),
+                    "01234567".to_string(),
+                    Fecha::new(1, 1, 1994),
+                ),
+                Err(VotacionError::DniDuplicado)
             );
         }
 
         #[ink::test]
-        fn test_postular_candidato_votacion_error_usuario_no_encontrado() {
+        fn test_dni_se_libera_al_darse_de_baja() {
             let accounts = default_accounts::<DefaultEnvironment>();
-            set_caller::<DefaultEnvironment>(accounts.alice);
-            let mut votacion = Votacion::new();
-            let id_eleccion = votacion
-                .crear_eleccion(Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024))
+            let mut votacion = default_con_roles();
+            set_caller::<DefaultEnvironment>(accounts.charlie);
+            votacion.darse_de_baja().unwrap();
+            set_caller::<DefaultEnvironment>(accounts.eve);
+            votacion
+                .postular_usuario(
+                    "Eve".to_string(),
+                    "Test".to_string(),
+                    "Calle Falsa 123".to_string(),
+                    "33333333".to_string(),
+                    Fecha::new(1, 1, 1994),
+                )
                 .unwrap();
-
-            set_block_timestamp::<DefaultEnvironment>(
-                Fecha::new(31, 12, 2023).timestamp().unwrap(),
-            );
-
-            set_caller::<DefaultEnvironment>(accounts.bob);
-            let resultado = votacion.postular_candidato(id_eleccion);
-            assert_eq!(resultado, Err(VotacionError::UsuarioNoEncontrado));
         }
 
         #[ink::test]
-        fn test_agregar_candidato_votacion() {
+        fn test_actualizar_usuario_dni() {
             let accounts = default_accounts::<DefaultEnvironment>();
-            set_caller::<DefaultEnvironment>(accounts.alice);
-            let mut votacion = Votacion::new();
-            let id_eleccion = votacion
-                .crear_eleccion(Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024))
-                .unwrap();
-
-            set_block_timestamp::<DefaultEnvironment>(
-                Fecha::new(31, 12, 2023).timestamp().unwrap(),
+            let mut votacion = default_con_roles();
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                votacion.actualizar_usuario(
+                    "Bob".to_string(),
+                    "Test".to_string(),
+                    "Calle Falsa 123".to_string(),
+                    "33333333".to_string(),
+                    Fecha::new(1, 1, 1994),
+                ),
+                Err(VotacionError::DniDuplicado)
+            );
+            assert_eq!(
+                votacion.actualizar_usuario(
+                    "Bob".to_string(),
+                    "Test".to_string(),
+                    "Calle Falsa 123".to_string(),
+                    "2222".to_string(),
+                    Fecha::new(1, 1, 1994),
+                ),
+                Err(VotacionError::DniLongitudInvalida)
             );
+            votacion
+                .actualizar_usuario(
+                    "Bob".to_string(),
+                    "Test".to_string(),
+                    "Calle Falsa 123".to_string(),
+                    "22222223".to_string(),
+                    Fecha::new(1, 1, 1994),
+                )
+                .unwrap();
 
-            set_caller::<DefaultEnvironment>(accounts.bob);
+            // el dni anterior queda libre
+            set_caller::<DefaultEnvironment>(accounts.eve);
             votacion
                 .postular_usuario(
-                    "Juan".to_string(),
-                    "Perez".to_string(),
+                    "Eve".to_string(),
+                    "Test".to_string(),
                     "Calle Falsa 123".to_string(),
-                    "12345678".to_string(),
-                    30,
+                    "22222222".to_string(),
+                    Fecha::new(1, 1, 1994),
                 )
                 .unwrap();
-            set_caller::<DefaultEnvironment>(accounts.alice);
-            votacion.aceptar_usuario(accounts.bob).unwrap();
-
-            set_caller::<DefaultEnvironment>(accounts.bob);
-            assert!(votacion.postular_candidato(id_eleccion).is_ok());
-            set_caller::<DefaultEnvironment>(accounts.alice);
-            assert!(votacion
-                .agregar_candidato(id_eleccion, accounts.bob)
-                .is_ok());
-            let eleccion = votacion.get_eleccion(id_eleccion).unwrap();
-            assert!(eleccion.is_candidato(&accounts.bob));
         }
 
+        // tests de modo privado
         #[ink::test]
-        fn test_agregar_candidato_votacion_error_no_es_admin() {
+        fn test_postular_usuario_privado() {
             let accounts = default_accounts::<DefaultEnvironment>();
             set_caller::<DefaultEnvironment>(accounts.alice);
-            let mut votacion = Votacion::new();
-            let id_eleccion = votacion
-                .crear_eleccion(Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024))
-                .unwrap();
-
-            set_block_timestamp::<DefaultEnvironment>(
-                Fecha::new(31, 12, 2023).timestamp().unwrap(),
+            let mut votacion = Votacion::new_privado();
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2023).timestamp().unwrap());
+            assert!(votacion.get_modo_privado());
+            let hash_datos = Votacion::calcular_hash_datos(
+                "12345678".to_string(),
+                "Calle Falsa 123".to_string(),
+                [0x7; 32],
             );
-
             set_caller::<DefaultEnvironment>(accounts.bob);
             votacion
-                .postular_usuario(
-                    "Juan".to_string(),
-                    "Perez".to_string(),
-                    "Calle Falsa 123".to_string(),
-                    "12345678".to_string(),
-                    30,
+                .postular_usuario_privado(
+                    "Bob".to_string(),
+                    "Test".to_string(),
+                    hash_datos,
+                    Fecha::new(1, 1, 1994),
                 )
                 .unwrap();
+            let usuario = votacion.get_usuario_sin_aceptar(accounts.bob).unwrap();
+            assert_eq!(usuario.get_dni(), "");
+            assert_eq!(usuario.get_direccion(), "");
+            // el registrador verifica los datos del postulado con el hash
+            assert_eq!(
+                usuario.get_hash_datos(),
+                Some(Votacion::calcular_hash_datos(
+                    "12345678".to_string(),
+                    "Calle Falsa 123".to_string(),
+                    [0x7; 32],
+                ))
+            );
+            assert_ne!(
+                usuario.get_hash_datos(),
+                Some(Votacion::calcular_hash_datos(
+                    "12345678".to_string(),
+                    "Calle Falsa 123".to_string(),
+                    [0x8; 32],
+                ))
+            );
             set_caller::<DefaultEnvironment>(accounts.alice);
             votacion.aceptar_usuario(accounts.bob).unwrap();
-
-            set_caller::<DefaultEnvironment>(accounts.bob);
-            assert!(votacion.postular_candidato(id_eleccion).is_ok());
-            let resultado = votacion.agregar_candidato(id_eleccion, accounts.bob);
-            assert_eq!(resultado, Err(VotacionError::NoEsAdmin));
         }
 
         #[ink::test]
-        fn test_postular_votante_votacion() {
+        fn test_modo_privado_errores() {
             let accounts = default_accounts::<DefaultEnvironment>();
             set_caller::<DefaultEnvironment>(accounts.alice);
-            let mut votacion = Votacion::new();
-            let id_eleccion = votacion
-                .crear_eleccion(Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024))
-                .unwrap();
+            let mut votacion = Votacion::new_privado();
+            assert_eq!(
+                votacion.postular_usuario(
+                    "Alice".to_string(),
+                    "Test".to_string(),
+                    "Calle Falsa 123".to_string(),
+                    "12345678".to_string(),
+                    Fecha::new(1, 1, 1994),
+                ),
+                Err(VotacionError::ModoPrivadoActivo)
+            );
+            assert_eq!(
+                votacion.actualizar_usuario(
+                    "Alice".to_string(),
+                    "Test".to_string(),
+                    "Calle Falsa 123".to_string(),
+                    "12345678".to_string(),
+                    Fecha::new(1, 1, 1994),
+                ),
+                Err(VotacionError::ModoPrivadoActivo)
+            );
 
-            set_block_timestamp::<DefaultEnvironment>(
-                Fecha::new(31, 12, 2023).timestamp().unwrap(),
+            let mut votacion = Votacion::new();
+            assert!(!votacion.get_modo_privado());
+            assert_eq!(
+                votacion.postular_usuario_privado(
+                    "Alice".to_string(),
+                    "Test".to_string(),
+                    [0x1; 32],
+                    Fecha::new(1, 1, 1994)
+                ),
+                Err(VotacionError::ModoPrivadoInactivo)
+            );
+            assert_eq!(
+                votacion.actualizar_usuario_privado(
+                    "Alice".to_string(),
+                    "Test".to_string(),
+                    [0x1; 32],
+                    Fecha::new(1, 1, 1994)
+                ),
+                Err(VotacionError::ModoPrivadoInactivo)
             );
+        }
 
+        #[ink::test]
+        fn test_actualizar_usuario_privado() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            let mut votacion = Votacion::new_privado();
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2023).timestamp().unwrap());
             set_caller::<DefaultEnvironment>(accounts.bob);
             votacion
-                .postular_usuario(
-                    "Juan".to_string(),
-                    "Perez".to_string(),
-                    "Calle Falsa 123".to_string(),
-                    "12345678".to_string(),
-                    30,
+                .postular_usuario_privado(
+                    "Bob".to_string(),
+                    "Test".to_string(),
+                    [0x1; 32],
+                    Fecha::new(1, 1, 1994),
                 )
                 .unwrap();
             set_caller::<DefaultEnvironment>(accounts.alice);
             votacion.aceptar_usuario(accounts.bob).unwrap();
 
+            // la fecha de nacimiento es un dato de identidad
             set_caller::<DefaultEnvironment>(accounts.bob);
-            assert!(votacion.postular_votante(id_eleccion).is_ok());
-            let eleccion = votacion.get_eleccion(id_eleccion).unwrap();
-            assert!(eleccion.is_postulado_votante(&accounts.bob));
-        }
-
-        #[ink::test]
-        fn test_postular_votante_votacion_error_usuario_no_aceptado() {
-            let accounts = default_accounts::<DefaultEnvironment>();
-            let mut votacion = Votacion::new();
-            let id_eleccion = votacion
-                .crear_eleccion(Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024))
+            votacion
+                .actualizar_usuario_privado(
+                    "Bob".to_string(),
+                    "Test".to_string(),
+                    [0x1; 32],
+                    Fecha::new(1, 1, 1993),
+                )
                 .unwrap();
-
-            set_block_timestamp::<DefaultEnvironment>(
-                Fecha::new(31, 12, 2023).timestamp().unwrap(),
+            assert_eq!(
+                votacion
+                    .get_usuario_sin_aceptar(accounts.bob)
+                    .unwrap()
+                    .get_fecha_nacimiento(),
+                Fecha::new(1, 1, 1993)
             );
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            votacion.aceptar_usuario(accounts.bob).unwrap();
 
-            //Creo el usuario pero no es aceptado
+            // un cambio en el hash de los datos requiere validar al usuario otra vez
             set_caller::<DefaultEnvironment>(accounts.bob);
             votacion
-                .postular_usuario(
-                    "Juan".to_string(),
-                    "Perez".to_string(),
-                    "Calle Falsa 123".to_string(),
-                    "12345678".to_string(),
-                    30,
+                .actualizar_usuario_privado(
+                    "Bob".to_string(),
+                    "Test".to_string(),
+                    [0x2; 32],
+                    Fecha::new(1, 1, 1993),
                 )
                 .unwrap();
-            //votacion.aceptar_usuario(accounts.bob).unwrap();
-
             assert_eq!(
-                votacion.postular_votante(id_eleccion),
-                Err(VotacionError::UsuarioNoAceptado)
+                votacion.get_usuario(accounts.bob),
+                Err(VotacionError::UsuarioNoEncontrado)
+            );
+            assert_eq!(
+                votacion
+                    .get_usuario_sin_aceptar(accounts.bob)
+                    .unwrap()
+                    .get_hash_datos(),
+                Some([0x2; 32])
             );
         }
 
+        // tests de actualización y baja de usuarios
         #[ink::test]
-        fn test_postular_votante_votacion_error_usuario_no_encontrado() {
+        fn test_actualizar_usuario_postulado() {
             let accounts = default_accounts::<DefaultEnvironment>();
-            let mut votacion = Votacion::new();
-            let id_eleccion = votacion
-                .crear_eleccion(Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024))
-                .unwrap();
-
-            set_block_timestamp::<DefaultEnvironment>(
-                Fecha::new(31, 12, 2023).timestamp().unwrap(),
-            );
-
+            let mut votacion = default_con_roles();
             set_caller::<DefaultEnvironment>(accounts.bob);
-            let resultado = votacion.postular_votante(id_eleccion);
-            assert_eq!(resultado, Err(VotacionError::UsuarioNoEncontrado));
+            votacion
+                .actualizar_usuario(
+                    "Roberto".to_string(),
+                    "Test".to_string(),
+                    "Calle Verdadera 456".to_string(),
+                    "22222222".to_string(),
+                    Fecha::new(1, 1, 1993),
+                )
+                .unwrap();
+            let usuario = votacion.get_usuario_sin_aceptar(accounts.bob).unwrap();
+            assert_eq!(usuario.get_nombre(), "Roberto");
+            assert_eq!(usuario.get_direccion(), "Calle Verdadera 456");
+            assert_eq!(usuario.get_fecha_nacimiento(), Fecha::new(1, 1, 1993));
         }
 
         #[ink::test]
-        fn test_agregar_votante_votacion() {
+        fn test_actualizar_usuario_aceptado() {
             let accounts = default_accounts::<DefaultEnvironment>();
-            set_caller::<DefaultEnvironment>(accounts.alice);
-            let mut votacion = Votacion::new();
-            let id_eleccion = votacion
-                .crear_eleccion(Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024))
+            let mut votacion = default_con_roles();
+            set_caller::<DefaultEnvironment>(accounts.charlie);
+            // sin cambios de identidad sigue aceptado
+            votacion
+                .actualizar_usuario(
+                    "Charlie".to_string(),
+                    "Test".to_string(),
+                    "Calle Verdadera 456".to_string(),
+                    "33333333".to_string(),
+                    Fecha::new(1, 1, 1994),
+                )
                 .unwrap();
-
-            set_block_timestamp::<DefaultEnvironment>(
-                Fecha::new(31, 12, 2023).timestamp().unwrap(),
+            assert_eq!(
+                votacion
+                    .get_usuario(accounts.charlie)
+                    .unwrap()
+                    .get_direccion(),
+                "Calle Verdadera 456"
             );
 
-            set_caller::<DefaultEnvironment>(accounts.bob);
+            // con cambios de identidad vuelve a validarse
             votacion
-                .postular_usuario(
-                    "Juan".to_string(),
-                    "Perez".to_string(),
-                    "Calle Falsa 123".to_string(),
-                    "12345678".to_string(),
-                    30,
+                .actualizar_usuario(
+                    "Charlie".to_string(),
+                    "Test".to_string(),
+                    "Calle Verdadera 456".to_string(),
+                    "33333334".to_string(),
+                    Fecha::new(1, 1, 1993),
                 )
                 .unwrap();
+            assert_eq!(
+                votacion.get_usuario(accounts.charlie),
+                Err(VotacionError::UsuarioNoEncontrado)
+            );
+            assert_eq!(
+                votacion
+                    .get_usuario_sin_aceptar(accounts.charlie)
+                    .unwrap()
+                    .get_dni(),
+                "33333334"
+            );
             set_caller::<DefaultEnvironment>(accounts.alice);
-            votacion.aceptar_usuario(accounts.bob).unwrap();
-
-            set_caller::<DefaultEnvironment>(accounts.bob);
-            assert!(votacion.postular_votante(id_eleccion).is_ok());
-
-            set_caller::<DefaultEnvironment>(accounts.alice);
-            assert!(votacion.agregar_votante(id_eleccion, accounts.bob).is_ok());
-            let eleccion = votacion.get_eleccion(id_eleccion).unwrap();
-            assert!(eleccion.is_votante(&accounts.bob));
+            votacion.aceptar_usuario(accounts.charlie).unwrap();
         }
 
         #[ink::test]
-        fn test_agregar_votante_votacion_error_no_es_admin() {
+        fn test_actualizar_usuario_error_usuario_no_encontrado() {
             let accounts = default_accounts::<DefaultEnvironment>();
-            set_caller::<DefaultEnvironment>(accounts.alice);
-            let mut votacion = Votacion::new();
-            let id_eleccion = votacion
-                .crear_eleccion(Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024))
-                .unwrap();
-
-            set_block_timestamp::<DefaultEnvironment>(
-                Fecha::new(31, 12, 2023).timestamp().unwrap(),
+            let mut votacion = default_con_roles();
+            set_caller::<DefaultEnvironment>(accounts.eve);
+            assert_eq!(
+                votacion.actualizar_usuario(
+                    "Eve".to_string(),
+                    "Test".to_string(),
+                    "Calle Falsa 123".to_string(),
+                    "55555555".to_string(),
+                    Fecha::new(1, 1, 1994),
+                ),
+                Err(VotacionError::UsuarioNoEncontrado)
             );
+        }
 
-            set_caller::<DefaultEnvironment>(accounts.bob);
-            votacion
-                .postular_usuario(
-                    "Juan".to_string(),
-                    "Perez".to_string(),
+        #[ink::test]
+        fn test_actualizar_usuario_error_participa_en_eleccion() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_roles();
+            votacion.agregar_votante(0, accounts.django, 1).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.django);
+            assert_eq!(
+                votacion.actualizar_usuario(
+                    "Django".to_string(),
+                    "Test".to_string(),
                     "Calle Falsa 123".to_string(),
-                    "12345678".to_string(),
-                    30,
+                    "44444445".to_string(),
+                    Fecha::new(1, 1, 1994),
+                ),
+                Err(VotacionError::UsuarioParticipaEnEleccion)
+            );
+            // los datos que no son de identidad se pueden cambiar
+            votacion
+                .actualizar_usuario(
+                    "Django".to_string(),
+                    "Test".to_string(),
+                    "Calle Verdadera 456".to_string(),
+                    "44444444".to_string(),
+                    Fecha::new(1, 1, 1994),
                 )
                 .unwrap();
-            set_caller::<DefaultEnvironment>(accounts.alice);
-            votacion.aceptar_usuario(accounts.bob).unwrap();
-
-            set_caller::<DefaultEnvironment>(accounts.bob);
-            assert!(votacion.postular_votante(id_eleccion).is_ok());
-            let resultado = votacion.agregar_votante(id_eleccion, accounts.bob);
-            assert_eq!(resultado, Err(VotacionError::NoEsAdmin));
+            assert!(votacion.get_usuario(accounts.django).is_ok());
         }
 
         #[ink::test]
-        fn test_votar_votacion() {
+        fn test_darse_de_baja() {
             let accounts = default_accounts::<DefaultEnvironment>();
-            let mut votacion = Votacion::new();
-            let id_eleccion = votacion
-                .crear_eleccion(Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024))
-                .unwrap();
+            let mut votacion = default_con_roles();
+            // usuario sin aceptar
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            votacion.darse_de_baja().unwrap();
+            assert_eq!(
+                votacion.get_usuario_sin_aceptar(accounts.bob),
+                Err(VotacionError::UsuarioSinAceptarNoEncontrado)
+            );
 
-            set_block_timestamp::<DefaultEnvironment>(
-                Fecha::new(31, 12, 2023).timestamp().unwrap(),
+            // usuario aceptado con postulaciones pendientes
+            set_caller::<DefaultEnvironment>(accounts.charlie);
+            votacion.darse_de_baja().unwrap();
+            assert_eq!(
+                votacion.get_usuario(accounts.charlie),
+                Err(VotacionError::UsuarioNoEncontrado)
+            );
+            assert_eq!(votacion.get_estado_participante(0, accounts.charlie), None);
+            assert_eq!(votacion.get_estado_participante(1, accounts.charlie), None);
+            assert_eq!(
+                votacion.darse_de_baja(),
+                Err(VotacionError::UsuarioNoEncontrado)
             );
 
-            set_caller::<DefaultEnvironment>(accounts.bob);
+            // puede volver a postularse
             votacion
                 .postular_usuario(
-                    "Bob".to_string(),
-                    "Perez".to_string(),
+                    "Charlie".to_string(),
+                    "Test".to_string(),
                     "Calle Falsa 123".to_string(),
-                    "12345678".to_string(),
-                    30,
+                    "33333333".to_string(),
+                    Fecha::new(1, 1, 1994),
                 )
                 .unwrap();
-            set_caller::<DefaultEnvironment>(accounts.alice);
-            votacion.aceptar_usuario(accounts.bob).unwrap();
+        }
 
-            votacion
-                .postular_usuario(
-                    "Alice".to_string(),
-                    "Perez".to_string(),
-                    "Calle Falsa 123".to_string(),
-                    "12345679".to_string(),
-                    30,
-                )
-                .unwrap();
-            votacion.aceptar_usuario(accounts.alice).unwrap();
+        #[ink::test]
+        fn test_darse_de_baja_error_participa_en_eleccion() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_roles();
+            votacion.agregar_candidato(1, accounts.charlie).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.charlie);
+            assert_eq!(
+                votacion.darse_de_baja(),
+                Err(VotacionError::UsuarioParticipaEnEleccion)
+            );
+            assert_eq!(
+                votacion.get_estado_participante(0, accounts.charlie),
+                Some(EstadoParticipante::PostuladoCandidato)
+            );
 
-            set_caller::<DefaultEnvironment>(accounts.bob);
-            votacion.postular_votante(id_eleccion).unwrap();
-            set_caller::<DefaultEnvironment>(accounts.alice);
-            votacion.postular_candidato(id_eleccion).unwrap();
-            votacion.agregar_votante(id_eleccion, accounts.bob).unwrap();
-            votacion
-                .agregar_candidato(id_eleccion, accounts.alice)
-                .unwrap();
+            // con la elección finalizada se puede dar de baja
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2025).timestamp().unwrap());
+            votacion.darse_de_baja().unwrap();
+            assert_eq!(
+                votacion.get_estado_participante(1, accounts.charlie),
+                Some(EstadoParticipante::Candidato)
+            );
+        }
+
+        #[ink::test]
+        fn test_darse_de_baja_error_balotaje_pendiente() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_config(config_balotaje(regla_balotaje(70, None)));
+            set_caller::<DefaultEnvironment>(accounts.django);
+            votacion.votar(0, accounts.charlie).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.eve);
+            votacion.votar(0, accounts.alice).unwrap();
+
+            // la elección cerró, pero sus participantes todavia pueden pasar a la segunda vuelta
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2025).timestamp().unwrap());
+            for usuario in [accounts.bob, accounts.frank] {
+                set_caller::<DefaultEnvironment>(usuario);
+                assert_eq!(
+                    votacion.darse_de_baja(),
+                    Err(VotacionError::UsuarioParticipaEnEleccion)
+                );
+            }
 
-            set_block_timestamp::<DefaultEnvironment>(Fecha::new(15, 6, 2024).timestamp().unwrap());
-            set_caller::<DefaultEnvironment>(accounts.bob);
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            assert_eq!(
+                votacion.aplicar_balotaje(0),
+                Ok(ResultadoBalotaje::SegundaVuelta(1))
+            );
 
-            assert!(votacion.votar(id_eleccion, accounts.alice).is_ok());
-            let eleccion = votacion.get_eleccion(id_eleccion).unwrap();
-            assert!(eleccion.ya_voto(&accounts.bob));
+            // bob no pasa a la segunda vuelta y frank la integra como votante
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(votacion.darse_de_baja(), Ok(()));
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            assert_eq!(
+                votacion.darse_de_baja(),
+                Err(VotacionError::UsuarioParticipaEnEleccion)
+            );
+            assert!(votacion.get_usuario(accounts.frank).is_ok());
         }
 
         #[ink::test]
-        fn test_votar_votacion_error_usuario_no_encontrado() {
+        fn test_elecciones_usuario_quita_concluidas() {
             let accounts = default_accounts::<DefaultEnvironment>();
-            set_caller::<DefaultEnvironment>(accounts.alice);
-            let mut votacion = Votacion::new();
-            let id_eleccion = votacion
-                .crear_eleccion(Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024))
-                .unwrap();
-            set_block_timestamp::<DefaultEnvironment>(
-                Fecha::new(31, 12, 2023).timestamp().unwrap(),
+            let mut votacion = default_con_participantes();
+            assert_eq!(
+                votacion.elecciones_usuario.get(accounts.charlie),
+                Some(vec![0, 1])
             );
 
-            set_caller::<DefaultEnvironment>(accounts.bob);
+            // al postularse a una nueva elección se olvidan las que ya concluyeron
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2025).timestamp().unwrap());
             votacion
-                .postular_usuario(
-                    "Bob".to_string(),
-                    "Perez".to_string(),
-                    "Calle Falsa 123".to_string(),
-                    "12345678".to_string(),
-                    30,
+                .crear_eleccion(
+                    Fecha::new(1, 2, 2025),
+                    Fecha::new(28, 2, 2025),
+                    ConfigEleccion::default(),
                 )
                 .unwrap();
-            set_caller::<DefaultEnvironment>(accounts.alice);
-            votacion.aceptar_usuario(accounts.bob).unwrap();
-
-            set_caller::<DefaultEnvironment>(accounts.bob);
-            votacion.postular_votante(id_eleccion).unwrap();
-
-            set_block_timestamp::<DefaultEnvironment>(Fecha::new(15, 6, 2024).timestamp().unwrap());
-            set_caller::<DefaultEnvironment>(accounts.bob);
+            set_caller::<DefaultEnvironment>(accounts.charlie);
+            votacion.postular_candidato(2).unwrap();
             assert_eq!(
-                votacion.votar(id_eleccion, accounts.alice),
-                Err(VotacionError::UsuarioNoEncontrado)
+                votacion.elecciones_usuario.get(accounts.charlie),
+                Some(vec![2])
             );
+
+            // al darse de baja se retira la postulación y se olvida la lista
+            votacion.darse_de_baja().unwrap();
+            assert_eq!(votacion.get_estado_participante(2, accounts.charlie), None);
+            assert_eq!(votacion.elecciones_usuario.get(accounts.charlie), None);
         }
 
+        // tests de rechazos
         #[ink::test]
-        fn test_ya_voto_votacion() {
+        fn test_rechazar_usuario() {
             let accounts = default_accounts::<DefaultEnvironment>();
-            set_caller::<DefaultEnvironment>(accounts.frank);
-            let mut votacion = Votacion::new();
-            let id_eleccion = votacion
-                .crear_eleccion(Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024))
+            let mut votacion = default_con_roles();
+            votacion
+                .rechazar_usuario(accounts.bob, MotivoRechazo::DocumentoDuplicado)
                 .unwrap();
-            set_block_timestamp::<DefaultEnvironment>(
-                Fecha::new(31, 12, 2023).timestamp().unwrap(),
+            assert_eq!(
+                votacion.get_usuario_sin_aceptar(accounts.bob),
+                Err(VotacionError::UsuarioSinAceptarNoEncontrado)
+            );
+            assert_eq!(
+                votacion.get_usuario(accounts.bob),
+                Err(VotacionError::UsuarioNoEncontrado)
+            );
+            assert_eq!(
+                votacion.get_rechazo_usuario(accounts.bob),
+                Some(MotivoRechazo::DocumentoDuplicado)
+            );
+            assert_eq!(
+                votacion.rechazar_usuario(accounts.bob, MotivoRechazo::DatosInvalidos),
+                Err(VotacionError::UsuarioSinAceptarNoEncontrado)
             );
 
+            // vuelve a postularse
             set_caller::<DefaultEnvironment>(accounts.bob);
             votacion
                 .postular_usuario(
                     "Bob".to_string(),
-                    "Perez".to_string(),
+                    "Test".to_string(),
                     "Calle Falsa 123".to_string(),
-                    "12345678".to_string(),
-                    30,
+                    "99999999".to_string(),
+                    Fecha::new(1, 1, 1994),
                 )
                 .unwrap();
-            set_caller::<DefaultEnvironment>(accounts.frank);
+            assert_eq!(votacion.get_rechazo_usuario(accounts.bob), None);
+            set_caller::<DefaultEnvironment>(accounts.alice);
             votacion.aceptar_usuario(accounts.bob).unwrap();
+        }
 
-            set_caller::<DefaultEnvironment>(accounts.alice);
+        #[ink::test]
+        fn test_rechazar_usuario_error_sin_rol() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_roles();
             votacion
-                .postular_usuario(
-                    "Alice".to_string(),
-                    "Perez".to_string(),
-                    "Calle Falsa 123".to_string(),
-                    "12345679".to_string(),
-                    30,
-                )
+                .otorgar_rol(accounts.eve, Rol::Autoridad(0))
                 .unwrap();
-            set_caller::<DefaultEnvironment>(accounts.frank);
-            votacion.aceptar_usuario(accounts.alice).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.eve);
+            assert_eq!(
+                votacion.rechazar_usuario(accounts.bob, MotivoRechazo::DatosInvalidos),
+                Err(VotacionError::NoTieneRol)
+            );
 
-            set_caller::<DefaultEnvironment>(accounts.bob);
-            votacion.postular_votante(id_eleccion).unwrap();
             set_caller::<DefaultEnvironment>(accounts.alice);
-            votacion.postular_candidato(id_eleccion).unwrap();
-            set_caller::<DefaultEnvironment>(accounts.frank);
-            votacion.agregar_votante(id_eleccion, accounts.bob).unwrap();
             votacion
-                .agregar_candidato(id_eleccion, accounts.alice)
+                .otorgar_rol(accounts.eve, Rol::Registrador)
+                .unwrap();
+            set_caller::<DefaultEnvironment>(accounts.eve);
+            votacion
+                .rechazar_usuario(
+                    accounts.bob,
+                    MotivoRechazo::Otro("Falta el domicilio".to_string()),
+                )
                 .unwrap();
-
-            set_block_timestamp::<DefaultEnvironment>(Fecha::new(15, 6, 2024).timestamp().unwrap());
-            set_caller::<DefaultEnvironment>(accounts.bob);
-            votacion.votar(id_eleccion, accounts.alice).unwrap();
-            set_caller::<DefaultEnvironment>(accounts.frank);
-            assert!(votacion.ya_voto(id_eleccion, accounts.bob).unwrap());
         }
 
         #[ink::test]
-        fn test_ya_voto_votacion_error_usuario_no_encontrado() {
+        fn test_rechazar_candidato() {
             let accounts = default_accounts::<DefaultEnvironment>();
-            set_caller::<DefaultEnvironment>(accounts.alice);
-            let mut votacion = Votacion::new();
-            let id_eleccion = votacion
-                .crear_eleccion(Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024))
+            let mut votacion = default_con_roles();
+            votacion
+                .otorgar_rol(accounts.eve, Rol::Autoridad(0))
                 .unwrap();
-            set_block_timestamp::<DefaultEnvironment>(
-                Fecha::new(31, 12, 2023).timestamp().unwrap(),
+            set_caller::<DefaultEnvironment>(accounts.eve);
+            votacion
+                .rechazar_candidato(0, accounts.charlie, MotivoRechazo::NoCumpleRequisitos)
+                .unwrap();
+            assert_eq!(votacion.get_estado_participante(0, accounts.charlie), None);
+            assert_eq!(
+                votacion.get_rechazo_postulacion(0, accounts.charlie),
+                Some(MotivoRechazo::NoCumpleRequisitos)
+            );
+            assert_eq!(votacion.get_rechazo_postulacion(1, accounts.charlie), None);
+            assert_eq!(
+                votacion.agregar_candidato(0, accounts.charlie),
+                Err(VotacionError::UsuarioNoPostuladoCandidato)
+            );
+            assert_eq!(
+                votacion.rechazar_candidato(1, accounts.charlie, MotivoRechazo::DatosInvalidos),
+                Err(VotacionError::NoTieneRol)
             );
 
-            set_caller::<DefaultEnvironment>(accounts.bob);
+            // vuelve a postularse, como votante
+            set_caller::<DefaultEnvironment>(accounts.charlie);
+            votacion.postular_votante(0).unwrap();
+            assert_eq!(votacion.get_rechazo_postulacion(0, accounts.charlie), None);
+        }
+
+        #[ink::test]
+        fn test_rechazar_votante() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_roles();
             votacion
-                .postular_usuario(
-                    "Bob".to_string(),
-                    "Perez".to_string(),
-                    "Calle Falsa 123".to_string(),
-                    "12345678".to_string(),
-                    30,
-                )
+                .rechazar_votante(1, accounts.django, MotivoRechazo::DatosInvalidos)
                 .unwrap();
-            set_caller::<DefaultEnvironment>(accounts.alice);
-            votacion.aceptar_usuario(accounts.bob).unwrap();
+            assert_eq!(votacion.get_estado_participante(1, accounts.django), None);
+            assert_eq!(
+                votacion.get_rechazo_postulacion(1, accounts.django),
+                Some(MotivoRechazo::DatosInvalidos)
+            );
+            assert_eq!(
+                votacion.rechazar_votante(1, accounts.django, MotivoRechazo::DatosInvalidos),
+                Err(VotacionError::UsuarioNoPostuladoVotante)
+            );
+            assert_eq!(
+                votacion.rechazar_votante(0, accounts.charlie, MotivoRechazo::DatosInvalidos),
+                Err(VotacionError::UsuarioNoPostuladoVotante)
+            );
+            assert_eq!(
+                votacion.rechazar_candidato(0, accounts.django, MotivoRechazo::DatosInvalidos),
+                Err(VotacionError::UsuarioNoPostuladoCandidato)
+            );
+            assert_eq!(
+                votacion.rechazar_votante(2, accounts.django, MotivoRechazo::DatosInvalidos),
+                Err(VotacionError::EleccionNoEncontrada)
+            );
 
-            set_caller::<DefaultEnvironment>(accounts.bob);
-            votacion.postular_votante(id_eleccion).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.django);
+            votacion.postular_votante(1).unwrap();
             set_caller::<DefaultEnvironment>(accounts.alice);
-            votacion.agregar_votante(id_eleccion, accounts.bob).unwrap();
+            votacion.agregar_votante(1, accounts.django, 1).unwrap();
+        }
 
-            set_block_timestamp::<DefaultEnvironment>(Fecha::new(15, 6, 2024).timestamp().unwrap());
+        // tests del consejo
+        #[ink::test]
+        fn test_configurar_consejo() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_consejo();
             assert_eq!(
-                votacion.ya_voto(id_eleccion, accounts.alice),
-                Err(VotacionError::UsuarioNoEncontrado)
+                votacion.get_consejo(),
+                (vec![accounts.alice, accounts.bob, accounts.charlie], 2)
+            );
+            assert_eq!(
+                votacion.configurar_consejo(vec![accounts.alice], 1),
+                Err(VotacionError::ConsejoYaConfigurado)
             );
         }
 
         #[ink::test]
-        fn test_ya_voto_votacion_error_no_es_admin() {
+        fn test_configurar_consejo_error_no_admin() {
             let accounts = default_accounts::<DefaultEnvironment>();
-            set_caller::<DefaultEnvironment>(accounts.frank);
+            set_caller::<DefaultEnvironment>(accounts.alice);
             let mut votacion = Votacion::new();
-            let id_eleccion = votacion
-                .crear_eleccion(Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024))
-                .unwrap();
-            set_block_timestamp::<DefaultEnvironment>(
-                Fecha::new(31, 12, 2023).timestamp().unwrap(),
-            );
-
-            set_caller::<DefaultEnvironment>(accounts.bob);
-            votacion
-                .postular_usuario(
-                    "Bob".to_string(),
-                    "Perez".to_string(),
-                    "Calle Falsa 123".to_string(),
-                    "12345678".to_string(),
-                    30,
-                )
-                .unwrap();
-            set_caller::<DefaultEnvironment>(accounts.frank);
-            votacion.aceptar_usuario(accounts.bob).unwrap();
-
             set_caller::<DefaultEnvironment>(accounts.bob);
-            votacion.postular_votante(id_eleccion).unwrap();
-            set_caller::<DefaultEnvironment>(accounts.frank);
-            votacion.agregar_votante(id_eleccion, accounts.bob).unwrap();
-
-            set_block_timestamp::<DefaultEnvironment>(Fecha::new(15, 6, 2024).timestamp().unwrap());
-            set_caller::<DefaultEnvironment>(accounts.alice);
             assert_eq!(
-                votacion.ya_voto(id_eleccion, accounts.bob),
+                votacion.configurar_consejo(vec![accounts.bob], 1),
                 Err(VotacionError::NoEsAdmin)
             );
         }
 
         #[ink::test]
-        fn test_get_votos_candidato_votacion() {
+        fn test_configurar_consejo_error_consejo_invalido() {
             let accounts = default_accounts::<DefaultEnvironment>();
-            set_caller::<DefaultEnvironment>(accounts.frank);
+            set_caller::<DefaultEnvironment>(accounts.alice);
             let mut votacion = Votacion::new();
-            let id_eleccion = votacion
-                .crear_eleccion(Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024))
-                .unwrap();
-            set_block_timestamp::<DefaultEnvironment>(
-                Fecha::new(31, 12, 2023).timestamp().unwrap(),
+            for (miembros, umbral) in [
+                (vec![accounts.alice, accounts.bob], 0),
+                (vec![accounts.alice, accounts.bob], 3),
+                (vec![accounts.alice, accounts.bob, accounts.alice], 2),
+                (vec![], 1),
+            ] {
+                assert_eq!(
+                    votacion.configurar_consejo(miembros, umbral),
+                    Err(VotacionError::ConsejoInvalido)
+                );
+            }
+            assert_eq!(votacion.get_consejo(), (vec![], 0));
+        }
+
+        #[ink::test]
+        fn test_accion_directa_error_requiere_aprobacion_consejo() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_consejo();
+            assert_eq!(
+                votacion.set_admin(accounts.bob),
+                Err(VotacionError::RequiereAprobacionConsejo)
+            );
+            assert_eq!(
+                votacion.set_reporte(accounts.bob),
+                Err(VotacionError::RequiereAprobacionConsejo)
+            );
+            assert_eq!(
+                votacion.crear_eleccion(
+                    Fecha::new(1, 1, 2024),
+                    Fecha::new(31, 12, 2024),
+                    ConfigEleccion::default()
+                ),
+                Err(VotacionError::RequiereAprobacionConsejo)
             );
+        }
 
-            set_caller::<DefaultEnvironment>(accounts.bob);
+        #[ink::test]
+        fn test_rol_admin_con_consejo() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_consejo();
+            assert_eq!(
+                votacion.otorgar_rol(accounts.eve, Rol::Admin),
+                Err(VotacionError::RequiereAprobacionConsejo)
+            );
+            // los demás roles se siguen otorgando de forma directa
             votacion
-                .postular_usuario(
-                    "Bob".to_string(),
-                    "Perez".to_string(),
-                    "Calle Falsa 123".to_string(),
-                    "12345678".to_string(),
-                    30,
-                )
+                .otorgar_rol(accounts.eve, Rol::Registrador)
                 .unwrap();
-            set_caller::<DefaultEnvironment>(accounts.frank);
-            votacion.aceptar_usuario(accounts.bob).unwrap();
+
+            let id_propuesta = votacion
+                .proponer(Accion::OtorgarRol(accounts.eve, Rol::Admin))
+                .unwrap();
+            assert!(!votacion.tiene_rol(accounts.eve, Rol::Admin));
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(votacion.aprobar_propuesta(id_propuesta), Ok(true));
+            assert!(votacion.tiene_rol(accounts.eve, Rol::Admin));
+            assert_eq!(
+                votacion.proponer(Accion::OtorgarRol(accounts.eve, Rol::Admin)),
+                Err(VotacionError::RolYaOtorgado)
+            );
+
+            // el nuevo admin tampoco puede otorgar ni revocar el rol Admin por su cuenta
+            set_caller::<DefaultEnvironment>(accounts.eve);
+            assert_eq!(
+                votacion.otorgar_rol(accounts.frank, Rol::Admin),
+                Err(VotacionError::RequiereAprobacionConsejo)
+            );
+            assert_eq!(
+                votacion.revocar_rol(accounts.eve, Rol::Admin),
+                Err(VotacionError::RequiereAprobacionConsejo)
+            );
 
             set_caller::<DefaultEnvironment>(accounts.alice);
-            votacion
-                .postular_usuario(
-                    "Alice".to_string(),
-                    "Perez".to_string(),
-                    "Calle Falsa 123".to_string(),
-                    "12345679".to_string(),
-                    30,
-                )
+            let id_propuesta = votacion
+                .proponer(Accion::RevocarRol(accounts.eve, Rol::Admin))
                 .unwrap();
-            set_caller::<DefaultEnvironment>(accounts.frank);
-            votacion.aceptar_usuario(accounts.alice).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.charlie);
+            assert_eq!(votacion.aprobar_propuesta(id_propuesta), Ok(true));
+            assert!(!votacion.tiene_rol(accounts.eve, Rol::Admin));
+            assert!(votacion.get_cuentas_rol(Rol::Admin).is_empty());
+        }
 
+        #[ink::test]
+        fn test_cancelar_nominacion_admin_con_consejo() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_consejo();
+            let id_rol = votacion
+                .proponer(Accion::OtorgarRol(accounts.eve, Rol::Admin))
+                .unwrap();
+            let id_propuesta = votacion
+                .proponer(Accion::CambiarAdmin(accounts.django))
+                .unwrap();
             set_caller::<DefaultEnvironment>(accounts.bob);
-            votacion.postular_votante(id_eleccion).unwrap();
+            votacion.aprobar_propuesta(id_rol).unwrap();
+            votacion.aprobar_propuesta(id_propuesta).unwrap();
+
+            // ni el admin ni otro admin pueden vetar la nominación aprobada por el consejo
+            for caller in [accounts.alice, accounts.eve] {
+                set_caller::<DefaultEnvironment>(caller);
+                assert_eq!(
+                    votacion.cancelar_nominacion_admin(),
+                    Err(VotacionError::RequiereAprobacionConsejo)
+                );
+            }
+            assert_eq!(votacion.get_admin_nominado(), Some(accounts.django));
+
+            set_caller::<DefaultEnvironment>(accounts.django);
+            votacion.cancelar_nominacion_admin().unwrap();
+            assert_eq!(votacion.get_admin_nominado(), None);
+        }
+
+        #[ink::test]
+        fn test_proponer_y_aprobar_propuesta() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_consejo();
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            let id_propuesta = votacion
+                .proponer(Accion::CambiarReporte(accounts.eve))
+                .unwrap();
+            let propuesta = votacion.get_propuesta(id_propuesta).unwrap();
+            assert_eq!(propuesta.proponente, accounts.bob);
+            assert_eq!(propuesta.aprobaciones, vec![accounts.bob]);
+            assert!(!propuesta.ejecutada);
+            assert_eq!(votacion.reporte, AccountId::from([0x10; 32]));
+
+            set_caller::<DefaultEnvironment>(accounts.charlie);
+            assert_eq!(votacion.aprobar_propuesta(id_propuesta), Ok(true));
+            assert_eq!(votacion.reporte, accounts.eve);
+            assert!(votacion.get_propuesta(id_propuesta).unwrap().ejecutada);
             set_caller::<DefaultEnvironment>(accounts.alice);
-            votacion.postular_candidato(id_eleccion).unwrap();
+            assert_eq!(
+                votacion.aprobar_propuesta(id_propuesta),
+                Err(VotacionError::PropuestaYaEjecutada)
+            );
+        }
 
-            set_caller::<DefaultEnvironment>(accounts.frank);
-            votacion.agregar_votante(id_eleccion, accounts.bob).unwrap();
-            votacion
-                .agregar_candidato(id_eleccion, accounts.alice)
+        #[ink::test]
+        fn test_propuesta_crear_eleccion_y_cambiar_admin() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_consejo();
+            let id_eleccion = votacion
+                .proponer(Accion::CrearEleccion(
+                    Fecha::new(1, 1, 2024),
+                    Fecha::new(31, 12, 2024),
+                    Box::default(),
+                ))
                 .unwrap();
+            let id_admin = votacion
+                .proponer(Accion::CambiarAdmin(accounts.django))
+                .unwrap();
+            assert_eq!(id_admin, 1);
+            assert!(votacion.get_eleccion(0).is_none());
 
-            set_block_timestamp::<DefaultEnvironment>(Fecha::new(15, 6, 2024).timestamp().unwrap());
-            set_caller::<DefaultEnvironment>(accounts.bob);
-            votacion.votar(id_eleccion, accounts.alice).unwrap();
-            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2025).timestamp().unwrap());
-            set_caller::<DefaultEnvironment>(accounts.frank);
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(votacion.aprobar_propuesta(id_eleccion), Ok(true));
+            assert_eq!(votacion.aprobar_propuesta(id_admin), Ok(true));
+            assert!(votacion.get_eleccion(0).is_some());
+            assert_eq!(votacion.get_admin_nominado(), Some(accounts.django));
+            set_caller::<DefaultEnvironment>(accounts.django);
+            votacion.aceptar_admin().unwrap();
+            assert_eq!(votacion.admin, accounts.django);
+        }
+
+        #[ink::test]
+        fn test_proponer_error_accion_invalida() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_consejo();
             assert_eq!(
-                votacion
-                    .get_votos_candidato(id_eleccion, accounts.alice)
-                    .unwrap(),
-                1
+                votacion.proponer(Accion::CrearEleccion(
+                    Fecha::new(31, 12, 2024),
+                    Fecha::new(1, 1, 2024),
+                    Box::default(),
+                )),
+                Err(VotacionError::FechaInicioMayorQueFin)
+            );
+            assert_eq!(
+                votacion.proponer(Accion::CambiarConsejo(vec![accounts.bob], 2)),
+                Err(VotacionError::ConsejoInvalido)
             );
+            assert!(votacion.get_propuesta(0).is_none());
         }
 
         #[ink::test]
-        fn test_get_votos_candidato_votacion_error_no_es_admin() {
+        fn test_proponer_error_no_es_miembro_consejo() {
             let accounts = default_accounts::<DefaultEnvironment>();
-            set_caller::<DefaultEnvironment>(accounts.frank);
+            set_caller::<DefaultEnvironment>(accounts.alice);
             let mut votacion = Votacion::new();
-            let id_eleccion = votacion
-                .crear_eleccion(Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024))
-                .unwrap();
-            set_block_timestamp::<DefaultEnvironment>(
-                Fecha::new(31, 12, 2023).timestamp().unwrap(),
+            assert_eq!(
+                votacion.proponer(Accion::CambiarAdmin(accounts.bob)),
+                Err(VotacionError::ConsejoNoConfigurado)
             );
 
-            set_caller::<DefaultEnvironment>(accounts.bob);
-            votacion
-                .postular_usuario(
-                    "Bob".to_string(),
-                    "Perez".to_string(),
-                    "Calle Falsa 123".to_string(),
-                    "12345678".to_string(),
-                    30,
-                )
+            let mut votacion = default_con_consejo();
+            let id_propuesta = votacion
+                .proponer(Accion::CambiarAdmin(accounts.bob))
                 .unwrap();
-            set_caller::<DefaultEnvironment>(accounts.frank);
-            votacion.aceptar_usuario(accounts.bob).unwrap();
+            set_caller::<DefaultEnvironment>(accounts.django);
+            assert_eq!(
+                votacion.proponer(Accion::CambiarAdmin(accounts.django)),
+                Err(VotacionError::NoEsMiembroConsejo)
+            );
+            assert_eq!(
+                votacion.aprobar_propuesta(id_propuesta),
+                Err(VotacionError::NoEsMiembroConsejo)
+            );
+        }
 
-            set_caller::<DefaultEnvironment>(accounts.alice);
-            votacion
-                .postular_usuario(
-                    "Alice".to_string(),
-                    "Perez".to_string(),
-                    "Calle Falsa 123".to_string(),
-                    "12345679".to_string(),
-                    30,
-                )
+        #[ink::test]
+        fn test_aprobar_propuesta_errores() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_consejo();
+            let id_propuesta = votacion
+                .proponer(Accion::CambiarAdmin(accounts.bob))
                 .unwrap();
-            set_caller::<DefaultEnvironment>(accounts.frank);
-            votacion.aceptar_usuario(accounts.alice).unwrap();
-
-            set_caller::<DefaultEnvironment>(accounts.bob);
-            votacion.postular_votante(id_eleccion).unwrap();
-            set_caller::<DefaultEnvironment>(accounts.alice);
-            votacion.postular_candidato(id_eleccion).unwrap();
+            assert_eq!(
+                votacion.aprobar_propuesta(id_propuesta),
+                Err(VotacionError::PropuestaYaAprobada)
+            );
+            assert_eq!(
+                votacion.aprobar_propuesta(1),
+                Err(VotacionError::PropuestaNoEncontrada)
+            );
+        }
 
-            set_caller::<DefaultEnvironment>(accounts.frank);
-            votacion.agregar_votante(id_eleccion, accounts.bob).unwrap();
-            votacion
-                .agregar_candidato(id_eleccion, accounts.alice)
+        #[ink::test]
+        fn test_aprobar_propuesta_error_vencida() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_consejo();
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2024).timestamp().unwrap());
+            let id_propuesta = votacion
+                .proponer(Accion::CambiarAdmin(accounts.bob))
                 .unwrap();
-
-            set_block_timestamp::<DefaultEnvironment>(Fecha::new(15, 6, 2024).timestamp().unwrap());
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(8, 1, 2024).timestamp().unwrap());
             set_caller::<DefaultEnvironment>(accounts.bob);
-            votacion.votar(id_eleccion, accounts.alice).unwrap();
-
-            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2025).timestamp().unwrap());
-            set_caller::<DefaultEnvironment>(accounts.alice);
             assert_eq!(
-                votacion.get_votos_candidato(id_eleccion, accounts.alice),
-                Err(VotacionError::NoEsAdmin)
+                votacion.aprobar_propuesta(id_propuesta),
+                Err(VotacionError::PropuestaVencida)
             );
+            assert_eq!(votacion.admin, accounts.alice);
         }
 
         #[ink::test]
-        fn test_get_votos_candidato_votacion_error_eleccion_no_encontrada() {
+        fn test_cambiar_consejo() {
             let accounts = default_accounts::<DefaultEnvironment>();
-            let votacion = Votacion::new();
+            let mut votacion = default_con_consejo();
+            let id_pendiente = votacion
+                .proponer(Accion::CambiarAdmin(accounts.bob))
+                .unwrap();
+            let id_consejo = votacion
+                .proponer(Accion::CambiarConsejo(vec![accounts.django], 1))
+                .unwrap();
+            set_caller::<DefaultEnvironment>(accounts.charlie);
+            assert_eq!(votacion.aprobar_propuesta(id_consejo), Ok(true));
+            assert_eq!(votacion.get_consejo(), (vec![accounts.django], 1));
+
+            // las propuestas del consejo anterior vencen
+            set_caller::<DefaultEnvironment>(accounts.django);
             assert_eq!(
-                votacion.get_votos_candidato(0, accounts.bob),
-                Err(VotacionError::EleccionNoEncontrada)
+                votacion.aprobar_propuesta(id_pendiente),
+                Err(VotacionError::PropuestaVencida)
             );
+            // con una sola aprobación necesaria la propuesta se ejecuta al crearse
+            let id_propuesta = votacion
+                .proponer(Accion::CambiarAdmin(accounts.eve))
+                .unwrap();
+            assert!(votacion.get_propuesta(id_propuesta).unwrap().ejecutada);
+            assert_eq!(votacion.get_admin_nominado(), Some(accounts.eve));
+        }
+
+        fn ultimo_evento<E: ink::scale::Decode>() -> E {
+            let evento = recorded_events()
+                .last()
+                .expect("No se emitio ningun evento");
+            E::decode(&mut &evento.data[..]).expect("No se pudo decodificar el evento")
         }
 
+        // tests de eventos
         #[ink::test]
-        fn test_get_votos_candidato_votacion_error_eleccion_no_finalizada() {
-            let accounts = default_accounts::<DefaultEnvironment>();
-            set_caller::<DefaultEnvironment>(accounts.frank);
+        fn test_evento_eleccion_creada() {
             let mut votacion = Votacion::new();
             let id_eleccion = votacion
-                .crear_eleccion(Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024))
+                .crear_eleccion(
+                    Fecha::new(1, 1, 2024),
+                    Fecha::new(31, 12, 2024),
+                    ConfigEleccion::default(),
+                )
                 .unwrap();
-            set_block_timestamp::<DefaultEnvironment>(
-                Fecha::new(31, 12, 2023).timestamp().unwrap(),
+
+            let evento = recorded_events().last().unwrap();
+            // Topic de la firma del evento + id_eleccion
+            assert_eq!(evento.topics.len(), 2);
+            assert_eq!(
+                ultimo_evento::<EleccionCreada>(),
+                EleccionCreada {
+                    id_eleccion,
+                    fecha_inicio: Fecha::new(1, 1, 2024),
+                    fecha_fin: Fecha::new(31, 12, 2024),
+                    config: ConfigEleccion::default(),
+                }
             );
+        }
 
+        #[ink::test]
+        fn test_evento_usuario_postulado_y_aceptado() {
+            let accounts = default_accounts::<DefaultEnvironment>();
             set_caller::<DefaultEnvironment>(accounts.alice);
+            let mut votacion = Votacion::new();
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2023).timestamp().unwrap());
+
+            set_caller::<DefaultEnvironment>(accounts.bob);
             votacion
                 .postular_usuario(
-                    "Alice".to_string(),
+                    "Bob".to_string(),
                     "Perez".to_string(),
                     "Calle Falsa 123".to_string(),
-                    "12345679".to_string(),
-                    30,
+                    "12345678".to_string(),
+                    Fecha::new(1, 1, 1994),
                 )
                 .unwrap();
-            set_caller::<DefaultEnvironment>(accounts.frank);
-            votacion.aceptar_usuario(accounts.alice).unwrap();
-            set_caller::<DefaultEnvironment>(accounts.alice);
-            votacion.postular_candidato(id_eleccion).unwrap();
-
-            set_caller::<DefaultEnvironment>(accounts.frank);
-            votacion
-                .agregar_candidato(id_eleccion, accounts.alice)
-                .unwrap();
-
-            set_block_timestamp::<DefaultEnvironment>(Fecha::new(5, 5, 2024).timestamp().unwrap());
+            assert_eq!(
+                ultimo_evento::<UsuarioPostulado>(),
+                UsuarioPostulado {
+                    usuario: accounts.bob
+                }
+            );
 
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            votacion.aceptar_usuario(accounts.bob).unwrap();
             assert_eq!(
-                votacion.get_votos_candidato(id_eleccion, accounts.alice),
-                Err(VotacionError::EleccionNoFinalizada)
+                ultimo_evento::<UsuarioAceptado>(),
+                UsuarioAceptado {
+                    usuario: accounts.bob
+                }
             );
+            assert_eq!(recorded_events().count(), 2);
         }
 
         #[ink::test]
-        fn test_get_votos_candidato_votacion_error_usuario_no_es_candidato() {
+        fn test_eventos_postulaciones_eleccion() {
             let accounts = default_accounts::<DefaultEnvironment>();
             set_caller::<DefaultEnvironment>(accounts.frank);
             let mut votacion = Votacion::new();
             let id_eleccion = votacion
-                .crear_eleccion(Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024))
+                .crear_eleccion(
+                    Fecha::new(1, 1, 2024),
+                    Fecha::new(31, 12, 2024),
+                    ConfigEleccion::default(),
+                )
                 .unwrap();
             set_block_timestamp::<DefaultEnvironment>(
                 Fecha::new(31, 12, 2023).timestamp().unwrap(),
@@ -2793,331 +13872,298 @@ mod votacion {
                     "Perez".to_string(),
                     "Calle Falsa 123".to_string(),
                     "12345679".to_string(),
-                    30,
+                    Fecha::new(1, 1, 1994),
+                )
+                .unwrap();
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            votacion
+                .postular_usuario(
+                    "Bob".to_string(),
+                    "Perez".to_string(),
+                    "Calle Falsa 123".to_string(),
+                    "12345678".to_string(),
+                    Fecha::new(1, 1, 1994),
                 )
                 .unwrap();
             set_caller::<DefaultEnvironment>(accounts.frank);
             votacion.aceptar_usuario(accounts.alice).unwrap();
+            votacion.aceptar_usuario(accounts.bob).unwrap();
+
             set_caller::<DefaultEnvironment>(accounts.alice);
             votacion.postular_candidato(id_eleccion).unwrap();
-
-            set_caller::<DefaultEnvironment>(accounts.frank);
-            votacion
-                .agregar_candidato(id_eleccion, accounts.alice)
-                .unwrap();
-
-            set_block_timestamp::<DefaultEnvironment>(Fecha::new(5, 5, 2025).timestamp().unwrap());
-
             assert_eq!(
-                votacion.get_votos_candidato(id_eleccion, accounts.bob),
-                Err(VotacionError::UsuarioNoEsCandidato)
+                ultimo_evento::<CandidatoPostulado>(),
+                CandidatoPostulado {
+                    id_eleccion,
+                    candidato: accounts.alice
+                }
             );
-        }
-
-        #[ink::test]
-        fn test_get_iniciada_votacion() {
-            let mut votacion = Votacion::new();
-            let id_eleccion = votacion
-                .crear_eleccion(Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024))
-                .unwrap();
-            set_block_timestamp::<DefaultEnvironment>(Fecha::new(15, 6, 2024).timestamp().unwrap());
-            assert!(votacion.get_iniciada(id_eleccion).unwrap());
-        }
 
-        #[ink::test]
-        fn test_get_iniciada_votacion_error_eleccion_no_encontrada() {
-            let votacion = Votacion::new();
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            votacion.postular_votante(id_eleccion).unwrap();
             assert_eq!(
-                votacion.get_iniciada(0),
-                Err(VotacionError::EleccionNoEncontrada)
+                ultimo_evento::<VotantePostulado>(),
+                VotantePostulado {
+                    id_eleccion,
+                    votante: accounts.bob
+                }
             );
-        }
 
-        #[ink::test]
-        fn test_get_finalizada_votacion() {
-            let mut votacion = Votacion::new();
-            let id_eleccion = votacion
-                .crear_eleccion(Fecha::new(1, 1, 2024), Fecha::new(31, 12, 2024))
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            votacion
+                .agregar_candidato(id_eleccion, accounts.alice)
                 .unwrap();
-            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2023).timestamp().unwrap());
-            assert!(!votacion.get_finalizada(id_eleccion).unwrap());
-
-            set_block_timestamp::<DefaultEnvironment>(Fecha::new(15, 6, 2024).timestamp().unwrap());
-            assert!(!votacion.get_finalizada(id_eleccion).unwrap());
-
-            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2025).timestamp().unwrap());
-            assert!(votacion.get_finalizada(id_eleccion).unwrap());
-        }
-
-        #[ink::test]
-        fn test_get_finalizada_votacion_error_eleccion_no_encontrada() {
-            let votacion = Votacion::new();
             assert_eq!(
-                votacion.get_finalizada(0),
-                Err(VotacionError::EleccionNoEncontrada)
+                ultimo_evento::<CandidatoAgregado>(),
+                CandidatoAgregado {
+                    id_eleccion,
+                    candidato: accounts.alice
+                }
             );
-        }
-
-        #[ink::test]
-        fn test_reporte_registro_votantes_eleccion() {
-            let accounts = default_accounts::<DefaultEnvironment>();
-            let eleccion = Eleccion {
-                id: 0,
-                fecha_inicio: Fecha::new(1, 1, 2024),
-                fecha_fin: Fecha::new(31, 12, 2024),
-                votantes_sin_aceptar: Vec::new(),
-                votantes: vec![accounts.alice, accounts.bob, accounts.charlie],
-                candidatos_sin_aceptar: Vec::new(),
-                candidatos: Vec::new(),
-                votos: Vec::new(),
-                votantes_voto: Vec::new(),
-            };
-
-            let votantes = eleccion.reporte_registro_votantes();
-            assert_eq!(votantes.len(), 3);
-            assert_eq!(votantes.get(0).unwrap(), &accounts.alice);
-            assert_eq!(votantes.get(1).unwrap(), &accounts.bob);
-            assert_eq!(votantes.get(2).unwrap(), &accounts.charlie);
-        }
-
-        #[ink::test]
-        fn test_reporte_participacion_eleccion() {
-            let accounts = default_accounts::<DefaultEnvironment>();
-            let eleccion = Eleccion {
-                id: 0,
-                fecha_inicio: Fecha::new(1, 1, 2024),
-                fecha_fin: Fecha::new(31, 12, 2024),
-                votantes_sin_aceptar: Vec::new(),
-                votantes: vec![accounts.alice, accounts.bob, accounts.charlie],
-                candidatos_sin_aceptar: Vec::new(),
-                candidatos: Vec::new(),
-                votos: Vec::new(),
-                votantes_voto: vec![accounts.alice, accounts.bob],
-            };
 
-            let participacion = eleccion
-                .reporte_participacion(&Fecha::new(1, 1, 2025))
+            votacion
+                .agregar_votante(id_eleccion, accounts.bob, 1)
                 .unwrap();
-            assert_eq!(participacion.0, 3);
-            assert_eq!(participacion.1, 2);
-        }
-
-        #[ink::test]
-        fn test_reporte_participacion_eleccion_error_eleccion_no_finalizada() {
-            let accounts = default_accounts::<DefaultEnvironment>();
-            let eleccion = Eleccion {
-                id: 0,
-                fecha_inicio: Fecha::new(1, 1, 2024),
-                fecha_fin: Fecha::new(31, 12, 2024),
-                votantes_sin_aceptar: Vec::new(),
-                votantes: vec![accounts.alice, accounts.bob, accounts.charlie],
-                candidatos_sin_aceptar: Vec::new(),
-                candidatos: Vec::new(),
-                votos: Vec::new(),
-                votantes_voto: vec![accounts.alice, accounts.bob],
-            };
-
             assert_eq!(
-                eleccion.reporte_participacion(&Fecha::new(1, 1, 2024)),
-                Err(VotacionError::EleccionNoFinalizada)
+                ultimo_evento::<VotanteAgregado>(),
+                VotanteAgregado {
+                    id_eleccion,
+                    votante: accounts.bob
+                }
             );
-        }
-
-        #[ink::test]
-        fn test_reporte_resultado_eleccion() {
-            let accounts = default_accounts::<DefaultEnvironment>();
-            let eleccion = Eleccion {
-                id: 0,
-                fecha_inicio: Fecha::new(1, 1, 2024),
-                fecha_fin: Fecha::new(31, 12, 2024),
-                votantes_sin_aceptar: Vec::new(),
-                votantes: vec![accounts.django, accounts.frank, accounts.charlie],
-                candidatos_sin_aceptar: vec![accounts.alice, accounts.bob],
-                candidatos: vec![accounts.alice, accounts.bob],
-                votos: vec![(accounts.alice, 2), (accounts.bob, 0)],
-                votantes_voto: vec![accounts.alice],
-            };
-
-            let mut resultado = eleccion.reporte_resultado(&Fecha::new(1, 1, 2025)).unwrap();
-            assert_eq!(resultado.len(), 2);
-            assert_eq!(resultado.pop().unwrap(), (accounts.bob, 0));
-            assert_eq!(resultado.pop().unwrap(), (accounts.alice, 2));
-        }
-
-        #[ink::test]
-        fn test_reporte_resultado_eleccion_error_eleccion_no_finalizada() {
-            let accounts = default_accounts::<DefaultEnvironment>();
-            let eleccion = Eleccion {
-                id: 0,
-                fecha_inicio: Fecha::new(1, 1, 2024),
-                fecha_fin: Fecha::new(31, 12, 2024),
-                votantes_sin_aceptar: Vec::new(),
-                votantes: vec![accounts.django, accounts.frank, accounts.charlie],
-                candidatos_sin_aceptar: vec![accounts.alice, accounts.bob],
-                candidatos: vec![accounts.alice, accounts.bob],
-                votos: vec![(accounts.alice, 2), (accounts.bob, 0)],
-                votantes_voto: vec![accounts.alice],
-            };
 
+            set_block_timestamp::<DefaultEnvironment>(Fecha::new(15, 6, 2024).timestamp().unwrap());
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            votacion.votar(id_eleccion, accounts.alice).unwrap();
             assert_eq!(
-                eleccion.reporte_resultado(&Fecha::new(1, 1, 2024)),
-                Err(VotacionError::EleccionNoFinalizada)
+                ultimo_evento::<VotoEmitido>(),
+                VotoEmitido {
+                    id_eleccion,
+                    votante: accounts.bob
+                }
             );
         }
 
-        // tests de ReportMessage
         #[ink::test]
-        fn test_reporte_registro_votantes() {
-            let id_reporte = AccountId::from([0x10; 32]);
-            let votacion = default_with_data(); //Eleccion de id = 0: 2 votantes aprobados/ eleccion de id = 1: 3 votantes aprobados
+        fn test_evento_admin_cambiado() {
             let accounts = default_accounts::<DefaultEnvironment>();
-            set_caller::<DefaultEnvironment>(id_reporte);
-            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2026).timestamp().unwrap());
-            let mut reporte = votacion.reporte_registro_votantes(0).unwrap();
-            assert_eq!(reporte.len(), 2);
-            assert_eq!(reporte.pop().unwrap(), accounts.django);
-            assert_eq!(reporte.pop().unwrap(), accounts.charlie);
-        }
-
-        #[ink::test]
-        fn test_reporte_participacion_votacion() {
-            let id_reporte = AccountId::from([0x10; 32]);
-            let votacion = default_with_data(); //Eleccion de id = 0: 2 votantes aprobados/ eleccion de id = 1: 3 votantes aprobados
-            set_caller::<DefaultEnvironment>(id_reporte);
-            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2026).timestamp().unwrap());
-            let reporte = votacion.reporte_participacion(0).unwrap();
-            assert_eq!(reporte.0, 2);
-            assert_eq!(reporte.1, 2);
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            let mut votacion = Votacion::new();
+            votacion.set_admin(accounts.bob).unwrap();
+            assert_eq!(
+                ultimo_evento::<AdminNominado>(),
+                AdminNominado {
+                    admin: accounts.alice,
+                    admin_nominado: accounts.bob
+                }
+            );
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            votacion.aceptar_admin().unwrap();
+            assert_eq!(
+                ultimo_evento::<AdminCambiado>(),
+                AdminCambiado {
+                    admin_anterior: accounts.alice,
+                    admin_nuevo: accounts.bob
+                }
+            );
         }
 
         #[ink::test]
-        fn test_reporte_resultado() {
-            let id_reporte = AccountId::from([0x10; 32]);
-            let votacion = default_with_data(); //Eleccion de id = 0: Alice = 2 votos y Bob = 0 votos
+        fn test_evento_nominacion_admin_cancelada() {
             let accounts = default_accounts::<DefaultEnvironment>();
-            set_caller::<DefaultEnvironment>(id_reporte);
-            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2026).timestamp().unwrap());
-            let mut reporte = votacion.reporte_resultado(0).unwrap();
-            let primero = reporte.pop().unwrap();
-            let segundo = reporte.pop().unwrap();
-            assert_eq!(primero.0, accounts.bob);
-            assert_eq!(primero.1, 0);
-            assert_eq!(segundo.0, accounts.alice);
-            assert_eq!(segundo.1, 2);
-        }
-
-        #[ink::test]
-        fn test_reporte_registro_votantes_no_es_reporte() {
-            let id_no_reporte = AccountId::from([0x11; 32]);
-            let votacion = Votacion::new();
-            set_caller::<DefaultEnvironment>(id_no_reporte);
-            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2026).timestamp().unwrap());
-            let result = votacion.reporte_registro_votantes(0);
-            assert!(result.is_err());
-            assert_eq!(result.unwrap_err(), VotacionError::SoloReportes);
-        }
-        #[ink::test]
-        fn test_reporte_registro_votantes_eleccion_no_encontrada() {
-            let id_reporte = AccountId::from([0x10; 32]);
-            let votacion = Votacion::new();
-            set_caller::<DefaultEnvironment>(id_reporte);
-            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2026).timestamp().unwrap());
-            let result = votacion.reporte_registro_votantes(99);
-            assert!(result.is_err());
-            assert_eq!(result.unwrap_err(), VotacionError::EleccionNoEncontrada);
-        }
-
-        #[ink::test]
-        fn test_reporte_participacion_no_es_reporte() {
-            let id_no_reporte = AccountId::from([0x11; 32]);
-            let votacion = Votacion::new();
-            set_caller::<DefaultEnvironment>(id_no_reporte);
-            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2026).timestamp().unwrap());
-            let result = votacion.reporte_participacion(0);
-            assert!(result.is_err());
-            assert_eq!(result.unwrap_err(), VotacionError::SoloReportes);
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            let mut votacion = Votacion::new();
+            votacion.set_admin(accounts.bob).unwrap();
+            votacion.cancelar_nominacion_admin().unwrap();
+            assert_eq!(
+                ultimo_evento::<NominacionAdminCancelada>(),
+                NominacionAdminCancelada {
+                    admin_nominado: accounts.bob
+                }
+            );
         }
 
         #[ink::test]
-        fn test_reporte_participacion_eleccion_no_encontrada() {
-            let id_reporte = AccountId::from([0x10; 32]);
-            let votacion = Votacion::new();
-            set_caller::<DefaultEnvironment>(id_reporte);
-            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2026).timestamp().unwrap());
-            let result = votacion.reporte_participacion(99);
-            assert!(result.is_err());
-            assert_eq!(result.unwrap_err(), VotacionError::EleccionNoEncontrada);
+        fn test_eventos_actualizacion_y_baja() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_roles();
+            set_caller::<DefaultEnvironment>(accounts.charlie);
+            votacion
+                .actualizar_usuario(
+                    "Carlos".to_string(),
+                    "Test".to_string(),
+                    "Calle Falsa 123".to_string(),
+                    "33333333".to_string(),
+                    Fecha::new(1, 1, 1994),
+                )
+                .unwrap();
+            assert_eq!(
+                ultimo_evento::<UsuarioActualizado>(),
+                UsuarioActualizado {
+                    usuario: accounts.charlie,
+                    revalidar: true
+                }
+            );
+            votacion.darse_de_baja().unwrap();
+            assert_eq!(votacion.get_estado_participante(0, accounts.charlie), None);
+            assert_eq!(
+                ultimo_evento::<UsuarioDadoDeBaja>(),
+                UsuarioDadoDeBaja {
+                    usuario: accounts.charlie
+                }
+            );
         }
 
         #[ink::test]
-        fn test_reporte_resultado_no_es_reporte() {
-            let id_no_reporte = AccountId::from([0x11; 32]);
-            let votacion = Votacion::new();
-            set_caller::<DefaultEnvironment>(id_no_reporte);
-            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2026).timestamp().unwrap());
-            let result = votacion.reporte_resultado(0);
-            assert!(result.is_err());
-            assert_eq!(result.unwrap_err(), VotacionError::SoloReportes);
+        fn test_eventos_rechazos() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = default_con_roles();
+            votacion
+                .rechazar_usuario(accounts.bob, MotivoRechazo::DatosInvalidos)
+                .unwrap();
+            assert_eq!(
+                ultimo_evento::<UsuarioRechazado>(),
+                UsuarioRechazado {
+                    usuario: accounts.bob,
+                    motivo: MotivoRechazo::DatosInvalidos
+                }
+            );
+            votacion
+                .rechazar_candidato(0, accounts.charlie, MotivoRechazo::NoCumpleRequisitos)
+                .unwrap();
+            assert_eq!(
+                ultimo_evento::<CandidatoRechazado>(),
+                CandidatoRechazado {
+                    id_eleccion: 0,
+                    candidato: accounts.charlie,
+                    motivo: MotivoRechazo::NoCumpleRequisitos
+                }
+            );
+            votacion
+                .rechazar_votante(
+                    0,
+                    accounts.django,
+                    MotivoRechazo::Otro("Sin DNI".to_string()),
+                )
+                .unwrap();
+            assert_eq!(
+                ultimo_evento::<VotanteRechazado>(),
+                VotanteRechazado {
+                    id_eleccion: 0,
+                    votante: accounts.django,
+                    motivo: MotivoRechazo::Otro("Sin DNI".to_string())
+                }
+            );
         }
 
         #[ink::test]
-        fn test_reporte_resultado_eleccion_no_encontrada() {
-            let id_reporte = AccountId::from([0x10; 32]);
-            let votacion = Votacion::new();
-            set_caller::<DefaultEnvironment>(id_reporte);
-            set_block_timestamp::<DefaultEnvironment>(Fecha::new(1, 1, 2026).timestamp().unwrap());
-            let result = votacion.reporte_resultado(99);
-            assert!(result.is_err());
-            assert_eq!(result.unwrap_err(), VotacionError::EleccionNoEncontrada);
+        fn test_evento_reporte_cambiado() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut votacion = Votacion::new();
+            votacion.set_reporte(accounts.eve).unwrap();
+            assert_eq!(
+                ultimo_evento::<ReporteCambiado>(),
+                ReporteCambiado {
+                    reporte_anterior: AccountId::from([0x10; 32]),
+                    reporte_nuevo: accounts.eve
+                }
+            );
         }
 
         #[ink::test]
-        fn test_reporte_participacion_eleccion_no_finalizada() {
-            let eleccion = Eleccion::new(0, Fecha::new(1, 1, 2026), Fecha::new(1, 2, 2026));
-
-            let current_time = Fecha::new(1, 1, 2026);
-            let result = eleccion.reporte_participacion(&current_time);
-            assert!(result.is_err());
-            assert_eq!(result.unwrap_err(), VotacionError::EleccionNoFinalizada);
+        fn test_eventos_consejo() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            let mut votacion = Votacion::new();
+            votacion
+                .configurar_consejo(vec![accounts.alice, accounts.bob, accounts.charlie], 3)
+                .unwrap();
+            assert_eq!(
+                ultimo_evento::<ConsejoCambiado>(),
+                ConsejoCambiado {
+                    miembros: vec![accounts.alice, accounts.bob, accounts.charlie],
+                    umbral: 3
+                }
+            );
+            let id_propuesta = votacion
+                .proponer(Accion::CambiarAdmin(accounts.bob))
+                .unwrap();
+            assert_eq!(
+                ultimo_evento::<PropuestaCreada>(),
+                PropuestaCreada {
+                    id_propuesta,
+                    proponente: accounts.alice,
+                    accion: Accion::CambiarAdmin(accounts.bob)
+                }
+            );
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            votacion.aprobar_propuesta(id_propuesta).unwrap();
+            assert_eq!(
+                ultimo_evento::<PropuestaAprobada>(),
+                PropuestaAprobada {
+                    id_propuesta,
+                    miembro: accounts.bob
+                }
+            );
+            set_caller::<DefaultEnvironment>(accounts.charlie);
+            votacion.aprobar_propuesta(id_propuesta).unwrap();
+            assert_eq!(
+                ultimo_evento::<PropuestaEjecutada>(),
+                PropuestaEjecutada { id_propuesta }
+            );
         }
 
         #[ink::test]
-        fn test_reporte_participacion_eleccion_finalizada() {
-            let mut eleccion = Eleccion::new(0, Fecha::new(1, 1, 2026), Fecha::new(1, 2, 2026));
-            eleccion.votantes = vec![AccountId::from([0x01; 32]), AccountId::from([0x02; 32])];
-            eleccion.votantes_voto = vec![AccountId::from([0x01; 32])];
-
-            let current_time = Fecha::new(2, 2, 2026);
-            let result = eleccion.reporte_participacion(&current_time).unwrap();
-            assert_eq!(result.0, 2);
-            assert_eq!(result.1, 1);
+        fn test_eventos_roles() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            let mut votacion = Votacion::new();
+            votacion
+                .otorgar_rol(accounts.bob, Rol::Registrador)
+                .unwrap();
+            assert_eq!(
+                ultimo_evento::<RolOtorgado>(),
+                RolOtorgado {
+                    cuenta: accounts.bob,
+                    rol: Rol::Registrador
+                }
+            );
+            votacion
+                .revocar_rol(accounts.bob, Rol::Registrador)
+                .unwrap();
+            assert_eq!(
+                ultimo_evento::<RolRevocado>(),
+                RolRevocado {
+                    cuenta: accounts.bob,
+                    rol: Rol::Registrador
+                }
+            );
         }
 
         #[ink::test]
-        fn test_reporte_resultado_eleccion_no_finalizada() {
-            let eleccion = Eleccion::new(0, Fecha::new(1, 1, 2026), Fecha::new(1, 2, 2026));
-
-            let current_time = Fecha::new(1, 1, 2026);
-            let result = eleccion.reporte_resultado(&current_time);
-            assert!(result.is_err());
-            assert_eq!(result.unwrap_err(), VotacionError::EleccionNoFinalizada);
+        fn test_eventos_no_se_emiten_en_error() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            let mut votacion = Votacion::new();
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            assert!(votacion.set_admin(accounts.bob).is_err());
+            assert!(votacion
+                .crear_eleccion(
+                    Fecha::new(1, 1, 2024),
+                    Fecha::new(31, 12, 2024),
+                    ConfigEleccion::default()
+                )
+                .is_err());
+            assert!(votacion.postular_candidato(0).is_err());
+            assert_eq!(recorded_events().count(), 0);
         }
 
         #[ink::test]
-        fn test_reporte_resultado_eleccion_finalizada() {
-            let mut eleccion = Eleccion::new(0, Fecha::new(1, 1, 2026), Fecha::new(1, 2, 2026));
-            eleccion.votos = vec![
-                (AccountId::from([0x01; 32]), 2),
-                (AccountId::from([0x02; 32]), 1),
-            ];
-
-            let current_time = Fecha::new(2, 2, 2026);
-            let result = eleccion.reporte_resultado(&current_time).unwrap();
-            assert_eq!(result.len(), 2);
-            assert_eq!(result[0].0, AccountId::from([0x01; 32]));
-            assert_eq!(result[0].1, 2);
-            assert_eq!(result[1].0, AccountId::from([0x02; 32]));
-            assert_eq!(result[1].1, 1);
+        fn test_eventos_default_with_data() {
+            default_with_data();
+            // 2 elecciones, 4 usuarios postulados y aceptados, 8 postulaciones y 8 aceptaciones
+            // a elecciones y 4 votos
+            assert_eq!(recorded_events().count(), 30);
         }
     }
 }
@@ -3244,6 +14290,22 @@ mod fecha {
                     && self.day > una_fecha.day)
         }
 
+        /// Devuelve los años cumplidos desde esta fecha hasta la fecha pasada por parametro,
+        /// 0 si la fecha pasada por parametro es anterior
+        pub fn anios_hasta(&self, fecha: &Fecha) -> u32 {
+            if self.year >= fecha.year {
+                return 0;
+            }
+
+            let anios = (fecha.year - self.year) as u32;
+            // Todavía no cumplió años en el año de la fecha pasada por parametro
+            if (fecha.month, fecha.day) < (self.month, self.day) {
+                anios - 1
+            } else {
+                anios
+            }
+        }
+
         /// Devuelve el timestamp en milisegundos
         pub fn timestamp(&self) -> Option<u64> {
             if !self.es_fecha_valida() {
@@ -3269,6 +14331,17 @@ mod fecha {
     mod tests {
         use super::*;
 
+        #[test]
+        fn test_anios_hasta() {
+            let nacimiento = Fecha::new(15, 6, 2000);
+            assert_eq!(nacimiento.anios_hasta(&Fecha::new(14, 6, 2018)), 17);
+            assert_eq!(nacimiento.anios_hasta(&Fecha::new(15, 6, 2018)), 18);
+            assert_eq!(nacimiento.anios_hasta(&Fecha::new(1, 1, 2019)), 18);
+            // una fecha anterior al nacimiento no tiene años cumplidos
+            assert_eq!(nacimiento.anios_hasta(&Fecha::new(1, 1, 1999)), 0);
+            assert_eq!(nacimiento.anios_hasta(&Fecha::new(31, 12, 2000)), 0);
+        }
+
         #[test]
         fn test_es_fecha_valida() {
             // Fecha válida
@@ -3415,6 +14488,90 @@ pub mod errors {
         UsuarioEsPostuladoCandidato,
         UsuarioEsPostuladoVotante,
         SoloReportes,
+        TipoEleccionInvalido,
+        PreferenciasVacias,
+        CandidatoRepetido,
+        CantidadCargosInvalida,
+        ListaVacia,
+        ListaNoEncontrada,
+        CandidatoEnOtraLista,
+        UmbralInvalido,
+        FechaRevelacionInvalida,
+        RevelacionNoFinalizada,
+        RevelacionFinalizada,
+        VotoNoComprometido,
+        VotoYaRevelado,
+        RevelacionInvalida,
+        ReglaBalotajeInvalida,
+        EleccionSinBalotaje,
+        BalotajeYaAplicado,
+        CandidatosInsuficientes,
+        EleccionEsSegundaVuelta,
+        DelegadoNoEsVotante,
+        DelegacionCiclica,
+        VotoNoDelegado,
+        PesoInvalido,
+        NoTieneRol,
+        RolYaOtorgado,
+        RolNoOtorgado,
+        RequiereAprobacionConsejo,
+        ConsejoYaConfigurado,
+        ConsejoNoConfigurado,
+        ConsejoInvalido,
+        NoEsMiembroConsejo,
+        PropuestaNoEncontrada,
+        PropuestaYaEjecutada,
+        PropuestaVencida,
+        PropuestaYaAprobada,
+        SinAdminNominado,
+        NoEsAdminNominado,
+        UsuarioParticipaEnEleccion,
+        DniVacio,
+        DniNoNumerico,
+        DniLongitudInvalida,
+        DniDuplicado,
+        ModoPrivadoActivo,
+        ModoPrivadoInactivo,
+        FechaNacimientoInvalida,
+        ReglasElegibilidadInvalidas,
+        EdadMenorAMinima,
+        EdadMayorAMaxima,
+        EdadMenorAMinimaCandidato,
+        UsuarioNoHabilitado,
+        EleccionSinHabilitados,
+        PeriodoInscripcionInvalido,
+        InscripcionNoIniciada,
+        InscripcionFinalizada,
+        EleccionSuspendida,
+        EleccionNoSuspendida,
+        EleccionCertificada,
+        EleccionCancelada,
+        TransicionEstadoInvalida,
+        ExtensionInvalida,
+        MotivoVacio,
+        PeriodoImpugnacionInvalido,
+        EleccionSinImpugnaciones,
+        PeriodoImpugnacionFinalizado,
+        PeriodoImpugnacionNoFinalizado,
+        UsuarioNoEsParticipante,
+        ImpugnacionNoEncontrada,
+        ImpugnacionYaResuelta,
+        ImpugnacionesPendientes,
+        ResultadoImpugnado,
+        EleccionNoCertificada,
+        UsuarioRetirado,
+        CandidatoEnLista,
+        ReglaQuorumInvalida,
+        EleccionSinQuorum,
+        QuorumAlcanzado,
+        QuorumNoAlcanzado,
+        EleccionInvalida,
+        EleccionEsSegundoLlamado,
+        SegundoLlamadoVencido,
+        MotivoDemasiadoLargo,
+        ImpugnacionYaPresentada,
+        EmpateFinalistas,
+        BalotajeNoAplicado,
     }
 
     impl core::fmt::Display for VotacionError {
@@ -3457,6 +14614,207 @@ pub mod errors {
                 VotacionError::SoloReportes => {
                     write!(f, "Solo el contrato Reportes puede realizar esta operación")
                 }
+                VotacionError::TipoEleccionInvalido => {
+                    write!(f, "La operación no corresponde al tipo de la elección")
+                }
+                VotacionError::PreferenciasVacias => {
+                    write!(f, "La boleta no ordena a ningún candidato")
+                }
+                VotacionError::CandidatoRepetido => write!(f, "Candidato repetido en la boleta"),
+                VotacionError::CantidadCargosInvalida => {
+                    write!(f, "Cantidad de cargos inválida para la elección")
+                }
+                VotacionError::ListaVacia => write!(f, "La lista no tiene candidatos"),
+                VotacionError::ListaNoEncontrada => write!(f, "Lista no encontrada"),
+                VotacionError::CandidatoEnOtraLista => {
+                    write!(f, "El candidato ya integra otra lista")
+                }
+                VotacionError::UmbralInvalido => {
+                    write!(f, "El umbral debe ser un porcentaje entre 0 y 100")
+                }
+                VotacionError::FechaRevelacionInvalida => {
+                    write!(f, "La fecha de fin de revelación es inválida")
+                }
+                VotacionError::RevelacionNoFinalizada => {
+                    write!(f, "El periodo de revelación no finalizó")
+                }
+                VotacionError::RevelacionFinalizada => {
+                    write!(f, "El periodo de revelación ya finalizó")
+                }
+                VotacionError::VotoNoComprometido => write!(f, "El votante no comprometió su voto"),
+                VotacionError::VotoYaRevelado => write!(f, "El voto ya fue revelado"),
+                VotacionError::RevelacionInvalida => {
+                    write!(f, "El candidato y la sal no corresponden al compromiso")
+                }
+                VotacionError::ReglaBalotajeInvalida => write!(f, "Regla de balotaje inválida"),
+                VotacionError::EleccionSinBalotaje => {
+                    write!(f, "La elección no tiene regla de balotaje")
+                }
+                VotacionError::BalotajeYaAplicado => {
+                    write!(f, "La regla de balotaje ya se aplicó")
+                }
+                VotacionError::CandidatosInsuficientes => {
+                    write!(f, "La elección no tiene candidatos suficientes")
+                }
+                VotacionError::DelegadoNoEsVotante => write!(f, "El delegado no es votante"),
+                VotacionError::DelegacionCiclica => {
+                    write!(f, "La delegación genera un ciclo de delegaciones")
+                }
+                VotacionError::VotoNoDelegado => write!(f, "El votante no delegó su voto"),
+                VotacionError::PesoInvalido => write!(f, "Peso del votante inválido"),
+                VotacionError::NoTieneRol => write!(f, "No tiene el rol requerido"),
+                VotacionError::RolYaOtorgado => write!(f, "La cuenta ya tiene el rol"),
+                VotacionError::RolNoOtorgado => write!(f, "La cuenta no tiene el rol"),
+                VotacionError::RequiereAprobacionConsejo => {
+                    write!(f, "La acción requiere la aprobación del consejo")
+                }
+                VotacionError::ConsejoYaConfigurado => write!(f, "El consejo ya está configurado"),
+                VotacionError::ConsejoNoConfigurado => write!(f, "El consejo no está configurado"),
+                VotacionError::ConsejoInvalido => write!(f, "Consejo inválido"),
+                VotacionError::NoEsMiembroConsejo => write!(f, "No es miembro del consejo"),
+                VotacionError::PropuestaNoEncontrada => write!(f, "Propuesta no encontrada"),
+                VotacionError::PropuestaYaEjecutada => write!(f, "La propuesta ya se ejecutó"),
+                VotacionError::PropuestaVencida => write!(f, "La propuesta venció"),
+                VotacionError::PropuestaYaAprobada => {
+                    write!(f, "El miembro ya aprobó la propuesta")
+                }
+                VotacionError::SinAdminNominado => write!(f, "No hay un admin nominado"),
+                VotacionError::NoEsAdminNominado => write!(f, "No es el admin nominado"),
+                VotacionError::UsuarioParticipaEnEleccion => {
+                    write!(f, "El usuario participa de una elección sin resultado")
+                }
+                VotacionError::DniVacio => write!(f, "El dni está vacío"),
+                VotacionError::DniNoNumerico => write!(f, "El dni solo puede tener dígitos"),
+                VotacionError::DniLongitudInvalida => write!(f, "El dni debe tener 7 u 8 dígitos"),
+                VotacionError::DniDuplicado => write!(f, "El dni ya está registrado"),
+                VotacionError::ModoPrivadoActivo => {
+                    write!(f, "El contrato solo guarda el hash de los datos personales")
+                }
+                VotacionError::ModoPrivadoInactivo => {
+                    write!(f, "El contrato no está en modo privado")
+                }
+                VotacionError::FechaNacimientoInvalida => write!(f, "Fecha de nacimiento inválida"),
+                VotacionError::ReglasElegibilidadInvalidas => {
+                    write!(f, "La edad mínima de los votantes es mayor que la máxima")
+                }
+                VotacionError::EdadMenorAMinima => {
+                    write!(f, "El votante no alcanza la edad mínima de la elección")
+                }
+                VotacionError::EdadMayorAMaxima => {
+                    write!(f, "El votante supera la edad máxima de la elección")
+                }
+                VotacionError::EdadMenorAMinimaCandidato => {
+                    write!(f, "El candidato no alcanza la edad mínima de la elección")
+                }
+                VotacionError::UsuarioNoHabilitado => {
+                    write!(f, "El usuario no está habilitado para la elección")
+                }
+                VotacionError::EleccionSinHabilitados => {
+                    write!(f, "La elección no restringe a usuarios habilitados")
+                }
+                VotacionError::PeriodoInscripcionInvalido => write!(
+                    f,
+                    "El período de inscripción debe cerrar antes del inicio de la votación"
+                ),
+                VotacionError::InscripcionNoIniciada => {
+                    write!(f, "El período de inscripción todavía no comenzó")
+                }
+                VotacionError::InscripcionFinalizada => {
+                    write!(f, "El período de inscripción ya cerró")
+                }
+                VotacionError::EleccionSuspendida => write!(f, "La elección está suspendida"),
+                VotacionError::EleccionNoSuspendida => write!(f, "La elección no está suspendida"),
+                VotacionError::EleccionCertificada => {
+                    write!(f, "El resultado de la elección ya fue certificado")
+                }
+                VotacionError::EleccionCancelada => write!(f, "La elección fue cancelada"),
+                VotacionError::TransicionEstadoInvalida => {
+                    write!(f, "La elección no admite ese cambio de estado")
+                }
+                VotacionError::ExtensionInvalida => {
+                    write!(f, "La nueva fecha de fin debe ser posterior a la actual")
+                }
+                VotacionError::MotivoVacio => write!(f, "El motivo no puede estar vacío"),
+                VotacionError::PeriodoImpugnacionInvalido => write!(
+                    f,
+                    "La fecha de fin de impugnación debe ser posterior al fin de la votación"
+                ),
+                VotacionError::EleccionSinImpugnaciones => {
+                    write!(f, "La elección no admite impugnaciones")
+                }
+                VotacionError::PeriodoImpugnacionFinalizado => {
+                    write!(f, "El período de impugnación ya terminó")
+                }
+                VotacionError::PeriodoImpugnacionNoFinalizado => {
+                    write!(f, "El período de impugnación todavía no terminó")
+                }
+                VotacionError::UsuarioNoEsParticipante => {
+                    write!(f, "Usuario no es votante ni candidato de la elección")
+                }
+                VotacionError::ImpugnacionNoEncontrada => write!(f, "Impugnación no encontrada"),
+                VotacionError::ImpugnacionYaResuelta => {
+                    write!(f, "La impugnación ya fue resuelta")
+                }
+                VotacionError::ImpugnacionesPendientes => {
+                    write!(f, "La elección tiene impugnaciones sin resolver")
+                }
+                VotacionError::ResultadoImpugnado => write!(
+                    f,
+                    "El resultado tiene impugnaciones aceptadas, la elección debe cancelarse"
+                ),
+                VotacionError::EleccionNoCertificada => {
+                    write!(f, "El resultado de la elección todavía no fue certificado")
+                }
+                VotacionError::UsuarioRetirado => {
+                    write!(f, "El usuario se retiró de la elección")
+                }
+                VotacionError::CandidatoEnLista => {
+                    write!(f, "El candidato integra una lista y no puede retirarse")
+                }
+                VotacionError::ReglaQuorumInvalida => write!(f, "La regla de quórum no es válida"),
+                VotacionError::EleccionSinQuorum => {
+                    write!(f, "La elección no tiene regla de quórum")
+                }
+                VotacionError::QuorumAlcanzado => write!(f, "La elección alcanzó el quórum"),
+                VotacionError::QuorumNoAlcanzado => {
+                    write!(f, "La elección no alcanzó el quórum")
+                }
+                VotacionError::EleccionInvalida => {
+                    write!(
+                        f,
+                        "La elección no alcanzó el quórum y su resultado no es válido"
+                    )
+                }
+                VotacionError::EleccionEsSegundoLlamado => write!(
+                    f,
+                    "Los participantes de un segundo llamado no se pueden modificar"
+                ),
+                VotacionError::SegundoLlamadoVencido => {
+                    write!(f, "Ya pasó la fecha de inicio del segundo llamado")
+                }
+                VotacionError::MotivoDemasiadoLargo => {
+                    write!(f, "El motivo supera la longitud máxima")
+                }
+                VotacionError::ImpugnacionYaPresentada => write!(
+                    f,
+                    "El usuario ya tiene una impugnación pendiente en la elección"
+                ),
+                VotacionError::BalotajeNoAplicado => {
+                    write!(
+                        f,
+                        "La regla de balotaje de la elección todavia no se aplicó"
+                    )
+                }
+                VotacionError::EmpateFinalistas => write!(
+                    f,
+                    "Un empate no permite elegir a los finalistas de la segunda vuelta"
+                ),
+                VotacionError::EleccionEsSegundaVuelta => {
+                    write!(
+                        f,
+                        "Los participantes de una segunda vuelta no se pueden modificar"
+                    )
+                }
             }
         }
     }